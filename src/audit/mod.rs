@@ -1,4 +1,6 @@
 use crate::council::types::CouncilAuditData;
+use crate::decomposition::PreflightDecision;
+use crate::review::FixItem;
 use crate::signals::IterationSignals;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -58,6 +60,12 @@ pub struct PhaseAudit {
     /// Sub-phase audits for phases that spawned sub-phases.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub sub_phase_audits: Vec<SubPhaseAudit>,
+    /// Review fix items and their final status (fixed, disputed, deferred, pending).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub review_fixes: Vec<FixItem>,
+    /// Pre-flight size estimate and decomposition decision, when enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preflight: Option<PreflightDecision>,
 }
 
 /// Audit record for a sub-phase.
@@ -116,6 +124,8 @@ impl PhaseAudit {
             compaction_events: Vec::new(),
             parent_phase: None,
             sub_phase_audits: Vec::new(),
+            review_fixes: Vec::new(),
+            preflight: None,
         }
     }
 
@@ -138,6 +148,8 @@ impl PhaseAudit {
             compaction_events: Vec::new(),
             parent_phase: Some(parent_phase.to_string()),
             sub_phase_audits: Vec::new(),
+            review_fixes: Vec::new(),
+            preflight: None,
         }
     }
 
@@ -169,6 +181,11 @@ impl PhaseAudit {
         });
     }
 
+    /// Record the review fix items tracked for this phase.
+    pub fn record_review_fixes(&mut self, items: Vec<FixItem>) {
+        self.review_fixes = items;
+    }
+
     /// Add an iteration audit entry to this phase.
     pub fn add_iteration(&mut self, iteration: IterationAudit) {
         self.iterations.push(iteration);
@@ -507,6 +524,10 @@ mod tests {
             merge_attempts: 2,
             chairman_decision: "merged_patch".to_string(),
             winning_worker: None,
+            score_matrix: Vec::new(),
+            rankings: Vec::new(),
+            hybrid_selections: Vec::new(),
+            worker_runs: Vec::new(),
         }));
 
         let json = serde_json::to_string(&iteration).unwrap();
@@ -559,4 +580,27 @@ mod tests {
         assert!(iteration.council_data.is_none());
         assert_eq!(iteration.iteration, 1);
     }
+
+    #[test]
+    fn test_phase_audit_review_fixes_serialization() {
+        use crate::review::{FindingSeverity, FixStatus, ReviewFinding};
+
+        let mut audit = PhaseAudit::new("05", "OAuth", "DONE");
+        let json = serde_json::to_string(&audit).unwrap();
+        assert!(!json.contains("review_fixes"));
+
+        audit.record_review_fixes(vec![FixItem {
+            id: "F1".to_string(),
+            specialist: "security-sentinel".to_string(),
+            finding: ReviewFinding::new(FindingSeverity::Error, "src/auth.rs", "Token logged"),
+            status: FixStatus::Disputed,
+            attempts: 1,
+            note: Some("Only the token id is logged".to_string()),
+        }]);
+
+        let json = serde_json::to_string(&audit).unwrap();
+        assert!(json.contains("\"status\":\"disputed\""));
+        let parsed: PhaseAudit = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.review_fixes, audit.review_fixes);
+    }
 }
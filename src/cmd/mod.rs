@@ -5,11 +5,13 @@
 //! | Module          | Commands handled                                   |
 //! |-----------------|-----------------------------------------------------|
 //! | `run`           | `Run`, `Phase`                                     |
-//! | `phase`         | `List`, `Status`, `Reset`, `Audit`                 |
+//! | `phase`         | `List`, `Status`, `Graph`, `Reset`, `Audit`        |
 //! | `project`       | `Init`, `Interview`, `Generate`, `Implement`       |
 //! | `patterns`      | `Learn`, `Patterns`                                |
 //! | `config`        | `Config`                                           |
 //! | `skills`        | `Skills`                                           |
+//! | `reviewers`     | `Reviewers`                                        |
+//! | `review`        | `Review`                                           |
 //! | `compact`       | `Compact`                                          |
 //! | `swarm`         | `Swarm`                                            |
 //! | `factory`       | `Factory`                                          |
@@ -23,6 +25,8 @@ pub mod factory;
 pub mod patterns;
 pub mod phase;
 pub mod project;
+pub mod review;
+pub mod reviewers;
 pub mod run;
 pub mod skills;
 pub mod swarm;
@@ -33,9 +37,11 @@ pub use compact::cmd_compact;
 pub use config::cmd_config;
 pub use factory::cmd_factory;
 pub use patterns::{cmd_learn, cmd_patterns};
-pub use phase::{cmd_audit, cmd_list, cmd_reset, cmd_status};
+pub use phase::{cmd_audit, cmd_graph, cmd_list, cmd_reset, cmd_status};
 pub use project::{cmd_generate, cmd_implement, cmd_init, cmd_interview};
+pub use review::{cmd_review, cmd_review_diff};
+pub use reviewers::cmd_reviewers;
 pub use run::{run_orchestrator, run_single_phase};
 pub use skills::cmd_skills;
-pub use swarm::{cmd_swarm, cmd_swarm_abort, cmd_swarm_status};
+pub use swarm::{cmd_swarm, cmd_swarm_control, cmd_swarm_status};
 pub use update::cmd_update;
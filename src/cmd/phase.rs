@@ -198,6 +198,30 @@ pub fn cmd_status(project_dir: &Path) -> Result<()> {
     Ok(())
 }
 
+pub fn cmd_graph(project_dir: &Path, format: forge::dag::GraphFormat) -> Result<()> {
+    use forge::dag::{DagGraph, PhaseHistory};
+    use forge::init::{get_forge_dir, has_phases};
+    use forge::orchestrator::StateManager;
+    use forge::phase::PhasesFile;
+
+    if !has_phases(project_dir) {
+        anyhow::bail!(
+            "No phases found. Run 'forge generate' first to create phases from your spec."
+        );
+    }
+
+    let forge_dir = get_forge_dir(project_dir);
+    let pf = PhasesFile::load(&forge_dir.join("phases.json"))?;
+    let entries = StateManager::new(forge_dir.join("state")).get_entries()?;
+
+    let graph = DagGraph::build(&pf.phases, &PhaseHistory::from_entries(&entries))?;
+    print!("{}", graph.render(format)?);
+    if format == forge::dag::GraphFormat::Json {
+        println!();
+    }
+    Ok(())
+}
+
 pub fn cmd_reset(project_dir: &Path, cli: &Cli, force: bool) -> Result<()> {
     use dialoguer::Confirm;
     use forge::config::Config;
@@ -0,0 +1,543 @@
+//! Review commands — `forge review`.
+//!
+//! Without a subcommand, runs the configured review specialists against a
+//! diff and fails when a gating review fails; subcommands work with the runs
+//! recorded in `.forge/reviews/` and the arbiter decision history.
+
+use anyhow::{Context, Result};
+
+use super::super::{BaselineCommands, Cli, ReviewArgs, ReviewCommands};
+
+/// Pick the diff to review from the command-line arguments.
+pub fn diff_source(args: &ReviewArgs) -> forge::review::DiffSource {
+    use forge::review::DiffSource;
+
+    if let Some(patch) = &args.pr {
+        DiffSource::Patch(patch.clone())
+    } else if args.staged {
+        DiffSource::Staged
+    } else if let Some(range) = &args.range {
+        DiffSource::Range(range.clone())
+    } else {
+        DiffSource::WorkingTree
+    }
+}
+
+pub async fn cmd_review_diff(
+    cli: &Cli,
+    project_dir: &std::path::Path,
+    args: &ReviewArgs,
+) -> Result<()> {
+    use forge::forge_config::ForgeToml;
+    use forge::init::get_forge_dir;
+    use forge::review::{
+        ArbiterConfig, DispatcherConfig, PhaseReviewConfig, ReviewDispatcher, ReviewSpecialist,
+        ReviewerRegistry,
+    };
+
+    let forge_dir = get_forge_dir(project_dir);
+    let forge_toml = ForgeToml::load_or_default(&forge_dir)
+        .context("Failed to load forge.toml configuration")?;
+    let registry =
+        ReviewerRegistry::load(&forge_dir).context("Failed to load reviewer definitions")?;
+
+    let configured = forge_toml.reviews.resolve_specialists(&registry);
+    let configs = match &args.specialists {
+        Some(names) => select_specialists(
+            configured,
+            &super::swarm::expand_review_specialists(names),
+            &registry,
+        ),
+        None => configured,
+    };
+    let specialists: Vec<ReviewSpecialist> = configs
+        .into_iter()
+        .map(|config| {
+            let specialist = ReviewSpecialist::new(config.specialist_type, config.gate);
+            if config.focus_areas.is_empty() {
+                specialist
+            } else {
+                specialist.with_focus_areas(config.focus_areas)
+            }
+        })
+        .collect();
+    if specialists.is_empty() {
+        anyhow::bail!(
+            "No review specialists configured. Add [[reviews.specialists]] to forge.toml or pass --specialists."
+        );
+    }
+
+    let source = diff_source(args);
+    let diff = source.load(project_dir)?;
+    if diff.is_empty() {
+        println!("No changes to review in {}.", diff.description);
+        return Ok(());
+    }
+    let base_revision = source.base(project_dir)?;
+    // Tool reviewers and inline suppressions read the reviewed files
+    let checkout = source.checkout(project_dir)?;
+    let review_dir = checkout
+        .as_ref()
+        .map_or(project_dir, |checkout| checkout.path());
+
+    println!(
+        "Reviewing {} ({} file(s)) with {} specialist(s)...",
+        diff.description,
+        diff.files.len(),
+        specialists.len()
+    );
+
+    let arbiter = ArbiterConfig::default()
+        .with_mode(forge_toml.reviews.resolution_mode())
+        .with_claude_cmd(&forge_toml.claude_cmd())
+        .with_skip_permissions(forge_toml.skip_permissions())
+        .with_verbose(cli.verbose);
+
+    let dispatcher_config = DispatcherConfig::default()
+        .with_claude_cmd(&forge_toml.claude_cmd())
+        .with_working_dir(review_dir.to_path_buf())
+        .with_parallel(forge_toml.reviews.parallel)
+        .with_verbose(cli.verbose)
+        .with_skip_permissions(forge_toml.skip_permissions())
+        .with_arbiter(arbiter)
+        .with_arbiter_enabled(args.arbiter)
+        .with_forge_dir(forge_dir.clone())
+        .with_export_formats(forge_toml.reviews.export.clone());
+
+    let review_config = PhaseReviewConfig::new("review", &diff.description)
+        .add_specialists(specialists)
+        .with_files_changed(diff.files.clone())
+        .with_base_revision(&base_revision)
+        .with_additional_context(&diff.review_context());
+
+    let result = ReviewDispatcher::new(dispatcher_config)
+        .dispatch(review_config)
+        .await?;
+
+    println!();
+    print!("{}", result.aggregation);
+    if let Some(arbiter_result) = &result.arbiter_result {
+        println!();
+        println!("Arbiter: {}", arbiter_result.decision.summary());
+    }
+    println!();
+
+    if !result.can_proceed() {
+        anyhow::bail!(
+            "Gating review failed: {}",
+            result.aggregation.failed_specialists().join(", ")
+        );
+    }
+
+    println!("Review passed.");
+    Ok(())
+}
+
+/// Narrow the configured specialists to `names`.
+///
+/// A configured specialist keeps its gating and focus areas; one that is
+/// not configured gates as its reviewer definition or type does by default.
+fn select_specialists(
+    configured: Vec<forge::phase::PhaseSpecialistConfig>,
+    names: &[String],
+    registry: &forge::review::ReviewerRegistry,
+) -> Vec<forge::phase::PhaseSpecialistConfig> {
+    use forge::review::SpecialistType;
+    use std::str::FromStr;
+
+    names
+        .iter()
+        .map(|name| {
+            let specialist_type = SpecialistType::from_str(name)
+                .unwrap_or_else(|_| SpecialistType::Custom(name.clone()));
+            configured
+                .iter()
+                .find(|config| config.specialist_type == specialist_type)
+                .cloned()
+                .unwrap_or_else(|| forge::phase::PhaseSpecialistConfig {
+                    gate: registry
+                        .get(&specialist_type.agent_name())
+                        .and_then(|definition| definition.gate)
+                        .unwrap_or_else(|| specialist_type.default_gating()),
+                    specialist_type,
+                    focus_areas: Vec::new(),
+                })
+        })
+        .collect()
+}
+
+pub fn cmd_review(project_dir: &std::path::Path, command: ReviewCommands) -> Result<()> {
+    use forge::init::get_forge_dir;
+    use forge::review::{ReviewRecord, ReviewStore};
+
+    let forge_dir = get_forge_dir(project_dir);
+    let store = ReviewStore::new(&forge_dir);
+
+    match command {
+        ReviewCommands::Export {
+            format,
+            phase,
+            latest,
+            output,
+        } => {
+            let records = if latest {
+                store.load_latest()?
+            } else {
+                store.load_all()?
+            };
+            let records: Vec<ReviewRecord> = records
+                .into_iter()
+                .filter(|record| {
+                    phase
+                        .as_deref()
+                        .is_none_or(|phase| record.aggregation.phase == phase)
+                })
+                .collect();
+
+            if records.is_empty() {
+                eprintln!(
+                    "No review runs recorded in {}",
+                    ReviewStore::reviews_dir(&forge_dir).display()
+                );
+            }
+
+            let rendered = format.render(&records)?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, rendered)
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    eprintln!(
+                        "Exported {} review run(s) to {}",
+                        records.len(),
+                        path.display()
+                    );
+                }
+                None => print!("{}", rendered),
+            }
+        }
+        ReviewCommands::Baseline { command } => cmd_baseline(&forge_dir, &store, command)?,
+        ReviewCommands::History {
+            phase,
+            limit,
+            overrides,
+        } => cmd_history(&forge_dir, phase.as_deref(), limit, overrides)?,
+        ReviewCommands::Override {
+            id,
+            verdict,
+            reason,
+        } => {
+            let entry = forge::review::ArbiterHistory::new(&forge_dir).record_override(
+                &id,
+                verdict,
+                reason.as_deref(),
+            )?;
+            println!(
+                "Recorded override of decision {} (phase {}): {} -> {}",
+                entry.id, entry.input.phase_id, entry.result.decision.decision, verdict
+            );
+            println!("Future arbiter decisions will see this as an example.");
+        }
+        ReviewCommands::Pending => cmd_pending(&forge_dir)?,
+        ReviewCommands::Resolve {
+            id,
+            fix,
+            accept,
+            dismiss,
+            fail,
+            note,
+        } => {
+            use forge::review::{FileReviewQueue, FindingAction, ManualResolution};
+
+            let mut resolution = if fail {
+                ManualResolution::fail(note.as_deref())
+            } else {
+                ManualResolution {
+                    note,
+                    ..Default::default()
+                }
+            };
+            for (ids, action) in [
+                (fix, FindingAction::Fix),
+                (accept, FindingAction::Accept),
+                (dismiss, FindingAction::Dismiss),
+            ] {
+                for finding in ids {
+                    resolution.set(finding.trim(), action, None);
+                }
+            }
+
+            FileReviewQueue::new(&forge_dir).decide(&id, &resolution)?;
+            println!("Recorded decision for review {}.", id);
+        }
+    }
+
+    Ok(())
+}
+
+fn cmd_history(
+    forge_dir: &std::path::Path,
+    phase: Option<&str>,
+    limit: usize,
+    overrides_only: bool,
+) -> Result<()> {
+    use forge::review::ArbiterHistory;
+
+    let entries: Vec<_> = ArbiterHistory::new(forge_dir)
+        .load()?
+        .into_iter()
+        .filter(|entry| phase.is_none_or(|phase| entry.input.phase_id == phase))
+        .filter(|entry| !overrides_only || entry.human_override.is_some())
+        .collect();
+
+    println!();
+    println!("Arbiter History");
+    println!("===============");
+    println!();
+    if entries.is_empty() {
+        println!(
+            "No arbiter decisions recorded in {}",
+            ArbiterHistory::path(forge_dir).display()
+        );
+        println!();
+        return Ok(());
+    }
+
+    let skip = entries.len().saturating_sub(limit);
+    for entry in &entries[skip..] {
+        let decision = &entry.result.decision;
+        println!(
+            "  {}  {}  phase {} {}",
+            entry.id,
+            entry.recorded_at.format("%Y-%m-%d %H:%M"),
+            entry.input.phase_id,
+            entry.input.phase_name
+        );
+        println!(
+            "      {} ({:.0}% confidence, {}): {}",
+            decision.decision,
+            decision.confidence * 100.0,
+            entry.result.source,
+            decision.reasoning
+        );
+        println!(
+            "      {} blocking finding(s) from {}",
+            entry.input.blocking_findings.len(),
+            entry.input.failed_specialists.join(", ")
+        );
+        if let Some(human) = &entry.human_override {
+            print!("      Overridden: {}", human.verdict);
+            if let Some(reason) = &human.reason {
+                print!(" - {}", reason);
+            }
+            println!();
+        }
+        println!();
+    }
+    println!(
+        "{} of {} decision(s) shown. Override one with 'forge review override <id> --verdict <verdict>'.",
+        entries.len() - skip,
+        entries.len()
+    );
+    println!();
+
+    Ok(())
+}
+
+fn cmd_pending(forge_dir: &std::path::Path) -> Result<()> {
+    use forge::review::FileReviewQueue;
+
+    let reviews = FileReviewQueue::new(forge_dir).list()?;
+
+    println!();
+    println!("Pending Reviews");
+    println!("===============");
+    println!();
+    if reviews.is_empty() {
+        println!("No reviews are waiting for a decision.");
+        println!();
+        return Ok(());
+    }
+
+    for review in &reviews {
+        println!(
+            "  {}  {}  phase {} {}",
+            review.id,
+            review.created_at.format("%Y-%m-%d %H:%M"),
+            review.phase,
+            review.phase_name
+        );
+        for item in &review.items {
+            println!("      {} {} ({})", item.id, item.label(), item.specialist);
+        }
+        println!();
+    }
+    println!(
+        "Decide with 'forge review resolve <id> [--fix F1,..] [--accept ..] [--dismiss ..] [--fail]'."
+    );
+    println!();
+
+    Ok(())
+}
+
+fn cmd_baseline(
+    forge_dir: &std::path::Path,
+    store: &forge::review::ReviewStore,
+    command: BaselineCommands,
+) -> Result<()> {
+    use forge::review::{ReviewBaseline, SpecialistType};
+    use std::str::FromStr;
+
+    let mut baseline = ReviewBaseline::load(forge_dir)?;
+
+    match command {
+        BaselineCommands::Update { phase, specialist } => {
+            let specialist = specialist.map(|name| {
+                SpecialistType::from_str(&name)
+                    .map(|specialist| specialist.agent_name())
+                    .unwrap_or(name)
+            });
+
+            let mut added = 0;
+            for record in store.load_latest()? {
+                if phase
+                    .as_deref()
+                    .is_some_and(|phase| record.aggregation.phase != phase)
+                {
+                    continue;
+                }
+                for report in &record.aggregation.reports {
+                    if specialist
+                        .as_deref()
+                        .is_some_and(|name| report.reviewer != name)
+                    {
+                        continue;
+                    }
+                    for finding in &report.findings {
+                        if baseline.add(&report.reviewer, finding) {
+                            added += 1;
+                        }
+                    }
+                }
+            }
+
+            let path = baseline.save(forge_dir)?;
+            println!(
+                "Accepted {} new finding(s); {} in baseline ({})",
+                added,
+                baseline.len(),
+                path.display()
+            );
+        }
+        BaselineCommands::Show => {
+            println!();
+            println!("Review Baseline");
+            println!("===============");
+            println!();
+            if baseline.is_empty() {
+                println!(
+                    "No accepted findings in {}",
+                    ReviewBaseline::path(forge_dir).display()
+                );
+                println!();
+                println!(
+                    "Run 'forge review baseline update' after a review run to accept its findings."
+                );
+                println!();
+                return Ok(());
+            }
+            for entry in &baseline.entries {
+                let location = match entry.line {
+                    Some(line) => format!("{}:{}", entry.file, line),
+                    None => entry.file.clone(),
+                };
+                println!(
+                    "  {} [{}] {}: {}",
+                    entry.fingerprint, entry.specialist, location, entry.issue
+                );
+            }
+            println!();
+            println!("{} accepted finding(s)", baseline.len());
+            println!();
+        }
+        BaselineCommands::Clear => {
+            let count = baseline.len();
+            ReviewBaseline::default().save(forge_dir)?;
+            println!("Removed {} accepted finding(s)", count);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use forge::review::DiffSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn diff_source_defaults_to_working_tree() {
+        assert_eq!(diff_source(&ReviewArgs::default()), DiffSource::WorkingTree);
+    }
+
+    #[test]
+    fn diff_source_prefers_explicit_selection() {
+        let range = ReviewArgs {
+            range: Some("origin/main..HEAD".to_string()),
+            ..ReviewArgs::default()
+        };
+        let staged = ReviewArgs {
+            staged: true,
+            ..ReviewArgs::default()
+        };
+        let patch = ReviewArgs {
+            pr: Some(PathBuf::from("pr.patch")),
+            ..ReviewArgs::default()
+        };
+
+        assert_eq!(
+            diff_source(&range),
+            DiffSource::Range("origin/main..HEAD".to_string())
+        );
+        assert_eq!(diff_source(&staged), DiffSource::Staged);
+        assert_eq!(
+            diff_source(&patch),
+            DiffSource::Patch(PathBuf::from("pr.patch"))
+        );
+    }
+
+    #[test]
+    fn select_specialists_keeps_configured_gating() {
+        use forge::phase::PhaseSpecialistConfig;
+        use forge::review::{ReviewerRegistry, SpecialistType};
+
+        let configured = vec![
+            PhaseSpecialistConfig {
+                specialist_type: SpecialistType::SecuritySentinel,
+                gate: false,
+                focus_areas: vec!["auth".to_string()],
+            },
+            PhaseSpecialistConfig {
+                specialist_type: SpecialistType::SimplicityReviewer,
+                gate: true,
+                focus_areas: Vec::new(),
+            },
+        ];
+        let names = vec!["security".to_string(), "architecture".to_string()];
+
+        let selected = super::select_specialists(configured, &names, &ReviewerRegistry::default());
+
+        assert_eq!(selected.len(), 2);
+        assert_eq!(
+            selected[0].specialist_type,
+            SpecialistType::SecuritySentinel
+        );
+        assert!(!selected[0].gate);
+        assert_eq!(selected[0].focus_areas, vec!["auth"]);
+        assert_eq!(
+            selected[1].specialist_type,
+            SpecialistType::ArchitectureStrategist
+        );
+        assert!(selected[1].gate);
+    }
+}
@@ -0,0 +1,124 @@
+//! Custom reviewer commands — `forge reviewers`.
+
+use anyhow::Result;
+
+use super::super::ReviewersCommands;
+
+pub fn cmd_reviewers(
+    project_dir: &std::path::Path,
+    command: Option<ReviewersCommands>,
+) -> Result<()> {
+    use forge::init::get_forge_dir;
+    use forge::review::{ReviewerRegistry, SpecialistType};
+
+    let forge_dir = get_forge_dir(project_dir);
+    let reviewers_dir = ReviewerRegistry::reviewers_dir(&forge_dir);
+    let registry = ReviewerRegistry::load(&forge_dir)?;
+
+    match command {
+        None | Some(ReviewersCommands::List) => {
+            println!();
+            println!("Custom Reviewers");
+            println!("================");
+            println!();
+
+            if registry.is_empty() {
+                println!(
+                    "No reviewer definitions found in {}",
+                    reviewers_dir.display()
+                );
+                println!();
+                println!("Add a <name>.toml or <name>.md file there and reference it from");
+                println!("[[reviews.specialists]] with type = \"<name>\".");
+                println!();
+                return Ok(());
+            }
+
+            println!("Reviewers directory: {}", reviewers_dir.display());
+            println!();
+            let mut count = 0;
+            for definition in registry.definitions() {
+                count += 1;
+                let gate = match (definition.gate, definition.is_tool()) {
+                    (Some(true), false) => "gating",
+                    (Some(true), true) => "gating, tool",
+                    (Some(false), false) => "advisory",
+                    (Some(false), true) => "advisory, tool",
+                    (None, false) => "advisory (default)",
+                    (None, true) => "advisory (default), tool",
+                };
+                match &definition.description {
+                    Some(description) => {
+                        println!("  - {} [{}] {}", definition.name, gate, description)
+                    }
+                    None => println!("  - {} [{}]", definition.name, gate),
+                }
+            }
+            println!();
+            println!("{} reviewer(s) available", count);
+            println!();
+            println!("Use 'forge reviewers show <name>' to view a reviewer definition.");
+            println!();
+        }
+        Some(ReviewersCommands::Show { name }) => {
+            let agent_name = SpecialistType::Custom(name.clone()).agent_name();
+            match registry.get(&agent_name) {
+                Some(definition) => {
+                    println!();
+                    println!("Reviewer: {}", definition.name);
+                    println!("Path:     {}", definition.source.display());
+                    if let Some(description) = &definition.description {
+                        println!("About:    {}", description);
+                    }
+                    println!(
+                        "Gate:     {}",
+                        definition
+                            .gate
+                            .map_or("unset (advisory)".to_string(), |gate| gate.to_string())
+                    );
+                    if definition.is_tool() {
+                        println!(
+                            "Command:  {}",
+                            definition.command.as_deref().unwrap_or_default()
+                        );
+                        println!("Format:   {:?}", definition.format);
+                    } else {
+                        println!(
+                            "Model:    {}",
+                            definition.model.as_deref().unwrap_or("default")
+                        );
+                    }
+                    if !definition.applies_to.is_empty() {
+                        println!("Applies:  {}", definition.applies_to.join(", "));
+                    }
+                    if !definition.focus_areas.is_empty() {
+                        println!("Focus:");
+                        for area in &definition.focus_areas {
+                            println!("  - {}", area);
+                        }
+                    }
+                    if !definition.severity_map.is_empty() {
+                        println!("Severity map:");
+                        for (label, severity) in &definition.severity_map {
+                            println!("  {} -> {}", label, severity);
+                        }
+                    }
+                    println!();
+                    if !definition.is_tool() {
+                        println!("--- Prompt ---");
+                        println!("{}", definition.prompt.trim_end());
+                        println!("--- End ---");
+                        println!();
+                    }
+                }
+                None => {
+                    println!("Reviewer '{}' not found.", name);
+                    println!();
+                    println!("Run 'forge reviewers' to see available reviewers.");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
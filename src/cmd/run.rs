@@ -35,21 +35,23 @@ pub async fn run_orchestrator(
         CompactionManager, DEFAULT_MODEL_WINDOW_CHARS, extract_output_summary,
     };
     use forge::config::Config;
+    use forge::decomposition::SizeEstimator;
     use forge::forge_config::{ForgeToml, PermissionMode};
     use forge::gates::{
         ApprovalGate, AutonomousGateStrategy, GateDecision, IterationDecision, ProgressTracker,
     };
     use forge::hooks::{HookAction, HookManager};
     use forge::init::get_forge_dir;
+    use forge::orchestrator::retry::run_with_retry;
     use forge::orchestrator::{
-        ClaudeRunner, IterationFeedback, PromptContext, ReviewIntegration, ReviewIntegrationConfig,
-        StateManager,
+        ClaudeRunner, FailureClass, IterationFeedback, PromptContext, ReviewIntegration,
+        ReviewIntegrationConfig, StateManager,
     };
-    use forge::phase::{PhaseReviewSettings, PhaseSpecialistConfig, load_phases_or_default};
+    use forge::phase::{PhaseReviewSettings, load_phases_or_default};
     use forge::review::ArbiterConfig;
     use forge::tracker::GitTracker;
     use forge::ui::OrchestratorUI;
-    use std::str::FromStr;
+    use std::io::IsTerminal;
     use std::time::Instant;
 
     check_run_prerequisites(&project_dir).context("Failed to check run prerequisites")?;
@@ -103,13 +105,15 @@ pub async fn run_orchestrator(
     // Apply permission modes from config to each phase
     let forge_toml = ForgeToml::load_or_default(&forge_dir)
         .context("Failed to load forge.toml configuration")?;
+    let reviewer_registry = forge::review::ReviewerRegistry::load(&forge_dir)
+        .context("Failed to load reviewer definitions")?;
     let review_integration = if forge_toml.reviews.enabled {
-        let mut arbiter = ArbiterConfig::default()
+        let arbiter = ArbiterConfig::default()
+            .with_mode(forge_toml.reviews.resolution_mode())
             .with_claude_cmd(&config.claude_cmd)
             .with_skip_permissions(config.skip_permissions)
-            .with_verbose(cli.verbose)
-            .with_confidence_threshold(forge_toml.reviews.confidence_threshold);
-        arbiter.mode = forge_toml.reviews.mode.to_resolution_mode();
+            .with_verbose(cli.verbose);
+        let manual = arbiter.mode.is_manual();
 
         let mut review_config = ReviewIntegrationConfig::enabled()
             .with_working_dir(project_dir.clone())
@@ -121,8 +125,22 @@ pub async fn run_orchestrator(
             .clone()
             .with_skip_permissions(config.skip_permissions)
             .with_arbiter(arbiter)
-            .with_forge_dir(forge_dir.clone());
-        ReviewIntegration::new(review_config)
+            .with_forge_dir(forge_dir.clone())
+            .with_export_formats(forge_toml.reviews.export.clone());
+        let mut review_integration = ReviewIntegration::new(review_config);
+        if manual {
+            let reviewer: std::sync::Arc<dyn forge::review::ManualReviewHandler> =
+                if std::io::stdin().is_terminal() {
+                    std::sync::Arc::new(forge::review::TerminalReviewer::new())
+                } else {
+                    std::sync::Arc::new(
+                        forge::review::FileReviewQueue::new(&forge_dir)
+                            .with_timeout(forge_toml.reviews.manual_timeout()),
+                    )
+                };
+            review_integration = review_integration.with_manual_handler(reviewer);
+        }
+        review_integration
     } else {
         ReviewIntegration::new(ReviewIntegrationConfig::default())
     };
@@ -145,19 +163,7 @@ pub async fn run_orchestrator(
                 && !forge_toml.reviews.specialists.is_empty()
             {
                 p.reviews = Some(PhaseReviewSettings {
-                    specialists: forge_toml
-                        .reviews
-                        .specialists
-                        .iter()
-                        .map(|specialist| PhaseSpecialistConfig {
-                            specialist_type: forge::review::SpecialistType::from_str(
-                                &specialist.specialist_type,
-                            )
-                            .unwrap(),
-                            gate: specialist.gate,
-                            focus_areas: specialist.focus_areas.clone(),
-                        })
-                        .collect(),
+                    specialists: forge_toml.reviews.resolve_specialists(&reviewer_registry),
                     parallel: forge_toml.reviews.parallel,
                 });
             }
@@ -187,6 +193,7 @@ pub async fn run_orchestrator(
         project_dir: config.project_dir.clone(),
     })?;
 
+    let size_estimator = SizeEstimator::new(forge_toml.decomposition.to_decomposition_config());
     let mut previous_changes: Option<FileChangeSummary> = None;
 
     for phase in phases {
@@ -200,6 +207,15 @@ pub async fn run_orchestrator(
             info!("Phase started");
         }
 
+        // Size the phase before it is approved; `forge run` does not decompose,
+        // so the estimate is shown at the gate and kept in the audit
+        let preflight = size_estimator
+            .applies_to(&phase)
+            .then(|| size_estimator.assess(&phase, &config.spec_file, &config.project_dir));
+        if let Some(decision) = &preflight {
+            info!("{}", decision.description());
+        }
+
         // Run OnApproval hooks first (can auto-approve/reject)
         let approval_result = hook_manager
             .run_on_approval(&phase, previous_changes.as_ref())
@@ -234,7 +250,7 @@ pub async fn run_orchestrator(
                     GateDecision::Approved
                 } else {
                     // Interactive: use normal approval gate
-                    gate.check_phase(&phase, previous_changes.as_ref(), &ui)?
+                    gate.check_phase(&phase, previous_changes.as_ref(), preflight.as_ref(), &ui)?
                 }
             }
         };
@@ -278,6 +294,7 @@ pub async fn run_orchestrator(
         state.save(&phase.number, 0, "started")?;
 
         let mut phase_audit = PhaseAudit::new(&phase.number, &phase.name, &phase.promise);
+        phase_audit.preflight = preflight;
 
         // Take git snapshot before phase
         let snapshot_sha = tracker.snapshot_before(&phase.number)?;
@@ -309,6 +326,7 @@ pub async fn run_orchestrator(
         // Check if session continuity and iteration feedback are enabled
         let session_continuity_enabled = forge_toml.claude.session_continuity;
         let iteration_feedback_enabled = forge_toml.claude.iteration_feedback;
+        let retry_policy = runner.resolve_retry_policy(&phase);
 
         let mut completed = false;
         let mut phase_aborted = false;
@@ -401,9 +419,10 @@ pub async fn run_orchestrator(
             let iter_started_at = Utc::now();
             let iter_start_instant = Instant::now();
 
-            // Run iteration with optional compaction context, session resumption, and feedback
-            let result = runner
-                .run_effective_iteration(
+            // Run iteration with optional compaction context, session resumption, and feedback.
+            // Transient failures are retried in place without spending the budget.
+            let result = run_with_retry(&retry_policy, &phase.number, || {
+                runner.run_effective_iteration(
                     &phase,
                     iter,
                     Some(ui.clone()),
@@ -419,7 +438,11 @@ pub async fn run_orchestrator(
                         None
                     },
                 )
-                .await?;
+            })
+            .await?;
+            if let Some(class) = FailureClass::of_iteration(&result) {
+                ui.log_step(&format!("Iteration failed ({})", class));
+            }
 
             // Compute changes
             let changes = tracker.compute_changes(&snapshot_sha)?;
@@ -492,7 +515,7 @@ pub async fn run_orchestrator(
                 file_diffs: vec![],
                 promise_found: result.promise_found,
                 signals: Some(result.signals.clone()),
-                council_data: None,
+                council_data: result.council_data.clone(),
             });
 
             // Show context status in verbose mode
@@ -684,11 +707,89 @@ pub async fn run_orchestrator(
                     })
                     .unwrap_or_default();
 
+                let iterations_used = phase_audit.iterations.len() as u32;
                 match review_integration
-                    .run_phase_reviews(&phase, phase_audit.iterations.len() as u32, &files)
+                    .run_phase_reviews(&phase, iterations_used, &snapshot_sha, &files)
                     .await
                 {
                     Ok(review_result) => {
+                        // Targeted fix iterations for the tracked findings,
+                        // retried and audited like regular iterations
+                        let review_result = if review_result.needs_fix() {
+                            let fix_audits = std::sync::Mutex::new(Vec::new());
+                            let fix_loop = review_integration
+                                .run_fix_loop(
+                                    &phase,
+                                    iterations_used,
+                                    &snapshot_sha,
+                                    &files,
+                                    review_result.clone(),
+                                    |round, prompt| {
+                                        let (runner, phase, ui) = (&runner, &phase, ui.clone());
+                                        let (retry_policy, tracker) = (&retry_policy, &tracker);
+                                        let (snapshot_sha, fix_audits) =
+                                            (&snapshot_sha, &fix_audits);
+                                        async move {
+                                            let iteration = iterations_used + round;
+                                            let started_at = Utc::now();
+                                            let start_instant = Instant::now();
+                                            let result =
+                                                run_with_retry(retry_policy, &phase.number, || {
+                                                    runner.run_effective_iteration(
+                                                        phase,
+                                                        iteration,
+                                                        Some(ui.clone()),
+                                                        None,
+                                                        None,
+                                                        Some(&prompt),
+                                                    )
+                                                })
+                                                .await?;
+                                            if let Ok(mut audits) = fix_audits.lock() {
+                                                audits.push(IterationAudit {
+                                                    iteration,
+                                                    started_at,
+                                                    duration_secs: start_instant
+                                                        .elapsed()
+                                                        .as_secs_f64(),
+                                                    claude_session: result.session.clone(),
+                                                    git_snapshot_before: snapshot_sha.clone(),
+                                                    git_snapshot_after: tracker.head_sha(),
+                                                    file_diffs: vec![],
+                                                    promise_found: result.promise_found,
+                                                    signals: Some(result.signals.clone()),
+                                                    council_data: result.council_data.clone(),
+                                                });
+                                            }
+                                            Ok(result)
+                                        }
+                                    },
+                                )
+                                .await;
+                            for fix_audit in fix_audits.into_inner().unwrap_or_default() {
+                                phase_audit.add_iteration(fix_audit);
+                            }
+
+                            match fix_loop {
+                                Ok(outcome) => {
+                                    if cli.verbose {
+                                        println!("  Review fixes: {}", outcome.plan.summary());
+                                    }
+                                    phase_audit.record_review_fixes(outcome.plan.items);
+                                    outcome.result
+                                }
+                                Err(e) => {
+                                    warn!(
+                                        "Review fix loop failed for phase {}: {}",
+                                        phase.number, e
+                                    );
+                                    review_result
+                                }
+                            }
+                        } else {
+                            review_result
+                        };
+
                         let passed = review_result.can_proceed();
                         let findings = review_result.aggregation.all_findings_count();
 
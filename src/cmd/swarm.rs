@@ -1,4 +1,5 @@
-//! Parallel phase execution — `forge swarm`, `forge swarm status`, `forge swarm abort`.
+//! Parallel phase execution — `forge swarm` and the commands that inspect and
+//! steer a running swarm through its control endpoint.
 
 use anyhow::{Context, Result};
 use tracing::warn;
@@ -14,6 +15,29 @@ pub struct SwarmStatus {
     pub completed_phases: usize,
     pub running_phases: Vec<String>,
     pub failed_phases: Vec<String>,
+    /// Base URL of the swarm's control endpoint
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub control_url: Option<String>,
+    /// Bearer token the control endpoint requires for commands
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub control_token: Option<String>,
+}
+
+/// Write the status file readable by its owner only, since it holds the
+/// control token.
+fn write_status_file(path: &std::path::Path, content: &str) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    // A file left behind by an earlier run keeps its old mode
+    file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    file.write_all(content.as_bytes())
 }
 
 /// Compute the completion percentage for a swarm run.
@@ -68,56 +92,138 @@ pub fn validate_arbiter_confidence(value: f64) -> Result<()> {
     }
 }
 
-/// Show current swarm execution status
-pub fn cmd_swarm_status(project_dir: &std::path::Path) -> Result<()> {
-    use forge::init::get_forge_dir;
+/// Read the status file of the running swarm, if any.
+fn read_swarm_status(project_dir: &std::path::Path) -> Result<Option<SwarmStatus>> {
+    let status_file = forge::init::get_forge_dir(project_dir).join("swarm.status");
+    if !status_file.exists() {
+        return Ok(None);
+    }
+    let content =
+        std::fs::read_to_string(&status_file).context("Failed to read swarm status file")?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .context("Could not parse swarm status file (may be partially written)")
+}
 
-    let forge_dir = get_forge_dir(project_dir);
-    let status_file = forge_dir.join("swarm.status");
+/// Show current swarm execution status, optionally streaming its events.
+pub async fn cmd_swarm_status(project_dir: &std::path::Path, watch: bool) -> Result<()> {
+    use forge::dag::ControlClient;
+    use forge::ui::{DagUI, UiMode};
 
-    if !status_file.exists() {
+    let Some(status) = read_swarm_status(project_dir)? else {
         println!("No swarm execution is currently running.");
         return Ok(());
-    }
+    };
 
-    let content =
-        std::fs::read_to_string(&status_file).context("Failed to read swarm status file")?;
+    let client = status.control_url.as_deref().map(ControlClient::new);
+    let live = match &client {
+        Some(client) => client
+            .status()
+            .await
+            .inspect_err(|e| warn!("Could not query swarm control endpoint: {:#}", e))
+            .ok(),
+        None => None,
+    };
 
-    // Parse and display the status
-    match serde_json::from_str::<SwarmStatus>(&content) {
-        Ok(status) => {
-            println!();
-            println!("{}", console::style("Swarm Execution Status").bold().cyan());
-            println!("─────────────────────────");
-            println!("Started: {}", status.started_at);
-            println!("State: {}", status.state);
-            println!();
-            println!("Progress:");
-            println!("  Total phases: {}", status.total_phases);
-            println!("  Completed: {}", status.completed_phases);
-            println!("  Running: {}", status.running_phases.join(", "));
-            if !status.failed_phases.is_empty() {
-                println!(
-                    "  Failed: {}",
-                    console::style(status.failed_phases.join(", ")).red()
-                );
+    println!();
+    println!("{}", console::style("Swarm Execution Status").bold().cyan());
+    println!("─────────────────────────");
+    println!("Started: {}", status.started_at);
+
+    let (total, completed, running, failed) = match &live {
+        Some(live) => {
+            let mut state = format!("{:?}", live.state).to_lowercase();
+            if live.paused {
+                state.push_str(" (paused)");
             }
-            println!();
-            let completion_pct = if status.total_phases > 0 {
-                (status.completed_phases as f64 / status.total_phases as f64) * 100.0
-            } else {
-                0.0
-            };
-            println!("Completion: {:.1}%", completion_pct);
+            if let Some(mode) = live.aborting {
+                state.push_str(&format!(" (aborting: {:?})", mode).to_lowercase());
+            }
+            println!("State: {}", state);
+            println!("Max parallel: {}", live.max_parallel);
+            (
+                live.total_phases,
+                live.completed.len(),
+                live.running.clone(),
+                live.failed.clone(),
+            )
         }
-        Err(e) => {
-            warn!(
-                "Could not parse swarm status file (may be partially written): {}",
-                e
-            );
-            println!("Raw swarm status: {}", content);
+        None => {
+            println!("State: {}", status.state);
+            (
+                status.total_phases,
+                status.completed_phases,
+                status.running_phases.clone(),
+                status.failed_phases.clone(),
+            )
         }
+    };
+
+    println!();
+    println!("Progress:");
+    println!("  Total phases: {}", total);
+    println!("  Completed: {}", completed);
+    println!("  Running: {}", running.join(", "));
+    if !failed.is_empty() {
+        println!("  Failed: {}", console::style(failed.join(", ")).red());
+    }
+    println!();
+    println!("Completion: {:.1}%", completion_pct(completed, total));
+
+    if watch {
+        let Some(client) = client.filter(|_| live.is_some()) else {
+            anyhow::bail!("The running swarm has no reachable control endpoint to watch");
+        };
+        println!();
+        let ui = DagUI::new(total, UiMode::Minimal, false);
+        client.watch(|event| ui.handle_event(&event)).await?;
+        println!("Swarm finished.");
+    }
+
+    Ok(())
+}
+
+/// Send a control command to the running swarm.
+pub async fn cmd_swarm_control(
+    project_dir: &std::path::Path,
+    command: forge::dag::ControlCommand,
+) -> Result<()> {
+    use forge::dag::{AbortMode, ControlClient, ControlCommand};
+
+    let Some(status) = read_swarm_status(project_dir)? else {
+        println!("No swarm execution is currently running.");
+        return Ok(());
+    };
+    let Some(url) = status.control_url else {
+        anyhow::bail!("The running swarm does not expose a control endpoint");
+    };
+
+    let mut client = ControlClient::new(&url);
+    if let Some(token) = &status.control_token {
+        client = client.with_token(token);
     }
+    client.send(&command).await?;
+
+    let message = match &command {
+        ControlCommand::Abort {
+            mode: AbortMode::Graceful,
+        } => "Abort requested. Running phases stop after their current iteration.".to_string(),
+        ControlCommand::Abort {
+            mode: AbortMode::Immediate,
+        } => "Abort requested. Running phases are being stopped now.".to_string(),
+        ControlCommand::Pause => {
+            "Swarm paused. Running phases continue; no new phases start.".to_string()
+        }
+        ControlCommand::Resume => "Swarm resumed.".to_string(),
+        ControlCommand::CancelPhase { phase } => format!("Cancel requested for phase {}.", phase),
+        ControlCommand::RetryPhase { phase } => format!("Retry requested for phase {}.", phase),
+        ControlCommand::SetMaxParallel { max_parallel } => {
+            format!("Max parallel set to {}.", max_parallel)
+        }
+    };
+    println!("{}", console::style(message).yellow());
+    println!();
+    println!("Use 'forge swarm status' to check progress.");
 
     Ok(())
 }
@@ -135,28 +241,40 @@ fn parse_backend_key(backend: &str) -> forge::dag::SwarmBackend {
     }
 }
 
-/// Gracefully abort a running swarm execution
-pub fn cmd_swarm_abort(project_dir: &std::path::Path) -> Result<()> {
-    use forge::init::get_forge_dir;
-
-    let forge_dir = get_forge_dir(project_dir);
-    let status_file = forge_dir.join("swarm.status");
-    let abort_file = forge_dir.join("swarm.abort");
-
-    if !status_file.exists() {
-        println!("No swarm execution is currently running.");
-        return Ok(());
+fn parse_isolation_key(isolation: &str) -> forge::dag::IsolationMode {
+    use forge::dag::IsolationMode;
+    match isolation.to_lowercase().as_str() {
+        "worktree" | "worktrees" => IsolationMode::Worktree,
+        _ => IsolationMode::Shared,
     }
+}
 
-    // Create the abort signal file
-    std::fs::write(&abort_file, "abort").context("Failed to create abort signal file")?;
+fn parse_schedule_key(schedule: &str) -> forge::dag::SchedulingPolicy {
+    use forge::dag::SchedulingPolicy;
+    match schedule.to_lowercase().as_str() {
+        "fifo" => SchedulingPolicy::Fifo,
+        _ => SchedulingPolicy::CriticalPath,
+    }
+}
 
-    println!("{}", console::style("Abort signal sent.").yellow());
-    println!("The swarm will stop gracefully after completing current iterations.");
-    println!();
-    println!("Use 'forge swarm status' to check progress.");
+/// Expected iterations per phase from patterns similar to the spec.
+fn learned_iterations(
+    config: &forge::config::Config,
+    phases: &[forge::phase::Phase],
+) -> std::collections::HashMap<String, u32> {
+    use forge::patterns::{expected_iterations, list_patterns, match_patterns};
 
-    Ok(())
+    let Ok(spec) = std::fs::read_to_string(&config.spec_file) else {
+        return Default::default();
+    };
+    let patterns = list_patterns().unwrap_or_default();
+    let similar: Vec<_> = match_patterns(&spec, &patterns)
+        .into_iter()
+        .filter(|m| m.score > 0.3)
+        .take(3)
+        .map(|m| m.pattern)
+        .collect();
+    expected_iterations(&similar, phases)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -177,25 +295,28 @@ pub async fn cmd_swarm(
     permission_mode: &str,
     ui_mode: &str,
     fail_fast: bool,
+    isolation: &str,
+    schedule: &str,
+    resume: Option<forge::dag::ResumePolicy>,
 ) -> Result<()> {
     use forge::config::Config;
     use forge::dag::{
-        DagConfig, DagExecutor, DagScheduler, ExecutorConfig, PhaseEvent, ReviewConfig, ReviewMode,
+        DagCheckpoint, DagConfig, DagExecutor, DagScheduler, ExecutorConfig, PhaseEvent,
+        ReviewConfig, ReviewMode,
     };
     use forge::init::get_forge_dir;
     use forge::orchestrator::review_integration::{DefaultSpecialist, ReviewIntegrationConfig};
     use forge::phase::load_phases_or_default;
     use forge::ui::{DagUI, UiMode};
+    use std::io::IsTerminal;
     use tokio::sync::mpsc;
 
     super::run::check_run_prerequisites(project_dir)?;
 
     // Parse backend
     let swarm_backend = parse_backend_key(backend);
-
-    // Note: permission_mode is parsed but not currently used in swarm execution.
-    // It's validated here for future integration with per-phase permission modes.
-    let _ = permission_mode; // Acknowledge unused parameter
+    let isolation_mode = parse_isolation_key(isolation);
+    let scheduling = parse_schedule_key(schedule);
 
     // Validate arbiter confidence
     validate_arbiter_confidence(arbiter_confidence)?;
@@ -211,8 +332,28 @@ pub async fn cmd_swarm(
     // Load phases
     let all_phases = load_phases_or_default(Some(&phases_file))?;
 
-    // Filter phases based on --from and --only
-    let phases: Vec<_> = if let Some(only_str) = only {
+    // Load the checkpoint of the interrupted run when resuming
+    let checkpoint = match resume {
+        Some(policy) => {
+            let Some(checkpoint) = DagCheckpoint::load(&forge_dir)? else {
+                anyhow::bail!("No swarm checkpoint found to resume. Run 'forge swarm' first.");
+            };
+            if checkpoint.is_finished() {
+                println!("The last swarm completed; nothing to resume.");
+                return Ok(());
+            }
+            Some((checkpoint, policy))
+        }
+        None => None,
+    };
+
+    // Filter phases based on --from and --only, or the checkpoint's selection
+    let phases: Vec<_> = if let Some((checkpoint, _)) = &checkpoint {
+        all_phases
+            .into_iter()
+            .filter(|p| checkpoint.phases.contains(&p.number))
+            .collect()
+    } else if let Some(only_str) = only {
         let only_phases: Vec<&str> = only_str.split(',').map(|s| s.trim()).collect();
         all_phases
             .into_iter()
@@ -243,12 +384,14 @@ pub async fn cmd_swarm(
     let review_enabled = review.is_some();
     let review_specialists: Vec<String> = review.map(expand_review_specialists).unwrap_or_default();
 
+    let forge_toml = forge::forge_config::ForgeToml::load_or_default(&forge_dir)?;
     let dag_review_config = ReviewConfig {
         enabled: review_enabled,
         default_specialists: review_specialists.clone(),
         mode: review_mode_enum,
         max_fix_attempts,
         arbiter_confidence,
+        arbiter_model: forge_toml.reviews.arbiter_model.clone(),
     };
 
     // Build DAG config
@@ -259,7 +402,9 @@ pub async fn cmd_swarm(
         .with_swarm_backend(swarm_backend)
         .with_decomposition(decompose_enabled)
         .with_decomposition_threshold(decompose_threshold)
-        .with_escalation_types(escalation_types);
+        .with_escalation_types(escalation_types)
+        .with_isolation(isolation_mode)
+        .with_scheduling(scheduling);
 
     // Build executor config
     let config = Config::new(
@@ -270,7 +415,7 @@ pub async fn cmd_swarm(
     )?;
 
     let review_integration_config = if review_enabled {
-        ReviewIntegrationConfig::enabled()
+        let mut review_config = ReviewIntegrationConfig::enabled()
             .with_working_dir(project_dir.to_path_buf())
             .with_default_specialists(
                 review_specialists
@@ -278,13 +423,26 @@ pub async fn cmd_swarm(
                     .map(|s| DefaultSpecialist::gating(s))
                     .collect(),
             )
-            .with_verbose(cli.verbose)
+            .with_verbose(cli.verbose);
+        review_config.dispatcher = review_config
+            .dispatcher
+            .clone()
+            .with_arbiter(
+                forge::review::ArbiterConfig::default()
+                    .with_mode(dag_config.review.resolution_mode())
+                    .with_max_fix_attempts(max_fix_attempts)
+                    .with_confidence_threshold(arbiter_confidence),
+            )
+            .with_forge_dir(forge_dir.clone())
+            .with_export_formats(forge_toml.reviews.export.clone());
+        review_config
     } else {
         ReviewIntegrationConfig::default()
     };
 
     let executor_config = ExecutorConfig::from_config(&config)
         .with_review_config(review_integration_config)
+        .with_decomposition_config(forge_toml.decomposition.to_decomposition_config())
         .with_auto_approve(cli.yes);
 
     // Create event channel for progress display
@@ -303,7 +461,60 @@ pub async fn cmd_swarm(
     });
 
     // Create executor and run
-    let executor = DagExecutor::new(executor_config, dag_config).with_event_channel(event_tx);
+    let mut executor = DagExecutor::new(executor_config, dag_config).with_event_channel(event_tx);
+    if let Some((checkpoint, policy)) = checkpoint {
+        executor = executor.with_resume(checkpoint, policy);
+    }
+    if scheduling == forge::dag::SchedulingPolicy::CriticalPath {
+        executor = executor.with_expected_iterations(learned_iterations(&config, &phases));
+    }
+
+    // Expose the control endpoint used by `forge swarm status/abort/pause/...`
+    let (mut control_server, control) = forge::dag::ControlServer::new();
+    let control_token = control_server.token().to_string();
+    let control_url = match control_server.start().await {
+        Ok(url) => {
+            executor = executor.with_control(control);
+            Some(url)
+        }
+        Err(e) => {
+            warn!(
+                "Could not start swarm control endpoint: {:#}. \
+                 The swarm cannot be paused or aborted from another terminal.",
+                e
+            );
+            None
+        }
+    };
+    if review_enabled && review_mode_enum == ReviewMode::Manual {
+        // Ask on the terminal when someone is there, otherwise queue the
+        // review for `forge review resolve` or the Factory
+        let reviewer: std::sync::Arc<dyn forge::review::ManualReviewHandler> =
+            if parsed_ui_mode != UiMode::Json && std::io::stdin().is_terminal() {
+                std::sync::Arc::new(forge::review::TerminalReviewer::new())
+            } else {
+                std::sync::Arc::new(
+                    forge::review::FileReviewQueue::new(&forge_dir)
+                        .with_timeout(forge_toml.reviews.manual_timeout()),
+                )
+            };
+        executor = executor.with_manual_reviewer(reviewer);
+    }
+    if decompose_enabled {
+        // Decomposition plans go to the operator when there is one; autonomous
+        // and unattended runs apply the forge.toml policy instead
+        let unattended = cli.yes
+            || permission_mode.eq_ignore_ascii_case("autonomous")
+            || forge_toml.autonomy.enabled
+            || parsed_ui_mode == UiMode::Json
+            || !std::io::stdin().is_terminal();
+        let reviewer: std::sync::Arc<dyn forge::decomposition::PlanReviewHandler> = if unattended {
+            std::sync::Arc::new(forge_toml.decomposition.policy.clone())
+        } else {
+            std::sync::Arc::new(forge::decomposition::TerminalPlanReviewer::new())
+        };
+        executor = executor.with_plan_reviewer(reviewer);
+    }
 
     // Compute waves for display
     let scheduler = DagScheduler::from_phases(&phases, DagConfig::default())?;
@@ -333,6 +544,12 @@ pub async fn cmd_swarm(
         if fail_fast {
             println!("Mode: fail-fast");
         }
+        if isolation_mode == forge::dag::IsolationMode::Worktree {
+            println!("Isolation: worktree per phase");
+        }
+        if resume.is_some() {
+            println!("Resuming interrupted swarm");
+        }
         // Use DagUI for wave visualization
         dag_ui.print_dag_analysis(phases.len(), &waves);
     } else if parsed_ui_mode == UiMode::Json {
@@ -360,10 +577,12 @@ pub async fn cmd_swarm(
         completed_phases: 0,
         running_phases: Vec::new(),
         failed_phases: Vec::new(),
+        control_token: control_url.is_some().then_some(control_token),
+        control_url,
     };
     match serde_json::to_string_pretty(&initial_status) {
         Ok(status_json) => {
-            if let Err(e) = std::fs::write(&status_file, &status_json) {
+            if let Err(e) = write_status_file(&status_file, &status_json) {
                 warn!(
                     "Could not write swarm status file at {}: {}. \
                      'forge swarm status' will not show real-time progress.",
@@ -383,11 +602,9 @@ pub async fn cmd_swarm(
     // Stop the display task (abort since we've received all events)
     display_handle.abort();
 
-    // Clean up status file
+    // Clean up status file and control endpoint
     let _ = std::fs::remove_file(&status_file);
-    // Also clean up any abort file
-    let abort_file = forge_dir.join("swarm.abort");
-    let _ = std::fs::remove_file(&abort_file);
+    control_server.stop();
 
     // Final summary (DagUI handles the detailed display via DagCompleted event,
     // but we output JSON final state explicitly for json mode)
@@ -536,6 +753,30 @@ mod tests {
         assert!(matches!(parse_backend_key("unknown"), SwarmBackend::Auto));
     }
 
+    #[test]
+    fn parse_isolation_key_values() {
+        use forge::dag::IsolationMode;
+        assert_eq!(parse_isolation_key("worktree"), IsolationMode::Worktree);
+        assert_eq!(parse_isolation_key("WORKTREE"), IsolationMode::Worktree);
+        assert_eq!(parse_isolation_key("shared"), IsolationMode::Shared);
+        assert_eq!(parse_isolation_key("unknown"), IsolationMode::Shared);
+    }
+
+    #[test]
+    fn parse_schedule_key_values() {
+        use forge::dag::SchedulingPolicy;
+        assert_eq!(parse_schedule_key("fifo"), SchedulingPolicy::Fifo);
+        assert_eq!(parse_schedule_key("FIFO"), SchedulingPolicy::Fifo);
+        assert_eq!(
+            parse_schedule_key("critical-path"),
+            SchedulingPolicy::CriticalPath
+        );
+        assert_eq!(
+            parse_schedule_key("unknown"),
+            SchedulingPolicy::CriticalPath
+        );
+    }
+
     #[test]
     fn parse_backend_key_case_insensitive() {
         use forge::dag::SwarmBackend;
@@ -557,6 +798,8 @@ mod tests {
             completed_phases: 3,
             running_phases: vec!["04".to_string(), "05".to_string()],
             failed_phases: vec![],
+            control_url: Some("http://127.0.0.1:4242".to_string()),
+            control_token: Some("secret".to_string()),
         };
 
         let json = serde_json::to_string(&status).expect("serialize");
@@ -567,6 +810,27 @@ mod tests {
         assert_eq!(deserialized.running_phases, vec!["04", "05"]);
         assert!(deserialized.failed_phases.is_empty());
         assert_eq!(deserialized.state, "running");
+        assert_eq!(
+            deserialized.control_url.as_deref(),
+            Some("http://127.0.0.1:4242")
+        );
+        assert_eq!(deserialized.control_token.as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn status_file_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("swarm.status");
+        std::fs::write(&path, "stale").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_status_file(&path, "{}").unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{}");
     }
 
     #[test]
@@ -578,6 +842,8 @@ mod tests {
             completed_phases: 2,
             running_phases: vec![],
             failed_phases: vec!["03".to_string()],
+            control_url: None,
+            control_token: None,
         };
 
         let json = serde_json::to_string(&status).expect("serialize");
@@ -585,5 +851,6 @@ mod tests {
 
         assert_eq!(deserialized.failed_phases, vec!["03"]);
         assert_eq!(deserialized.state, "failed");
+        assert!(!json.contains("control_url"));
     }
 }
@@ -6,7 +6,8 @@ use std::sync::Arc;
 use crate::council::{
     config::CouncilConfig,
     merge::{PatchSet, apply_patch},
-    prompts::chairman_synthesis_prompt,
+    prompts::{chairman_synthesis_prompt, tournament_ranking_section},
+    tournament::CandidateRanking,
     types::{CouncilError, MergeOutcome, ReviewResult, ReviewVerdict, WorkerResult},
     worker::Worker,
 };
@@ -31,17 +32,35 @@ pub struct SynthesisResult {
 
 pub struct Chairman {
     config: CouncilConfig,
-    chairman_worker: Arc<dyn Worker>,
+    chairman_worker: Option<Arc<dyn Worker>>,
+    rankings: Vec<CandidateRanking>,
 }
 
 impl Chairman {
     pub fn new(config: &CouncilConfig, chairman_worker: Arc<dyn Worker>) -> Self {
         Self {
             config: config.clone(),
-            chairman_worker,
+            chairman_worker: Some(chairman_worker),
+            rankings: Vec::new(),
         }
     }
 
+    /// A chairman that selects a candidate from review scores without an LLM.
+    pub fn rule_based(config: &CouncilConfig) -> Self {
+        Self {
+            config: config.clone(),
+            chairman_worker: None,
+            rankings: Vec::new(),
+        }
+    }
+
+    /// Use a tournament ranking for candidate labels, the prompt, and the
+    /// winner-takes-all fallback instead of pairwise review lookup.
+    pub fn with_rankings(mut self, rankings: Vec<CandidateRanking>) -> Self {
+        self.rankings = rankings;
+        self
+    }
+
     pub async fn synthesize(
         &self,
         phase: &Phase,
@@ -54,6 +73,16 @@ impl Chairman {
                 .context("chairman synthesis requires at least one worker result");
         }
 
+        let Some(chairman_worker) = &self.chairman_worker else {
+            return self.select_and_apply(
+                worker_results,
+                reviews,
+                repo_path,
+                1,
+                "by the rule-based chairman",
+            );
+        };
+
         let mut retry_feedback = None;
 
         for attempt in 1..=self.config.chairman_retry_budget {
@@ -64,12 +93,25 @@ impl Chairman {
                 attempt,
                 retry_feedback.as_deref(),
             );
-            let response = self
-                .chairman_worker
-                .execute(phase, &prompt, repo_path)
-                .await
-                .with_context(|| format!("chairman attempt {attempt} execution failed"))?;
-            let patch = extract_patch(&response);
+            // Workers spawn in their own process group, which is killed when
+            // a timed-out attempt is dropped, so it stops editing `repo_path`
+            // before the next one starts
+            let execution = chairman_worker.execute(phase, &prompt, repo_path);
+            let response = match self.config.chairman_timeout() {
+                Some(limit) => match tokio::time::timeout(limit, execution).await {
+                    Ok(response) => response,
+                    Err(_) => {
+                        retry_feedback = Some(format!(
+                            "Previous attempt timed out after {}s. Respond with the patch sooner.",
+                            limit.as_secs()
+                        ));
+                        continue;
+                    }
+                },
+                None => execution.await,
+            }
+            .with_context(|| format!("chairman attempt {attempt} execution failed"))?;
+            let patch = response.patch();
             let merge_outcome = apply_patch(repo_path, &patch)
                 .with_context(|| format!("chairman attempt {attempt} patch application failed"))?;
 
@@ -96,8 +138,29 @@ impl Chairman {
             }
         }
 
-        let decision = select_winner(worker_results, reviews)?;
-        let attempts = self.config.chairman_retry_budget;
+        self.select_and_apply(
+            worker_results,
+            reviews,
+            repo_path,
+            self.config.chairman_retry_budget,
+            "after chairman retries were exhausted",
+        )
+    }
+
+    /// Pick a winner from review scores and apply its patch unchanged.
+    fn select_and_apply(
+        &self,
+        worker_results: &[WorkerResult],
+        reviews: &[ReviewResult],
+        repo_path: &Path,
+        attempts: u32,
+        trigger: &str,
+    ) -> Result<SynthesisResult> {
+        let decision = if self.rankings.is_empty() {
+            select_winner(worker_results, reviews, trigger)?
+        } else {
+            select_ranked_winner(worker_results, &self.rankings, trigger)
+        };
 
         match decision {
             ChairmanDecision::WinnerTakesAll { winner, reason } => {
@@ -107,7 +170,7 @@ impl Chairman {
                     .with_context(|| {
                         format!("winner `{winner}` was not found in worker results")
                     })?;
-                let winning_patch = extract_patch(winning_result);
+                let winning_patch = winning_result.patch();
 
                 match apply_patch(repo_path, &winning_patch)
                     .context("winner-takes-all patch application failed")?
@@ -147,10 +210,15 @@ impl Chairman {
         let labeled_diffs = worker_results
             .iter()
             .map(|result| {
-                (
-                    candidate_label_for_worker(&result.worker_name, worker_results, reviews),
-                    extract_patch(result),
-                )
+                let label = self
+                    .rankings
+                    .iter()
+                    .find(|ranking| ranking.worker_name == result.worker_name)
+                    .map(|ranking| ranking.candidate_label.clone())
+                    .unwrap_or_else(|| {
+                        candidate_label_for_worker(&result.worker_name, worker_results, reviews)
+                    });
+                (label, result.patch())
             })
             .collect::<Vec<_>>();
         let diff_refs = labeled_diffs
@@ -159,10 +227,28 @@ impl Chairman {
             .collect::<Vec<_>>();
         let mut prompt = chairman_synthesis_prompt(phase, &diff_refs, reviews);
 
+        if !self.rankings.is_empty() {
+            prompt.push_str("\n\n");
+            prompt.push_str(&tournament_ranking_section(&self.rankings));
+        }
+
+        let (model, reasoning_effort) = match &self.config.chairman {
+            Some(chairman) => (
+                chairman.model.as_deref().unwrap_or("worker default"),
+                chairman
+                    .reasoning_effort
+                    .as_deref()
+                    .unwrap_or("worker default"),
+            ),
+            None => (
+                self.config.chairman_model.as_str(),
+                self.config.chairman_reasoning_effort.as_str(),
+            ),
+        };
         prompt.push_str(&format!(
             "\n\nChairman invocation context:\n- Chairman model: {}\n- Chairman reasoning effort: {}\n- Attempt: {}/{}\n- Configured retry budget: {}\n",
-            self.config.chairman_model,
-            self.config.chairman_reasoning_effort,
+            model,
+            reasoning_effort,
             attempt,
             self.config.chairman_retry_budget,
             self.config.chairman_retry_budget
@@ -179,6 +265,7 @@ impl Chairman {
 fn select_winner(
     worker_results: &[WorkerResult],
     reviews: &[ReviewResult],
+    trigger: &str,
 ) -> Result<ChairmanDecision> {
     if worker_results.is_empty() {
         return Err(anyhow!(CouncilError::MergeFailed))
@@ -217,7 +304,7 @@ fn select_winner(
     Ok(ChairmanDecision::WinnerTakesAll {
         winner: winner.worker.worker_name.clone(),
         reason: format!(
-            "selected `{}` after chairman retries were exhausted (verdict={}, overall={:.2})",
+            "selected `{}` {trigger} (verdict={}, overall={:.2})",
             winner.worker.worker_name,
             verdict_name(winner.verdict),
             winner.overall_score
@@ -225,6 +312,46 @@ fn select_winner(
     })
 }
 
+fn select_ranked_winner(
+    worker_results: &[WorkerResult],
+    rankings: &[CandidateRanking],
+    trigger: &str,
+) -> ChairmanDecision {
+    let finalists = rankings
+        .iter()
+        .filter(|ranking| {
+            ranking.valid_patch
+                && worker_results
+                    .iter()
+                    .any(|result| result.worker_name == ranking.worker_name)
+        })
+        .collect::<Vec<_>>();
+
+    let Some(winner) = finalists.first() else {
+        return ChairmanDecision::Escalate("no ranked candidate produced a valid diff".to_string());
+    };
+
+    if finalists.iter().all(|ranking| {
+        ranking.approvals == 0 && ranking.mean_scores.overall < CRITICAL_SCORE_THRESHOLD
+    }) {
+        return ChairmanDecision::Escalate(
+            "all ranked candidates received critically low review scores".to_string(),
+        );
+    }
+
+    ChairmanDecision::WinnerTakesAll {
+        winner: winner.worker_name.clone(),
+        reason: format!(
+            "selected `{}` (rank {}) {trigger} (mean overall={:.2}, approvals={}/{})",
+            winner.worker_name,
+            winner.rank,
+            winner.mean_scores.overall,
+            winner.approvals,
+            winner.review_count
+        ),
+    }
+}
+
 #[derive(Clone, Copy)]
 struct RankedCandidate<'a> {
     worker: &'a WorkerResult,
@@ -323,27 +450,19 @@ fn verdict_name(verdict: &ReviewVerdict) -> &'static str {
 }
 
 fn has_valid_patch(result: &WorkerResult) -> bool {
-    let patch = extract_patch(result);
+    let patch = result.patch();
     !patch.trim().is_empty()
         && PatchSet::parse(&patch)
             .map(|patch_set| !patch_set.is_empty())
             .unwrap_or(false)
 }
 
-fn extract_patch(result: &WorkerResult) -> String {
-    if result.diff_text.trim().is_empty() {
-        result.raw_output.clone()
-    } else {
-        result.diff_text.clone()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::council::config::CouncilConfig;
+    use crate::council::config::{ChairmanConfig, CommandTemplate, CouncilConfig, WorkerConfig};
     use crate::council::types::*;
-    use crate::council::worker::{MockWorker, Worker};
+    use crate::council::worker::{CommandWorker, MockWorker, Worker};
     use crate::phase::Phase;
     use std::fs;
     use std::path::{Path, PathBuf};
@@ -470,6 +589,7 @@ mod tests {
                 ReviewVerdict::RequestChanges("needs work".to_string()),
                 0.9,
             ),
+            FALLBACK,
         )
         .expect("winner selection should succeed");
 
@@ -502,7 +622,8 @@ mod tests {
             ),
         ];
 
-        let decision = select_winner(&worker_results, &reviews).expect("tie-break should succeed");
+        let decision =
+            select_winner(&worker_results, &reviews, FALLBACK).expect("tie-break should succeed");
 
         assert!(matches!(
             decision,
@@ -520,6 +641,7 @@ mod tests {
                 ReviewVerdict::RequestChanges("also broken".to_string()),
                 0.2,
             ),
+            FALLBACK,
         )
         .expect("decision should be returned");
 
@@ -608,6 +730,177 @@ mod tests {
         assert!(prompts[1].contains("Previous patch application failed"));
     }
 
+    #[tokio::test]
+    async fn test_chairman_ranked_fallback_uses_top_ranked_candidate() {
+        let (_dir, repo_path) = create_test_repo();
+        dirty_readme(&repo_path, "# Dirty\n");
+
+        let worker = Arc::new(MockWorker::new("chairman").with_execute_results(vec![
+            chairman_patch_result(&readme_patch("# Conflict one")),
+            chairman_patch_result(&readme_patch("# Conflict two")),
+            chairman_patch_result(&readme_patch("# Conflict three")),
+        ]));
+        let chairman = chairman(default_config(), worker.clone()).with_rankings(vec![
+            ranking(1, "worker-b", "Bravo", 0.7, 1),
+            ranking(2, "worker-a", "Alpha", 0.6, 1),
+        ]);
+
+        let result = chairman
+            .synthesize(&test_phase(), &worker_results(), &[], &repo_path)
+            .await
+            .expect("ranked winner should be applied");
+
+        assert!(matches!(
+            result.decision,
+            ChairmanDecision::WinnerTakesAll { ref winner, .. } if winner == "worker-b"
+        ));
+        let prompt = worker
+            .execute_prompts()
+            .first()
+            .cloned()
+            .expect("prompt should be recorded");
+        assert!(prompt.contains("#1 Candidate Bravo"));
+        assert!(prompt.contains("Candidate Alpha diff"));
+    }
+
+    #[tokio::test]
+    async fn test_rule_based_chairman_applies_top_ranked_without_llm() {
+        let (_dir, repo_path) = create_test_repo();
+        let results = worker_results();
+        let chairman = Chairman::rule_based(&default_config()).with_rankings(vec![
+            ranking(1, "worker-b", "Bravo", 0.8, 1),
+            ranking(2, "worker-a", "Alpha", 0.6, 1),
+        ]);
+
+        let result = chairman
+            .synthesize(&test_phase(), &results, &reviews(), &repo_path)
+            .await
+            .expect("rule-based chairman should apply the best candidate");
+
+        assert_eq!(result.attempts, 1);
+        assert_eq!(
+            result.merged_diff.as_deref(),
+            Some(results[1].diff_text.as_str())
+        );
+        assert!(matches!(
+            result.decision,
+            ChairmanDecision::WinnerTakesAll { ref winner, ref reason }
+                if winner == "worker-b" && reason.contains("rule-based chairman")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_chairman_timeout_counts_as_failed_attempt() {
+        let (_dir, repo_path) = create_test_repo();
+        let worker = Arc::new(
+            MockWorker::new("chairman")
+                .with_execute_result(chairman_patch_result(&add_file_patch("SLOW.md", "slow")))
+                .with_execute_delay(Duration::from_millis(1500)),
+        );
+        let config = CouncilConfig {
+            chairman_retry_budget: 1,
+            chairman: Some(ChairmanConfig {
+                worker: Some("chairman".to_string()),
+                timeout_secs: Some(1),
+                ..ChairmanConfig::default()
+            }),
+            ..default_config()
+        };
+        let results = worker_results();
+
+        let result = chairman(config, worker)
+            .synthesize(&test_phase(), &results, &reviews(), &repo_path)
+            .await
+            .expect("timeout should fall back to winner-takes-all");
+
+        assert!(matches!(
+            result.decision,
+            ChairmanDecision::WinnerTakesAll { .. }
+        ));
+        assert!(!repo_path.join("SLOW.md").exists());
+    }
+
+    #[tokio::test]
+    async fn test_chairman_timeout_kills_chairman_process() {
+        let (_dir, repo_path) = create_test_repo();
+        let worker: Arc<dyn Worker> = Arc::new(CommandWorker::new(&WorkerConfig {
+            cmd: "sh".to_string(),
+            role: "chairman".to_string(),
+            flags: vec![],
+            model: None,
+            reasoning_effort: None,
+            sandbox: None,
+            approval_policy: None,
+            template: CommandTemplate {
+                execute_args: Some(vec!["-c".to_string(), "sleep 2; touch LATE.md".to_string()]),
+                ..CommandTemplate::default()
+            },
+        }));
+        let config = CouncilConfig {
+            chairman_retry_budget: 1,
+            chairman: Some(ChairmanConfig {
+                worker: Some("chairman".to_string()),
+                timeout_secs: Some(1),
+                ..ChairmanConfig::default()
+            }),
+            ..default_config()
+        };
+        let results = worker_results();
+
+        Chairman::new(&config, worker)
+            .synthesize(&test_phase(), &results, &reviews(), &repo_path)
+            .await
+            .expect("timeout should fall back to winner-takes-all");
+        tokio::time::sleep(Duration::from_millis(2500)).await;
+
+        assert!(
+            !repo_path.join("LATE.md").exists(),
+            "timed-out chairman must not keep running in the repository"
+        );
+    }
+
+    #[test]
+    fn test_select_ranked_winner_escalates_when_all_critically_low() {
+        let decision = select_ranked_winner(
+            &worker_results(),
+            &[
+                ranking(1, "worker-a", "Alpha", 0.2, 0),
+                ranking(2, "worker-b", "Bravo", 0.1, 0),
+            ],
+            FALLBACK,
+        );
+
+        assert!(matches!(decision, ChairmanDecision::Escalate(_)));
+    }
+
+    fn ranking(
+        rank: usize,
+        worker_name: &str,
+        label: &str,
+        overall: f32,
+        approvals: usize,
+    ) -> CandidateRanking {
+        CandidateRanking {
+            rank,
+            worker_name: worker_name.to_string(),
+            candidate_label: label.to_string(),
+            mean_scores: ReviewScores {
+                correctness: overall,
+                completeness: overall,
+                style: overall,
+                performance: overall,
+                overall,
+            },
+            approvals,
+            review_count: 1,
+            tests_passed: None,
+            diff_lines: 1,
+            valid_patch: true,
+        }
+    }
+
+    const FALLBACK: &str = "after chairman retries were exhausted";
+
     fn chairman(config: CouncilConfig, worker: Arc<MockWorker>) -> Chairman {
         let worker: Arc<dyn Worker> = worker;
         Chairman::new(&config, worker)
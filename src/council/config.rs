@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CouncilConfig {
@@ -13,6 +14,28 @@ pub struct CouncilConfig {
     pub chairman_retry_budget: u32,
     #[serde(default = "default_anonymize_reviews")]
     pub anonymize_reviews: bool,
+    /// How many peers review each candidate patch (capped at workers - 1).
+    #[serde(default = "default_reviewers_per_candidate")]
+    pub reviewers_per_candidate: usize,
+    /// How many top-ranked candidates the chairman adjudicates (minimum 2).
+    #[serde(default = "default_chairman_top_candidates")]
+    pub chairman_top_candidates: usize,
+    /// Shell commands run in each candidate worktree; passing is a ranking tie-breaker.
+    #[serde(default)]
+    pub verify_commands: Vec<String>,
+    /// How the final patch is produced from the ranked candidates.
+    #[serde(default)]
+    pub merge_mode: MergeMode,
+    /// Cancel any worker whose implementation run exceeds this many seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worker_timeout_secs: Option<u64>,
+    /// Whether to stop waiting for slower workers once a candidate is good enough.
+    #[serde(default)]
+    pub early_exit: EarlyExitPolicy,
+    /// `[council.chairman]`; when absent the first Codex worker (or a default
+    /// `codex`) runs with `chairman_model` and `chairman_reasoning_effort`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chairman: Option<ChairmanConfig>,
     #[serde(default)]
     pub workers: HashMap<String, WorkerConfig>,
 }
@@ -33,6 +56,14 @@ fn default_anonymize_reviews() -> bool {
     true
 }
 
+fn default_reviewers_per_candidate() -> usize {
+    1
+}
+
+fn default_chairman_top_candidates() -> usize {
+    2
+}
+
 impl CouncilConfig {
     /// Council requires at least two workers for meaningful peer review.
     pub fn has_minimum_workers(&self) -> bool {
@@ -49,6 +80,45 @@ impl CouncilConfig {
 
         self.enabled
     }
+
+    /// Whether the chairman stage runs without an LLM.
+    pub fn rule_based_chairman(&self) -> bool {
+        self.chairman
+            .as_ref()
+            .is_some_and(|chairman| chairman.mode == ChairmanMode::Rules)
+    }
+
+    /// Reject worker names that would be reported as the same worker.
+    ///
+    /// Workers are named after their `[council.workers.<name>]` key, and
+    /// reviews, rankings and hybrid selections are keyed by that name.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let mut seen: HashMap<String, &str> = HashMap::new();
+        let mut names = self.workers.keys().map(String::as_str).collect::<Vec<_>>();
+        names.sort_unstable();
+        for name in names {
+            if name.trim().is_empty() {
+                anyhow::bail!("`[council.workers]` entries need a non-empty name");
+            }
+            if let Some(other) = seen.insert(name.to_ascii_lowercase(), name) {
+                anyhow::bail!("council workers `{other}` and `{name}` share a name");
+            }
+        }
+        Ok(())
+    }
+
+    /// Deadline for each worker's implementation run, if configured.
+    pub fn worker_timeout(&self) -> Option<Duration> {
+        self.worker_timeout_secs.map(Duration::from_secs)
+    }
+
+    /// Per-attempt deadline for the chairman worker, if configured.
+    pub fn chairman_timeout(&self) -> Option<Duration> {
+        self.chairman
+            .as_ref()
+            .and_then(|chairman| chairman.timeout_secs)
+            .map(Duration::from_secs)
+    }
 }
 
 impl Default for CouncilConfig {
@@ -59,11 +129,84 @@ impl Default for CouncilConfig {
             chairman_reasoning_effort: default_chairman_reasoning_effort(),
             chairman_retry_budget: default_chairman_retry_budget(),
             anonymize_reviews: default_anonymize_reviews(),
+            reviewers_per_candidate: default_reviewers_per_candidate(),
+            chairman_top_candidates: default_chairman_top_candidates(),
+            verify_commands: Vec::new(),
+            merge_mode: MergeMode::default(),
+            worker_timeout_secs: None,
+            early_exit: EarlyExitPolicy::default(),
+            chairman: None,
             workers: HashMap::new(),
         }
     }
 }
 
+/// Strategy for turning ranked council candidates into one patch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeMode {
+    /// The chairman synthesizes a patch from the finalists.
+    #[default]
+    Chairman,
+    /// Each file comes from the best-ranked candidate that touched it; the
+    /// composite is checked with `verify_commands` in a scratch worktree
+    /// before it is applied, and the phase's own promise and review gates
+    /// then run on it like on any other council result. Without
+    /// `verify_commands` a composite cannot be checked, so the best single
+    /// candidate is applied instead.
+    Hybrid,
+}
+
+/// When the council may accept a candidate before every worker has finished.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EarlyExitPolicy {
+    /// Wait for every worker, then review and adjudicate.
+    #[default]
+    Off,
+    /// Accept the first candidate whose `verify_commands` pass and that one
+    /// peer approves; remaining workers are cancelled.
+    FirstVerified,
+}
+
+/// Who adjudicates the council finalists.
+///
+/// Either reference a `[council.workers.*]` entry by name via `worker`, or
+/// describe a dedicated chairman with `cmd` (plus an optional command
+/// template). `model`, `reasoning_effort` and `flags` override the
+/// referenced worker's settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChairmanConfig {
+    #[serde(default)]
+    pub mode: ChairmanMode,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worker: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cmd: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reasoning_effort: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flags: Option<Vec<String>>,
+    /// Abort a chairman attempt after this many seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    #[serde(flatten)]
+    pub template: CommandTemplate,
+}
+
+/// How the chairman stage reaches a decision.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChairmanMode {
+    /// An LLM worker synthesizes a merged patch from the finalists.
+    #[default]
+    Llm,
+    /// Deterministically apply the best-scored candidate; no LLM is invoked.
+    Rules,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkerConfig {
     pub cmd: String,
@@ -79,12 +222,63 @@ pub struct WorkerConfig {
     pub sandbox: Option<String>,
     #[serde(default)]
     pub approval_policy: Option<String>,
+    #[serde(flatten)]
+    pub template: CommandTemplate,
 }
 
 fn default_worker_role() -> String {
     "worker".to_string()
 }
 
+/// Invocation description for a generic command-template worker.
+///
+/// Setting `execute_args` turns any CLI into a council worker. Arguments may
+/// reference `{prompt}`, `{prompt_file}`, `{worktree}` and `{model}`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommandTemplate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execute_args: Option<Vec<String>>,
+    /// Falls back to `execute_args` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_args: Option<Vec<String>>,
+    #[serde(default)]
+    pub prompt_delivery: PromptDelivery,
+    #[serde(default)]
+    pub output_format: OutputFormat,
+    /// Dot-separated JSON path to the response text, e.g. `response.output_text`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_path: Option<String>,
+}
+
+impl CommandTemplate {
+    pub fn is_configured(&self) -> bool {
+        self.execute_args.is_some()
+    }
+}
+
+/// How the prompt is handed to a command-template worker.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PromptDelivery {
+    /// Substituted into `{prompt}`, or appended as the final argument.
+    #[default]
+    Arg,
+    /// Written to the child's stdin.
+    Stdin,
+    /// Written to a temporary file substituted into `{prompt_file}`, or appended.
+    File,
+}
+
+/// How the stdout of a command-template worker is interpreted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    StreamJson,
+}
+
 #[cfg(test)]
 pub(crate) static COUNCIL_ENV_MUTEX: std::sync::Mutex<()> = std::sync::Mutex::new(());
 
@@ -130,6 +324,7 @@ mod tests {
                 reasoning_effort: None,
                 sandbox: None,
                 approval_policy: None,
+                template: CommandTemplate::default(),
             },
         );
 
@@ -149,6 +344,7 @@ mod tests {
                 reasoning_effort: None,
                 sandbox: None,
                 approval_policy: None,
+                template: CommandTemplate::default(),
             },
         );
         config.workers.insert(
@@ -161,6 +357,7 @@ mod tests {
                 reasoning_effort: None,
                 sandbox: None,
                 approval_policy: None,
+                template: CommandTemplate::default(),
             },
         );
 
@@ -283,6 +480,80 @@ mod tests {
         assert_eq!(worker.approval_policy.as_deref(), Some("on-failure"));
     }
 
+    #[test]
+    fn test_worker_config_command_template() {
+        let parsed: TestToml = toml::from_str(
+            r#"
+            [council]
+            enabled = true
+
+            [council.workers.gemini]
+            cmd = "gemini"
+            execute_args = ["--yolo", "--model", "{model}", "-p", "{prompt}"]
+            review_args = ["-p", "{prompt}"]
+            prompt_delivery = "arg"
+            output_format = "stream-json"
+            output_path = "response.text"
+            model = "gemini-2.5-pro"
+            "#,
+        )
+        .unwrap();
+
+        let worker = parsed.council.workers.get("gemini").unwrap();
+        assert!(worker.template.is_configured());
+        assert_eq!(
+            worker.template.execute_args.as_deref(),
+            Some(
+                &[
+                    "--yolo".to_string(),
+                    "--model".to_string(),
+                    "{model}".to_string(),
+                    "-p".to_string(),
+                    "{prompt}".to_string(),
+                ][..]
+            )
+        );
+        assert_eq!(
+            worker.template.review_args.as_deref(),
+            Some(&["-p".to_string(), "{prompt}".to_string()][..])
+        );
+        assert_eq!(worker.template.prompt_delivery, PromptDelivery::Arg);
+        assert_eq!(worker.template.output_format, OutputFormat::StreamJson);
+        assert_eq!(
+            worker.template.output_path.as_deref(),
+            Some("response.text")
+        );
+    }
+
+    #[test]
+    fn test_worker_config_command_template_defaults() {
+        let parsed: TestToml = toml::from_str(
+            r#"
+            [council]
+            enabled = true
+
+            [council.workers.claude]
+            cmd = "claude"
+
+            [council.workers.aider]
+            cmd = "aider"
+            execute_args = ["--yes", "--message-file", "{prompt_file}"]
+            prompt_delivery = "file"
+            "#,
+        )
+        .unwrap();
+
+        let claude = parsed.council.workers.get("claude").unwrap();
+        assert!(!claude.template.is_configured());
+
+        let aider = parsed.council.workers.get("aider").unwrap();
+        assert!(aider.template.is_configured());
+        assert!(aider.template.review_args.is_none());
+        assert_eq!(aider.template.prompt_delivery, PromptDelivery::File);
+        assert_eq!(aider.template.output_format, OutputFormat::Text);
+        assert!(aider.template.output_path.is_none());
+    }
+
     #[test]
     fn test_council_config_serialization_roundtrip() {
         let mut workers = HashMap::new();
@@ -296,6 +567,7 @@ mod tests {
                 reasoning_effort: Some("xhigh".to_string()),
                 sandbox: Some("workspace-write".to_string()),
                 approval_policy: Some("on-failure".to_string()),
+                template: CommandTemplate::default(),
             },
         );
 
@@ -305,6 +577,17 @@ mod tests {
             chairman_reasoning_effort: "xhigh".to_string(),
             chairman_retry_budget: 5,
             anonymize_reviews: true,
+            reviewers_per_candidate: 2,
+            chairman_top_candidates: 3,
+            verify_commands: vec!["cargo test".to_string()],
+            merge_mode: MergeMode::Hybrid,
+            worker_timeout_secs: Some(900),
+            early_exit: EarlyExitPolicy::FirstVerified,
+            chairman: Some(ChairmanConfig {
+                worker: Some("codex".to_string()),
+                timeout_secs: Some(300),
+                ..ChairmanConfig::default()
+            }),
             workers,
         };
 
@@ -322,6 +605,15 @@ mod tests {
             original.chairman_retry_budget
         );
         assert_eq!(round_trip.anonymize_reviews, original.anonymize_reviews);
+        assert_eq!(round_trip.reviewers_per_candidate, 2);
+        assert_eq!(round_trip.chairman_top_candidates, 3);
+        assert_eq!(round_trip.verify_commands, vec!["cargo test"]);
+        assert_eq!(round_trip.merge_mode, MergeMode::Hybrid);
+        assert_eq!(round_trip.worker_timeout_secs, Some(900));
+        assert_eq!(round_trip.early_exit, EarlyExitPolicy::FirstVerified);
+        let chairman = round_trip.chairman.as_ref().unwrap();
+        assert_eq!(chairman.worker.as_deref(), Some("codex"));
+        assert_eq!(chairman.timeout_secs, Some(300));
         assert_eq!(round_trip.workers.len(), 1);
 
         let worker = round_trip.workers.get("codex").unwrap();
@@ -349,6 +641,101 @@ mod tests {
         assert_eq!(CouncilConfig::default().chairman_retry_budget, 3);
     }
 
+    #[test]
+    fn test_council_config_tournament_defaults() {
+        let config = CouncilConfig::default();
+
+        assert_eq!(config.reviewers_per_candidate, 1);
+        assert_eq!(config.chairman_top_candidates, 2);
+        assert!(config.verify_commands.is_empty());
+        assert_eq!(config.merge_mode, MergeMode::Chairman);
+        assert!(config.worker_timeout().is_none());
+        assert_eq!(config.early_exit, EarlyExitPolicy::Off);
+    }
+
+    #[test]
+    fn test_council_config_parse_tournament_settings() {
+        let parsed: TestToml = toml::from_str(
+            r#"
+            [council]
+            enabled = true
+            reviewers_per_candidate = 2
+            chairman_top_candidates = 3
+            verify_commands = ["cargo test --quiet"]
+            merge_mode = "hybrid"
+            worker_timeout_secs = 600
+            early_exit = "first_verified"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            parsed.council.worker_timeout(),
+            Some(Duration::from_secs(600))
+        );
+        assert_eq!(parsed.council.early_exit, EarlyExitPolicy::FirstVerified);
+        assert_eq!(parsed.council.merge_mode, MergeMode::Hybrid);
+        assert_eq!(parsed.council.reviewers_per_candidate, 2);
+        assert_eq!(parsed.council.chairman_top_candidates, 3);
+        assert_eq!(parsed.council.verify_commands, vec!["cargo test --quiet"]);
+    }
+
+    #[test]
+    fn test_council_config_parse_chairman_worker_reference() {
+        let parsed: TestToml = toml::from_str(
+            r#"
+            [council]
+            enabled = true
+
+            [council.chairman]
+            worker = "claude"
+            model = "claude-opus-4"
+            flags = ["--print"]
+            timeout_secs = 600
+            "#,
+        )
+        .unwrap();
+
+        let chairman = parsed.council.chairman.as_ref().unwrap();
+        assert_eq!(chairman.mode, ChairmanMode::Llm);
+        assert_eq!(chairman.worker.as_deref(), Some("claude"));
+        assert_eq!(chairman.model.as_deref(), Some("claude-opus-4"));
+        assert_eq!(
+            chairman.flags.as_deref(),
+            Some(&["--print".to_string()][..])
+        );
+        assert!(!parsed.council.rule_based_chairman());
+        assert_eq!(
+            parsed.council.chairman_timeout(),
+            Some(Duration::from_secs(600))
+        );
+    }
+
+    #[test]
+    fn test_council_config_parse_rule_based_chairman() {
+        let parsed: TestToml = toml::from_str(
+            r#"
+            [council]
+            enabled = true
+
+            [council.chairman]
+            mode = "rules"
+            "#,
+        )
+        .unwrap();
+
+        assert!(parsed.council.rule_based_chairman());
+        assert!(parsed.council.chairman_timeout().is_none());
+    }
+
+    #[test]
+    fn test_council_config_chairman_section_optional() {
+        let config = CouncilConfig::default();
+
+        assert!(config.chairman.is_none());
+        assert!(!config.rule_based_chairman());
+    }
+
     #[test]
     fn test_council_config_anonymize_reviews_default_true() {
         assert!(CouncilConfig::default().anonymize_reviews);
@@ -1,15 +1,25 @@
 use anyhow::{Context, Result, anyhow};
+use futures::StreamExt;
 use futures::future::join_all;
+use futures::stream::FuturesUnordered;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tracing::info;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
 
 use crate::council::{
     chairman::Chairman,
-    config::CouncilConfig,
+    config::{CouncilConfig, EarlyExitPolicy, MergeMode},
+    hybrid::{FileSelection, plan_hybrid_merge},
     merge::{WorktreeManager, apply_patch},
+    prompts::generate_labels,
     reviewer::PeerReviewEngine,
-    types::{CouncilError, CouncilPhaseResult, MergeOutcome, WorkerResult},
+    tournament::{CandidateRanking, rank_candidates, score_matrix, verify_candidate},
+    types::{
+        CouncilError, CouncilPhaseResult, MergeOutcome, ReviewResult, ReviewVerdict, WorkerResult,
+        WorkerRun, WorkerRunStatus,
+    },
     worker::Worker,
 };
 use crate::phase::Phase;
@@ -17,7 +27,8 @@ use crate::phase::Phase;
 pub struct CouncilEngine {
     config: CouncilConfig,
     workers: Vec<Arc<dyn Worker>>,
-    chairman_worker: Arc<dyn Worker>,
+    /// `None` selects the rule-based chairman.
+    chairman_worker: Option<Arc<dyn Worker>>,
     repo_path: PathBuf,
 }
 
@@ -25,7 +36,7 @@ impl CouncilEngine {
     pub fn new(
         config: CouncilConfig,
         workers: Vec<Arc<dyn Worker>>,
-        chairman_worker: Arc<dyn Worker>,
+        chairman_worker: Option<Arc<dyn Worker>>,
         repo_path: PathBuf,
     ) -> Self {
         Self {
@@ -69,33 +80,158 @@ impl CouncilEngine {
         manager: &WorktreeManager,
         worktrees: &[WorkerWorktree],
     ) -> Result<CouncilPhaseResult> {
-        let executions = worktrees.iter().map(|worktree| {
-            let phase = phase.clone();
-            let prompt = prompt.to_string();
-            let worker = Arc::clone(&worktree.worker);
-            let path = worktree.path.clone();
-
-            async move {
-                let result = worker.execute(&phase, &prompt, &path).await;
-                (worker, path, result)
-            }
-        });
+        let timeout = self.config.worker_timeout();
+        let stage_started = Instant::now();
+        let mut pending = worktrees
+            .iter()
+            .enumerate()
+            .map(|(index, worktree)| {
+                let phase = phase.clone();
+                let prompt = prompt.to_string();
+                let worker = Arc::clone(&worktree.worker);
+                let path = worktree.path.clone();
+
+                async move {
+                    let started = Instant::now();
+                    let execution = worker.execute(&phase, &prompt, &path);
+                    let result = match timeout {
+                        Some(limit) => tokio::time::timeout(limit, execution).await.ok(),
+                        None => Some(execution.await),
+                    };
+                    (index, worker, path, result, started.elapsed())
+                }
+            })
+            .collect::<FuturesUnordered<_>>();
 
-        let mut successful_workers = Vec::new();
-        let mut worker_results = Vec::new();
+        let mut completed = Vec::new();
+        let mut worker_runs = Vec::new();
         let mut failures = Vec::new();
-
-        for (worker, worktree_path, execution) in join_all(executions).await {
-            match execution {
-                Ok(mut result) => {
-                    result.worker_name = worker.name().to_string();
+        // Early-exit reviews run alongside the remaining workers, so their
+        // output keeps draining and their wall time stays their own
+        let mut checks = FuturesUnordered::new();
+        let mut unchecked = Vec::new();
+
+        while !pending.is_empty() {
+            tokio::select! {
+                Some((index, worker, worktree_path, execution, wall_time)) = pending.next() => {
+                    let worker_name = worker.name().to_string();
+                    let mut result = match execution {
+                        Some(Ok(result)) => result,
+                        Some(Err(error)) => {
+                            worker_runs.push(worker_run(
+                                &worker_name,
+                                WorkerRunStatus::Failed,
+                                wall_time,
+                            ));
+                            failures.push((worker_name, error));
+                            continue;
+                        }
+                        None => {
+                            worker_runs.push(worker_run(
+                                &worker_name,
+                                WorkerRunStatus::TimedOut,
+                                wall_time,
+                            ));
+                            failures.push((
+                                worker_name,
+                                anyhow!("timed out after {}s", wall_time.as_secs()),
+                            ));
+                            continue;
+                        }
+                    };
+
+                    result.worker_name = worker_name.clone();
                     hydrate_diff_if_missing(manager, &worktree_path, &mut result)?;
-                    successful_workers.push(worker);
-                    worker_results.push(result);
+                    worker_runs.push(WorkerRun {
+                        token_usage: result.token_usage.clone(),
+                        ..worker_run(&worker_name, WorkerRunStatus::Completed, wall_time)
+                    });
+                    completed.push((index, worker, worktree_path, result));
+
+                    if self.config.early_exit == EarlyExitPolicy::FirstVerified {
+                        // Only a worker that has finished may review a candidate
+                        unchecked.push(completed.len() - 1);
+                        unchecked.retain(|&position| {
+                            let (_, _, worktree_path, candidate) = &completed[position];
+                            let Some(reviewer) = completed
+                                .iter()
+                                .map(|(_, worker, ..)| worker)
+                                .find(|worker| worker.name() != candidate.worker_name)
+                            else {
+                                return true;
+                            };
+                            checks.push(self.early_exit_review(
+                                phase,
+                                position,
+                                candidate.clone(),
+                                worktree_path.clone(),
+                                Arc::clone(reviewer),
+                            ));
+                            false
+                        });
+                    }
+                }
+                Some((position, review)) = checks.next() => {
+                    let Some(review) = review else {
+                        continue;
+                    };
+                    let (_, _, _, candidate) = &completed[position];
+                    let Some(winning_diff) = self.apply_early_exit(candidate) else {
+                        continue;
+                    };
+
+                    drop(pending);
+                    drop(checks);
+                    for worktree in worktrees {
+                        let name = worktree.worker.name();
+                        if !worker_runs.iter().any(|run| run.worker_name == name) {
+                            worker_runs.push(worker_run(
+                                name,
+                                WorkerRunStatus::Cancelled,
+                                stage_started.elapsed(),
+                            ));
+                        }
+                    }
+
+                    info!(
+                        phase_number = %phase.number,
+                        phase_name = %phase.name,
+                        accepted_worker = %candidate.worker_name,
+                        reviewer = %review.reviewer_name,
+                        "Council early exit: accepted first verified and approved candidate"
+                    );
+
+                    completed.sort_by_key(|(index, ..)| *index);
+
+                    return Ok(CouncilPhaseResult {
+                        winning_diff: winning_diff.clone(),
+                        worker_results: completed
+                            .into_iter()
+                            .map(|(_, _, _, result)| result)
+                            .collect(),
+                        review_results: vec![review],
+                        merge_outcome: MergeOutcome::Clean(winning_diff),
+                        merge_attempts: 1,
+                        score_matrix: Vec::new(),
+                        rankings: Vec::new(),
+                        hybrid_selections: Vec::new(),
+                        worker_runs,
+                        early_exit: true,
+                    });
                 }
-                Err(error) => failures.push((worker.name().to_string(), error)),
             }
         }
+        drop(checks);
+
+        completed.sort_by_key(|(index, ..)| *index);
+        let mut successful_workers = Vec::new();
+        let mut successful_paths = Vec::new();
+        let mut worker_results = Vec::new();
+        for (_, worker, worktree_path, result) in completed {
+            successful_workers.push(worker);
+            successful_paths.push(worktree_path);
+            worker_results.push(result);
+        }
 
         if worker_results.is_empty() {
             let summary = failures
@@ -116,15 +252,12 @@ impl CouncilEngine {
                 "Council fallback: using single worker output directly"
             );
 
-            return build_single_worker_result(&self.repo_path, worker_results);
+            return build_single_worker_result(&self.repo_path, worker_results, worker_runs);
         }
 
-        if worker_results.len() != 2 {
-            return Err(anyhow!(CouncilError::ReviewFailed)).context(format!(
-                "peer review requires exactly two successful workers, got {}",
-                worker_results.len()
-            ));
-        }
+        let tests_passed = self
+            .verify_candidates(&worker_results, &successful_paths)
+            .await;
 
         info!(
             phase_number = %phase.number,
@@ -137,20 +270,80 @@ impl CouncilEngine {
             .await
             .context("council peer review failed")?;
 
+        let rankings = rank_candidates(
+            &worker_results,
+            &review_round.reviews,
+            &review_round.label_map,
+            &tests_passed,
+        );
+        let score_matrix = score_matrix(&review_round.reviews, &review_round.label_map);
+
+        if self.config.merge_mode == MergeMode::Hybrid {
+            info!(
+                phase_number = %phase.number,
+                phase_name = %phase.name,
+                review_count = review_round.reviews.len(),
+                "Council stage 3: hybrid merge"
+            );
+
+            let merge = self
+                .hybrid_merge(phase, manager, &worker_results, &rankings)
+                .await
+                .context("council hybrid merge failed")?;
+
+            return Ok(CouncilPhaseResult {
+                winning_diff: merge.patch,
+                worker_results,
+                review_results: review_round.reviews,
+                merge_outcome: merge.outcome,
+                merge_attempts: merge.attempts,
+                score_matrix,
+                rankings,
+                hybrid_selections: merge.selections,
+                worker_runs,
+                early_exit: false,
+            });
+        }
+        let finalists = rankings
+            .iter()
+            .take(self.config.chairman_top_candidates.max(2))
+            .cloned()
+            .collect::<Vec<_>>();
+        let finalist_results = finalists
+            .iter()
+            .filter_map(|finalist| {
+                worker_results
+                    .iter()
+                    .find(|result| result.worker_name == finalist.worker_name)
+                    .cloned()
+            })
+            .collect::<Vec<_>>();
+        let finalist_reviews = review_round
+            .reviews
+            .iter()
+            .filter(|review| {
+                finalists
+                    .iter()
+                    .any(|finalist| finalist.candidate_label == review.candidate_label)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
         info!(
             phase_number = %phase.number,
             phase_name = %phase.name,
             review_count = review_round.reviews.len(),
+            finalist_count = finalists.len(),
             "Council stage 3: chairman synthesis"
         );
 
-        let synthesis = Chairman::new(&self.config, Arc::clone(&self.chairman_worker))
-            .synthesize(
-                phase,
-                &worker_results,
-                &review_round.reviews,
-                &self.repo_path,
-            )
+        let chairman = match &self.chairman_worker {
+            Some(worker) => Chairman::new(&self.config, Arc::clone(worker)),
+            None => Chairman::rule_based(&self.config),
+        };
+        let synthesis = chairman
+            .with_rankings(finalists)
+            .synthesize(phase, &finalist_results, &finalist_reviews, &self.repo_path)
             .await
             .context("council chairman synthesis failed")?;
         let winning_diff = synthesis
@@ -164,9 +357,213 @@ impl CouncilEngine {
             review_results: review_round.reviews,
             merge_outcome: synthesis.merge_outcome,
             merge_attempts: synthesis.attempts,
+            score_matrix,
+            rankings,
+            hybrid_selections: Vec::new(),
+            worker_runs,
+            early_exit: false,
         })
     }
 
+    /// Verify `candidate` and ask `reviewer`, a worker that has already
+    /// finished, to approve it for early exit. Returns the candidate's
+    /// position with the approving review.
+    async fn early_exit_review(
+        &self,
+        phase: &Phase,
+        position: usize,
+        candidate: WorkerResult,
+        worktree_path: PathBuf,
+        reviewer: Arc<dyn Worker>,
+    ) -> (usize, Option<ReviewResult>) {
+        match verify_candidate(&self.config.verify_commands, &worktree_path).await {
+            Ok(Some(true)) => {}
+            Ok(_) => return (position, None),
+            Err(error) => {
+                warn!(worker = %candidate.worker_name, "Council early-exit verification failed to run: {error:#}");
+                return (position, None);
+            }
+        }
+
+        let candidate_label = if self.config.anonymize_reviews {
+            generate_labels(1).remove(0)
+        } else {
+            candidate.worker_name.clone()
+        };
+        let patch = candidate.patch();
+        let review = reviewer.review(phase, &patch, &candidate_label);
+        let review = match self.config.worker_timeout() {
+            Some(limit) => tokio::time::timeout(limit, review)
+                .await
+                .unwrap_or_else(|_| Err(anyhow!("timed out after {}s", limit.as_secs()))),
+            None => review.await,
+        };
+        let mut review = match review {
+            Ok(review) => review,
+            Err(error) => {
+                warn!(reviewer = %reviewer.name(), "Council early-exit review failed: {error:#}");
+                return (position, None);
+            }
+        };
+        if !matches!(review.verdict, ReviewVerdict::Approve) {
+            return (position, None);
+        }
+        review.reviewer_name = reviewer.name().to_string();
+        review.candidate_label = candidate_label;
+
+        (position, Some(review))
+    }
+
+    /// Apply an approved early-exit candidate, returning the applied diff.
+    fn apply_early_exit(&self, candidate: &WorkerResult) -> Option<String> {
+        match apply_patch(&self.repo_path, &candidate.patch()) {
+            Ok(MergeOutcome::Clean(diff)) => Some(diff),
+            Ok(outcome) => {
+                warn!(worker = %candidate.worker_name, "Council early-exit patch did not apply: {outcome:?}");
+                None
+            }
+            Err(error) => {
+                warn!(worker = %candidate.worker_name, "Council early-exit patch failed: {error:#}");
+                None
+            }
+        }
+    }
+
+    /// Compose a per-file hybrid patch, verify it in a scratch worktree and
+    /// apply it; fall back to the top-ranked candidate if the composite fails
+    /// or there are no `verify_commands` to check it with.
+    async fn hybrid_merge(
+        &self,
+        phase: &Phase,
+        manager: &WorktreeManager,
+        worker_results: &[WorkerResult],
+        rankings: &[CandidateRanking],
+    ) -> Result<HybridMergeResult> {
+        let plan = plan_hybrid_merge(worker_results, rankings)
+            .ok_or_else(|| anyhow!(CouncilError::EscalationRequired))
+            .context("no council candidate produced a usable patch")?;
+        let contributors = plan.contributors();
+        let composite = contributors.len() > 1 && !self.config.verify_commands.is_empty();
+        if contributors.len() > 1 && !composite {
+            warn!(
+                phase_number = %phase.number,
+                "Council hybrid patch skipped: no verify_commands to check it, applying best candidate"
+            );
+        }
+
+        if composite {
+            let scratch = hybrid_worktree_name(phase);
+            let scratch_path = manager
+                .create_worktree(&scratch)
+                .context("failed to create hybrid scratch worktree")?;
+            let verified =
+                verify_composite(&self.config.verify_commands, &scratch_path, &plan.patch).await;
+            if let Err(error) = manager.remove_worktree(&scratch) {
+                warn!(
+                    phase_number = %phase.number,
+                    "Failed to remove hybrid scratch worktree `{scratch}`: {error:#}"
+                );
+            }
+
+            match verified {
+                Ok(()) => match apply_patch(&self.repo_path, &plan.patch)
+                    .context("failed to apply hybrid patch")?
+                {
+                    MergeOutcome::Clean(diff) => {
+                        info!(
+                            phase_number = %phase.number,
+                            contributors = %contributors.join(", "),
+                            file_count = plan.selections.len(),
+                            "Council hybrid patch applied"
+                        );
+                        return Ok(HybridMergeResult {
+                            patch: diff.clone(),
+                            outcome: MergeOutcome::Clean(diff),
+                            attempts: 1,
+                            selections: plan.selections,
+                        });
+                    }
+                    outcome => warn!(
+                        phase_number = %phase.number,
+                        "Council hybrid patch did not apply to the repository: {outcome:?}"
+                    ),
+                },
+                Err(error) => warn!(
+                    phase_number = %phase.number,
+                    "Council hybrid patch rejected, falling back to best candidate: {error:#}"
+                ),
+            }
+        }
+
+        let attempts = if composite { 2 } else { 1 };
+        let best = rankings
+            .iter()
+            .filter(|ranking| ranking.valid_patch)
+            .find_map(|ranking| {
+                worker_results
+                    .iter()
+                    .find(|result| result.worker_name == ranking.worker_name)
+            })
+            .ok_or_else(|| anyhow!(CouncilError::EscalationRequired))
+            .context("no ranked council candidate is available for fallback")?;
+
+        match apply_patch(&self.repo_path, &best.patch())
+            .with_context(|| format!("failed to apply patch from `{}`", best.worker_name))?
+        {
+            MergeOutcome::Clean(diff) => Ok(HybridMergeResult {
+                patch: diff.clone(),
+                outcome: MergeOutcome::Clean(diff),
+                attempts,
+                selections: Vec::new(),
+            }),
+            MergeOutcome::Conflict(paths) => Err(anyhow!(CouncilError::EscalationRequired))
+                .context(format!(
+                    "best candidate `{}` conflicted in: {}",
+                    best.worker_name,
+                    paths.join(", ")
+                )),
+            MergeOutcome::Failure(message) => Err(anyhow!(CouncilError::EscalationRequired))
+                .context(format!(
+                    "best candidate `{}` failed to apply: {message}",
+                    best.worker_name
+                )),
+        }
+    }
+
+    /// Run `verify_commands` in every candidate worktree concurrently.
+    ///
+    /// Candidates whose verification could not be run are left out of the map
+    /// and rank as "unknown" rather than failing the council.
+    async fn verify_candidates(
+        &self,
+        worker_results: &[WorkerResult],
+        worktree_paths: &[PathBuf],
+    ) -> HashMap<String, bool> {
+        let commands = &self.config.verify_commands;
+        let verifications =
+            worker_results
+                .iter()
+                .zip(worktree_paths)
+                .map(|(result, path)| async move {
+                    (
+                        result.worker_name.clone(),
+                        verify_candidate(commands, path).await,
+                    )
+                });
+
+        join_all(verifications)
+            .await
+            .into_iter()
+            .filter_map(|(worker_name, outcome)| match outcome {
+                Ok(passed) => passed.map(|passed| (worker_name, passed)),
+                Err(error) => {
+                    warn!(worker = %worker_name, "Council verification failed to run: {error:#}");
+                    None
+                }
+            })
+            .collect()
+    }
+
     fn create_worktrees(
         &self,
         manager: &WorktreeManager,
@@ -195,6 +592,36 @@ impl CouncilEngine {
     }
 }
 
+struct HybridMergeResult {
+    patch: String,
+    outcome: MergeOutcome,
+    attempts: u32,
+    selections: Vec<FileSelection>,
+}
+
+/// Apply `patch` in a scratch worktree and run the verification commands there.
+///
+/// `verify_commands` are the only checks that can run before the patch is
+/// applied: a phase has no commands of its own, and its promise and gating
+/// reviews run on the applied council result exactly as they do for a
+/// single-candidate patch, so a composite that fails them goes through the
+/// phase's usual fix iterations.
+async fn verify_composite(commands: &[String], scratch_path: &Path, patch: &str) -> Result<()> {
+    match apply_patch(scratch_path, patch).context("failed to apply hybrid patch to scratch")? {
+        MergeOutcome::Clean(_) => {}
+        MergeOutcome::Conflict(paths) => {
+            anyhow::bail!("hybrid patch conflicted in: {}", paths.join(", "))
+        }
+        MergeOutcome::Failure(message) => anyhow::bail!("hybrid patch failed to apply: {message}"),
+    }
+
+    if verify_candidate(commands, scratch_path).await? == Some(false) {
+        anyhow::bail!("verification commands failed on the hybrid patch");
+    }
+
+    Ok(())
+}
+
 #[derive(Clone)]
 struct WorkerWorktree {
     name: String,
@@ -235,8 +662,9 @@ fn hydrate_diff_if_missing(
 fn build_single_worker_result(
     repo_path: &Path,
     worker_results: Vec<WorkerResult>,
+    worker_runs: Vec<WorkerRun>,
 ) -> Result<CouncilPhaseResult> {
-    let winning_diff = worker_patch(&worker_results[0]);
+    let winning_diff = worker_results[0].patch();
     let merge_outcome =
         apply_patch(repo_path, &winning_diff).context("failed to apply single-worker patch")?;
 
@@ -247,6 +675,11 @@ fn build_single_worker_result(
             review_results: Vec::new(),
             merge_outcome: MergeOutcome::Clean(diff),
             merge_attempts: 0,
+            score_matrix: Vec::new(),
+            rankings: Vec::new(),
+            hybrid_selections: Vec::new(),
+            worker_runs,
+            early_exit: false,
         }),
         MergeOutcome::Conflict(paths) => Err(anyhow!(CouncilError::MergeFailed)).context(format!(
             "single-worker patch conflicted in: {}",
@@ -257,11 +690,12 @@ fn build_single_worker_result(
     }
 }
 
-fn worker_patch(result: &WorkerResult) -> String {
-    if result.diff_text.trim().is_empty() {
-        result.raw_output.clone()
-    } else {
-        result.diff_text.clone()
+fn worker_run(worker_name: &str, status: WorkerRunStatus, wall_time: Duration) -> WorkerRun {
+    WorkerRun {
+        worker_name: worker_name.to_string(),
+        status,
+        wall_time,
+        token_usage: None,
     }
 }
 
@@ -274,6 +708,10 @@ fn worktree_name(phase: &Phase, worker_name: &str, index: usize) -> String {
     )
 }
 
+fn hybrid_worktree_name(phase: &Phase) -> String {
+    format!("phase-{}-hybrid", sanitize_name(&phase.number))
+}
+
 fn sanitize_name(value: &str) -> String {
     value
         .chars()
@@ -667,6 +1105,480 @@ mod tests {
         assert!(matches!(result.merge_outcome, MergeOutcome::Clean(_)));
     }
 
+    #[tokio::test]
+    async fn test_engine_three_workers_chairman_sees_top_two() {
+        let (_dir, repo_path) = create_test_repo();
+        let worker_a = Arc::new(
+            MockWorker::new("worker-a")
+                .with_execute_result(worker_result("worker-a", &add_file_patch("A.md", "alpha")))
+                .with_review_result(review_result(
+                    "worker-a",
+                    "ignored",
+                    ReviewVerdict::RequestChanges("weak".to_string()),
+                    0.3,
+                    "review from worker-a",
+                )),
+        );
+        let worker_b = Arc::new(
+            MockWorker::new("worker-b")
+                .with_execute_result(worker_result("worker-b", &add_file_patch("B.md", "beta")))
+                .with_review_result(review_result(
+                    "worker-b",
+                    "ignored",
+                    ReviewVerdict::Approve,
+                    0.9,
+                    "review from worker-b",
+                )),
+        );
+        let worker_c = Arc::new(
+            MockWorker::new("worker-c")
+                .with_execute_result(worker_result("worker-c", &add_file_patch("C.md", "gamma")))
+                .with_review_result(review_result(
+                    "worker-c",
+                    "ignored",
+                    ReviewVerdict::Approve,
+                    0.8,
+                    "review from worker-c",
+                )),
+        );
+        let chairman = Arc::new(
+            MockWorker::new("chairman").with_execute_result(worker_result(
+                "chairman",
+                &add_file_patch("MERGED.md", "merged result"),
+            )),
+        );
+        let engine = engine(
+            &repo_path,
+            vec![worker_a.clone(), worker_b.clone(), worker_c.clone()],
+            chairman.clone(),
+        );
+
+        let result = engine
+            .run_phase(&test_phase(), "implement the phase")
+            .await
+            .expect("three-worker council should succeed");
+
+        assert_eq!(result.worker_results.len(), 3);
+        assert_eq!(result.review_results.len(), 3);
+        assert_eq!(result.score_matrix.len(), 3);
+        let order = result
+            .rankings
+            .iter()
+            .map(|ranking| ranking.worker_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["worker-a", "worker-b", "worker-c"]);
+
+        let prompt = chairman
+            .execute_prompts()
+            .last()
+            .cloned()
+            .expect("chairman prompt should be recorded");
+        assert!(prompt.contains("A.md"));
+        assert!(prompt.contains("B.md"));
+        assert!(!prompt.contains("C.md"));
+        assert!(prompt.contains("Tournament ranking"));
+        assert!(!prompt.contains("worker-a"));
+    }
+
+    #[tokio::test]
+    async fn test_engine_verify_commands_break_ties() {
+        let (_dir, repo_path) = create_test_repo();
+        let worker_a = Arc::new(
+            MockWorker::new("worker-a")
+                .with_execute_result(worker_result("worker-a", &add_file_patch("A.md", "alpha"))),
+        );
+        let worker_b = Arc::new(
+            MockWorker::new("worker-b")
+                .with_execute_result(worker_result("worker-b", &add_file_patch("B.md", "beta"))),
+        );
+        let chairman = Arc::new(
+            MockWorker::new("chairman").with_execute_result(worker_result(
+                "chairman",
+                &add_file_patch("MERGED.md", "merged result"),
+            )),
+        );
+        let workers: Vec<Arc<dyn Worker>> = vec![worker_a, worker_b];
+        let config = CouncilConfig {
+            verify_commands: vec!["pwd | grep -q worker-b".to_string()],
+            ..CouncilConfig::default()
+        };
+        let engine = CouncilEngine::new(config, workers, Some(chairman), repo_path.clone());
+
+        let result = engine
+            .run_phase(&test_phase(), "implement the phase")
+            .await
+            .expect("engine should succeed");
+
+        assert_eq!(result.rankings[0].worker_name, "worker-b");
+        assert_eq!(result.rankings[0].tests_passed, Some(true));
+        assert_eq!(result.rankings[1].tests_passed, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_engine_hybrid_merge_composes_best_file_per_candidate() {
+        let (_dir, repo_path) = create_test_repo();
+        let chairman = Arc::new(MockWorker::new("chairman"));
+        let engine = hybrid_engine(&repo_path, vec!["true".to_string()], chairman.clone());
+
+        let result = engine
+            .run_phase(&test_phase(), "implement the phase")
+            .await
+            .expect("hybrid merge should succeed");
+
+        assert_eq!(result.merge_attempts, 1);
+        assert!(chairman.execute_prompts().is_empty());
+        let selected = result
+            .hybrid_selections
+            .iter()
+            .map(|selection| (selection.path.as_str(), selection.worker_name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            selected,
+            vec![
+                ("B.md", "worker-b"),
+                ("SHARED.md", "worker-b"),
+                ("A.md", "worker-a"),
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(repo_path.join("SHARED.md")).unwrap(),
+            "from b\n"
+        );
+        assert!(repo_path.join("A.md").exists());
+        assert!(repo_path.join("B.md").exists());
+        assert_eq!(result.to_audit_data().chairman_decision, "hybrid_merge");
+    }
+
+    #[tokio::test]
+    async fn test_engine_hybrid_merge_applies_best_candidate_without_verify_commands() {
+        let (_dir, repo_path) = create_test_repo();
+        let chairman = Arc::new(MockWorker::new("chairman"));
+        let engine = hybrid_engine(&repo_path, Vec::new(), chairman.clone());
+
+        let result = engine
+            .run_phase(&test_phase(), "implement the phase")
+            .await
+            .expect("best candidate should be applied");
+
+        assert_eq!(result.merge_attempts, 1);
+        assert!(result.hybrid_selections.is_empty());
+        assert!(repo_path.join("B.md").exists());
+        assert!(!repo_path.join("A.md").exists());
+        assert!(
+            !repo_path
+                .join(".forge/council-worktrees/phase-11-hybrid")
+                .exists()
+        );
+        let audit = result.to_audit_data();
+        assert_eq!(audit.chairman_decision, "winner_takes_all");
+        assert_eq!(audit.winning_worker.as_deref(), Some("worker-b"));
+    }
+
+    #[tokio::test]
+    async fn test_engine_hybrid_merge_falls_back_when_composite_fails_verification() {
+        let (_dir, repo_path) = create_test_repo();
+        let chairman = Arc::new(MockWorker::new("chairman"));
+        let engine = hybrid_engine(
+            &repo_path,
+            vec!["test ! -f A.md".to_string()],
+            chairman.clone(),
+        );
+
+        let result = engine
+            .run_phase(&test_phase(), "implement the phase")
+            .await
+            .expect("fallback to best candidate should succeed");
+
+        assert_eq!(result.merge_attempts, 2);
+        assert!(result.hybrid_selections.is_empty());
+        assert!(repo_path.join("B.md").exists());
+        assert!(!repo_path.join("A.md").exists());
+        assert!(
+            !repo_path
+                .join(".forge/council-worktrees/phase-11-hybrid")
+                .exists()
+        );
+        let audit = result.to_audit_data();
+        assert_eq!(audit.chairman_decision, "winner_takes_all");
+        assert_eq!(audit.winning_worker.as_deref(), Some("worker-b"));
+    }
+
+    #[tokio::test]
+    async fn test_engine_rule_based_chairman_applies_best_candidate() {
+        let (_dir, repo_path) = create_test_repo();
+        let worker_a = Arc::new(
+            MockWorker::new("worker-a")
+                .with_execute_result(worker_result("worker-a", &add_file_patch("A.md", "alpha")))
+                .with_review_result(review_result(
+                    "worker-a",
+                    "Candidate Beta",
+                    ReviewVerdict::Approve,
+                    0.92,
+                    "worker-a approves beta",
+                )),
+        );
+        let worker_b = Arc::new(
+            MockWorker::new("worker-b")
+                .with_execute_result(worker_result("worker-b", &add_file_patch("B.md", "beta")))
+                .with_review_result(review_result(
+                    "worker-b",
+                    "Candidate Alpha",
+                    ReviewVerdict::Approve,
+                    0.88,
+                    "worker-b approves alpha",
+                )),
+        );
+        let workers: Vec<Arc<dyn Worker>> = vec![worker_a, worker_b];
+        let engine = CouncilEngine::new(CouncilConfig::default(), workers, None, repo_path.clone());
+
+        let result = engine
+            .run_phase(&test_phase(), "implement the phase")
+            .await
+            .expect("rule-based chairman should succeed");
+
+        assert_eq!(result.merge_attempts, 1);
+        assert!(repo_path.join("B.md").exists());
+        assert!(!repo_path.join("A.md").exists());
+        assert_eq!(
+            result.to_audit_data().winning_worker.as_deref(),
+            Some("worker-b")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_engine_worker_timeout_cancels_slow_worker() {
+        let (_dir, repo_path) = create_test_repo();
+        let worker_a = Arc::new(
+            MockWorker::new("worker-a")
+                .with_execute_result(worker_result("worker-a", &add_file_patch("A.md", "alpha"))),
+        );
+        let worker_b = Arc::new(
+            MockWorker::new("worker-b")
+                .with_execute_result(worker_result("worker-b", &add_file_patch("B.md", "beta")))
+                .with_execute_delay(Duration::from_secs(30)),
+        );
+        let workers: Vec<Arc<dyn Worker>> = vec![worker_a, worker_b];
+        let config = CouncilConfig {
+            worker_timeout_secs: Some(1),
+            ..CouncilConfig::default()
+        };
+        let chairman = Arc::new(MockWorker::new("chairman"));
+        let engine = CouncilEngine::new(config, workers, Some(chairman), repo_path.clone());
+
+        let result = timeout(
+            Duration::from_secs(10),
+            engine.run_phase(&test_phase(), "implement the phase"),
+        )
+        .await
+        .expect("timeout should bound the slow worker")
+        .expect("surviving worker should be used");
+
+        assert_eq!(result.worker_results.len(), 1);
+        assert!(repo_path.join("A.md").exists());
+        let audit = result.to_audit_data();
+        let status = |name: &str| {
+            audit
+                .worker_runs
+                .iter()
+                .find(|run| run.worker_name == name)
+                .map(|run| run.status)
+        };
+        assert_eq!(status("worker-a"), Some(WorkerRunStatus::Completed));
+        assert_eq!(status("worker-b"), Some(WorkerRunStatus::TimedOut));
+    }
+
+    #[tokio::test]
+    async fn test_engine_early_exit_accepts_first_verified_candidate() {
+        let (_dir, repo_path) = create_test_repo();
+        let worker_a = Arc::new(
+            MockWorker::new("worker-a")
+                .with_execute_result(worker_result("worker-a", &add_file_patch("A.md", "alpha")))
+                .with_review_result(review_result(
+                    "worker-a",
+                    "Candidate Alpha",
+                    ReviewVerdict::RequestChanges("not yet".to_string()),
+                    0.2,
+                    "worker-a rejects bravo",
+                )),
+        );
+        let worker_b = Arc::new(
+            MockWorker::new("worker-b")
+                .with_execute_result(worker_result("worker-b", &add_file_patch("B.md", "beta")))
+                .with_execute_delay(Duration::from_millis(100))
+                .with_review_result(review_result(
+                    "worker-b",
+                    "Candidate Alpha",
+                    ReviewVerdict::Approve,
+                    0.9,
+                    "worker-b approves alpha",
+                )),
+        );
+        let worker_c = Arc::new(
+            MockWorker::new("worker-c")
+                .with_execute_result(worker_result("worker-c", &add_file_patch("C.md", "gamma")))
+                .with_execute_delay(Duration::from_secs(30)),
+        );
+        let workers: Vec<Arc<dyn Worker>> = vec![worker_a, worker_b, worker_c.clone()];
+        let config = CouncilConfig {
+            verify_commands: vec!["true".to_string()],
+            early_exit: EarlyExitPolicy::FirstVerified,
+            ..CouncilConfig::default()
+        };
+        let chairman = Arc::new(MockWorker::new("chairman"));
+        let engine = CouncilEngine::new(config, workers, Some(chairman.clone()), repo_path.clone());
+
+        let result = timeout(
+            Duration::from_secs(10),
+            engine.run_phase(&test_phase(), "implement the phase"),
+        )
+        .await
+        .expect("early exit should not wait for the slow worker")
+        .expect("early exit should succeed");
+
+        assert!(result.early_exit);
+        assert_eq!(result.review_results.len(), 1);
+        assert_eq!(result.review_results[0].reviewer_name, "worker-b");
+        assert!(repo_path.join("A.md").exists());
+        assert!(chairman.execute_prompts().is_empty());
+        assert_eq!(worker_c.review_count(), 0);
+        let audit = result.to_audit_data();
+        assert_eq!(audit.chairman_decision, "early_exit");
+        assert_eq!(audit.winning_worker.as_deref(), Some("worker-a"));
+        assert!(
+            audit.worker_runs.iter().any(
+                |run| run.worker_name == "worker-c" && run.status == WorkerRunStatus::Cancelled
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_engine_early_exit_never_asks_a_running_worker_to_review() {
+        let (_dir, repo_path) = create_test_repo();
+        let worker_a = Arc::new(
+            MockWorker::new("worker-a")
+                .with_execute_result(worker_result("worker-a", &add_file_patch("A.md", "alpha"))),
+        );
+        let worker_b = Arc::new(
+            MockWorker::new("worker-b")
+                .with_execute_result(worker_result("worker-b", &add_file_patch("B.md", "beta")))
+                .with_execute_delay(Duration::from_secs(30)),
+        );
+        let workers: Vec<Arc<dyn Worker>> = vec![worker_a, worker_b.clone()];
+        let config = CouncilConfig {
+            verify_commands: vec!["true".to_string()],
+            early_exit: EarlyExitPolicy::FirstVerified,
+            worker_timeout_secs: Some(1),
+            ..CouncilConfig::default()
+        };
+        let engine = CouncilEngine::new(config, workers, None, repo_path.clone());
+
+        let result = engine
+            .run_phase(&test_phase(), "implement the phase")
+            .await
+            .expect("the finished worker should be used on its own");
+
+        assert!(!result.early_exit);
+        assert_eq!(worker_b.review_count(), 0);
+        let audit = result.to_audit_data();
+        assert!(
+            audit.worker_runs.iter().any(
+                |run| run.worker_name == "worker-b" && run.status == WorkerRunStatus::TimedOut
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_engine_early_exit_requires_passing_verification() {
+        let (_dir, repo_path) = create_test_repo();
+        let worker_a = Arc::new(
+            MockWorker::new("worker-a")
+                .with_execute_result(worker_result("worker-a", &add_file_patch("A.md", "alpha"))),
+        );
+        let worker_b = Arc::new(
+            MockWorker::new("worker-b")
+                .with_execute_result(worker_result("worker-b", &add_file_patch("B.md", "beta")))
+                .with_execute_delay(Duration::from_millis(200)),
+        );
+        let workers: Vec<Arc<dyn Worker>> = vec![worker_a, worker_b];
+        let config = CouncilConfig {
+            verify_commands: vec!["false".to_string()],
+            early_exit: EarlyExitPolicy::FirstVerified,
+            ..CouncilConfig::default()
+        };
+        let chairman = Arc::new(
+            MockWorker::new("chairman").with_execute_result(worker_result(
+                "chairman",
+                &add_file_patch("MERGED.md", "merged result"),
+            )),
+        );
+        let engine = CouncilEngine::new(config, workers, Some(chairman), repo_path.clone());
+
+        let result = engine
+            .run_phase(&test_phase(), "implement the phase")
+            .await
+            .expect("full council should run");
+
+        assert!(!result.early_exit);
+        assert_eq!(result.worker_results.len(), 2);
+        assert!(
+            result
+                .worker_runs
+                .iter()
+                .all(|run| run.status == WorkerRunStatus::Completed)
+        );
+    }
+
+    fn hybrid_engine(
+        repo_path: &Path,
+        verify_commands: Vec<String>,
+        chairman: Arc<MockWorker>,
+    ) -> CouncilEngine {
+        let worker_a = Arc::new(
+            MockWorker::new("worker-a")
+                .with_execute_result(worker_result(
+                    "worker-a",
+                    &format!(
+                        "{}{}",
+                        add_file_patch("A.md", "alpha"),
+                        add_file_patch("SHARED.md", "from a")
+                    ),
+                ))
+                .with_review_result(review_result(
+                    "worker-a",
+                    "Candidate Beta",
+                    ReviewVerdict::Approve,
+                    0.92,
+                    "worker-a approves beta",
+                )),
+        );
+        let worker_b = Arc::new(
+            MockWorker::new("worker-b")
+                .with_execute_result(worker_result(
+                    "worker-b",
+                    &format!(
+                        "{}{}",
+                        add_file_patch("B.md", "beta"),
+                        add_file_patch("SHARED.md", "from b")
+                    ),
+                ))
+                .with_review_result(review_result(
+                    "worker-b",
+                    "Candidate Alpha",
+                    ReviewVerdict::Approve,
+                    0.88,
+                    "worker-b approves alpha",
+                )),
+        );
+        let workers: Vec<Arc<dyn Worker>> = vec![worker_a, worker_b];
+        let config = CouncilConfig {
+            merge_mode: MergeMode::Hybrid,
+            verify_commands,
+            ..CouncilConfig::default()
+        };
+
+        CouncilEngine::new(config, workers, Some(chairman), repo_path.to_path_buf())
+    }
+
     async fn run_successful_engine() -> CouncilPhaseResult {
         let (_dir, repo_path) = create_test_repo();
         let worker_a = Arc::new(
@@ -719,7 +1631,7 @@ mod tests {
         CouncilEngine::new(
             CouncilConfig::default(),
             workers,
-            chairman,
+            Some(chairman),
             repo_path.to_path_buf(),
         )
     }
@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+
+use crate::council::merge::PatchSet;
+use crate::council::tournament::CandidateRanking;
+use crate::council::types::WorkerResult;
+
+/// Which candidate supplied a file in a hybrid composite patch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileSelection {
+    pub path: String,
+    pub worker_name: String,
+}
+
+/// A composite patch assembled file-by-file from several candidates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HybridPlan {
+    pub patch: String,
+    pub selections: Vec<FileSelection>,
+}
+
+impl HybridPlan {
+    /// Names of the candidates that contributed at least one file, in rank order.
+    pub fn contributors(&self) -> Vec<&str> {
+        let mut contributors: Vec<&str> = Vec::new();
+        for selection in &self.selections {
+            if !contributors.contains(&selection.worker_name.as_str()) {
+                contributors.push(&selection.worker_name);
+            }
+        }
+        contributors
+    }
+}
+
+/// Pick the best candidate for every file touched by an eligible candidate.
+///
+/// Candidates are visited in rank order and each file is claimed by the
+/// highest-ranked candidate that changes it, so the selections never overlap.
+/// The top-ranked candidate is always eligible; lower-ranked candidates must
+/// have a valid patch and at least one peer approval to contribute files.
+/// Returns `None` when no candidate has a usable patch.
+pub fn plan_hybrid_merge(
+    worker_results: &[WorkerResult],
+    rankings: &[CandidateRanking],
+) -> Option<HybridPlan> {
+    let mut patch = String::new();
+    let mut selections: Vec<FileSelection> = Vec::new();
+
+    for (index, ranking) in rankings.iter().enumerate() {
+        if !ranking.valid_patch || (index > 0 && ranking.approvals == 0) {
+            continue;
+        }
+        let Some(result) = worker_results
+            .iter()
+            .find(|result| result.worker_name == ranking.worker_name)
+        else {
+            continue;
+        };
+        let Ok(patch_set) = PatchSet::parse(&result.patch()) else {
+            continue;
+        };
+
+        for path in patch_set.files_changed() {
+            if selections.iter().any(|selection| selection.path == path) {
+                continue;
+            }
+            let Some(file_diff) = patch_set.file_diff(path) else {
+                continue;
+            };
+
+            patch.push_str(file_diff);
+            if !patch.ends_with('\n') {
+                patch.push('\n');
+            }
+            selections.push(FileSelection {
+                path: path.to_string(),
+                worker_name: ranking.worker_name.clone(),
+            });
+        }
+    }
+
+    if selections.is_empty() {
+        return None;
+    }
+
+    Some(HybridPlan { patch, selections })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::council::types::ReviewScores;
+    use std::time::Duration;
+
+    fn worker_result(worker_name: &str, diff_text: &str) -> WorkerResult {
+        WorkerResult {
+            worker_name: worker_name.to_string(),
+            diff_text: diff_text.to_string(),
+            exit_code: 0,
+            duration: Duration::from_millis(1),
+            token_usage: None,
+            raw_output: String::new(),
+            signals: Vec::new(),
+        }
+    }
+
+    fn ranking(rank: usize, worker_name: &str, approvals: usize, valid: bool) -> CandidateRanking {
+        CandidateRanking {
+            rank,
+            worker_name: worker_name.to_string(),
+            candidate_label: format!("Candidate {worker_name}"),
+            mean_scores: ReviewScores {
+                correctness: 0.5,
+                completeness: 0.5,
+                style: 0.5,
+                performance: 0.5,
+                overall: 0.5,
+            },
+            approvals,
+            review_count: 1,
+            tests_passed: None,
+            diff_lines: 1,
+            valid_patch: valid,
+        }
+    }
+
+    fn file_patch(path: &str, content: &str) -> String {
+        format!(
+            "diff --git a/{path} b/{path}\nnew file mode 100644\n--- /dev/null\n+++ b/{path}\n@@ -0,0 +1 @@\n+{content}\n"
+        )
+    }
+
+    #[test]
+    fn test_plan_hybrid_merge_best_candidate_claims_shared_files() {
+        let results = vec![
+            worker_result(
+                "worker-a",
+                &format!("{}{}", file_patch("a.rs", "a1"), file_patch("b.rs", "b1")),
+            ),
+            worker_result(
+                "worker-b",
+                &format!("{}{}", file_patch("b.rs", "b2"), file_patch("c.rs", "c2")),
+            ),
+        ];
+        let rankings = vec![
+            ranking(1, "worker-b", 1, true),
+            ranking(2, "worker-a", 1, true),
+        ];
+
+        let plan = plan_hybrid_merge(&results, &rankings).expect("plan should exist");
+
+        let selected = plan
+            .selections
+            .iter()
+            .map(|selection| (selection.path.as_str(), selection.worker_name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            selected,
+            vec![
+                ("b.rs", "worker-b"),
+                ("c.rs", "worker-b"),
+                ("a.rs", "worker-a"),
+            ]
+        );
+        assert!(plan.patch.contains("+b2"));
+        assert!(!plan.patch.contains("+b1"));
+        assert!(plan.patch.contains("+a1"));
+        assert_eq!(plan.contributors(), vec!["worker-b", "worker-a"]);
+    }
+
+    #[test]
+    fn test_plan_hybrid_merge_skips_unapproved_and_invalid_candidates() {
+        let results = vec![
+            worker_result("worker-a", &file_patch("a.rs", "a")),
+            worker_result("worker-b", &file_patch("b.rs", "b")),
+            worker_result("worker-c", "not a diff"),
+        ];
+        let rankings = vec![
+            ranking(1, "worker-a", 0, true),
+            ranking(2, "worker-b", 0, true),
+            ranking(3, "worker-c", 2, false),
+        ];
+
+        let plan = plan_hybrid_merge(&results, &rankings).expect("plan should exist");
+
+        assert_eq!(plan.contributors(), vec!["worker-a"]);
+        assert_eq!(plan.patch, file_patch("a.rs", "a"));
+    }
+
+    #[test]
+    fn test_plan_hybrid_merge_none_without_valid_patches() {
+        let results = vec![worker_result("worker-a", "")];
+        let rankings = vec![ranking(1, "worker-a", 1, false)];
+
+        assert!(plan_hybrid_merge(&results, &rankings).is_none());
+    }
+}
@@ -9,6 +9,7 @@ use std::process::{Command, Output, Stdio};
 pub struct WorktreeManager {
     repo: Repository,
     repo_path: PathBuf,
+    dir_name: String,
 }
 
 impl WorktreeManager {
@@ -20,7 +21,17 @@ impl WorktreeManager {
             .or_else(|| repo.path().parent().map(Path::to_path_buf))
             .context("Git repository does not have a usable working directory")?;
 
-        Ok(Self { repo, repo_path })
+        Ok(Self {
+            repo,
+            repo_path,
+            dir_name: "council-worktrees".to_string(),
+        })
+    }
+
+    /// Keep worktrees in `.forge/<dir_name>` instead of `.forge/council-worktrees`.
+    pub fn with_dir_name(mut self, dir_name: &str) -> Self {
+        self.dir_name = dir_name.to_string();
+        self
     }
 
     pub fn create_worktree(&self, name: &str) -> Result<PathBuf> {
@@ -139,7 +150,7 @@ impl WorktreeManager {
     }
 
     fn worktree_root(&self) -> PathBuf {
-        self.repo_path.join(".forge").join("council-worktrees")
+        self.repo_path.join(".forge").join(&self.dir_name)
     }
 
     fn worktree_path(&self, name: &str) -> PathBuf {
@@ -261,6 +272,14 @@ impl PatchSet {
     pub fn is_empty(&self) -> bool {
         self.files.is_empty()
     }
+
+    /// The complete `diff --git` section for `path`, if this patch touches it.
+    pub fn file_diff(&self, path: &str) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|file| file.path == path)
+            .map(|file| file.diff.as_str())
+    }
 }
 
 fn parse_diff_header_path(header: &str) -> Result<String> {
@@ -364,7 +383,7 @@ fn git_diff_head(repo_path: &Path) -> Result<String> {
     String::from_utf8(output.stdout).context("git diff HEAD produced invalid UTF-8")
 }
 
-fn run_git_with_input<const N: usize>(
+pub(crate) fn run_git_with_input<const N: usize>(
     repo_path: &Path,
     args: [&str; N],
     input: &str,
@@ -389,7 +408,7 @@ fn run_git_with_input<const N: usize>(
         .with_context(|| format!("Failed to read git {:?} output", args))
 }
 
-fn git_error_message(prefix: &str, output: &Output) -> String {
+pub(crate) fn git_error_message(prefix: &str, output: &Output) -> String {
     let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
     let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
 
@@ -835,6 +854,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_patch_set_file_diff_returns_section() {
+        let diff = concat!(
+            "diff --git a/src/a.rs b/src/a.rs\n",
+            "--- a/src/a.rs\n",
+            "+++ b/src/a.rs\n",
+            "@@ -1 +1 @@\n",
+            "-a\n",
+            "+b\n",
+            "diff --git a/src/b.rs b/src/b.rs\n",
+            "--- a/src/b.rs\n",
+            "+++ b/src/b.rs\n",
+            "@@ -1 +1 @@\n",
+            "-c\n",
+            "+d\n",
+        );
+
+        let patch_set = PatchSet::parse(diff).expect("multi-file diff should parse");
+
+        let section = patch_set
+            .file_diff("src/b.rs")
+            .expect("src/b.rs section should exist");
+        assert!(section.starts_with("diff --git a/src/b.rs b/src/b.rs\n"));
+        assert!(section.ends_with("+d\n"));
+        assert!(!section.contains("src/a.rs"));
+        assert!(patch_set.file_diff("src/c.rs").is_none());
+    }
+
     #[test]
     fn test_patch_set_is_empty_true() {
         let patch_set = PatchSet::parse("").expect("empty diff should parse");
@@ -1,16 +1,23 @@
 pub mod chairman;
 pub mod config;
 pub mod engine;
+pub mod hybrid;
 pub mod merge;
 pub mod prompts;
 pub mod reviewer;
+pub mod tournament;
 pub mod types;
 pub mod worker;
 
 pub use chairman::{Chairman, ChairmanDecision, SynthesisResult};
-pub use config::{CouncilConfig, WorkerConfig};
+pub use config::{
+    ChairmanConfig, ChairmanMode, CommandTemplate, CouncilConfig, EarlyExitPolicy, MergeMode,
+    OutputFormat, PromptDelivery, WorkerConfig,
+};
 pub use engine::CouncilEngine;
+pub use hybrid::{FileSelection, HybridPlan, plan_hybrid_merge};
 pub use merge::{PatchSet, WorktreeManager, apply_patch, detect_conflicts};
 pub use reviewer::{PeerReviewEngine, ReviewRound};
+pub use tournament::{CandidateRanking, ScoreMatrixEntry};
 pub use types::*;
-pub use worker::{ClaudeWorker, MockWorker, Worker};
+pub use worker::{ClaudeWorker, CommandWorker, MockWorker, Worker};
@@ -1,40 +1,40 @@
+use crate::council::tournament::CandidateRanking;
 use crate::council::types::ReviewResult;
 use crate::phase::Phase;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-const LABEL_PAIRS: [(&str, &str); 13] = [
-    ("Alpha", "Bravo"),
-    ("Charlie", "Delta"),
-    ("Echo", "Foxtrot"),
-    ("Golf", "Hotel"),
-    ("India", "Juliet"),
-    ("Kilo", "Lima"),
-    ("Mike", "November"),
-    ("Oscar", "Papa"),
-    ("Quebec", "Romeo"),
-    ("Sierra", "Tango"),
-    ("Uniform", "Victor"),
-    ("Whiskey", "Xray"),
-    ("Yankee", "Zulu"),
+const LABELS: [&str; 26] = [
+    "Alpha", "Bravo", "Charlie", "Delta", "Echo", "Foxtrot", "Golf", "Hotel", "India", "Juliet",
+    "Kilo", "Lima", "Mike", "November", "Oscar", "Papa", "Quebec", "Romeo", "Sierra", "Tango",
+    "Uniform", "Victor", "Whiskey", "Xray", "Yankee", "Zulu",
 ];
 
 const DEFAULT_CHAIRMAN_RETRY_BUDGET: u32 = 3;
 
-static NEXT_LABEL_PAIR_INDEX: AtomicUsize = AtomicUsize::new(0);
+static NEXT_LABEL_INDEX: AtomicUsize = AtomicUsize::new(0);
 
-pub fn generate_label_pair() -> (String, String) {
+/// Generate `count` distinct anonymized candidate labels from a rotating start.
+pub fn generate_labels(count: usize) -> Vec<String> {
     let time_seed = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|duration| duration.subsec_nanos() as usize)
         .unwrap_or(0);
-    let index = NEXT_LABEL_PAIR_INDEX
-        .fetch_add(1, Ordering::Relaxed)
+    let start = NEXT_LABEL_INDEX
+        .fetch_add(count.max(1), Ordering::Relaxed)
         .wrapping_add(time_seed)
-        % LABEL_PAIRS.len();
-    let (first, second) = LABEL_PAIRS[index];
-    (first.to_string(), second.to_string())
+        % LABELS.len();
+
+    (0..count)
+        .map(|offset| {
+            let label = LABELS[(start + offset) % LABELS.len()];
+            match offset / LABELS.len() {
+                0 => label.to_string(),
+                round => format!("{label} {}", round + 1),
+            }
+        })
+        .collect()
 }
 
 pub fn anonymize_label<'a>(worker_name: &str, label_map: &'a HashMap<String, String>) -> &'a str {
@@ -108,7 +108,7 @@ pub fn chairman_synthesis_prompt(
 
     format!(
         "You are the chairman for Stage 3 council synthesis in Forge.\n\
-         Produce the best merged result for the phase below. Do not mechanically union both diffs; resolve disagreements intentionally.\n\n\
+         Produce the best merged result for the phase below. Do not mechanically union the candidate diffs; resolve disagreements intentionally.\n\n\
          Phase context:\n\
          - Phase: {} {}\n\
          - Promise tag: {}\n\
@@ -133,6 +133,36 @@ pub fn chairman_synthesis_prompt(
     )
 }
 
+/// Summarize the tournament ranking for the chairman using anonymized labels only.
+pub fn tournament_ranking_section(rankings: &[CandidateRanking]) -> String {
+    let rows = rankings
+        .iter()
+        .map(|ranking| {
+            let tests = match ranking.tests_passed {
+                Some(true) => "passed",
+                Some(false) => "failed",
+                None => "not run",
+            };
+            format!(
+                "- #{} {}: mean overall={:.2}, approvals={}/{}, tests={}, diff lines={}",
+                ranking.rank,
+                candidate_display_label(&ranking.candidate_label),
+                ranking.mean_scores.overall,
+                ranking.approvals,
+                ranking.review_count,
+                tests,
+                ranking.diff_lines
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "Tournament ranking (strongest first):\n{rows}\n\
+         Favor the higher-ranked candidates when they disagree."
+    )
+}
+
 fn candidate_display_label(label: &str) -> String {
     if label.starts_with("Candidate ") {
         label.to_string()
@@ -215,22 +245,32 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_label_pair_returns_two_different_labels() {
-        let (a, b) = generate_label_pair();
-        assert_ne!(a, b);
+    fn test_generate_labels_returns_two_different_labels() {
+        let labels = generate_labels(2);
+        assert_eq!(labels.len(), 2);
+        assert_ne!(labels[0], labels[1]);
     }
 
     #[test]
-    fn test_generate_label_pair_labels_from_known_pool() {
-        let known_labels: HashSet<&str> = LABEL_PAIRS.iter().flat_map(|(a, b)| [*a, *b]).collect();
+    fn test_generate_labels_from_known_pool() {
+        let known_labels: HashSet<&str> = LABELS.iter().copied().collect();
 
         for _ in 0..100 {
-            let (a, b) = generate_label_pair();
-            assert!(known_labels.contains(a.as_str()));
-            assert!(known_labels.contains(b.as_str()));
+            for label in generate_labels(5) {
+                assert!(known_labels.contains(label.as_str()));
+            }
         }
     }
 
+    #[test]
+    fn test_generate_labels_distinct_beyond_pool_size() {
+        let labels = generate_labels(30);
+        let unique: HashSet<&String> = labels.iter().collect();
+
+        assert_eq!(labels.len(), 30);
+        assert_eq!(unique.len(), 30);
+    }
+
     #[test]
     fn test_anonymize_label_maps_correctly() {
         let mut map = HashMap::new();
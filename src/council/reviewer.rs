@@ -4,7 +4,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::council::config::CouncilConfig;
-use crate::council::prompts::generate_label_pair;
+use crate::council::prompts::generate_labels;
+use crate::council::tournament::review_assignments;
 use crate::council::types::{CouncilError, ReviewResult, WorkerResult};
 use crate::council::worker::Worker;
 use crate::phase::Phase;
@@ -18,51 +19,61 @@ pub struct ReviewRound {
 #[derive(Debug, Clone)]
 pub struct PeerReviewEngine {
     anonymize_reviews: bool,
+    reviewers_per_candidate: usize,
 }
 
 impl PeerReviewEngine {
     pub fn new(config: &CouncilConfig) -> Self {
         Self {
             anonymize_reviews: config.anonymize_reviews,
+            reviewers_per_candidate: config.reviewers_per_candidate,
         }
     }
 
+    /// Have every candidate reviewed by `reviewers_per_candidate` of its peers.
+    ///
+    /// `workers` and `results` must describe the same workers; results are
+    /// matched to workers by name.
     pub async fn run_reviews(
         &self,
         phase: &Phase,
         workers: &[Arc<dyn Worker>],
         results: &[WorkerResult],
     ) -> Result<ReviewRound> {
-        if workers.len() != 2 {
+        if workers.len() < 2 {
             return Err(anyhow!(CouncilError::ReviewFailed)).context(format!(
-                "peer review requires exactly two workers, got {}",
+                "peer review requires at least two workers, got {}",
                 workers.len()
             ));
         }
 
-        if results.len() != 2 {
+        if results.len() != workers.len() {
             return Err(anyhow!(CouncilError::ReviewFailed)).context(format!(
-                "peer review requires exactly two worker results, got {}",
-                results.len()
+                "peer review requires one result per worker, got {} results for {} workers",
+                results.len(),
+                workers.len()
             ));
         }
 
-        let label_map = self.build_label_map(results);
-
-        let review_futures = workers
+        let ordered_results = workers
             .iter()
             .map(|worker| {
-                let reviewer_name = worker.name().to_string();
-                let own_result = results
+                results
                     .iter()
-                    .find(|result| result.worker_name == reviewer_name)
+                    .find(|result| result.worker_name == worker.name())
                     .with_context(|| {
-                        format!("missing worker result for reviewer `{reviewer_name}`")
-                    })?;
-                let candidate_result = results
-                    .iter()
-                    .find(|result| result.worker_name != reviewer_name)
-                    .with_context(|| format!("missing peer review target for `{reviewer_name}`"))?;
+                        format!("missing worker result for reviewer `{}`", worker.name())
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let label_map = self.build_label_map(&ordered_results);
+
+        let review_futures = review_assignments(workers.len(), self.reviewers_per_candidate)
+            .into_iter()
+            .map(|(reviewer_index, candidate_index)| {
+                let reviewer = Arc::clone(&workers[reviewer_index]);
+                let reviewer_name = reviewer.name().to_string();
+                let candidate_result = ordered_results[candidate_index];
                 let candidate_label = label_map
                     .get(&candidate_result.worker_name)
                     .cloned()
@@ -73,14 +84,13 @@ impl PeerReviewEngine {
                         )
                     })?;
 
-                if own_result.worker_name == candidate_result.worker_name {
+                if reviewer_name == candidate_result.worker_name {
                     return Err(anyhow!(CouncilError::ReviewFailed)).context(format!(
                         "worker `{reviewer_name}` was assigned its own diff"
                     ));
                 }
 
                 let phase = phase.clone();
-                let reviewer = Arc::clone(worker);
                 let diff = candidate_result.diff_text.clone();
 
                 Ok(async move {
@@ -118,13 +128,13 @@ impl PeerReviewEngine {
         Ok(ReviewRound { reviews, label_map })
     }
 
-    fn build_label_map(&self, results: &[WorkerResult]) -> HashMap<String, String> {
+    fn build_label_map(&self, results: &[&WorkerResult]) -> HashMap<String, String> {
         if self.anonymize_reviews {
-            let (first_label, second_label) = generate_label_pair();
-            HashMap::from([
-                (results[0].worker_name.clone(), first_label),
-                (results[1].worker_name.clone(), second_label),
-            ])
+            results
+                .iter()
+                .map(|result| result.worker_name.clone())
+                .zip(generate_labels(results.len()))
+                .collect()
         } else {
             results
                 .iter()
@@ -506,4 +516,89 @@ mod tests {
                 .any(|review| review.reviewer_name == "worker-b")
         );
     }
+
+    #[tokio::test]
+    async fn test_peer_review_three_workers_round_robin() {
+        let mut config = default_config();
+        config.anonymize_reviews = false;
+
+        let engine = PeerReviewEngine::new(&config);
+        let worker_a = Arc::new(MockWorker::new("worker-a"));
+        let worker_b = Arc::new(MockWorker::new("worker-b"));
+        let worker_c = Arc::new(MockWorker::new("worker-c"));
+        let workers: Vec<Arc<dyn Worker>> =
+            vec![worker_a.clone(), worker_b.clone(), worker_c.clone()];
+        let mut results = results();
+        results.push(worker_result("worker-c", "diff-c"));
+
+        let round = engine
+            .run_reviews(&test_phase(), &workers, &results)
+            .await
+            .expect("three-way peer review should succeed");
+
+        assert_eq!(round.reviews.len(), 3);
+        assert_eq!(round.label_map.len(), 3);
+        assert_eq!(worker_a.last_review_diff().as_deref(), Some("diff-c"));
+        assert_eq!(worker_b.last_review_diff().as_deref(), Some("diff-a"));
+        assert_eq!(worker_c.last_review_diff().as_deref(), Some("diff-b"));
+        assert!(
+            round
+                .reviews
+                .iter()
+                .all(|review| review.reviewer_name != review.candidate_label)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_peer_review_multiple_reviewers_per_candidate() {
+        let mut config = default_config();
+        config.reviewers_per_candidate = 2;
+
+        let engine = PeerReviewEngine::new(&config);
+        let worker_a = Arc::new(MockWorker::new("worker-a"));
+        let worker_b = Arc::new(MockWorker::new("worker-b"));
+        let worker_c = Arc::new(MockWorker::new("worker-c"));
+        let workers: Vec<Arc<dyn Worker>> =
+            vec![worker_a.clone(), worker_b.clone(), worker_c.clone()];
+        let mut results = results();
+        results.push(worker_result("worker-c", "diff-c"));
+
+        let round = engine
+            .run_reviews(&test_phase(), &workers, &results)
+            .await
+            .expect("peer review should succeed");
+
+        assert_eq!(round.reviews.len(), 6);
+        assert_eq!(worker_a.review_count(), 2);
+        assert_eq!(worker_b.review_count(), 2);
+        assert_eq!(worker_c.review_count(), 2);
+        for label in round.label_map.values() {
+            assert_eq!(
+                round
+                    .reviews
+                    .iter()
+                    .filter(|review| &review.candidate_label == label)
+                    .count(),
+                2
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_peer_review_rejects_single_worker() {
+        let engine = PeerReviewEngine::new(&default_config());
+        let worker_a = Arc::new(MockWorker::new("worker-a"));
+        let workers: Vec<Arc<dyn Worker>> = vec![worker_a];
+
+        let error = engine
+            .run_reviews(
+                &test_phase(),
+                &workers,
+                &[worker_result("worker-a", "diff-a")],
+            )
+            .await
+            .expect_err("a single worker cannot be peer reviewed");
+
+        assert!(format!("{error:#}").contains("at least two workers"));
+    }
 }
@@ -0,0 +1,466 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::process::Command;
+
+use crate::council::merge::PatchSet;
+use crate::council::types::{ReviewResult, ReviewScores, ReviewVerdict, WorkerResult};
+
+/// One reviewer's scores for one candidate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreMatrixEntry {
+    pub reviewer: String,
+    pub candidate: String,
+    pub candidate_label: String,
+    pub verdict: String,
+    pub scores: ReviewScores,
+}
+
+/// Aggregated standing of a candidate after peer review.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandidateRanking {
+    /// 1-based position; 1 is the strongest candidate.
+    pub rank: usize,
+    pub worker_name: String,
+    pub candidate_label: String,
+    pub mean_scores: ReviewScores,
+    pub approvals: usize,
+    pub review_count: usize,
+    pub tests_passed: Option<bool>,
+    pub diff_lines: usize,
+    pub valid_patch: bool,
+}
+
+/// Assign reviewers to candidates round-robin so nobody reviews their own patch.
+///
+/// Returns `(reviewer_index, candidate_index)` pairs. Each candidate receives
+/// `reviewers_per_candidate` reviews, capped at `worker_count - 1`.
+pub fn review_assignments(
+    worker_count: usize,
+    reviewers_per_candidate: usize,
+) -> Vec<(usize, usize)> {
+    let per_candidate = reviewers_per_candidate
+        .max(1)
+        .min(worker_count.saturating_sub(1));
+
+    (0..worker_count)
+        .flat_map(|candidate| {
+            (1..=per_candidate).map(move |offset| ((candidate + offset) % worker_count, candidate))
+        })
+        .collect()
+}
+
+/// Flatten reviews into a reviewer × candidate score matrix.
+pub fn score_matrix(
+    reviews: &[ReviewResult],
+    label_map: &HashMap<String, String>,
+) -> Vec<ScoreMatrixEntry> {
+    let workers_by_label = invert_label_map(label_map);
+
+    reviews
+        .iter()
+        .map(|review| ScoreMatrixEntry {
+            reviewer: review.reviewer_name.clone(),
+            candidate: workers_by_label
+                .get(review.candidate_label.as_str())
+                .map(|worker| worker.to_string())
+                .unwrap_or_else(|| review.candidate_label.clone()),
+            candidate_label: review.candidate_label.clone(),
+            verdict: verdict_name(&review.verdict).to_string(),
+            scores: review.scores.clone(),
+        })
+        .collect()
+}
+
+/// Rank candidates by mean overall review score.
+///
+/// Ties are broken by verification outcome (passing before unknown before
+/// failing), then by smaller diff, then by worker name. Candidates without a
+/// parseable patch always rank last.
+pub fn rank_candidates(
+    worker_results: &[WorkerResult],
+    reviews: &[ReviewResult],
+    label_map: &HashMap<String, String>,
+    tests_passed: &HashMap<String, bool>,
+) -> Vec<CandidateRanking> {
+    let matrix = score_matrix(reviews, label_map);
+
+    let mut rankings = worker_results
+        .iter()
+        .map(|result| {
+            let received = matrix
+                .iter()
+                .filter(|entry| entry.candidate == result.worker_name)
+                .collect::<Vec<_>>();
+            let patch = result.patch();
+
+            CandidateRanking {
+                rank: 0,
+                worker_name: result.worker_name.clone(),
+                candidate_label: label_map
+                    .get(&result.worker_name)
+                    .cloned()
+                    .unwrap_or_else(|| result.worker_name.clone()),
+                mean_scores: mean_scores(received.iter().map(|entry| &entry.scores)),
+                approvals: received
+                    .iter()
+                    .filter(|entry| entry.verdict == "approve")
+                    .count(),
+                review_count: received.len(),
+                tests_passed: tests_passed.get(&result.worker_name).copied(),
+                diff_lines: diff_lines(&patch),
+                valid_patch: PatchSet::parse(&patch)
+                    .map(|patch_set| !patch_set.is_empty())
+                    .unwrap_or(false),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    rankings.sort_by(compare_rankings);
+    for (index, ranking) in rankings.iter_mut().enumerate() {
+        ranking.rank = index + 1;
+    }
+
+    rankings
+}
+
+/// Run the configured verification commands in `dir`.
+///
+/// Returns `None` when no commands are configured, otherwise whether every
+/// command exited successfully.
+pub async fn verify_candidate(commands: &[String], dir: &Path) -> Result<Option<bool>> {
+    if commands.is_empty() {
+        return Ok(None);
+    }
+
+    for command in commands {
+        let status = Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .current_dir(dir)
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .status()
+            .await
+            .with_context(|| format!("failed to run verify command `{command}`"))?;
+
+        if !status.success() {
+            return Ok(Some(false));
+        }
+    }
+
+    Ok(Some(true))
+}
+
+fn compare_rankings(left: &CandidateRanking, right: &CandidateRanking) -> Ordering {
+    right
+        .valid_patch
+        .cmp(&left.valid_patch)
+        .then_with(|| {
+            right
+                .mean_scores
+                .overall
+                .partial_cmp(&left.mean_scores.overall)
+                .unwrap_or(Ordering::Equal)
+        })
+        .then_with(|| tests_rank(right.tests_passed).cmp(&tests_rank(left.tests_passed)))
+        .then_with(|| left.diff_lines.cmp(&right.diff_lines))
+        .then_with(|| left.worker_name.cmp(&right.worker_name))
+}
+
+fn tests_rank(tests_passed: Option<bool>) -> u8 {
+    match tests_passed {
+        Some(true) => 2,
+        None => 1,
+        Some(false) => 0,
+    }
+}
+
+fn mean_scores<'a>(scores: impl Iterator<Item = &'a ReviewScores>) -> ReviewScores {
+    let mut total = ReviewScores {
+        correctness: 0.0,
+        completeness: 0.0,
+        style: 0.0,
+        performance: 0.0,
+        overall: 0.0,
+    };
+    let mut count = 0.0_f32;
+
+    for score in scores {
+        total.correctness += score.correctness;
+        total.completeness += score.completeness;
+        total.style += score.style;
+        total.performance += score.performance;
+        total.overall += score.overall;
+        count += 1.0;
+    }
+
+    if count > 0.0 {
+        total.correctness /= count;
+        total.completeness /= count;
+        total.style /= count;
+        total.performance /= count;
+        total.overall /= count;
+    }
+
+    total
+}
+
+fn diff_lines(patch: &str) -> usize {
+    patch
+        .lines()
+        .filter(|line| {
+            (line.starts_with('+') && !line.starts_with("+++"))
+                || (line.starts_with('-') && !line.starts_with("---"))
+        })
+        .count()
+}
+
+fn invert_label_map(label_map: &HashMap<String, String>) -> HashMap<&str, &str> {
+    label_map
+        .iter()
+        .map(|(worker, label)| (label.as_str(), worker.as_str()))
+        .collect()
+}
+
+fn verdict_name(verdict: &ReviewVerdict) -> &'static str {
+    match verdict {
+        ReviewVerdict::Approve => "approve",
+        ReviewVerdict::RequestChanges(_) => "request_changes",
+        ReviewVerdict::Abstain => "abstain",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn worker_result(worker_name: &str, diff_text: &str) -> WorkerResult {
+        WorkerResult {
+            worker_name: worker_name.to_string(),
+            diff_text: diff_text.to_string(),
+            exit_code: 0,
+            duration: Duration::ZERO,
+            token_usage: None,
+            raw_output: diff_text.to_string(),
+            signals: Vec::new(),
+        }
+    }
+
+    fn review(reviewer: &str, label: &str, verdict: ReviewVerdict, overall: f32) -> ReviewResult {
+        ReviewResult {
+            reviewer_name: reviewer.to_string(),
+            candidate_label: label.to_string(),
+            verdict,
+            scores: ReviewScores {
+                correctness: overall,
+                completeness: overall,
+                style: overall,
+                performance: overall,
+                overall,
+            },
+            issues: Vec::new(),
+            summary: String::new(),
+            duration: Duration::ZERO,
+        }
+    }
+
+    fn add_file_patch(path: &str, lines: usize) -> String {
+        let body = (0..lines)
+            .map(|line| format!("+line {line}\n"))
+            .collect::<String>();
+        format!(
+            "diff --git a/{path} b/{path}\nnew file mode 100644\n--- /dev/null\n+++ b/{path}\n@@ -0,0 +1,{lines} @@\n{body}"
+        )
+    }
+
+    fn label_map() -> HashMap<String, String> {
+        HashMap::from([
+            ("worker-a".to_string(), "Alpha".to_string()),
+            ("worker-b".to_string(), "Bravo".to_string()),
+            ("worker-c".to_string(), "Charlie".to_string()),
+        ])
+    }
+
+    fn three_results() -> Vec<WorkerResult> {
+        vec![
+            worker_result("worker-a", &add_file_patch("A.md", 3)),
+            worker_result("worker-b", &add_file_patch("B.md", 1)),
+            worker_result("worker-c", &add_file_patch("C.md", 2)),
+        ]
+    }
+
+    #[test]
+    fn test_review_assignments_two_workers_cross_review() {
+        assert_eq!(review_assignments(2, 1), vec![(1, 0), (0, 1)]);
+    }
+
+    #[test]
+    fn test_review_assignments_never_self_review() {
+        for workers in 2..6 {
+            for per_candidate in 1..6 {
+                let assignments = review_assignments(workers, per_candidate);
+                assert!(
+                    assignments
+                        .iter()
+                        .all(|(reviewer, candidate)| reviewer != candidate)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_review_assignments_caps_reviewers_per_candidate() {
+        let assignments = review_assignments(3, 10);
+
+        assert_eq!(assignments.len(), 6);
+        for candidate in 0..3 {
+            assert_eq!(
+                assignments
+                    .iter()
+                    .filter(|(_, assigned)| *assigned == candidate)
+                    .count(),
+                2
+            );
+        }
+    }
+
+    #[test]
+    fn test_review_assignments_spreads_load() {
+        let assignments = review_assignments(4, 2);
+
+        for reviewer in 0..4 {
+            assert_eq!(
+                assignments
+                    .iter()
+                    .filter(|(assigned, _)| *assigned == reviewer)
+                    .count(),
+                2
+            );
+        }
+    }
+
+    #[test]
+    fn test_score_matrix_resolves_labels_to_workers() {
+        let reviews = vec![
+            review("worker-a", "Bravo", ReviewVerdict::Approve, 0.9),
+            review(
+                "worker-b",
+                "Charlie",
+                ReviewVerdict::RequestChanges("tests".to_string()),
+                0.4,
+            ),
+        ];
+
+        let matrix = score_matrix(&reviews, &label_map());
+
+        assert_eq!(matrix.len(), 2);
+        assert_eq!(matrix[0].reviewer, "worker-a");
+        assert_eq!(matrix[0].candidate, "worker-b");
+        assert_eq!(matrix[0].verdict, "approve");
+        assert_eq!(matrix[1].candidate, "worker-c");
+        assert_eq!(matrix[1].verdict, "request_changes");
+    }
+
+    #[test]
+    fn test_rank_candidates_orders_by_mean_overall() {
+        let reviews = vec![
+            review("worker-b", "Alpha", ReviewVerdict::Approve, 0.6),
+            review("worker-c", "Alpha", ReviewVerdict::Approve, 0.8),
+            review("worker-a", "Bravo", ReviewVerdict::Approve, 0.9),
+            review("worker-a", "Charlie", ReviewVerdict::Abstain, 0.3),
+        ];
+
+        let rankings = rank_candidates(&three_results(), &reviews, &label_map(), &HashMap::new());
+
+        let order = rankings
+            .iter()
+            .map(|ranking| ranking.worker_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["worker-b", "worker-a", "worker-c"]);
+        assert_eq!(rankings[0].rank, 1);
+        assert_eq!(rankings[1].review_count, 2);
+        assert_eq!(rankings[1].approvals, 2);
+        assert!((rankings[1].mean_scores.overall - 0.7).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rank_candidates_tie_broken_by_tests_then_diff_size() {
+        let reviews = vec![
+            review("worker-b", "Alpha", ReviewVerdict::Approve, 0.8),
+            review("worker-c", "Bravo", ReviewVerdict::Approve, 0.8),
+            review("worker-a", "Charlie", ReviewVerdict::Approve, 0.8),
+        ];
+        let tests_passed = HashMap::from([
+            ("worker-a".to_string(), true),
+            ("worker-b".to_string(), false),
+        ]);
+
+        let rankings = rank_candidates(&three_results(), &reviews, &label_map(), &tests_passed);
+
+        let order = rankings
+            .iter()
+            .map(|ranking| ranking.worker_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["worker-a", "worker-c", "worker-b"]);
+
+        let rankings = rank_candidates(&three_results(), &reviews, &label_map(), &HashMap::new());
+        let order = rankings
+            .iter()
+            .map(|ranking| ranking.worker_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["worker-b", "worker-c", "worker-a"]);
+    }
+
+    #[test]
+    fn test_rank_candidates_invalid_patch_ranks_last() {
+        let results = vec![
+            worker_result("worker-a", "no patch here"),
+            worker_result("worker-b", &add_file_patch("B.md", 1)),
+        ];
+        let reviews = vec![
+            review("worker-b", "Alpha", ReviewVerdict::Approve, 1.0),
+            review("worker-a", "Bravo", ReviewVerdict::Approve, 0.1),
+        ];
+
+        let rankings = rank_candidates(&results, &reviews, &label_map(), &HashMap::new());
+
+        assert_eq!(rankings[0].worker_name, "worker-b");
+        assert!(!rankings[1].valid_patch);
+    }
+
+    #[tokio::test]
+    async fn test_verify_candidate_without_commands_is_unknown() {
+        let dir = TempDir::new().expect("temp dir should be created");
+
+        assert_eq!(
+            verify_candidate(&[], dir.path())
+                .await
+                .expect("verify should run"),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_verify_candidate_reports_pass_and_fail() {
+        let dir = TempDir::new().expect("temp dir should be created");
+        std::fs::write(dir.path().join("marker"), "ok").expect("marker should be written");
+
+        let passing = verify_candidate(&["test -f marker".to_string()], dir.path())
+            .await
+            .expect("verify should run");
+        let failing = verify_candidate(
+            &["true".to_string(), "test -f missing".to_string()],
+            dir.path(),
+        )
+        .await
+        .expect("verify should run");
+
+        assert_eq!(passing, Some(true));
+        assert_eq!(failing, Some(false));
+    }
+}
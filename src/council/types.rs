@@ -1,4 +1,6 @@
 use crate::audit::TokenUsage;
+use crate::council::hybrid::FileSelection;
+use crate::council::tournament::{CandidateRanking, ScoreMatrixEntry};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use thiserror::Error;
@@ -15,6 +17,18 @@ pub struct WorkerResult {
     pub signals: Vec<String>,
 }
 
+impl WorkerResult {
+    /// The candidate's patch, falling back to the raw output for workers
+    /// that print the diff instead of reporting it.
+    pub fn patch(&self) -> String {
+        if self.diff_text.trim().is_empty() {
+            self.raw_output.clone()
+        } else {
+            self.diff_text.clone()
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ReviewVerdict {
     Approve,
@@ -57,6 +71,18 @@ pub struct CouncilPhaseResult {
     pub review_results: Vec<ReviewResult>,
     pub merge_outcome: MergeOutcome,
     pub merge_attempts: u32,
+    #[serde(default)]
+    pub score_matrix: Vec<ScoreMatrixEntry>,
+    #[serde(default)]
+    pub rankings: Vec<CandidateRanking>,
+    /// Per-file provenance when the patch was composed in hybrid merge mode.
+    #[serde(default)]
+    pub hybrid_selections: Vec<FileSelection>,
+    #[serde(default)]
+    pub worker_runs: Vec<WorkerRun>,
+    /// The candidate was accepted before every worker finished.
+    #[serde(default)]
+    pub early_exit: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,6 +92,35 @@ pub struct CouncilAuditData {
     pub merge_attempts: u32,
     pub chairman_decision: String,
     pub winning_worker: Option<String>,
+    #[serde(default)]
+    pub score_matrix: Vec<ScoreMatrixEntry>,
+    #[serde(default)]
+    pub rankings: Vec<CandidateRanking>,
+    /// Per-file provenance when the patch was composed in hybrid merge mode.
+    #[serde(default)]
+    pub hybrid_selections: Vec<FileSelection>,
+    #[serde(default)]
+    pub worker_runs: Vec<WorkerRun>,
+}
+
+/// Wall time, token usage and outcome of one worker's implementation run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerRun {
+    pub worker_name: String,
+    pub status: WorkerRunStatus,
+    #[serde(with = "duration_serde")]
+    pub wall_time: Duration,
+    pub token_usage: Option<TokenUsage>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkerRunStatus {
+    Completed,
+    Failed,
+    TimedOut,
+    /// Stopped because another candidate was accepted early.
+    Cancelled,
 }
 
 impl CouncilPhaseResult {
@@ -89,8 +144,20 @@ impl CouncilPhaseResult {
                 })
                 .collect(),
             merge_attempts: self.merge_attempts,
-            chairman_decision: chairman_decision_for_result(&self.merge_outcome, &winning_worker),
+            chairman_decision: if self.early_exit {
+                "early_exit".to_string()
+            } else {
+                chairman_decision_for_result(
+                    &self.merge_outcome,
+                    &winning_worker,
+                    !self.hybrid_selections.is_empty(),
+                )
+            },
             winning_worker,
+            score_matrix: self.score_matrix.clone(),
+            rankings: self.rankings.clone(),
+            hybrid_selections: self.hybrid_selections.clone(),
+            worker_runs: self.worker_runs.clone(),
         }
     }
 
@@ -134,9 +201,11 @@ fn review_verdict_to_audit_string(verdict: &ReviewVerdict) -> String {
 fn chairman_decision_for_result(
     merge_outcome: &MergeOutcome,
     winning_worker: &Option<String>,
+    hybrid: bool,
 ) -> String {
     match merge_outcome {
         MergeOutcome::Clean(_) if winning_worker.is_some() => "winner_takes_all".to_string(),
+        MergeOutcome::Clean(_) if hybrid => "hybrid_merge".to_string(),
         MergeOutcome::Clean(_) => "merged_patch".to_string(),
         MergeOutcome::Conflict(_) => "conflict".to_string(),
         MergeOutcome::Failure(_) => "failure".to_string(),
@@ -167,6 +236,7 @@ mod duration_serde {
 mod tests {
     use super::*;
     use crate::audit::TokenUsage;
+    use crate::council::tournament::ScoreMatrixEntry;
     use std::time::Duration;
 
     #[test]
@@ -358,6 +428,11 @@ mod tests {
             review_results: vec![review_result],
             merge_outcome: MergeOutcome::Clean("winning diff".to_string()),
             merge_attempts: 1,
+            score_matrix: Vec::new(),
+            rankings: Vec::new(),
+            hybrid_selections: Vec::new(),
+            worker_runs: Vec::new(),
+            early_exit: false,
         };
 
         assert_eq!(phase_result.winning_diff, "winning diff");
@@ -405,6 +480,10 @@ mod tests {
             merge_attempts: 2,
             chairman_decision: "winner_takes_all".to_string(),
             winning_worker: Some("codex".to_string()),
+            score_matrix: Vec::new(),
+            rankings: Vec::new(),
+            hybrid_selections: Vec::new(),
+            worker_runs: Vec::new(),
         };
 
         assert_eq!(audit_data.workers_used, vec!["claude", "codex"]);
@@ -474,6 +553,23 @@ mod tests {
                 "diff --git a/src/lib.rs b/src/lib.rs\n+winner\n".to_string(),
             ),
             merge_attempts: 2,
+            score_matrix: vec![ScoreMatrixEntry {
+                reviewer: "claude".to_string(),
+                candidate: "codex".to_string(),
+                candidate_label: "Candidate Alpha".to_string(),
+                verdict: "approve".to_string(),
+                scores: ReviewScores {
+                    correctness: 9.0,
+                    completeness: 8.5,
+                    style: 8.0,
+                    performance: 8.0,
+                    overall: 8.4,
+                },
+            }],
+            rankings: Vec::new(),
+            hybrid_selections: Vec::new(),
+            worker_runs: Vec::new(),
+            early_exit: false,
         };
 
         let audit_data = council_result.to_audit_data();
@@ -492,5 +588,24 @@ mod tests {
         assert_eq!(audit_data.merge_attempts, 2);
         assert_eq!(audit_data.chairman_decision, "winner_takes_all");
         assert_eq!(audit_data.winning_worker.as_deref(), Some("codex"));
+        assert_eq!(audit_data.score_matrix.len(), 1);
+        assert_eq!(audit_data.score_matrix[0].reviewer, "claude");
+        assert_eq!(audit_data.score_matrix[0].candidate, "codex");
+    }
+
+    #[test]
+    fn test_council_audit_data_backward_compat_without_scores() {
+        let json = r#"{
+            "workers_used": ["claude"],
+            "review_verdicts": [],
+            "merge_attempts": 0,
+            "chairman_decision": "winner_takes_all",
+            "winning_worker": "claude"
+        }"#;
+
+        let audit_data: CouncilAuditData = serde_json::from_str(json).unwrap();
+
+        assert!(audit_data.score_matrix.is_empty());
+        assert!(audit_data.rankings.is_empty());
     }
 }
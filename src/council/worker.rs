@@ -3,21 +3,26 @@ use async_trait::async_trait;
 use serde::Deserialize;
 use std::collections::VecDeque;
 use std::path::Path;
+use std::process::Stdio;
 use std::sync::{
     Arc, Mutex,
     atomic::{AtomicU32, Ordering},
 };
 use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use tokio::time::sleep;
 
 use crate::audit::TokenUsage;
-use crate::council::config::{CouncilConfig, WorkerConfig};
+use crate::council::config::{
+    ChairmanMode, CommandTemplate, CouncilConfig, OutputFormat, PromptDelivery, WorkerConfig,
+};
 use crate::council::prompts::review_prompt;
 use crate::council::types::{ReviewResult, ReviewScores, ReviewVerdict, WorkerResult};
 use crate::phase::Phase;
 use crate::signals::extract_signals;
 use crate::stream::{ContentBlock, StreamEvent};
+use crate::util::ProcessGroup;
 
 #[async_trait]
 pub trait Worker: Send + Sync {
@@ -79,6 +84,12 @@ impl ClaudeWorker {
         }
     }
 
+    /// Report under `name` instead of the command's binary name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
     pub fn build_execute_args(
         &self,
         _phase: &Phase,
@@ -226,15 +237,23 @@ impl ClaudeWorker {
         current_dir: Option<&Path>,
     ) -> Result<(String, i32)> {
         let mut cmd = Command::new(&self.command);
-        cmd.args(args);
+        // Council timeouts and early exit cancel workers by dropping this
+        // future, which kills the worker's whole process group.
+        cmd.args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
         if let Some(dir) = current_dir {
             cmd.current_dir(dir);
         }
 
-        let output = cmd
-            .output()
+        let (child, group) = ProcessGroup::spawn(&mut cmd)
+            .with_context(|| format!("failed to execute `{}`", self.command))?;
+        let output = child
+            .wait_with_output()
             .await
             .with_context(|| format!("failed to execute `{}`", self.command))?;
+        group.release();
 
         let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
         let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
@@ -453,6 +472,77 @@ struct ParsedCodexOutput {
     token_usage: Option<TokenUsage>,
 }
 
+/// Parse newline-delimited JSON events into a single response.
+///
+/// With an `output_path`, the last event resolving that path wins; otherwise
+/// the Codex event heuristics decide which text is final.
+fn parse_json_lines(raw: &str, output_path: Option<&str>) -> ParsedCodexOutput {
+    let mut accumulated_text = Vec::new();
+    let mut final_output: Option<String> = None;
+    let mut is_error = false;
+    let mut token_usage: Option<TokenUsage> = None;
+
+    for line in raw.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(parsed) => {
+                if let Some(usage) = extract_token_usage(&parsed) {
+                    token_usage = Some(usage);
+                }
+
+                if codex_event_is_error(&parsed) {
+                    is_error = true;
+                }
+
+                if let Some(path) = output_path {
+                    if let Some(text) = lookup_json_path(&parsed, path).and_then(json_value_text) {
+                        final_output = Some(text);
+                    }
+                } else if let Some(text) = extract_codex_text(&parsed) {
+                    if codex_event_is_final(&parsed) {
+                        final_output = Some(text);
+                    } else {
+                        accumulated_text.push(text);
+                    }
+                }
+            }
+            Err(_) => accumulated_text.push(line.to_string()),
+        }
+    }
+
+    let combined_output = final_output
+        .unwrap_or_else(|| accumulated_text.join("\n"))
+        .trim_end_matches('\n')
+        .to_string();
+
+    ParsedCodexOutput {
+        combined_output,
+        is_error,
+        token_usage,
+    }
+}
+
+/// Resolve a dot-separated path such as `response.output.0.text`.
+fn lookup_json_path<'a>(value: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |current, segment| match current {
+            serde_json::Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => current.get(segment),
+        })
+}
+
+fn json_value_text(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(text) => Some(text.clone()),
+        other => extract_codex_text(other).or_else(|| Some(other.to_string())),
+    }
+}
+
 impl CodexWorker {
     pub fn new(config: &WorkerConfig) -> Self {
         let name = std::path::Path::new(&config.cmd)
@@ -475,6 +565,12 @@ impl CodexWorker {
         }
     }
 
+    /// Report under `name` instead of the command's binary name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
     pub fn build_execute_args(
         &self,
         _phase: &Phase,
@@ -576,65 +672,334 @@ impl CodexWorker {
     }
 
     fn parse_json_output(&self, raw: &str) -> Result<ParsedCodexOutput> {
-        let mut accumulated_text = Vec::new();
-        let mut final_output: Option<String> = None;
-        let mut is_error = false;
-        let mut token_usage: Option<TokenUsage> = None;
+        Ok(parse_json_lines(raw, None))
+    }
 
-        for line in raw.lines() {
-            if line.trim().is_empty() {
-                continue;
-            }
+    async fn run_command(
+        &self,
+        args: &[String],
+        current_dir: Option<&Path>,
+    ) -> Result<(String, i32)> {
+        let mut cmd = Command::new(&self.command);
+        cmd.args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if let Some(dir) = current_dir {
+            cmd.current_dir(dir);
+        }
 
-            match serde_json::from_str::<serde_json::Value>(line) {
-                Ok(parsed) => {
-                    if let Some(usage) = extract_token_usage(&parsed) {
-                        token_usage = Some(usage);
-                    }
+        let (child, group) = ProcessGroup::spawn(&mut cmd)
+            .with_context(|| format!("failed to execute `{}`", self.command))?;
+        let output = child
+            .wait_with_output()
+            .await
+            .with_context(|| format!("failed to execute `{}`", self.command))?;
+        group.release();
 
-                    if codex_event_is_error(&parsed) {
-                        is_error = true;
-                    }
+        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+        let raw = match (stdout.trim().is_empty(), stderr.trim().is_empty()) {
+            (false, true) => stdout,
+            (true, false) => stderr,
+            (false, false) => format!("{stdout}\n{stderr}"),
+            (true, true) => String::new(),
+        };
+        let exit_code = output
+            .status
+            .code()
+            .unwrap_or_else(|| if output.status.success() { 0 } else { 1 });
 
-                    if let Some(text) = extract_codex_text(&parsed) {
-                        if codex_event_is_final(&parsed) {
-                            final_output = Some(text);
-                        } else {
-                            accumulated_text.push(text);
-                        }
+        Ok((raw, exit_code))
+    }
+}
+
+#[async_trait]
+impl Worker for CodexWorker {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn execute(
+        &self,
+        phase: &Phase,
+        prompt: &str,
+        worktree_path: &Path,
+    ) -> Result<WorkerResult> {
+        let start = Instant::now();
+        let args = self.build_execute_args(phase, prompt, worktree_path);
+        let (raw, exit_code) = self.run_command(&args, Some(worktree_path)).await?;
+        let mut result = self.parse_execute_output(&raw)?;
+        result.duration = start.elapsed();
+        result.exit_code = exit_code.max(result.exit_code);
+        Ok(result)
+    }
+
+    async fn review(
+        &self,
+        phase: &Phase,
+        diff: &str,
+        candidate_label: &str,
+    ) -> Result<ReviewResult> {
+        let start = Instant::now();
+        let args = self.build_review_args(phase, diff, candidate_label);
+        let (raw, exit_code) = self.run_command(&args, None).await?;
+        let mut result = self.parse_review_output(&raw)?;
+        result.duration = start.elapsed();
+        if exit_code != 0 {
+            anyhow::bail!("Codex review command failed with exit code {exit_code}");
+        }
+        Ok(result)
+    }
+}
+
+/// Council worker driven entirely by a `[council.workers.<name>]` command template.
+#[derive(Debug, Clone)]
+pub struct CommandWorker {
+    command: String,
+    flags: Vec<String>,
+    name: String,
+    model: Option<String>,
+    template: CommandTemplate,
+}
+
+impl CommandWorker {
+    pub fn new(config: &WorkerConfig) -> Self {
+        Self {
+            command: config.cmd.clone(),
+            flags: config.flags.clone(),
+            name: worker_command(&config.cmd).to_string(),
+            model: config.model.clone(),
+            template: config.template.clone(),
+        }
+    }
+
+    /// Report under `name` instead of the command's binary name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn build_execute_args(
+        &self,
+        _phase: &Phase,
+        prompt: &str,
+        worktree_path: &Path,
+        prompt_file: Option<&Path>,
+    ) -> Vec<String> {
+        self.render_args(
+            self.execute_template(),
+            prompt,
+            prompt_file,
+            &worktree_path.display().to_string(),
+        )
+    }
+
+    /// Reviews have no candidate worktree, so `{worktree}` renders as the
+    /// directory the review command runs in.
+    pub fn build_review_args(
+        &self,
+        phase: &Phase,
+        diff: &str,
+        label: &str,
+        review_dir: &Path,
+        prompt_file: Option<&Path>,
+    ) -> Vec<String> {
+        let template = self
+            .template
+            .review_args
+            .as_deref()
+            .unwrap_or_else(|| self.execute_template());
+        self.render_args(
+            template,
+            &review_prompt(phase, diff, label),
+            prompt_file,
+            &review_dir.display().to_string(),
+        )
+    }
+
+    pub fn parse_execute_output(&self, raw: &str) -> Result<WorkerResult> {
+        let parsed = self.parse_output(raw);
+        let signals = extract_signals(raw);
+
+        Ok(WorkerResult {
+            worker_name: self.name.clone(),
+            diff_text: parsed.combined_output.clone(),
+            exit_code: if parsed.is_error { 1 } else { 0 },
+            duration: Duration::ZERO,
+            token_usage: parsed.token_usage,
+            raw_output: parsed.combined_output,
+            signals: serialize_signals(&signals),
+        })
+    }
+
+    pub fn parse_review_output(&self, raw: &str) -> Result<ReviewResult> {
+        let parsed = self.parse_output(raw);
+        let payload = parse_review_payload(&parsed.combined_output)
+            .or_else(|_| parse_review_payload(raw))
+            .with_context(|| format!("failed to parse `{}` review output", self.name))?;
+
+        let verdict = match payload.verdict.as_str() {
+            "approve" => ReviewVerdict::Approve,
+            "request_changes" => ReviewVerdict::RequestChanges(
+                payload
+                    .request_changes_reason
+                    .unwrap_or_else(|| "changes requested".to_string()),
+            ),
+            "abstain" => ReviewVerdict::Abstain,
+            other => anyhow::bail!("unsupported `{}` review verdict `{other}`", self.name),
+        };
+
+        Ok(ReviewResult {
+            reviewer_name: self.name.clone(),
+            candidate_label: payload.candidate_label,
+            verdict,
+            scores: payload.scores,
+            issues: payload.issues,
+            summary: payload.summary,
+            duration: Duration::ZERO,
+        })
+    }
+
+    fn execute_template(&self) -> &[String] {
+        self.template.execute_args.as_deref().unwrap_or_default()
+    }
+
+    fn render_args(
+        &self,
+        template: &[String],
+        prompt: &str,
+        prompt_file: Option<&Path>,
+        worktree: &str,
+    ) -> Vec<String> {
+        let prompt_file = prompt_file
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+        let model = self.model.clone().unwrap_or_default();
+
+        let mut args = self.flags.clone();
+        args.extend(template.iter().map(|arg| {
+            arg.replace("{prompt_file}", &prompt_file)
+                .replace("{worktree}", worktree)
+                .replace("{model}", &model)
+                .replace("{prompt}", prompt)
+        }));
+
+        let references = |placeholder: &str| template.iter().any(|arg| arg.contains(placeholder));
+        match self.template.prompt_delivery {
+            PromptDelivery::Arg if !references("{prompt}") => args.push(prompt.to_string()),
+            PromptDelivery::File if !references("{prompt_file}") => args.push(prompt_file),
+            _ => {}
+        }
+
+        args
+    }
+
+    fn parse_output(&self, raw: &str) -> ParsedCodexOutput {
+        let output_path = self.template.output_path.as_deref();
+
+        match self.template.output_format {
+            OutputFormat::Text => ParsedCodexOutput {
+                combined_output: raw.trim_end_matches('\n').to_string(),
+                is_error: false,
+                token_usage: None,
+            },
+            OutputFormat::Json => match serde_json::from_str::<serde_json::Value>(raw.trim()) {
+                Ok(parsed) => {
+                    let text = match output_path {
+                        Some(path) => lookup_json_path(&parsed, path).and_then(json_value_text),
+                        None => extract_codex_text(&parsed),
+                    };
+                    ParsedCodexOutput {
+                        combined_output: text.unwrap_or_default(),
+                        is_error: codex_event_is_error(&parsed),
+                        token_usage: extract_token_usage(&parsed),
                     }
                 }
-                Err(_) => accumulated_text.push(line.to_string()),
-            }
+                Err(_) => parse_json_lines(raw, output_path),
+            },
+            OutputFormat::StreamJson => parse_json_lines(raw, output_path),
         }
+    }
 
-        let combined_output = final_output
-            .unwrap_or_else(|| accumulated_text.join("\n"))
-            .trim_end_matches('\n')
-            .to_string();
+    async fn run_template(
+        &self,
+        args: impl FnOnce(Option<&Path>) -> Vec<String>,
+        prompt: &str,
+        current_dir: Option<&Path>,
+    ) -> Result<(String, i32)> {
+        let prompt_file = match self.template.prompt_delivery {
+            PromptDelivery::File => {
+                let path = std::env::temp_dir().join(format!(
+                    "forge-council-{}-{}.md",
+                    self.name,
+                    uuid::Uuid::new_v4()
+                ));
+                tokio::fs::write(&path, prompt)
+                    .await
+                    .with_context(|| format!("failed to write prompt file {}", path.display()))?;
+                Some(path)
+            }
+            PromptDelivery::Arg | PromptDelivery::Stdin => None,
+        };
+        let stdin = (self.template.prompt_delivery == PromptDelivery::Stdin).then_some(prompt);
 
-        Ok(ParsedCodexOutput {
-            combined_output,
-            is_error,
-            token_usage,
-        })
+        let result = self
+            .run_command(&args(prompt_file.as_deref()), current_dir, stdin)
+            .await;
+
+        if let Some(path) = prompt_file {
+            let _ = tokio::fs::remove_file(path).await;
+        }
+
+        result
     }
 
     async fn run_command(
         &self,
         args: &[String],
         current_dir: Option<&Path>,
+        stdin: Option<&str>,
     ) -> Result<(String, i32)> {
         let mut cmd = Command::new(&self.command);
-        cmd.args(args);
+        cmd.args(args)
+            .stdin(if stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
         if let Some(dir) = current_dir {
             cmd.current_dir(dir);
         }
 
-        let output = cmd
-            .output()
+        let (mut child, group) = ProcessGroup::spawn(&mut cmd)
+            .with_context(|| format!("failed to execute `{}`", self.command))?;
+
+        let writer = match (stdin, child.stdin.take()) {
+            (Some(input), Some(mut handle)) => {
+                let input = input.to_string();
+                Some(tokio::spawn(async move {
+                    handle.write_all(input.as_bytes()).await?;
+                    handle.shutdown().await
+                }))
+            }
+            _ => None,
+        };
+
+        let output = child
+            .wait_with_output()
             .await
             .with_context(|| format!("failed to execute `{}`", self.command))?;
+        group.release();
+
+        if let Some(writer) = writer {
+            writer
+                .await
+                .context("prompt writer task panicked")?
+                .with_context(|| format!("failed to write prompt to `{}` stdin", self.command))?;
+        }
 
         let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
         let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
@@ -654,7 +1019,7 @@ impl CodexWorker {
 }
 
 #[async_trait]
-impl Worker for CodexWorker {
+impl Worker for CommandWorker {
     fn name(&self) -> &str {
         &self.name
     }
@@ -666,8 +1031,13 @@ impl Worker for CodexWorker {
         worktree_path: &Path,
     ) -> Result<WorkerResult> {
         let start = Instant::now();
-        let args = self.build_execute_args(phase, prompt, worktree_path);
-        let (raw, exit_code) = self.run_command(&args, Some(worktree_path)).await?;
+        let (raw, exit_code) = self
+            .run_template(
+                |prompt_file| self.build_execute_args(phase, prompt, worktree_path, prompt_file),
+                prompt,
+                Some(worktree_path),
+            )
+            .await?;
         let mut result = self.parse_execute_output(&raw)?;
         result.duration = start.elapsed();
         result.exit_code = exit_code.max(result.exit_code);
@@ -681,37 +1051,107 @@ impl Worker for CodexWorker {
         candidate_label: &str,
     ) -> Result<ReviewResult> {
         let start = Instant::now();
-        let args = self.build_review_args(phase, diff, candidate_label);
-        let (raw, exit_code) = self.run_command(&args, None).await?;
-        let mut result = self.parse_review_output(&raw)?;
-        result.duration = start.elapsed();
+        let prompt = review_prompt(phase, diff, candidate_label);
+        let review_dir =
+            std::env::current_dir().context("failed to resolve the review directory")?;
+        let (raw, exit_code) = self
+            .run_template(
+                |prompt_file| {
+                    self.build_review_args(phase, diff, candidate_label, &review_dir, prompt_file)
+                },
+                &prompt,
+                Some(&review_dir),
+            )
+            .await?;
+        // A crashed command may still have printed something that parses
         if exit_code != 0 {
-            anyhow::bail!("Codex review command failed with exit code {exit_code}");
+            anyhow::bail!(
+                "`{}` review command failed with exit code {exit_code}",
+                self.name
+            );
         }
+        let mut result = self.parse_review_output(&raw)?;
+        result.duration = start.elapsed();
         Ok(result)
     }
 }
 
 pub fn create_workers(config: &CouncilConfig) -> Result<Vec<Arc<dyn Worker>>> {
     let mut worker_entries = config.workers.iter().collect::<Vec<_>>();
-    worker_entries.sort_by(|(left_name, _), (right_name, _)| left_name.cmp(right_name));
+    worker_entries.sort_by_key(|(name, _)| *name);
 
     worker_entries
         .into_iter()
         .map(|(name, worker_config)| {
-            create_worker(worker_config)
+            create_worker(name, worker_config)
                 .with_context(|| format!("failed to create council worker `{name}`"))
         })
         .collect()
 }
 
-pub fn create_chairman_worker(config: &CouncilConfig) -> Result<Arc<dyn Worker>> {
+/// Build the chairman worker, or `None` when the rule-based chairman is configured.
+pub fn create_chairman_worker(config: &CouncilConfig) -> Result<Option<Arc<dyn Worker>>> {
+    let Some(chairman) = &config.chairman else {
+        let chairman_config = legacy_chairman_config(config);
+        return create_worker(worker_command(&chairman_config.cmd), &chairman_config)
+            .map(Some)
+            .context("failed to create chairman worker");
+    };
+
+    if chairman.mode == ChairmanMode::Rules {
+        return Ok(None);
+    }
+
+    let mut chairman_config =
+        match (&chairman.worker, &chairman.cmd) {
+            (Some(name), _) => config.workers.get(name).cloned().with_context(|| {
+                format!("`[council.chairman]` references unknown worker `{name}`")
+            })?,
+            (None, Some(cmd)) => WorkerConfig {
+                cmd: cmd.clone(),
+                role: "chairman".to_string(),
+                flags: Vec::new(),
+                model: None,
+                reasoning_effort: None,
+                sandbox: None,
+                approval_policy: None,
+                template: chairman.template.clone(),
+            },
+            (None, None) => anyhow::bail!(
+                "`[council.chairman]` needs either `worker` or `cmd` unless `mode = \"rules\"`"
+            ),
+        };
+
+    chairman_config.role = "chairman".to_string();
+    if let Some(model) = &chairman.model {
+        chairman_config.model = Some(model.clone());
+    }
+    if let Some(reasoning_effort) = &chairman.reasoning_effort {
+        chairman_config.reasoning_effort = Some(reasoning_effort.clone());
+    }
+    if let Some(flags) = &chairman.flags {
+        chairman_config.flags = flags.clone();
+    }
+
+    let name = chairman
+        .worker
+        .as_deref()
+        .unwrap_or_else(|| worker_command(&chairman_config.cmd));
+    create_worker(name, &chairman_config)
+        .map(Some)
+        .context("failed to create chairman worker")
+}
+
+/// Pre-`[council.chairman]` behaviour: the first Codex worker, or plain `codex`.
+fn legacy_chairman_config(config: &CouncilConfig) -> WorkerConfig {
     let mut codex_entries = config
         .workers
         .iter()
-        .filter(|(_, worker_config)| worker_command(&worker_config.cmd) == "codex")
+        .filter(|(_, worker_config)| {
+            !worker_config.template.is_configured() && worker_command(&worker_config.cmd) == "codex"
+        })
         .collect::<Vec<_>>();
-    codex_entries.sort_by(|(left_name, _), (right_name, _)| left_name.cmp(right_name));
+    codex_entries.sort_by_key(|(name, _)| *name);
 
     let mut chairman_config = codex_entries
         .into_iter()
@@ -725,20 +1165,26 @@ pub fn create_chairman_worker(config: &CouncilConfig) -> Result<Arc<dyn Worker>>
             reasoning_effort: None,
             sandbox: None,
             approval_policy: None,
+            template: CommandTemplate::default(),
         });
 
     chairman_config.role = "chairman".to_string();
     chairman_config.model = Some(config.chairman_model.clone());
     chairman_config.reasoning_effort = Some(config.chairman_reasoning_effort.clone());
-
-    create_worker(&chairman_config).context("failed to create chairman worker")
+    chairman_config
 }
 
-fn create_worker(config: &WorkerConfig) -> Result<Arc<dyn Worker>> {
+fn create_worker(name: &str, config: &WorkerConfig) -> Result<Arc<dyn Worker>> {
+    if config.template.is_configured() {
+        return Ok(Arc::new(CommandWorker::new(config).with_name(name)));
+    }
+
     match worker_command(&config.cmd) {
-        "claude" => Ok(Arc::new(ClaudeWorker::new(config))),
-        "codex" => Ok(Arc::new(CodexWorker::new(config))),
-        other => anyhow::bail!("unknown worker command `{other}`"),
+        "claude" => Ok(Arc::new(ClaudeWorker::new(config).with_name(name))),
+        "codex" => Ok(Arc::new(CodexWorker::new(config).with_name(name))),
+        other => anyhow::bail!(
+            "unknown worker command `{other}`; set `execute_args` to run it as a command-template worker"
+        ),
     }
 }
 
@@ -1143,6 +1589,7 @@ mod tests {
 
     mod test_create {
         use super::*;
+        use crate::council::config::ChairmanConfig;
         use std::collections::HashMap;
         use std::fs;
         use std::os::unix::fs::PermissionsExt;
@@ -1157,6 +1604,7 @@ mod tests {
                 reasoning_effort: None,
                 sandbox: Some("workspace-write".to_string()),
                 approval_policy: None,
+                template: CommandTemplate::default(),
             }
         }
 
@@ -1205,6 +1653,26 @@ mod tests {
             assert_eq!(names, vec!["claude".to_string(), "codex".to_string()]);
         }
 
+        #[test]
+        fn test_create_workers_named_after_config_key() {
+            let mut opus = worker_config("claude");
+            opus.model = Some("opus".to_string());
+            let mut sonnet = worker_config("/usr/local/bin/claude");
+            sonnet.model = Some("sonnet".to_string());
+
+            let workers = create_workers(&council_config(HashMap::from([
+                ("claude-opus".to_string(), opus),
+                ("claude-sonnet".to_string(), sonnet),
+            ])))
+            .expect("workers sharing a command should be created");
+
+            let names = workers
+                .iter()
+                .map(|worker| worker.name().to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec!["claude-opus", "claude-sonnet"]);
+        }
+
         #[test]
         fn test_create_workers_unknown_cmd_errors() {
             let error = match create_workers(&council_config(HashMap::from([(
@@ -1220,6 +1688,21 @@ mod tests {
             assert!(error_text.contains("unknown"));
         }
 
+        #[test]
+        fn test_create_workers_command_template_for_unknown_cmd() {
+            let mut config = worker_config("gemini");
+            config.template.execute_args = Some(vec!["-p".to_string(), "{prompt}".to_string()]);
+
+            let workers = create_workers(&council_config(HashMap::from([(
+                "gemini".to_string(),
+                config,
+            )])))
+            .expect("command-template worker should be created");
+
+            assert_eq!(workers.len(), 1);
+            assert_eq!(workers[0].name(), "gemini");
+        }
+
         #[test]
         fn test_create_workers_empty_config() {
             let workers = create_workers(&CouncilConfig::default())
@@ -1239,7 +1722,8 @@ mod tests {
             };
 
             let worker = create_chairman_worker(&config)
-                .expect("chairman worker should be created from config");
+                .expect("chairman worker should be created from config")
+                .expect("llm chairman should produce a worker");
 
             tokio::runtime::Builder::new_current_thread()
                 .enable_all()
@@ -1257,6 +1741,92 @@ mod tests {
             assert!(args.lines().any(|line| line == "gpt-5.4-chairman"));
         }
 
+        #[test]
+        fn test_create_chairman_worker_references_configured_worker() {
+            let mut claude = worker_config("claude");
+            claude.model = Some("sonnet".to_string());
+            let config = CouncilConfig {
+                chairman: Some(ChairmanConfig {
+                    worker: Some("claude".to_string()),
+                    model: Some("opus".to_string()),
+                    ..ChairmanConfig::default()
+                }),
+                workers: HashMap::from([("claude".to_string(), claude)]),
+                ..CouncilConfig::default()
+            };
+
+            let worker = create_chairman_worker(&config)
+                .expect("chairman worker should be created")
+                .expect("llm chairman should produce a worker");
+
+            assert_eq!(worker.name(), "claude");
+        }
+
+        #[test]
+        fn test_create_chairman_worker_dedicated_command() {
+            let config = CouncilConfig {
+                chairman: Some(ChairmanConfig {
+                    cmd: Some("my-judge".to_string()),
+                    template: CommandTemplate {
+                        execute_args: Some(vec!["{prompt}".to_string()]),
+                        ..CommandTemplate::default()
+                    },
+                    ..ChairmanConfig::default()
+                }),
+                ..CouncilConfig::default()
+            };
+
+            let worker = create_chairman_worker(&config)
+                .expect("dedicated chairman should be created")
+                .expect("llm chairman should produce a worker");
+
+            assert_eq!(worker.name(), "my-judge");
+        }
+
+        #[test]
+        fn test_create_chairman_worker_unknown_reference_errors() {
+            let config = CouncilConfig {
+                chairman: Some(ChairmanConfig {
+                    worker: Some("missing".to_string()),
+                    ..ChairmanConfig::default()
+                }),
+                ..CouncilConfig::default()
+            };
+
+            let error = create_chairman_worker(&config)
+                .err()
+                .expect("unknown worker reference should fail");
+
+            assert!(format!("{error:#}").contains("unknown worker `missing`"));
+        }
+
+        #[test]
+        fn test_create_chairman_worker_requires_worker_or_cmd() {
+            let config = CouncilConfig {
+                chairman: Some(ChairmanConfig::default()),
+                ..CouncilConfig::default()
+            };
+
+            assert!(create_chairman_worker(&config).is_err());
+        }
+
+        #[test]
+        fn test_create_chairman_worker_rule_based_needs_no_worker() {
+            let config = CouncilConfig {
+                chairman: Some(ChairmanConfig {
+                    mode: ChairmanMode::Rules,
+                    ..ChairmanConfig::default()
+                }),
+                ..CouncilConfig::default()
+            };
+
+            assert!(
+                create_chairman_worker(&config)
+                    .expect("rule-based chairman should resolve")
+                    .is_none()
+            );
+        }
+
         #[test]
         fn test_create_workers_returns_arc_dyn_worker() {
             let workers: Vec<Arc<dyn Worker>> = create_workers(&council_config(HashMap::from([(
@@ -1282,6 +1852,7 @@ mod tests {
                 reasoning_effort: None,
                 sandbox: None,
                 approval_policy: None,
+                template: CommandTemplate::default(),
             }
         }
 
@@ -1493,6 +2064,7 @@ mod tests {
                 reasoning_effort: Some("xhigh".to_string()),
                 sandbox: Some("workspace-write".to_string()),
                 approval_policy: None,
+                template: CommandTemplate::default(),
             }
         }
 
@@ -1659,4 +2231,286 @@ mod tests {
             assert_eq!(result.summary, "Needs tests.");
         }
     }
+
+    mod test_command_worker {
+        use super::*;
+        use std::fs;
+        use std::os::unix::fs::PermissionsExt;
+        use tempfile::TempDir;
+
+        fn worker_config(template: CommandTemplate) -> WorkerConfig {
+            WorkerConfig {
+                cmd: "/usr/local/bin/agent".to_string(),
+                role: "worker".to_string(),
+                flags: vec![],
+                model: Some("agent-large".to_string()),
+                reasoning_effort: None,
+                sandbox: None,
+                approval_policy: None,
+                template,
+            }
+        }
+
+        fn template(args: &[&str]) -> CommandTemplate {
+            CommandTemplate {
+                execute_args: Some(args.iter().map(|arg| arg.to_string()).collect()),
+                ..CommandTemplate::default()
+            }
+        }
+
+        fn script_worker(body: &str, template: CommandTemplate) -> (TempDir, CommandWorker) {
+            let temp_dir = TempDir::new().expect("temp dir should be created");
+            let script_path = temp_dir.path().join("agent");
+            fs::write(&script_path, format!("#!/bin/sh\n{body}\n"))
+                .expect("script should be written");
+            let mut permissions = fs::metadata(&script_path)
+                .expect("script metadata should be readable")
+                .permissions();
+            permissions.set_mode(0o755);
+            fs::set_permissions(&script_path, permissions).expect("script should be executable");
+
+            let mut config = worker_config(template);
+            config.cmd = script_path.display().to_string();
+            (temp_dir, CommandWorker::new(&config))
+        }
+
+        #[test]
+        fn test_command_worker_name_from_cmd() {
+            let worker = CommandWorker::new(&worker_config(template(&[])));
+
+            assert_eq!(worker.name(), "agent");
+        }
+
+        #[test]
+        fn test_command_worker_build_execute_args_substitutes_placeholders() {
+            let worker = CommandWorker::new(&worker_config(template(&[
+                "--model",
+                "{model}",
+                "--cwd={worktree}",
+                "-p",
+                "{prompt}",
+            ])));
+
+            let args = worker.build_execute_args(
+                &test_phase(),
+                "implement feature",
+                Path::new("/tmp/council-worktree"),
+                None,
+            );
+
+            assert_eq!(
+                args,
+                vec![
+                    "--model",
+                    "agent-large",
+                    "--cwd=/tmp/council-worktree",
+                    "-p",
+                    "implement feature",
+                ]
+            );
+        }
+
+        #[test]
+        fn test_command_worker_arg_delivery_appends_prompt_without_placeholder() {
+            let mut config = worker_config(template(&["--yes"]));
+            config.flags = vec!["--verbose".to_string()];
+            let worker = CommandWorker::new(&config);
+
+            let args = worker.build_execute_args(&test_phase(), "do it", Path::new("/tmp"), None);
+
+            assert_eq!(args, vec!["--verbose", "--yes", "do it"]);
+        }
+
+        #[test]
+        fn test_command_worker_file_delivery_appends_prompt_file() {
+            let mut template = template(&["--message-file"]);
+            template.prompt_delivery = PromptDelivery::File;
+            let worker = CommandWorker::new(&worker_config(template));
+
+            let args = worker.build_execute_args(
+                &test_phase(),
+                "do it",
+                Path::new("/tmp"),
+                Some(Path::new("/tmp/prompt.md")),
+            );
+
+            assert_eq!(args, vec!["--message-file", "/tmp/prompt.md"]);
+        }
+
+        #[test]
+        fn test_command_worker_stdin_delivery_leaves_args_untouched() {
+            let mut template = template(&["run"]);
+            template.prompt_delivery = PromptDelivery::Stdin;
+            let worker = CommandWorker::new(&worker_config(template));
+
+            let args = worker.build_execute_args(&test_phase(), "do it", Path::new("/tmp"), None);
+
+            assert_eq!(args, vec!["run"]);
+        }
+
+        #[test]
+        fn test_command_worker_review_args_fall_back_to_execute_args() {
+            let worker = CommandWorker::new(&worker_config(template(&["-p", "{prompt}"])));
+
+            let args = worker.build_review_args(
+                &test_phase(),
+                "diff",
+                "Candidate Alpha",
+                Path::new("/tmp"),
+                None,
+            );
+
+            assert_eq!(args[0], "-p");
+            assert!(args[1].contains("Candidate Alpha"));
+            assert!(args[1].contains("Return JSON only"));
+        }
+
+        #[test]
+        fn test_command_worker_parse_text_output() {
+            let worker = CommandWorker::new(&worker_config(template(&[])));
+
+            let result = worker
+                .parse_execute_output("diff --git a/a b/a\n<progress>50</progress>\n")
+                .expect("text output should parse");
+
+            assert_eq!(
+                result.diff_text,
+                "diff --git a/a b/a\n<progress>50</progress>"
+            );
+            assert_eq!(result.exit_code, 0);
+            assert!(
+                result
+                    .signals
+                    .iter()
+                    .any(|signal| signal.contains("<progress>50</progress>"))
+            );
+        }
+
+        #[test]
+        fn test_command_worker_parse_json_output_with_path() {
+            let mut template = template(&[]);
+            template.output_format = OutputFormat::Json;
+            template.output_path = Some("response.candidates.0.text".to_string());
+            let worker = CommandWorker::new(&worker_config(template));
+
+            let result = worker
+                .parse_execute_output(
+                    r#"{"response":{"candidates":[{"text":"patched"}]},"usage":{"input_tokens":10,"output_tokens":4}}"#,
+                )
+                .expect("json output should parse");
+
+            assert_eq!(result.diff_text, "patched");
+            assert_eq!(
+                result.token_usage.as_ref().map(|usage| usage.output_tokens),
+                Some(4)
+            );
+        }
+
+        #[test]
+        fn test_command_worker_parse_stream_json_takes_last_match() {
+            let mut template = template(&[]);
+            template.output_format = OutputFormat::StreamJson;
+            template.output_path = Some("result".to_string());
+            let worker = CommandWorker::new(&worker_config(template));
+
+            let raw = concat!(
+                r#"{"type":"progress","message":"thinking"}"#,
+                "\n",
+                r#"{"type":"result","result":"first"}"#,
+                "\n",
+                r#"{"type":"result","result":"final answer"}"#
+            );
+            let result = worker
+                .parse_execute_output(raw)
+                .expect("stream-json output should parse");
+
+            assert_eq!(result.diff_text, "final answer");
+        }
+
+        #[test]
+        fn test_command_worker_parse_review_output_from_json_path() {
+            let mut template = template(&[]);
+            template.output_format = OutputFormat::Json;
+            template.output_path = Some("text".to_string());
+            let worker = CommandWorker::new(&worker_config(template));
+            let raw = r#"{"text":"{\"candidate_label\":\"Candidate Beta\",\"verdict\":\"request_changes\",\"request_changes_reason\":\"Missing tests\",\"scores\":{\"correctness\":0.6,\"completeness\":0.5,\"style\":0.75,\"performance\":0.7,\"overall\":0.6375},\"issues\":[],\"summary\":\"Needs tests.\"}"}"#;
+
+            let result = worker
+                .parse_review_output(raw)
+                .expect("review output should parse");
+
+            assert_eq!(result.reviewer_name, "agent");
+            assert_eq!(result.candidate_label, "Candidate Beta");
+            match result.verdict {
+                ReviewVerdict::RequestChanges(reason) => assert_eq!(reason, "Missing tests"),
+                other => panic!("expected request changes verdict, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn test_command_worker_parse_review_output_malformed() {
+            let worker = CommandWorker::new(&worker_config(template(&[])));
+
+            let error = worker
+                .parse_review_output("no json here")
+                .expect_err("malformed review output should error");
+
+            assert!(format!("{error:#}").contains("review output"));
+        }
+
+        #[tokio::test]
+        async fn test_command_worker_execute_via_stdin() {
+            let mut template = template(&[]);
+            template.prompt_delivery = PromptDelivery::Stdin;
+            let (_temp_dir, worker) = script_worker("printf 'got: '\ncat", template);
+
+            let result = worker
+                .execute(&test_phase(), "hello council", Path::new("/tmp"))
+                .await
+                .expect("stdin execute should succeed");
+
+            assert_eq!(result.diff_text, "got: hello council");
+            assert_eq!(result.exit_code, 0);
+        }
+
+        #[tokio::test]
+        async fn test_command_worker_execute_via_prompt_file() {
+            let mut template = template(&["--message-file", "{prompt_file}"]);
+            template.prompt_delivery = PromptDelivery::File;
+            let (_temp_dir, worker) = script_worker("cat \"$2\"", template);
+
+            let result = worker
+                .execute(&test_phase(), "from a file", Path::new("/tmp"))
+                .await
+                .expect("file execute should succeed");
+
+            assert_eq!(result.diff_text, "from a file");
+        }
+
+        #[tokio::test]
+        async fn test_command_worker_execute_propagates_exit_code() {
+            let (_temp_dir, worker) = script_worker("echo failed\nexit 3", template(&[]));
+
+            let result = worker
+                .execute(&test_phase(), "prompt", Path::new("/tmp"))
+                .await
+                .expect("failing command should still produce a result");
+
+            assert_eq!(result.exit_code, 3);
+            assert_eq!(result.diff_text, "failed");
+        }
+
+        #[tokio::test]
+        async fn test_command_worker_review_fails_on_exit_code_before_parsing() {
+            let (_temp_dir, worker) =
+                script_worker("echo 'thread main panicked'\nexit 2", template(&[]));
+
+            let error = worker
+                .review(&test_phase(), "diff", "A")
+                .await
+                .expect_err("a crashed review command should not yield a review");
+
+            assert!(format!("{error:#}").contains("exit code 2"));
+        }
+    }
 }
@@ -102,6 +102,31 @@ impl PhaseGraph {
             .iter()
             .all(|dep| completed.contains(dep))
     }
+
+    /// Insert child phases of a running phase into the graph.
+    ///
+    /// Phases that depend on `parent` are re-wired to also wait for every
+    /// child. Children are appended, so existing indices stay valid; the
+    /// indices of the new phases are returned. The graph is left unchanged
+    /// if the children reference unknown phases or would create a cycle.
+    pub fn insert_phases(
+        &mut self,
+        parent: PhaseIndex,
+        children: Vec<Phase>,
+    ) -> Result<Vec<PhaseIndex>> {
+        let child_numbers: Vec<String> = children.iter().map(|p| p.number.clone()).collect();
+        let mut phases = self.phases.clone();
+        for &dependent in self.dependents(parent) {
+            phases[dependent]
+                .depends_on
+                .extend(child_numbers.iter().cloned());
+        }
+
+        let start = phases.len();
+        phases.extend(children);
+        *self = DagBuilder::new(phases).build()?;
+        Ok((start..self.len()).collect())
+    }
 }
 
 /// Builder for constructing phase graphs.
@@ -332,4 +357,38 @@ mod tests {
         // Phase 03 can now run
         assert!(graph.dependencies_satisfied(2, &completed));
     }
+
+    #[test]
+    fn test_insert_phases_rewires_dependents() {
+        let phases = vec![
+            phase("01", vec![]),
+            phase("02", vec!["01"]),
+            phase("03", vec!["02"]),
+        ];
+        let mut graph = DagBuilder::new(phases).build().unwrap();
+
+        let added = graph
+            .insert_phases(
+                1,
+                vec![phase("02.1", vec!["02"]), phase("02.2", vec!["02.1"])],
+            )
+            .unwrap();
+
+        assert_eq!(added, vec![3, 4]);
+        assert_eq!(graph.get_index("02"), Some(1));
+        assert_eq!(graph.dependencies(3), &[1]);
+        assert_eq!(graph.dependencies(4), &[3]);
+        // Phase 03 now waits for the children as well as their parent
+        assert_eq!(graph.dependencies(2), &[1, 3, 4]);
+        assert_eq!(graph.leaf_phases(), vec![2]);
+
+        // Unknown dependencies leave the graph untouched
+        assert!(
+            graph
+                .insert_phases(0, vec![phase("01.1", vec!["99"])])
+                .is_err()
+        );
+        assert_eq!(graph.len(), 5);
+        assert_eq!(graph.dependents(0), &[1]);
+    }
 }
@@ -0,0 +1,490 @@
+//! Persisted swarm state for `forge swarm --resume`.
+//!
+//! The executor records every [`PhaseEvent`] into a [`DagCheckpoint`] and
+//! writes it to `.forge/swarm-state.json`. When a swarm is interrupted, the
+//! checkpoint is used to rebuild the scheduler: completed phases stay done,
+//! decomposed phases keep their plan and sub-task progress, sub-phases they
+//! spawned are scheduled again from the parent's result, and failed
+//! phases are queued again while the [`ResumePolicy`] allows another attempt.
+//! Phases that were still running when the swarm stopped simply run again.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::dag::executor::PhaseEvent;
+use crate::dag::scheduler::DagScheduler;
+use crate::dag::state::{DagState, DagSummary, PhaseResult};
+use crate::decomposition::{PreflightDecision, TaskStatus};
+use crate::phase::Phase;
+
+/// File name of the checkpoint inside `.forge/`.
+pub const CHECKPOINT_FILE: &str = "swarm-state.json";
+
+/// Decides which failed phases run again on resume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResumePolicy {
+    /// Total attempts a phase gets, counting the first run
+    pub max_attempts: u32,
+}
+
+impl Default for ResumePolicy {
+    fn default() -> Self {
+        Self { max_attempts: 3 }
+    }
+}
+
+impl ResumePolicy {
+    /// Whether a phase that failed after `attempts` runs may run again.
+    pub fn allows_retry(&self, attempts: u32) -> bool {
+        attempts < self.max_attempts
+    }
+}
+
+/// Progress of a sub-task of a decomposed phase.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubTaskProgress {
+    pub name: String,
+    pub status: TaskStatus,
+    #[serde(default)]
+    pub iterations: u32,
+}
+
+/// Sub-task progress of a decomposed phase.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecompositionProgress {
+    pub task_count: usize,
+    pub total_budget: u32,
+    /// Sub-tasks by ID
+    #[serde(default)]
+    pub tasks: BTreeMap<String, SubTaskProgress>,
+}
+
+impl DecompositionProgress {
+    /// IDs of the sub-tasks that completed.
+    pub fn completed_tasks(&self) -> Vec<&str> {
+        self.tasks
+            .iter()
+            .filter(|(_, t)| t.status == TaskStatus::Completed)
+            .map(|(id, _)| id.as_str())
+            .collect()
+    }
+}
+
+/// Snapshot of a swarm execution, updated after every event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DagCheckpoint {
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub state: DagState,
+    /// Phases selected for this swarm, so `--from`/`--only` need not be repeated
+    pub phases: Vec<String>,
+    pub summary: DagSummary,
+    /// Number of times each phase was started
+    #[serde(default)]
+    pub attempts: HashMap<String, u32>,
+    /// Sub-task progress of decomposed phases
+    #[serde(default)]
+    pub decompositions: HashMap<String, DecompositionProgress>,
+    /// Pre-flight size estimates and decomposition decisions by phase
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub preflight: HashMap<String, PreflightDecision>,
+}
+
+impl DagCheckpoint {
+    /// Start a checkpoint for the given phases.
+    pub fn new(phases: &[Phase]) -> Self {
+        let now = Utc::now();
+        Self {
+            started_at: now,
+            updated_at: now,
+            state: DagState::Idle,
+            phases: phases.iter().map(|p| p.number.clone()).collect(),
+            summary: DagSummary::new(phases.len()),
+            attempts: HashMap::new(),
+            decompositions: HashMap::new(),
+            preflight: HashMap::new(),
+        }
+    }
+
+    /// Path of the checkpoint inside the given `.forge` directory.
+    pub fn path(forge_dir: &Path) -> PathBuf {
+        forge_dir.join(CHECKPOINT_FILE)
+    }
+
+    /// Load the checkpoint, if one was written.
+    pub fn load(forge_dir: &Path) -> Result<Option<Self>> {
+        let path = Self::path(forge_dir);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .map(Some)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Write the checkpoint to disk.
+    pub fn save(&self, forge_dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(forge_dir)?;
+        let path = Self::path(forge_dir);
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Number of times the phase was started.
+    pub fn attempts(&self, phase: &str) -> u32 {
+        self.attempts.get(phase).copied().unwrap_or(0)
+    }
+
+    /// Update the checkpoint from an executor event.
+    pub fn record(&mut self, event: &PhaseEvent) {
+        self.updated_at = Utc::now();
+        match event {
+            PhaseEvent::Started { phase, .. } => {
+                *self.attempts.entry(phase.clone()).or_default() += 1;
+                self.state = DagState::Running;
+            }
+            PhaseEvent::Completed { phase, result } => {
+                self.summary.remove_result(phase);
+                self.summary.add_result((**result).clone());
+            }
+            PhaseEvent::PreflightEstimated { phase, decision } => {
+                self.preflight.insert(phase.clone(), (**decision).clone());
+            }
+            PhaseEvent::DecompositionCompleted {
+                phase,
+                task_count,
+                total_budget,
+            } => {
+                let progress = self.decompositions.entry(phase.clone()).or_default();
+                progress.task_count = *task_count;
+                progress.total_budget = *total_budget;
+            }
+            PhaseEvent::SubTaskStarted {
+                phase,
+                task_id,
+                task_name,
+            } => {
+                self.decompositions
+                    .entry(phase.clone())
+                    .or_default()
+                    .tasks
+                    .insert(
+                        task_id.clone(),
+                        SubTaskProgress {
+                            name: task_name.clone(),
+                            status: TaskStatus::InProgress,
+                            iterations: 0,
+                        },
+                    );
+            }
+            PhaseEvent::SubTaskCompleted {
+                phase,
+                task_id,
+                success,
+                iterations,
+            } => {
+                let progress = self.decompositions.entry(phase.clone()).or_default();
+                let task =
+                    progress
+                        .tasks
+                        .entry(task_id.clone())
+                        .or_insert_with(|| SubTaskProgress {
+                            name: task_id.clone(),
+                            status: TaskStatus::Pending,
+                            iterations: 0,
+                        });
+                task.status = if *success {
+                    TaskStatus::Completed
+                } else {
+                    TaskStatus::Failed
+                };
+                task.iterations = *iterations;
+            }
+            PhaseEvent::DagCompleted { summary, .. } => {
+                self.summary = summary.clone();
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether there is anything left to run.
+    pub fn is_finished(&self) -> bool {
+        self.state == DagState::Completed
+    }
+
+    /// Apply the checkpoint to a freshly built scheduler and summary.
+    ///
+    /// Returns the results that were restored, in phase order. Failed phases
+    /// the policy allows to retry are left pending and not returned.
+    pub fn restore(
+        &self,
+        scheduler: &mut DagScheduler,
+        summary: &mut DagSummary,
+        policy: &ResumePolicy,
+    ) -> Vec<PhaseResult> {
+        let mut results: Vec<&PhaseResult> = self.summary.phase_results.values().collect();
+        results.sort_by(|a, b| a.phase.cmp(&b.phase));
+
+        let mut restored = Vec::new();
+        for result in results {
+            if scheduler.get_node(&result.phase).is_none() {
+                continue;
+            }
+            if result.is_success() {
+                // Phases spawned at runtime are rebuilt from their parent's result
+                if !result.spawned_phases.is_empty() {
+                    match scheduler.insert_phases(&result.phase, result.spawned_phases.clone()) {
+                        Ok(added) => summary.total_phases += added.len(),
+                        Err(e) => warn!("Cannot restore sub-phases: {:#}", e),
+                    }
+                }
+                scheduler.mark_completed(&result.phase, result.iterations);
+            } else if policy.allows_retry(self.attempts(&result.phase)) {
+                continue;
+            } else {
+                scheduler.mark_failed(&result.phase, result.error().unwrap_or("Unknown error"));
+            }
+            summary.add_result(result.clone());
+            restored.push(result.clone());
+        }
+        restored
+    }
+}
+
+/// Records events into a checkpoint and writes it after each one.
+pub struct CheckpointWriter {
+    forge_dir: PathBuf,
+    checkpoint: Mutex<DagCheckpoint>,
+}
+
+impl CheckpointWriter {
+    pub fn new(forge_dir: &Path, checkpoint: DagCheckpoint) -> Self {
+        Self {
+            forge_dir: forge_dir.to_path_buf(),
+            checkpoint: Mutex::new(checkpoint),
+        }
+    }
+
+    /// Record an event and persist the checkpoint.
+    pub fn record(&self, event: &PhaseEvent) {
+        self.update(|checkpoint| checkpoint.record(event));
+    }
+
+    /// Set the overall state and persist the checkpoint.
+    pub fn set_state(&self, state: DagState) {
+        self.update(|checkpoint| checkpoint.state = state);
+    }
+
+    fn update(&self, apply: impl FnOnce(&mut DagCheckpoint)) {
+        let mut checkpoint = self
+            .checkpoint
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        apply(&mut checkpoint);
+        if let Err(e) = checkpoint.save(&self.forge_dir) {
+            warn!("Failed to save swarm checkpoint: {:#}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::FileChangeSummary;
+    use crate::dag::scheduler::{DagConfig, PhaseStatus};
+    use std::time::Duration;
+    use tempfile::tempdir;
+
+    fn phases() -> Vec<Phase> {
+        vec![
+            Phase::new("01", "Setup", "SETUP DONE", 5, "Setup", vec![]),
+            Phase::new("02", "Api", "API DONE", 5, "Api", vec!["01".into()]),
+            Phase::new("03", "Db", "DB DONE", 5, "Db", vec!["01".into()]),
+            Phase::new("04", "Ui", "UI DONE", 5, "Ui", vec!["02".into()]),
+        ]
+    }
+
+    fn completed(phase: &str, iterations: u32) -> PhaseEvent {
+        PhaseEvent::Completed {
+            phase: phase.to_string(),
+            result: Box::new(PhaseResult::success(
+                phase,
+                iterations,
+                FileChangeSummary::default(),
+                Duration::ZERO,
+            )),
+        }
+    }
+
+    fn failed(phase: &str) -> PhaseEvent {
+        PhaseEvent::Completed {
+            phase: phase.to_string(),
+            result: Box::new(PhaseResult::failure(phase, "boom", 5, Duration::ZERO)),
+        }
+    }
+
+    fn started(phase: &str) -> PhaseEvent {
+        PhaseEvent::Started {
+            phase: phase.to_string(),
+            wave: 0,
+        }
+    }
+
+    #[test]
+    fn test_record_tracks_attempts_and_results() {
+        let mut checkpoint = DagCheckpoint::new(&phases());
+        checkpoint.record(&started("01"));
+        checkpoint.record(&failed("01"));
+        checkpoint.record(&started("01"));
+        checkpoint.record(&completed("01", 3));
+
+        assert_eq!(checkpoint.attempts("01"), 2);
+        assert_eq!(checkpoint.state, DagState::Running);
+        assert_eq!(checkpoint.summary.completed, 1);
+        assert_eq!(checkpoint.summary.failed, 0);
+    }
+
+    #[test]
+    fn test_record_subtask_progress() {
+        let mut checkpoint = DagCheckpoint::new(&phases());
+        checkpoint.record(&PhaseEvent::DecompositionCompleted {
+            phase: "02".into(),
+            task_count: 2,
+            total_budget: 6,
+        });
+        for id in ["t1", "t2"] {
+            checkpoint.record(&PhaseEvent::SubTaskStarted {
+                phase: "02".into(),
+                task_id: id.into(),
+                task_name: format!("Task {id}"),
+            });
+        }
+        checkpoint.record(&PhaseEvent::SubTaskCompleted {
+            phase: "02".into(),
+            task_id: "t1".into(),
+            success: true,
+            iterations: 2,
+        });
+
+        let progress = &checkpoint.decompositions["02"];
+        assert_eq!(progress.task_count, 2);
+        assert_eq!(progress.completed_tasks(), vec!["t1"]);
+        assert_eq!(progress.tasks["t2"].status, TaskStatus::InProgress);
+    }
+
+    #[test]
+    fn test_record_preflight_decision() {
+        let mut checkpoint = DagCheckpoint::new(&phases());
+        let decision = PreflightDecision {
+            estimate: Default::default(),
+            budget: 5,
+            threshold_percent: 100,
+            decompose: false,
+        };
+        checkpoint.record(&PhaseEvent::PreflightEstimated {
+            phase: "02".into(),
+            decision: Box::new(decision.clone()),
+        });
+
+        assert_eq!(checkpoint.preflight["02"], decision);
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let dir = tempdir().unwrap();
+        assert!(DagCheckpoint::load(dir.path()).unwrap().is_none());
+
+        let mut checkpoint = DagCheckpoint::new(&phases());
+        checkpoint.record(&started("01"));
+        checkpoint.record(&completed("01", 2));
+        checkpoint.save(dir.path()).unwrap();
+
+        let loaded = DagCheckpoint::load(dir.path()).unwrap().unwrap();
+        assert_eq!(loaded.phases, vec!["01", "02", "03", "04"]);
+        assert_eq!(loaded.attempts("01"), 1);
+        assert!(loaded.summary.phase_results["01"].is_success());
+    }
+
+    #[test]
+    fn test_restore_requeues_failed_phases_within_policy() {
+        let mut checkpoint = DagCheckpoint::new(&phases());
+        checkpoint.record(&started("01"));
+        checkpoint.record(&completed("01", 2));
+        checkpoint.record(&started("02"));
+        checkpoint.record(&failed("02"));
+        checkpoint.record(&started("03"));
+        checkpoint.record(&failed("03"));
+        checkpoint.record(&started("03"));
+        checkpoint.record(&failed("03"));
+
+        let mut scheduler = DagScheduler::from_phases(&phases(), DagConfig::default()).unwrap();
+        let mut summary = DagSummary::new(4);
+        let policy = ResumePolicy { max_attempts: 2 };
+        let restored = checkpoint.restore(&mut scheduler, &mut summary, &policy);
+
+        let restored: Vec<&str> = restored.iter().map(|r| r.phase.as_str()).collect();
+        assert_eq!(restored, vec!["01", "03"]);
+        assert_eq!(summary.completed, 1);
+        assert_eq!(summary.failed, 1);
+        assert!(matches!(
+            scheduler.get_node("03").unwrap().status,
+            PhaseStatus::Failed { .. }
+        ));
+
+        // 02 failed once and is allowed a second attempt
+        let ready: Vec<String> = scheduler
+            .get_ready_phases()
+            .iter()
+            .map(|n| n.phase.number.clone())
+            .collect();
+        assert_eq!(ready, vec!["02"]);
+    }
+
+    #[test]
+    fn test_restore_rebuilds_spawned_phases() {
+        let parent = &phases()[1];
+        let child =
+            crate::phase::SubPhase::new("02", 1, "Auth", "AUTH DONE", 3, "").to_phase(parent);
+        let mut checkpoint = DagCheckpoint::new(&phases());
+        checkpoint.record(&completed("01", 2));
+        checkpoint.record(&PhaseEvent::Completed {
+            phase: "02".to_string(),
+            result: Box::new(
+                PhaseResult::success("02", 4, FileChangeSummary::default(), Duration::ZERO)
+                    .with_spawned_phases(vec![child]),
+            ),
+        });
+        checkpoint.record(&completed("02.1", 3));
+
+        let mut scheduler = DagScheduler::from_phases(&phases(), DagConfig::default()).unwrap();
+        let mut summary = DagSummary::new(4);
+        let restored = checkpoint.restore(&mut scheduler, &mut summary, &ResumePolicy::default());
+
+        assert_eq!(restored.len(), 3);
+        assert_eq!(summary.total_phases, 5);
+        assert!(scheduler.get_node("02.1").unwrap().status.is_success());
+        let ready: Vec<String> = scheduler
+            .get_ready_phases()
+            .iter()
+            .map(|n| n.phase.number.clone())
+            .collect();
+        assert_eq!(ready, vec!["03", "04"]);
+    }
+
+    #[test]
+    fn test_retry_policy() {
+        let policy = ResumePolicy::default();
+        assert!(policy.allows_retry(1));
+        assert!(policy.allows_retry(2));
+        assert!(!policy.allows_retry(3));
+        assert!(!ResumePolicy { max_attempts: 1 }.allows_retry(1));
+    }
+}
@@ -0,0 +1,514 @@
+//! Control channel for a running swarm.
+//!
+//! A [`ControlServer`] binds a loopback HTTP endpoint while `forge swarm` runs
+//! and announces its URL in `.forge/swarm.status`. Other `forge swarm`
+//! invocations use [`ControlClient`] to talk to it:
+//!
+//! | Endpoint        | Purpose                                              |
+//! |-----------------|------------------------------------------------------|
+//! | `GET /health`   | Liveness check                                       |
+//! | `GET /status`   | Current [`ControlStatus`]                            |
+//! | `GET /events`   | [`PhaseEvent`]s as newline-delimited JSON, live      |
+//! | `POST /command` | Send a [`ControlCommand`] to the executor            |
+//!
+//! `POST /command` requires `Authorization: Bearer <token>` with the per-run
+//! token the server generates; `forge swarm` stores it next to the URL in
+//! the status file, which only the owner can read.
+//!
+//! Commands are queued for the [`DagExecutor`](super::DagExecutor), which
+//! applies them between scheduling steps through its [`SwarmControl`] handle.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result, bail};
+use axum::{
+    Json, Router,
+    body::Body,
+    extract::State,
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+use tokio::sync::{Mutex, Notify, RwLock, broadcast, mpsc, oneshot};
+use tracing::{error, warn};
+
+use super::executor::PhaseEvent;
+use super::state::DagState;
+use crate::util::bearer_token_matches;
+
+/// Number of events buffered for slow `/events` subscribers.
+const EVENT_BUFFER: usize = 256;
+
+/// How to stop a running swarm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AbortMode {
+    /// Start no new phases and stop running ones after their current iteration.
+    Graceful,
+    /// Kill running phases right away.
+    Immediate,
+}
+
+/// A command for a running swarm.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ControlCommand {
+    Abort {
+        mode: AbortMode,
+    },
+    /// Stop starting new phases; running phases continue.
+    Pause,
+    Resume,
+    /// Stop a running phase, or prevent a pending one from starting.
+    CancelPhase {
+        phase: String,
+    },
+    /// Run a failed or cancelled phase again.
+    RetryPhase {
+        phase: String,
+    },
+    SetMaxParallel {
+        max_parallel: usize,
+    },
+}
+
+/// Live state of a running swarm, as served by `GET /status`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ControlStatus {
+    pub state: DagState,
+    pub paused: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aborting: Option<AbortMode>,
+    pub max_parallel: usize,
+    pub total_phases: usize,
+    pub pending: Vec<String>,
+    pub running: Vec<String>,
+    pub completed: Vec<String>,
+    pub failed: Vec<String>,
+}
+
+/// Executor side of the control channel.
+pub struct SwarmControl {
+    commands: Mutex<mpsc::UnboundedReceiver<ControlCommand>>,
+    wakeup: Arc<Notify>,
+    events: broadcast::Sender<PhaseEvent>,
+    status: Arc<RwLock<ControlStatus>>,
+}
+
+impl SwarmControl {
+    /// Take the next queued command, if any.
+    pub async fn try_recv(&self) -> Option<ControlCommand> {
+        self.commands.lock().await.try_recv().ok()
+    }
+
+    /// Resolve when a new command has been queued.
+    pub async fn notified(&self) {
+        self.wakeup.notified().await;
+    }
+
+    /// Forward an event to `/events` subscribers.
+    pub fn publish(&self, event: &PhaseEvent) {
+        // No subscribers is not an error
+        let _ = self.events.send(event.clone());
+    }
+
+    /// Replace the status served by `/status`.
+    pub async fn set_status(&self, status: ControlStatus) {
+        *self.status.write().await = status;
+    }
+}
+
+/// State shared between the HTTP handlers.
+#[derive(Clone)]
+struct ControlState {
+    commands: mpsc::UnboundedSender<ControlCommand>,
+    wakeup: Arc<Notify>,
+    events: broadcast::Sender<PhaseEvent>,
+    status: Arc<RwLock<ControlStatus>>,
+    /// Bearer token required to send commands
+    token: String,
+}
+
+/// Loopback HTTP server exposing the control channel.
+pub struct ControlServer {
+    state: ControlState,
+    shutdown_tx: Option<oneshot::Sender<()>>,
+    addr: Option<SocketAddr>,
+}
+
+impl ControlServer {
+    /// Create a server and the matching executor handle.
+    pub fn new() -> (Self, SwarmControl) {
+        let (commands_tx, commands_rx) = mpsc::unbounded_channel();
+        let (events, _) = broadcast::channel(EVENT_BUFFER);
+        let wakeup = Arc::new(Notify::new());
+        let status = Arc::new(RwLock::new(ControlStatus::default()));
+
+        let server = Self {
+            state: ControlState {
+                commands: commands_tx,
+                wakeup: wakeup.clone(),
+                events: events.clone(),
+                status: status.clone(),
+                token: uuid::Uuid::new_v4().simple().to_string(),
+            },
+            shutdown_tx: None,
+            addr: None,
+        };
+        let control = SwarmControl {
+            commands: Mutex::new(commands_rx),
+            wakeup,
+            events,
+            status,
+        };
+        (server, control)
+    }
+
+    /// The bearer token clients must send with commands.
+    pub fn token(&self) -> &str {
+        &self.state.token
+    }
+
+    /// Start serving on a dynamic loopback port and return the base URL.
+    pub async fn start(&mut self) -> Result<String> {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .context("Failed to bind swarm control server")?;
+        let addr = listener
+            .local_addr()
+            .context("Failed to get control server address")?;
+        self.addr = Some(addr);
+
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        self.shutdown_tx = Some(shutdown_tx);
+
+        let app = build_router(self.state.clone());
+        tokio::spawn(async move {
+            if let Err(e) = axum::serve(listener, app)
+                .with_graceful_shutdown(async {
+                    let _ = shutdown_rx.await;
+                })
+                .await
+            {
+                error!("Swarm control server error: {}", e);
+            }
+        });
+
+        Ok(format!("http://{}", addr))
+    }
+
+    /// Stop the server.
+    pub fn stop(&mut self) {
+        if let Some(tx) = self.shutdown_tx.take() {
+            let _ = tx.send(());
+        }
+        self.addr = None;
+    }
+
+    /// Base URL of the server if running.
+    pub fn url(&self) -> Option<String> {
+        self.addr.map(|addr| format!("http://{}", addr))
+    }
+}
+
+fn build_router(state: ControlState) -> Router {
+    Router::new()
+        .route("/health", get(|| async { "ok" }))
+        .route("/status", get(status_handler))
+        .route("/events", get(events_handler))
+        .route("/command", post(command_handler))
+        .with_state(state)
+}
+
+async fn status_handler(State(state): State<ControlState>) -> Json<ControlStatus> {
+    Json(state.status.read().await.clone())
+}
+
+async fn command_handler(
+    State(state): State<ControlState>,
+    headers: HeaderMap,
+    Json(command): Json<ControlCommand>,
+) -> StatusCode {
+    let authorization = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok());
+    if !bearer_token_matches(authorization, &state.token) {
+        warn!("Rejected swarm control command without a valid token");
+        return StatusCode::UNAUTHORIZED;
+    }
+    if state.commands.send(command).is_err() {
+        return StatusCode::SERVICE_UNAVAILABLE;
+    }
+    state.wakeup.notify_one();
+    StatusCode::ACCEPTED
+}
+
+async fn events_handler(State(state): State<ControlState>) -> Response {
+    let rx = state.events.subscribe();
+    let stream = futures::stream::unfold(rx, |mut rx| async move {
+        loop {
+            match rx.recv().await {
+                Ok(event) => {
+                    let mut line = serde_json::to_string(&event).ok()?;
+                    line.push('\n');
+                    return Some((Ok::<_, std::convert::Infallible>(line), rx));
+                }
+                // A slow reader missed some events; carry on with the next one
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+    (
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(stream),
+    )
+        .into_response()
+}
+
+/// Client for the control server of a running swarm.
+pub struct ControlClient {
+    base_url: String,
+    token: Option<String>,
+    http: reqwest::Client,
+}
+
+impl ControlClient {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            token: None,
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Set the bearer token sent with commands.
+    pub fn with_token(mut self, token: &str) -> Self {
+        self.token = Some(token.to_string());
+        self
+    }
+
+    /// Fetch the current status.
+    pub async fn status(&self) -> Result<ControlStatus> {
+        let response = self
+            .http
+            .get(format!("{}/status", self.base_url))
+            .send()
+            .await
+            .context("Swarm control server is not reachable")?;
+        Ok(response.error_for_status()?.json().await?)
+    }
+
+    /// Queue a command for the executor.
+    pub async fn send(&self, command: &ControlCommand) -> Result<()> {
+        let mut request = self
+            .http
+            .post(format!("{}/command", self.base_url))
+            .json(command);
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .await
+            .context("Swarm control server is not reachable")?;
+        if !response.status().is_success() {
+            bail!("Swarm rejected the command ({})", response.status());
+        }
+        Ok(())
+    }
+
+    /// Stream events, calling `on_event` for each until the swarm completes
+    /// or the connection closes.
+    pub async fn watch(&self, mut on_event: impl FnMut(PhaseEvent)) -> Result<()> {
+        let mut response = self
+            .http
+            .get(format!("{}/events", self.base_url))
+            .send()
+            .await
+            .context("Swarm control server is not reachable")?
+            .error_for_status()?;
+
+        let mut buffer = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            buffer.extend_from_slice(&chunk);
+            while let Some(pos) = buffer.iter().position(|&b| b == b'\n') {
+                let line: Vec<u8> = buffer.drain(..=pos).collect();
+                if let Ok(event) = serde_json::from_slice::<PhaseEvent>(&line) {
+                    let finished = matches!(event, PhaseEvent::DagCompleted { .. });
+                    on_event(event);
+                    if finished {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::Request;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_control_command_serialization() {
+        let json = serde_json::to_string(&ControlCommand::Abort {
+            mode: AbortMode::Immediate,
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"command":"abort","mode":"immediate"}"#);
+
+        let parsed: ControlCommand =
+            serde_json::from_str(r#"{"command":"set_max_parallel","max_parallel":8}"#).unwrap();
+        assert_eq!(parsed, ControlCommand::SetMaxParallel { max_parallel: 8 });
+    }
+
+    #[tokio::test]
+    async fn test_command_endpoint_queues_command() {
+        let (server, control) = ControlServer::new();
+        let app = build_router(server.state.clone());
+
+        let response = app
+            .oneshot(
+                Request::post("/command")
+                    .header("content-type", "application/json")
+                    .header("authorization", format!("Bearer {}", server.token()))
+                    .body(Body::from(r#"{"command":"cancel_phase","phase":"03"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        assert_eq!(
+            control.try_recv().await,
+            Some(ControlCommand::CancelPhase {
+                phase: "03".to_string()
+            })
+        );
+        assert_eq!(control.try_recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_command_endpoint_requires_token() {
+        let (server, control) = ControlServer::new();
+
+        for authorization in [None, Some("Bearer not-the-token")] {
+            let mut request = Request::post("/command").header("content-type", "application/json");
+            if let Some(value) = authorization {
+                request = request.header("authorization", value);
+            }
+            let response = build_router(server.state.clone())
+                .oneshot(request.body(Body::from(r#"{"command":"pause"}"#)).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+        assert_eq!(control.try_recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_command_endpoint_after_executor_finished() {
+        let (server, control) = ControlServer::new();
+        drop(control);
+        let app = build_router(server.state.clone());
+
+        let response = app
+            .oneshot(
+                Request::post("/command")
+                    .header("content-type", "application/json")
+                    .header("authorization", format!("Bearer {}", server.token()))
+                    .body(Body::from(r#"{"command":"pause"}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_status_endpoint() {
+        let (server, control) = ControlServer::new();
+        control
+            .set_status(ControlStatus {
+                state: DagState::Running,
+                paused: true,
+                max_parallel: 3,
+                total_phases: 2,
+                running: vec!["01".to_string()],
+                ..Default::default()
+            })
+            .await;
+        let app = build_router(server.state.clone());
+
+        let response = app
+            .oneshot(Request::get("/status").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let status: ControlStatus = serde_json::from_slice(&body).unwrap();
+
+        assert!(status.paused);
+        assert_eq!(status.running, vec!["01"]);
+        assert_eq!(status.state, DagState::Running);
+    }
+
+    #[tokio::test]
+    async fn test_client_roundtrip() {
+        let (mut server, control) = ControlServer::new();
+        let url = match server.start().await {
+            Ok(url) => url,
+            Err(e) => {
+                // Binding may be forbidden in sandboxed environments
+                eprintln!("Skipping test_client_roundtrip: {:?}", e);
+                return;
+            }
+        };
+        assert_eq!(server.url().as_deref(), Some(url.as_str()));
+        control
+            .set_status(ControlStatus {
+                max_parallel: 4,
+                ..Default::default()
+            })
+            .await;
+
+        let client = ControlClient::new(&url);
+        assert_eq!(client.status().await.unwrap().max_parallel, 4);
+        assert!(client.send(&ControlCommand::Pause).await.is_err());
+
+        let client = client.with_token(server.token());
+        client.send(&ControlCommand::Pause).await.unwrap();
+        assert_eq!(control.try_recv().await, Some(ControlCommand::Pause));
+
+        server.stop();
+        assert!(server.url().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_events_endpoint_streams_ndjson() {
+        let (server, control) = ControlServer::new();
+        let app = build_router(server.state.clone());
+
+        let response = app
+            .oneshot(Request::get("/events").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        control.publish(&PhaseEvent::ReviewStarted {
+            phase: "02".to_string(),
+        });
+        drop(control);
+        drop(server);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+        assert_eq!(text, "{\"type\":\"review_started\",\"phase\":\"02\"}\n");
+    }
+}
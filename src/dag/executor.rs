@@ -11,6 +11,43 @@
 //! for progress reporting; they do not impose a barrier.  The real concurrency
 //! gate is the `max_parallel` semaphore, which limits how many phases may
 //! execute simultaneously regardless of their position in the dependency graph.
+//! A phase with a `weight` takes that many slots of the semaphore.
+//!
+//! Ready phases are started in the scheduler's priority order. When the next
+//! phase does not fit in the free slots, nothing behind it is started either,
+//! so a heavy phase on the critical path is not starved by lighter ones.
+//!
+//! ## Control
+//!
+//! With a [`SwarmControl`] attached, queued [`ControlCommand`]s are applied
+//! between scheduling steps: pausing stops new phases from starting, a
+//! graceful abort also asks running phases to stop after their current
+//! iteration, and an immediate abort kills them. Cancelled phases count as
+//! failed until retried.
+//!
+//! ## Isolation
+//!
+//! By default every phase runs in the project directory. With
+//! [`IsolationMode::Worktree`] each phase runs in its own git worktree and
+//! is merged back before it is marked completed, so dependents always start
+//! from a tree that contains their dependencies' work. A phase whose merge
+//! fails is reported as failed.
+//!
+//! ## Sub-phases
+//!
+//! When a phase decomposes, or requests sub-phases with `<spawn-subphase>`,
+//! the new phases are inserted into the running DAG once the phase finishes.
+//! They are scheduled like any other phase under the same `max_parallel`
+//! limit, and the phase's dependents wait for them as well.
+//!
+//! With pre-flight decomposition enabled, each top-level phase is sized before
+//! its first iteration (see [`SizeEstimator`]). A phase estimated above the
+//! threshold spends that iteration on a decomposition plan instead.
+//!
+//! With a [`PlanReviewHandler`] attached, every decomposition plan is reviewed
+//! before it runs. An approved or edited plan becomes sub-phases, a rejected
+//! one leaves the phase undivided, and a re-requested one makes the next
+//! iteration produce a new plan.
 //!
 //! ## Cancellation Semantics
 //!
@@ -20,23 +57,36 @@
 //! cancelled before the executor returns.
 
 use crate::config::Config;
-use crate::dag::scheduler::{DagConfig, DagScheduler};
+use crate::council::MergeOutcome;
+use crate::dag::checkpoint::{CheckpointWriter, DagCheckpoint, ResumePolicy};
+use crate::dag::control::{AbortMode, ControlCommand, ControlStatus, SwarmControl};
+use crate::dag::isolation::{
+    IsolationMode, PhaseWorktree, WorktreeIsolation, files_with_conflict_markers,
+};
+use crate::dag::scheduler::{DagConfig, DagScheduler, PhaseStatus};
+use crate::dag::scope::{ConflictLog, PhaseChanges, ScopeConflict};
 use crate::dag::state::{DagState, DagSummary, ExecutionTimer, PhaseResult};
 use crate::decomposition::{
-    DecompositionConfig, DecompositionDetector, DecompositionExecutor, ExecutionSignals,
-    parse_decomposition_output, parse_decomposition_request,
+    DecompositionConfig, DecompositionDetector, DecompositionExecutor, DecompositionTrigger,
+    ExecutionSignals, PendingPlan, PlanDecision, PlanReviewHandler, PreflightDecision,
+    SizeEstimator, TriggerReason, parse_decomposition_output, parse_decomposition_request,
+    replan_prompt,
 };
 use crate::forge_config::ForgeToml;
 use crate::init::get_forge_dir;
+use crate::orchestrator::retry::run_with_retry;
 use crate::orchestrator::review_integration::{ReviewIntegration, ReviewIntegrationConfig};
-use crate::orchestrator::{ClaudeRunner, IterationFeedback};
+use crate::orchestrator::{ClaudeRunner, FailureClass, IterationFeedback, PromptContext};
 use crate::phase::Phase;
+use crate::review::{ManualResolution, ManualReviewHandler, PendingReview};
+use crate::subphase::SubPhaseManager;
 use crate::tracker::GitTracker;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use tokio::sync::{Mutex, Semaphore, mpsc};
 use tokio::task::JoinHandle;
@@ -62,12 +112,30 @@ pub enum PhaseEvent {
     },
     /// Reviews started for a phase.
     ReviewStarted { phase: String },
+    /// A phase is waiting for a manual review decision.
+    ReviewPending {
+        phase: String,
+        review: Box<PendingReview>,
+    },
+    /// A pending manual review was decided.
+    ReviewResolved {
+        phase: String,
+        review_id: String,
+        resolution: Box<ManualResolution>,
+    },
     /// Reviews completed for a phase.
     ReviewCompleted {
         phase: String,
         passed: bool,
         findings_count: usize,
     },
+    /// Two phases that ran concurrently touched the same files; they will
+    /// not be scheduled together again.
+    ScopeConflict {
+        phase: String,
+        other: String,
+        files: Vec<PathBuf>,
+    },
     /// A wave of phases has started.
     WaveStarted { wave: usize, phases: Vec<String> },
     /// A wave of phases has completed.
@@ -78,8 +146,18 @@ pub enum PhaseEvent {
     },
     /// DAG execution completed.
     DagCompleted { success: bool, summary: DagSummary },
+    /// A phase was sized before its first iteration.
+    PreflightEstimated {
+        phase: String,
+        decision: Box<PreflightDecision>,
+    },
     /// A phase is being decomposed.
     DecompositionStarted { phase: String, reason: String },
+    /// A decomposition plan was approved, edited, re-requested or rejected.
+    DecompositionReviewed {
+        phase: String,
+        decision: Box<PlanDecision>,
+    },
     /// A phase decomposition completed.
     DecompositionCompleted {
         phase: String,
@@ -174,6 +252,14 @@ pub struct DagExecutor {
     dag_config: DagConfig,
     /// Event sender for progress updates.
     event_tx: Option<mpsc::Sender<PhaseEvent>>,
+    /// Reviewers asked for human decisions.
+    reviewers: PhaseReviewers,
+    /// Control channel of a running swarm.
+    control: Option<SwarmControl>,
+    /// Checkpoint of an interrupted run to continue from.
+    resume: Option<(DagCheckpoint, ResumePolicy)>,
+    /// Expected iterations per phase, for critical path scheduling
+    expected_iterations: HashMap<String, u32>,
 }
 
 impl DagExecutor {
@@ -183,24 +269,108 @@ impl DagExecutor {
             config,
             dag_config,
             event_tx: None,
+            reviewers: PhaseReviewers::default(),
+            control: None,
+            resume: None,
+            expected_iterations: HashMap::new(),
         }
     }
 
+    /// Continue an interrupted run from its checkpoint.
+    ///
+    /// Failed phases run again while `policy` allows another attempt.
+    pub fn with_resume(mut self, checkpoint: DagCheckpoint, policy: ResumePolicy) -> Self {
+        self.resume = Some((checkpoint, policy));
+        self
+    }
+
+    /// Estimate phase lengths from history instead of budgets when ranking
+    /// phases by their remaining critical path.
+    pub fn with_expected_iterations(mut self, expected: HashMap<String, u32>) -> Self {
+        self.expected_iterations = expected;
+        self
+    }
+
+    /// Accept commands from a swarm control channel and publish events to it.
+    pub fn with_control(mut self, control: SwarmControl) -> Self {
+        self.control = Some(control);
+        self
+    }
+
     /// Set the event channel for progress updates.
     pub fn with_event_channel(mut self, tx: mpsc::Sender<PhaseEvent>) -> Self {
         self.event_tx = Some(tx);
         self
     }
 
+    /// Set the reviewer asked for decisions in manual review mode.
+    ///
+    /// Each request is announced as [`PhaseEvent::ReviewPending`] first.
+    pub fn with_manual_reviewer(mut self, reviewer: Arc<dyn ManualReviewHandler>) -> Self {
+        self.reviewers.manual = Some(reviewer);
+        self
+    }
+
+    /// Set the reviewer that decides on decomposition plans before they run.
+    ///
+    /// Without one, a valid plan runs as soon as it is parsed.
+    pub fn with_plan_reviewer(mut self, reviewer: Arc<dyn PlanReviewHandler>) -> Self {
+        self.reviewers.plan = Some(reviewer);
+        self
+    }
+
     /// Execute all phases in the DAG.
     pub async fn execute(&self, phases: &[Phase]) -> Result<ExecutionResult> {
         let timer = ExecutionTimer::start();
 
         // Build the scheduler
-        let scheduler = DagScheduler::from_phases(phases, self.dag_config.clone())
+        let mut scheduler = DagScheduler::from_phases(phases, self.dag_config.clone())
             .context("Failed to build DAG scheduler")?;
 
+        // Keep apart phases that conflicted in earlier runs
+        let forge_dir = get_forge_dir(&self.config.project_dir);
+        let mut conflict_log = ConflictLog::load(&forge_dir).unwrap_or_else(|e| {
+            warn!("Ignoring unreadable scope conflict log: {:#}", e);
+            ConflictLog::default()
+        });
+        for conflict in &conflict_log.conflicts {
+            let (Some(a), Some(b)) = (
+                scheduler.get_node(&conflict.phases[0]),
+                scheduler.get_node(&conflict.phases[1]),
+            ) else {
+                continue;
+            };
+            if conflict.applies_to(&a.phase, &b.phase) {
+                scheduler.add_conflict(&conflict.phases[0], &conflict.phases[1]);
+            }
+        }
+
+        for (phase, iterations) in &self.expected_iterations {
+            scheduler.set_expected_iterations(phase, *iterations);
+        }
+
         let mut summary = DagSummary::new(scheduler.phase_count());
+        // Decomposed tasks scheduled as phases: phase -> (parent, task ID)
+        let mut sub_tasks: HashMap<String, (String, String)> = HashMap::new();
+
+        // Pick up where an interrupted run left off
+        let mut checkpoint = DagCheckpoint::new(phases);
+        let mut restored = Vec::new();
+        if let Some((previous, policy)) = &self.resume {
+            restored = previous.restore(&mut scheduler, &mut summary, policy);
+            for result in &restored {
+                sub_tasks.extend(sub_task_ids(result));
+            }
+            checkpoint.started_at = previous.started_at;
+            checkpoint.attempts = previous.attempts.clone();
+            checkpoint.decompositions = previous.decompositions.clone();
+            checkpoint.preflight = previous.preflight.clone();
+        }
+        checkpoint.summary = summary.clone();
+        let events = EventSink {
+            tx: self.event_tx.clone(),
+            checkpoint: Some(Arc::new(CheckpointWriter::new(&forge_dir, checkpoint))),
+        };
 
         if scheduler.phase_count() == 0 {
             return Ok(ExecutionResult {
@@ -225,19 +395,119 @@ impl DagExecutor {
             }
         }
 
+        for result in restored {
+            self.emit_event(
+                &events,
+                PhaseEvent::Completed {
+                    phase: result.phase.clone(),
+                    result: Box::new(result),
+                },
+            )
+            .await;
+        }
+
         // Create shared state
-        let semaphore = Arc::new(Semaphore::new(self.dag_config.max_parallel));
+        let mut max_parallel = self.dag_config.max_parallel;
+        let mut permits = max_parallel;
+        let mut paused = false;
+        let mut aborting: Option<AbortMode> = None;
+        let stop_requested = Arc::new(AtomicBool::new(false));
+        let semaphore = Arc::new(Semaphore::new(max_parallel));
         let scheduler = Arc::new(Mutex::new(scheduler));
         let (result_tx, mut result_rx) = mpsc::channel::<(String, PhaseResult)>(100);
+        let isolation = (self.dag_config.isolation == IsolationMode::Worktree)
+            .then(|| Arc::new(WorktreeIsolation::new(&self.config.project_dir)));
 
-        // Track active tasks
+        // Track active tasks and the slots each phase takes
         let mut active_tasks: HashMap<String, JoinHandle<()>> = HashMap::new();
+        let mut phase_slots: HashMap<String, usize> = scheduler
+            .lock()
+            .await
+            .nodes()
+            .iter()
+            .map(|n| (n.phase.number.clone(), n.phase.slots()))
+            .collect();
         let mut current_wave = 0;
 
+        // Phases that overlapped in time, and the files each one touched
+        let mut concurrent: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut touched: HashMap<String, PhaseChanges> = HashMap::new();
+
         // Main execution loop
         loop {
+            // Apply queued control commands
+            if let Some(control) = &self.control {
+                while let Some(command) = control.try_recv().await {
+                    info!(?command, "Swarm control command received");
+                    match command {
+                        ControlCommand::Abort { mode } => {
+                            aborting = Some(mode);
+                            stop_requested.store(true, Ordering::Relaxed);
+                            if mode == AbortMode::Immediate {
+                                for (_, handle) in active_tasks.drain() {
+                                    handle.abort();
+                                }
+                            }
+                        }
+                        ControlCommand::Pause => paused = true,
+                        ControlCommand::Resume => paused = false,
+                        ControlCommand::SetMaxParallel { max_parallel: max } => {
+                            if max == 0 {
+                                warn!("Ignoring max_parallel of 0");
+                                continue;
+                            }
+                            if max > permits {
+                                semaphore.add_permits(max - permits);
+                                permits = max;
+                            }
+                            max_parallel = max;
+                        }
+                        ControlCommand::CancelPhase { phase } => {
+                            let was_running = match active_tasks.remove(&phase) {
+                                Some(handle) => {
+                                    handle.abort();
+                                    true
+                                }
+                                None => false,
+                            };
+                            let cancelled = scheduler.lock().await.mark_cancelled(&phase);
+                            if !cancelled && !was_running {
+                                warn!(phase = %phase, "Cannot cancel a phase that has finished");
+                                continue;
+                            }
+                            let result =
+                                PhaseResult::failure(&phase, "cancelled", 0, Duration::ZERO);
+                            self.emit_event(
+                                &events,
+                                PhaseEvent::Completed {
+                                    phase: phase.clone(),
+                                    result: Box::new(result.clone()),
+                                },
+                            )
+                            .await;
+                            summary.add_result(result);
+                        }
+                        ControlCommand::RetryPhase { phase } => {
+                            if scheduler.lock().await.reset_failed(&phase) {
+                                summary.remove_result(&phase);
+                            } else {
+                                warn!(phase = %phase, "Only failed or cancelled phases can be retried");
+                            }
+                        }
+                    }
+                }
+            }
+
+            if aborting == Some(AbortMode::Immediate)
+                || (aborting.is_some() && active_tasks.is_empty())
+            {
+                break;
+            }
+
             // Get ready phases
-            let ready_phases: Vec<Phase> = {
+            let ready_phases: Vec<Phase> = if paused || aborting.is_some() {
+                Vec::new()
+            } else {
                 let sched = scheduler.lock().await;
                 sched
                     .get_ready_phases()
@@ -251,21 +521,40 @@ impl DagExecutor {
                 let wave_phases: Vec<String> =
                     ready_phases.iter().map(|p| p.number.clone()).collect();
 
-                self.emit_event(PhaseEvent::WaveStarted {
-                    wave: current_wave,
-                    phases: wave_phases.clone(),
-                })
+                self.emit_event(
+                    &events,
+                    PhaseEvent::WaveStarted {
+                        wave: current_wave,
+                        phases: wave_phases.clone(),
+                    },
+                )
                 .await;
 
-                // Spawn tasks for ready phases
+                // Spawn tasks for ready phases, in priority order
                 for phase in ready_phases {
                     // Check if we should spawn (respecting max_parallel)
-                    if active_tasks.len() >= self.dag_config.max_parallel {
+                    let used: usize = active_tasks
+                        .keys()
+                        .map(|p| phase_slots.get(p).copied().unwrap_or(1).min(max_parallel))
+                        .sum();
+                    let slots = phase.slots().min(max_parallel);
+                    if used + slots > max_parallel {
                         break;
                     }
 
                     let phase_number = phase.number.clone();
 
+                    for running in active_tasks.keys() {
+                        concurrent
+                            .entry(running.clone())
+                            .or_default()
+                            .insert(phase_number.clone());
+                        concurrent
+                            .entry(phase_number.clone())
+                            .or_default()
+                            .insert(running.clone());
+                    }
+
                     // Mark as running
                     {
                         let mut sched = scheduler.lock().await;
@@ -281,24 +570,65 @@ impl DagExecutor {
                         info!("DAG phase started");
                     }
 
-                    self.emit_event(PhaseEvent::Started {
-                        phase: phase_number.clone(),
-                        wave: current_wave,
-                    })
+                    self.emit_event(
+                        &events,
+                        PhaseEvent::Started {
+                            phase: phase_number.clone(),
+                            wave: current_wave,
+                        },
+                    )
                     .await;
+                    if let Some((parent, task_id)) = sub_tasks.get(&phase_number) {
+                        self.emit_event(
+                            &events,
+                            PhaseEvent::SubTaskStarted {
+                                phase: parent.clone(),
+                                task_id: task_id.clone(),
+                                task_name: phase.name.clone(),
+                            },
+                        )
+                        .await;
+                    }
 
                     // Spawn the phase execution task
-                    let permit = semaphore.clone().acquire_owned().await?;
+                    let permit = semaphore.clone().acquire_many_owned(slots as u32).await?;
                     let result_tx = result_tx.clone();
                     let config = self.config.clone();
                     let dag_config = self.dag_config.clone();
-                    let event_tx = self.event_tx.clone();
+                    let events = events.clone();
+                    let reviewers = self.reviewers.clone();
+                    let stop_requested = stop_requested.clone();
+                    let isolation = isolation.clone();
 
                     let handle = tokio::spawn(async move {
                         let _permit = permit; // Hold until complete
 
-                        let result =
-                            execute_single_phase(&phase, &config, &dag_config, event_tx).await;
+                        let result = match isolation {
+                            Some(isolation) => {
+                                execute_isolated_phase(
+                                    &phase,
+                                    &config,
+                                    &dag_config,
+                                    &isolation,
+                                    events,
+                                    reviewers,
+                                    stop_requested,
+                                )
+                                .await
+                            }
+                            None => {
+                                execute_single_phase(
+                                    &phase,
+                                    &config,
+                                    &config.project_dir,
+                                    &dag_config,
+                                    events,
+                                    reviewers,
+                                    stop_requested,
+                                )
+                                .await
+                            }
+                        };
 
                         result_tx.send((phase.number.clone(), result)).await.ok();
                     });
@@ -307,14 +637,55 @@ impl DagExecutor {
                 }
             }
 
-            // Wait for a result if we have active tasks
+            // Wait for a result (or a control command) if we have active tasks
             if !active_tasks.is_empty() {
-                match result_rx.recv().await {
-                    Some((phase_number, result)) => {
-                        // Remove from active tasks
-                        if let Some(handle) = active_tasks.remove(&phase_number) {
-                            // Wait for the task to complete
-                            handle.await.ok();
+                let received = match &self.control {
+                    Some(control) => tokio::select! {
+                        received = result_rx.recv() => received.map(Some),
+                        _ = control.notified() => Some(None),
+                    },
+                    None => result_rx.recv().await.map(Some),
+                };
+                match received {
+                    Some(Some((phase_number, result))) => {
+                        // Remove from active tasks; results of cancelled phases are dropped
+                        let Some(handle) = active_tasks.remove(&phase_number) else {
+                            continue;
+                        };
+                        // Wait for the task to complete
+                        handle.await.ok();
+
+                        // Schedule spawned sub-phases and decomposed tasks as
+                        // phases of their own, ahead of this phase's dependents
+                        let mut result = result;
+                        if result.success && !result.spawned_phases.is_empty() {
+                            let inserted = scheduler
+                                .lock()
+                                .await
+                                .insert_phases(&phase_number, result.spawned_phases.clone());
+                            match inserted {
+                                Ok(numbers) => {
+                                    info!(
+                                        phase = %phase_number,
+                                        sub_phases = ?numbers,
+                                        "Scheduling sub-phases"
+                                    );
+                                    summary.total_phases += numbers.len();
+                                    for child in &result.spawned_phases {
+                                        phase_slots.insert(child.number.clone(), child.slots());
+                                    }
+                                    sub_tasks.extend(sub_task_ids(&result));
+                                }
+                                Err(e) => {
+                                    warn!(phase = %phase_number, "{:#}", e);
+                                    result = PhaseResult::failure(
+                                        &phase_number,
+                                        &format!("{:#}", e),
+                                        result.iterations,
+                                        result.duration,
+                                    );
+                                }
+                            }
                         }
 
                         // Update scheduler state
@@ -337,13 +708,84 @@ impl DagExecutor {
                             "DAG phase completed"
                         );
 
+                        // Compare diffs with phases that ran alongside this one.
+                        // Isolated phases are reconciled when they merge back.
+                        let changes =
+                            PhaseChanges::new(&result.files_changed, &result.edited_files);
+                        let peers = match isolation {
+                            Some(_) => None,
+                            None => concurrent.get(&phase_number),
+                        };
+                        for peer in peers.into_iter().flatten() {
+                            let Some(peer_changes) = touched.get(peer) else {
+                                continue;
+                            };
+                            let shared = changes.shared_with(peer_changes);
+                            if shared.is_empty() {
+                                continue;
+                            }
+                            // Declared scopes are trusted; only guess for undeclared ones.
+                            let mut sched = scheduler.lock().await;
+                            if sched.conflicts(&phase_number, peer)
+                                || (sched.has_declared_scope(&phase_number)
+                                    && sched.has_declared_scope(peer))
+                            {
+                                continue;
+                            }
+                            warn!(
+                                phase = %phase_number,
+                                other = %peer,
+                                files = shared.len(),
+                                "Concurrent phases touched the same files; serializing them"
+                            );
+                            sched.add_conflict(&phase_number, peer);
+                            let (Some(a), Some(b)) =
+                                (sched.get_node(&phase_number), sched.get_node(peer))
+                            else {
+                                continue;
+                            };
+                            let conflict = ScopeConflict::new(&a.phase, &b.phase, shared.clone());
+                            drop(sched);
+
+                            conflict_log.record(conflict);
+                            if let Err(e) = conflict_log.save(&forge_dir) {
+                                warn!("Failed to save scope conflict log: {:#}", e);
+                            }
+                            self.emit_event(
+                                &events,
+                                PhaseEvent::ScopeConflict {
+                                    phase: phase_number.clone(),
+                                    other: peer.clone(),
+                                    files: shared,
+                                },
+                            )
+                            .await;
+                        }
+                        touched.insert(phase_number.clone(), changes);
+
                         // Emit completion event
-                        self.emit_event(PhaseEvent::Completed {
-                            phase: phase_number.clone(),
-                            result: Box::new(result.clone()),
-                        })
+                        self.emit_event(
+                            &events,
+                            PhaseEvent::Completed {
+                                phase: phase_number.clone(),
+                                result: Box::new(result.clone()),
+                            },
+                        )
                         .await;
 
+                        if let Some((parent, task_id)) = sub_tasks.get(&phase_number) {
+                            self.emit_event(
+                                &events,
+                                PhaseEvent::SubTaskCompleted {
+                                    phase: parent.clone(),
+                                    task_id: task_id.clone(),
+                                    success: result.success,
+                                    iterations: result.iterations,
+                                },
+                            )
+                            .await;
+                        }
+
                         // Add to summary
                         summary.add_result(result);
 
@@ -357,6 +799,7 @@ impl DagExecutor {
                             break;
                         }
                     }
+                    Some(None) => continue,
                     None => break,
                 }
             }
@@ -392,42 +835,67 @@ impl DagExecutor {
             };
 
             if completed_in_wave {
-                self.emit_event(PhaseEvent::WaveCompleted {
-                    wave: current_wave,
-                    success_count: summary.completed,
-                    failed_count: summary.failed,
-                })
+                self.emit_event(
+                    &events,
+                    PhaseEvent::WaveCompleted {
+                        wave: current_wave,
+                        success_count: summary.completed,
+                        failed_count: summary.failed,
+                    },
+                )
                 .await;
                 current_wave += 1;
             }
 
+            {
+                let sched = scheduler.lock().await;
+                self.publish_status(&sched, DagState::Running, paused, aborting, max_parallel)
+                    .await;
+            }
+
             // Small delay to prevent tight looping
             tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
         }
 
         // Final wave completion event
-        self.emit_event(PhaseEvent::WaveCompleted {
-            wave: current_wave,
-            success_count: summary.completed,
-            failed_count: summary.failed,
-        })
+        self.emit_event(
+            &events,
+            PhaseEvent::WaveCompleted {
+                wave: current_wave,
+                success_count: summary.completed,
+                failed_count: summary.failed,
+            },
+        )
         .await;
 
         // Determine final state
-        let state = if summary.failed > 0 {
+        let state = if aborting.is_some() {
+            DagState::Cancelled
+        } else if summary.failed > 0 {
             DagState::Failed
         } else {
             DagState::Completed
         };
+        {
+            let sched = scheduler.lock().await;
+            self.publish_status(&sched, state, paused, aborting, max_parallel)
+                .await;
+        }
+        if let Some(checkpoint) = &events.checkpoint {
+            checkpoint.set_state(state);
+        }
 
         summary.duration = timer.elapsed();
         let success = summary.all_success();
 
         // Emit final event
-        self.emit_event(PhaseEvent::DagCompleted {
-            success,
-            summary: summary.clone(),
-        })
+        self.emit_event(
+            &events,
+            PhaseEvent::DagCompleted {
+                success,
+                summary: summary.clone(),
+            },
+        )
         .await;
 
         Ok(ExecutionResult {
@@ -438,9 +906,60 @@ impl DagExecutor {
         })
     }
 
-    /// Emit an event to the event channel if configured.
-    async fn emit_event(&self, event: PhaseEvent) {
-        if let Some(ref tx) = self.event_tx {
+    /// Publish the scheduler state to the control channel, if attached.
+    async fn publish_status(
+        &self,
+        sched: &DagScheduler,
+        state: DagState,
+        paused: bool,
+        aborting: Option<AbortMode>,
+        max_parallel: usize,
+    ) {
+        let Some(control) = &self.control else {
+            return;
+        };
+        let mut status = ControlStatus {
+            state,
+            paused,
+            aborting,
+            max_parallel,
+            total_phases: sched.phase_count(),
+            ..Default::default()
+        };
+        for node in sched.nodes() {
+            let number = node.phase.number.clone();
+            match node.status {
+                PhaseStatus::Running { .. } => status.running.push(number),
+                PhaseStatus::Completed { .. } => status.completed.push(number),
+                PhaseStatus::Failed { .. } | PhaseStatus::Skipped => status.failed.push(number),
+                _ => status.pending.push(number),
+            }
+        }
+        control.set_status(status).await;
+    }
+
+    /// Emit an event to the control channel and the event sink.
+    async fn emit_event(&self, events: &EventSink, event: PhaseEvent) {
+        if let Some(control) = &self.control {
+            control.publish(&event);
+        }
+        events.emit(event).await;
+    }
+}
+
+/// Delivers events to the progress channel and the swarm checkpoint.
+#[derive(Clone, Default)]
+struct EventSink {
+    tx: Option<mpsc::Sender<PhaseEvent>>,
+    checkpoint: Option<Arc<CheckpointWriter>>,
+}
+
+impl EventSink {
+    async fn emit(&self, event: PhaseEvent) {
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.record(&event);
+        }
+        if let Some(tx) = &self.tx {
             tx.send(event).await.ok();
         }
     }
@@ -536,34 +1055,144 @@ impl IterationTracker {
     }
 }
 
+/// Reviewers a phase asks for human decisions.
+#[derive(Clone, Default)]
+struct PhaseReviewers {
+    /// Resolves gating review failures in manual review mode.
+    manual: Option<Arc<dyn ManualReviewHandler>>,
+    /// Decides on decomposition plans before they run.
+    plan: Option<Arc<dyn PlanReviewHandler>>,
+}
+
+/// Announces pending manual reviews on the event channel before asking,
+/// and their resolution once decided.
+struct AnnouncingReviewer {
+    inner: Arc<dyn ManualReviewHandler>,
+    events: EventSink,
+}
+
+#[async_trait::async_trait]
+impl ManualReviewHandler for AnnouncingReviewer {
+    async fn resolve(&self, review: &PendingReview) -> Result<ManualResolution> {
+        self.events
+            .emit(PhaseEvent::ReviewPending {
+                phase: review.phase.clone(),
+                review: Box::new(review.clone()),
+            })
+            .await;
+        let resolution = self.inner.resolve(review).await?;
+        self.events
+            .emit(PhaseEvent::ReviewResolved {
+                phase: review.phase.clone(),
+                review_id: review.id.clone(),
+                resolution: Box::new(resolution.clone()),
+            })
+            .await;
+        Ok(resolution)
+    }
+}
+
+/// Build a runner whose Claude processes work in `work_dir`.
+///
+/// Spec, logs and state stay in the project's `.forge` directory.
+fn phase_runner(config: &ExecutorConfig, work_dir: &Path) -> Result<ClaudeRunner> {
+    let mut runner_config = Config::new(
+        config.project_dir.clone(),
+        config.verbose,
+        5, // auto_approve_threshold
+        None,
+    )?;
+    runner_config.project_dir = work_dir.to_path_buf();
+    Ok(ClaudeRunner::new(runner_config))
+}
+
+/// Decomposition trigger for the first iteration of a phase sized above the
+/// pre-flight threshold, which is asked for a plan.
+fn preflight_trigger(decision: &PreflightDecision) -> DecompositionTrigger {
+    DecompositionTrigger::triggered(
+        TriggerReason::PreflightEstimate {
+            estimated_iterations: decision.estimate.estimated_iterations,
+            budget: decision.budget,
+        },
+        1,
+        decision.budget,
+        0,
+    )
+}
+
+/// Ask the plan reviewer which plan to run, announcing its decision.
+///
+/// Without a reviewer every plan is approved. A reviewer that fails rejects
+/// the plan rather than letting it run unreviewed.
+async fn review_plan(
+    reviewer: Option<&Arc<dyn PlanReviewHandler>>,
+    pending: &PendingPlan,
+    events: &EventSink,
+) -> PlanDecision {
+    let Some(reviewer) = reviewer else {
+        return PlanDecision::Approve;
+    };
+    let decision = reviewer
+        .review(pending)
+        .await
+        .unwrap_or_else(|e| PlanDecision::Reject {
+            reason: Some(format!("plan review failed: {:#}", e)),
+        });
+    events
+        .emit(PhaseEvent::DecompositionReviewed {
+            phase: pending.phase.clone(),
+            decision: Box::new(decision.clone()),
+        })
+        .await;
+    decision
+}
+
+/// Map the phases created for a decomposed phase's tasks to
+/// `(phase, (parent, task ID))`.
+///
+/// Task phases are the last of the spawned phases, in task order.
+fn sub_task_ids(result: &PhaseResult) -> Vec<(String, (String, String))> {
+    let Some(decomposed) = &result.decomposition else {
+        return Vec::new();
+    };
+    let tasks = decomposed.decomposition.all_tasks();
+    let offset = result.spawned_phases.len().saturating_sub(tasks.len());
+    tasks
+        .iter()
+        .zip(&result.spawned_phases[offset..])
+        .map(|(task, phase)| {
+            (
+                phase.number.clone(),
+                (result.phase.clone(), task.id.clone()),
+            )
+        })
+        .collect()
+}
+
 /// Execute a single phase with review integration and decomposition support.
+///
+/// Claude runs and changes are tracked in `work_dir`, which is the project
+/// directory unless the phase is isolated in a worktree.
 async fn execute_single_phase(
     phase: &Phase,
     config: &ExecutorConfig,
+    work_dir: &Path,
     dag_config: &DagConfig,
-    event_tx: Option<mpsc::Sender<PhaseEvent>>,
+    events: EventSink,
+    reviewers: PhaseReviewers,
+    stop_requested: Arc<AtomicBool>,
 ) -> PhaseResult {
     let timer = ExecutionTimer::start();
 
-    // Create runner config
-    let runner_config = Config::new(
-        config.project_dir.clone(),
-        config.verbose,
-        5, // auto_approve_threshold
-        None,
-    );
-
-    let runner_config = match runner_config {
-        Ok(c) => c,
+    let runner = match phase_runner(config, work_dir) {
+        Ok(r) => r,
         Err(e) => {
             return PhaseResult::failure(&phase.number, &e.to_string(), 0, timer.elapsed());
         }
     };
 
-    let runner = ClaudeRunner::new(runner_config.clone());
-
     // Create git tracker for change detection
-    let tracker = match GitTracker::new(&config.project_dir) {
+    let tracker = match GitTracker::new(work_dir) {
         Ok(t) => t,
         Err(e) => {
             return PhaseResult::failure(
@@ -593,6 +1222,40 @@ async fn execute_single_phase(
     let decomposition_executor = DecompositionExecutor::new(config.decomposition_config.clone());
     let mut accumulated_signals = ExecutionSignals::new();
 
+    // A plan requested from the worker and the trigger it is parsed under,
+    // for the next iteration only
+    let mut plan_request: Option<PromptContext> = None;
+    let mut forced_trigger: Option<DecompositionTrigger> = None;
+
+    // Size the phase up-front; an oversized phase asks for a plan first
+    let size_estimator = SizeEstimator::new(config.decomposition_config.clone());
+    let preflight = (dag_config.decomposition_enabled && size_estimator.applies_to(phase))
+        .then(|| size_estimator.assess(phase, runner.spec_file(), work_dir));
+    if let Some(decision) = &preflight {
+        debug!(phase = %phase.number, "{}", decision.description());
+        events
+            .emit(PhaseEvent::PreflightEstimated {
+                phase: phase.number.clone(),
+                decision: Box::new(decision.clone()),
+            })
+            .await;
+        if decision.decompose {
+            let task_budget = config
+                .decomposition_config
+                .available_budget(phase.budget.saturating_sub(1));
+            plan_request = Some(PromptContext {
+                compaction_summary: None,
+                additional_context: Some(decision.planning_prompt(task_budget)),
+            });
+            forced_trigger = Some(preflight_trigger(decision));
+        }
+    }
+
+    // Sub-phases requested with <spawn-subphase> are collected on a copy of
+    // the phase and handed to the scheduler when the phase finishes
+    let sub_phase_manager = SubPhaseManager::new();
+    let mut spawner = phase.clone();
+
     // Load config for session continuity settings
     let forge_dir = get_forge_dir(&config.project_dir);
     let forge_toml = ForgeToml::load_or_default(&forge_dir)
@@ -616,64 +1279,87 @@ async fn execute_single_phase(
     let mut completed = false;
     let mut iteration = 0;
     let mut decomposition_triggered = false;
+    let mut decomposed_phase = None;
     let mut iter_tracker = IterationTracker::new(3); // stall window = 3
+    let mut edited_files = BTreeSet::new();
+    let timeout_duration = runner.resolve_iteration_timeout(phase);
+    let retry_policy = runner.resolve_retry_policy(phase);
 
     for iter in 1..=phase.budget {
+        if stop_requested.load(Ordering::Relaxed) {
+            return PhaseResult::failure(
+                &phase.number,
+                &format!("Aborted after iteration {}", iteration),
+                iteration,
+                timer.elapsed(),
+            );
+        }
         iteration = iter;
 
         // Emit progress event
-        if let Some(ref tx) = event_tx {
-            tx.send(PhaseEvent::Progress {
+        events
+            .emit(PhaseEvent::Progress {
                 phase: phase.number.clone(),
                 iteration: iter,
                 budget: phase.budget,
                 percent: None,
             })
-            .await
-            .ok();
-        }
+            .await;
 
-        // Run the iteration with session continuity and feedback,
-        // wrapped in a per-iteration timeout when configured.
-        let iteration_fut = runner.run_iteration_with_context(
-            phase,
-            iter,
-            None,
-            None,
-            if session_continuity_enabled {
-                active_session_id.as_deref()
-            } else {
-                None
-            },
-            if iteration_feedback_enabled {
-                previous_feedback.as_deref()
-            } else {
-                None
-            },
-        );
-
-        let timeout_duration = runner.resolve_iteration_timeout(phase);
-        let result = match timeout_duration {
-            Some(duration) => match tokio::time::timeout(duration, iteration_fut).await {
-                Ok(inner) => inner,
-                Err(_elapsed) => {
-                    warn!(
-                        phase = %phase.number,
-                        timeout_secs = duration.as_secs(),
-                        "DAG iteration timed out"
-                    );
-                    Err(crate::errors::OrchestratorError::IterationTimeout {
-                        phase: phase.number.clone(),
-                        timeout_secs: duration.as_secs(),
+        // Run the iteration with session continuity and feedback, wrapped in a
+        // per-iteration timeout when configured. Transient failures are retried
+        // in place without spending the budget.
+        let result = run_with_retry(&retry_policy, &phase.number, || async {
+            let iteration_fut = runner.run_iteration_with_context(
+                phase,
+                iter,
+                None,
+                plan_request.as_ref(),
+                if session_continuity_enabled {
+                    active_session_id.as_deref()
+                } else {
+                    None
+                },
+                if iteration_feedback_enabled {
+                    previous_feedback.as_deref()
+                } else {
+                    None
+                },
+            );
+            match timeout_duration {
+                Some(duration) => match tokio::time::timeout(duration, iteration_fut).await {
+                    Ok(inner) => inner,
+                    Err(_elapsed) => {
+                        warn!(
+                            phase = %phase.number,
+                            timeout_secs = duration.as_secs(),
+                            "DAG iteration timed out"
+                        );
+                        Err(crate::errors::OrchestratorError::IterationTimeout {
+                            phase: phase.number.clone(),
+                            timeout_secs: duration.as_secs(),
+                        }
+                        .into())
                     }
-                    .into())
-                }
-            },
-            None => iteration_fut.await,
-        };
+                },
+                None => iteration_fut.await,
+            }
+        })
+        .await;
+
+        let plan_requested = plan_request.take().is_some();
 
         match result {
             Ok(output) => {
+                edited_files.extend(output.edited_files.iter().cloned());
+                for (signal, validation) in sub_phase_manager
+                    .process_spawn_signals(&mut spawner, output.signals.get_sub_phase_spawns())
+                {
+                    if let Some(msg) = validation.error_message() {
+                        warn!(phase = %phase.number, "Sub-phase '{}' rejected: {}", signal.name, msg);
+                    }
+                }
+
                 if output.promise_found {
                     completed = true;
                     break;
@@ -718,82 +1404,120 @@ async fn execute_single_phase(
 
                 // Check for decomposition trigger
                 if dag_config.decomposition_enabled {
-                    let trigger = decomposition_detector.check_trigger_with_signals(
-                        phase,
-                        &accumulated_signals,
-                        iter,
-                        progress,
-                    );
+                    let trigger = match forced_trigger.take() {
+                        Some(trigger) => trigger,
+                        None => decomposition_detector.check_trigger_with_signals(
+                            phase,
+                            &accumulated_signals,
+                            iter,
+                            progress,
+                        ),
+                    };
 
                     if trigger.should_decompose() {
                         // Try to parse decomposition from output
                         if let Ok(Some(decomposition)) = parse_decomposition_output(&output.output)
                         {
                             // Emit decomposition started event
-                            if let Some(ref tx) = event_tx {
-                                tx.send(PhaseEvent::DecompositionStarted {
+                            events
+                                .emit(PhaseEvent::DecompositionStarted {
                                     phase: phase.number.clone(),
                                     reason: trigger.description(),
                                 })
-                                .await
-                                .ok();
-                            }
+                                .await;
 
-                            // Validate and log decomposition
-                            match decomposition_executor.convert_to_subphases(
+                            // Let the operator or the autonomy policy decide on the plan
+                            let pending = PendingPlan::new(
                                 phase,
-                                decomposition.clone(),
-                                iter,
                                 &trigger.description(),
-                            ) {
-                                Ok(_decomposed) => {
-                                    // Emit decomposition completed event
-                                    if let Some(ref tx) = event_tx {
-                                        tx.send(PhaseEvent::DecompositionCompleted {
-                                            phase: phase.number.clone(),
-                                            task_count: decomposition.task_count(),
-                                            total_budget: decomposition.total_budget(),
-                                        })
-                                        .await
-                                        .ok();
-                                    }
-
-                                    if config.verbose {
-                                        debug!(
-                                            "Phase {} decomposed into {} tasks (budget: {})",
-                                            phase.number,
-                                            decomposition.task_count(),
-                                            decomposition.total_budget()
-                                        );
-                                    }
-
-                                    decomposition_triggered = true;
-                                    break;
+                                config
+                                    .decomposition_config
+                                    .available_budget(phase.budget.saturating_sub(iter)),
+                                decomposition,
+                            );
+                            let decision =
+                                review_plan(reviewers.plan.as_ref(), &pending, &events).await;
+                            match &decision {
+                                PlanDecision::Rerequest { feedback } => {
+                                    plan_request = Some(PromptContext {
+                                        compaction_summary: None,
+                                        additional_context: Some(replan_prompt(
+                                            feedback.as_deref(),
+                                        )),
+                                    });
+                                    forced_trigger = Some(trigger.clone());
                                 }
-                                Err(e) => {
+                                PlanDecision::Reject { .. } => {
                                     warn!(
-                                        "Failed to convert decomposition for phase {}: {}",
-                                        phase.number, e
+                                        phase = %phase.number,
+                                        "Decomposition plan {}; continuing undivided",
+                                        decision
                                     );
-                                    // Continue execution without decomposition
+                                }
+                                PlanDecision::Approve | PlanDecision::Edit { .. } => {}
+                            }
+
+                            if let Some(decomposition) = decision.into_plan(pending.plan) {
+                                // Validate and log decomposition
+                                match decomposition_executor.convert_to_subphases(
+                                    phase,
+                                    decomposition.clone(),
+                                    iter,
+                                    &trigger.description(),
+                                ) {
+                                    Ok(decomposed) => {
+                                        // Emit decomposition completed event
+                                        events
+                                            .emit(PhaseEvent::DecompositionCompleted {
+                                                phase: phase.number.clone(),
+                                                task_count: decomposition.task_count(),
+                                                total_budget: decomposition.total_budget(),
+                                            })
+                                            .await;
+
+                                        if config.verbose {
+                                            debug!(
+                                                "Phase {} decomposed into {} tasks (budget: {})",
+                                                phase.number,
+                                                decomposition.task_count(),
+                                                decomposition.total_budget()
+                                            );
+                                        }
+
+                                        decomposed_phase = Some(decomposed);
+                                        decomposition_triggered = true;
+                                        break;
+                                    }
+                                    Err(e) => {
+                                        warn!(
+                                            "Failed to convert decomposition for phase {}: {}",
+                                            phase.number, e
+                                        );
+                                        // Continue execution without decomposition
+                                    }
                                 }
                             }
                         }
                     }
                 }
 
+                if plan_requested && plan_request.is_none() {
+                    warn!(
+                        phase = %phase.number,
+                        "No usable decomposition plan; running the phase undivided"
+                    );
+                }
+
                 // Emit progress with percentage if available
-                if let Some(pct) = output.signals.latest_progress()
-                    && let Some(ref tx) = event_tx
-                {
-                    tx.send(PhaseEvent::Progress {
-                        phase: phase.number.clone(),
-                        iteration: iter,
-                        budget: phase.budget,
-                        percent: Some(pct as u32),
-                    })
-                    .await
-                    .ok();
+                if let Some(pct) = output.signals.latest_progress() {
+                    events
+                        .emit(PhaseEvent::Progress {
+                            phase: phase.number.clone(),
+                            iteration: iter,
+                            budget: phase.budget,
+                            percent: Some(pct as u32),
+                        })
+                        .await;
                 }
 
                 // Capture session ID for --resume in next iteration
@@ -815,6 +1539,7 @@ async fn execute_single_phase(
                 previous_feedback = feedback_builder.build();
             }
             Err(e) => {
+                let class = FailureClass::of_error(&e);
                 // Check for iteration timeout specifically
                 if let Some(timeout_err) = e.downcast_ref::<crate::errors::OrchestratorError>()
                     && let crate::errors::OrchestratorError::IterationTimeout {
@@ -826,9 +1551,11 @@ async fn execute_single_phase(
                         &format!("Iteration {} timed out after {}s", iter, timeout_secs),
                         iter,
                         timer.elapsed(),
-                    );
+                    )
+                    .with_failure_class(class);
                 }
-                return PhaseResult::failure(&phase.number, &e.to_string(), iter, timer.elapsed());
+                return PhaseResult::failure(&phase.number, &e.to_string(), iter, timer.elapsed())
+                    .with_failure_class(class);
             }
         }
 
@@ -836,19 +1563,32 @@ async fn execute_single_phase(
         tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
     }
 
-    // Handle decomposition - for now we report it as a special state
-    // A full implementation would execute sub-phases recursively here
+    let mut spawned: Vec<Phase> = spawner
+        .sub_phases
+        .iter()
+        .map(|sub| sub.to_phase(phase))
+        .collect();
+
+    // A decomposed phase is done; its tasks are scheduled as phases of their own
     if decomposition_triggered {
         // Compute changes so far
         // unwrap_or_default is intentional: git diff failure should not abort
         // phase execution; an empty diff is a valid state (e.g., no commits yet).
         let files_changed = tracker.compute_changes(&snapshot_sha).unwrap_or_default();
 
-        // Return a result indicating decomposition occurred
-        // The caller can choose how to handle this (e.g., spawn sub-phase execution)
-        return PhaseResult::success(&phase.number, iteration, files_changed, timer.elapsed())
-            .with_note("Phase was decomposed into sub-tasks")
-            .with_decomposition();
+        let result = PhaseResult::success(&phase.number, iteration, files_changed, timer.elapsed())
+            .with_edited_files(edited_files)
+            .with_note("Phase was decomposed into sub-tasks");
+        return match decomposed_phase {
+            Some(decomposed) => {
+                // Tasks come last, numbered after any spawned sub-phases
+                spawned.extend(decomposition_executor.create_phases(&decomposed, &spawner));
+                result
+                    .with_decomposed_phase(decomposed)
+                    .with_spawned_phases(spawned)
+            }
+            None => result.with_decomposition().with_spawned_phases(spawned),
+        };
     }
 
     // Compute changes
@@ -859,7 +1599,8 @@ async fn execute_single_phase(
     if !completed {
         let latest_pct = accumulated_signals.latest_progress();
         let diagnosis = iter_tracker.failure_diagnosis(latest_pct);
-        return PhaseResult::failure(&phase.number, &diagnosis, iteration, timer.elapsed());
+        return PhaseResult::failure(&phase.number, &diagnosis, iteration, timer.elapsed())
+            .with_failure_class(FailureClass::BudgetExhausted);
     }
 
     let mut result = PhaseResult::success(
@@ -867,19 +1608,30 @@ async fn execute_single_phase(
         iteration,
         files_changed.clone(),
         timer.elapsed(),
-    );
+    )
+    .with_edited_files(edited_files)
+    .with_spawned_phases(spawned);
 
     // Run reviews if configured
     if dag_config.review.enabled {
-        if let Some(ref tx) = event_tx {
-            tx.send(PhaseEvent::ReviewStarted {
+        events
+            .emit(PhaseEvent::ReviewStarted {
                 phase: phase.number.clone(),
             })
-            .await
-            .ok();
-        }
+            .await;
 
-        let review_integration = ReviewIntegration::new(config.review_config.clone());
+        let mut review_config = config.review_config.clone();
+        if work_dir != config.project_dir {
+            review_config = review_config.with_working_dir(work_dir.to_path_buf());
+        }
+        let mut review_integration = ReviewIntegration::new(review_config);
+        if let Some(reviewer) = reviewers.manual {
+            review_integration =
+                review_integration.with_manual_handler(Arc::new(AnnouncingReviewer {
+                    inner: reviewer,
+                    events: events.clone(),
+                }));
+        }
 
         let files: Vec<String> = files_changed
             .files_added
@@ -889,22 +1641,83 @@ async fn execute_single_phase(
             .collect();
 
         match review_integration
-            .run_phase_reviews(phase, iteration, &files)
+            .run_phase_reviews(phase, iteration, &snapshot_sha, &files)
             .await
         {
             Ok(review_result) => {
+                // Targeted fix iterations until the arbiter stops asking for fixes
+                let review_result = if review_result.needs_fix() {
+                    let fix_loop = review_integration
+                        .run_fix_loop(
+                            phase,
+                            iteration,
+                            &snapshot_sha,
+                            &files,
+                            review_result.clone(),
+                            |round, prompt| {
+                                let runner = &runner;
+                                let retry_policy = &retry_policy;
+                                async move {
+                                    run_with_retry(retry_policy, &phase.number, || {
+                                        let fix_fut = runner.run_iteration_with_context(
+                                            phase,
+                                            iteration + round,
+                                            None,
+                                            None,
+                                            None,
+                                            Some(&prompt),
+                                        );
+                                        async move {
+                                            match timeout_duration {
+                                                Some(duration) => {
+                                                    tokio::time::timeout(duration, fix_fut)
+                                                        .await
+                                                        .map_err(|_| {
+                                                            anyhow::anyhow!(
+                                                                "Fix iteration timed out after {}s",
+                                                                duration.as_secs()
+                                                            )
+                                                        })?
+                                                }
+                                                None => fix_fut.await,
+                                            }
+                                        }
+                                    })
+                                    .await
+                                }
+                            },
+                        )
+                        .await;
+
+                    match fix_loop {
+                        Ok(outcome) => {
+                            result.iterations += outcome.fix_iterations;
+                            result.edited_files.extend(outcome.edited_files);
+                            if let Ok(changes) = tracker.compute_changes(&snapshot_sha) {
+                                result.files_changed = changes;
+                            }
+                            result = result.with_review_fixes(outcome.plan.items);
+                            outcome.result
+                        }
+                        Err(e) => {
+                            warn!("Review fix loop failed for phase {}: {}", phase.number, e);
+                            review_result
+                        }
+                    }
+                } else {
+                    review_result
+                };
+
                 let passed = review_result.can_proceed();
                 let findings_count = review_result.aggregation.all_findings_count();
 
-                if let Some(ref tx) = event_tx {
-                    tx.send(PhaseEvent::ReviewCompleted {
+                events
+                    .emit(PhaseEvent::ReviewCompleted {
                         phase: phase.number.clone(),
                         passed,
                         findings_count,
                     })
-                    .await
-                    .ok();
-                }
+                    .await;
 
                 result = result.with_review(review_result);
 
@@ -923,6 +1736,186 @@ async fn execute_single_phase(
     result
 }
 
+/// Execute a phase in its own worktree and merge it back on success.
+async fn execute_isolated_phase(
+    phase: &Phase,
+    config: &ExecutorConfig,
+    dag_config: &DagConfig,
+    isolation: &WorktreeIsolation,
+    events: EventSink,
+    reviewers: PhaseReviewers,
+    stop_requested: Arc<AtomicBool>,
+) -> PhaseResult {
+    let timer = ExecutionTimer::start();
+
+    let mut worktree = match isolation.branch(&phase.number).await {
+        Ok(worktree) => WorktreeCleanup {
+            isolation,
+            worktree,
+        },
+        Err(e) => {
+            return PhaseResult::failure(
+                &phase.number,
+                &format!("Failed to create worktree: {:#}", e),
+                0,
+                timer.elapsed(),
+            );
+        }
+    };
+
+    let mut result = execute_single_phase(
+        phase,
+        config,
+        &worktree.worktree.path,
+        dag_config,
+        events,
+        reviewers,
+        stop_requested,
+    )
+    .await;
+
+    if result.success {
+        match merge_back(
+            phase,
+            config,
+            isolation,
+            &mut worktree.worktree,
+            result.iterations,
+        )
+        .await
+        {
+            Ok(0) => {}
+            Ok(fix_iterations) => {
+                result.iterations += fix_iterations;
+                result = result.with_note("Merge conflicts resolved in a fix iteration");
+            }
+            Err(e) => {
+                warn!(phase = %phase.number, "Merging worktree failed: {:#}", e);
+                result = PhaseResult::failure(
+                    &phase.number,
+                    &format!("Merge failed: {:#}", e),
+                    result.iterations,
+                    timer.elapsed(),
+                );
+            }
+        }
+    }
+
+    result
+}
+
+/// Removes a phase worktree when dropped, including when the phase task is
+/// aborted by a cancel or an immediate abort.
+struct WorktreeCleanup<'a> {
+    isolation: &'a WorktreeIsolation,
+    worktree: PhaseWorktree,
+}
+
+impl Drop for WorktreeCleanup<'_> {
+    fn drop(&mut self) {
+        if let Err(e) = self.isolation.remove(&self.worktree) {
+            warn!(
+                "Failed to remove worktree {}: {:#}",
+                self.worktree.path.display(),
+                e
+            );
+        }
+    }
+}
+
+/// Conflict fix iterations a phase may spend merging back before it fails.
+const MAX_MERGE_FIX_ITERATIONS: u32 = 3;
+
+/// Merge a finished phase's worktree into the project directory.
+///
+/// When the patch overlaps phases merged in the meantime, the worktree is
+/// rebased onto them and any remaining conflict markers get a fix
+/// iteration. The merge ledger is released while the fix runs so other
+/// phases can merge; if one did, the worktree is rebased again. Returns the
+/// number of fix iterations spent.
+async fn merge_back(
+    phase: &Phase,
+    config: &ExecutorConfig,
+    isolation: &WorktreeIsolation,
+    worktree: &mut PhaseWorktree,
+    iteration: u32,
+) -> Result<u32> {
+    let mut fix_iterations = 0;
+    loop {
+        let mut ledger = isolation.lock().await;
+        let patch = isolation.phase_patch(worktree)?;
+
+        let reason = match isolation.merge(&ledger, worktree, &patch)? {
+            MergeOutcome::Clean(applied) => {
+                ledger.push(&phase.number, applied);
+                return Ok(fix_iterations);
+            }
+            MergeOutcome::Conflict(files) => format!("conflicts in {}", files.join(", ")),
+            MergeOutcome::Failure(message) => message,
+        };
+
+        let newer: Vec<String> = ledger
+            .since(worktree.base_len)
+            .iter()
+            .map(|m| m.phase.clone())
+            .collect();
+        if newer.is_empty() {
+            anyhow::bail!("patch does not apply to the project directory: {}", reason);
+        }
+        info!(
+            phase = %phase.number,
+            merged_since = ?newer,
+            "Rebasing worktree onto newly merged phases ({})",
+            reason
+        );
+
+        let conflicted = isolation.rebase(&ledger, worktree, &patch)?;
+        if conflicted.is_empty() {
+            continue;
+        }
+        if fix_iterations >= MAX_MERGE_FIX_ITERATIONS {
+            anyhow::bail!("conflicts in {} after rebase", conflicted.join(", "));
+        }
+        // The fix runs for minutes; let other phases merge meanwhile
+        drop(ledger);
+
+        fix_iterations += 1;
+        let prompt = format!(
+            "Your changes for this phase were rebased onto work merged meanwhile by \
+             phase(s) {}. Git could not combine them automatically and left conflict \
+             markers in: {}. Resolve every conflict so that both sets of changes are \
+             kept, remove all conflict markers, and make sure the result still builds.",
+            newer.join(", "),
+            conflicted.join(", ")
+        );
+        let runner = phase_runner(config, &worktree.path)?;
+        let fix_fut = runner.run_iteration_with_context(
+            phase,
+            iteration + fix_iterations,
+            None,
+            None,
+            None,
+            Some(&prompt),
+        );
+        match runner.resolve_iteration_timeout(phase) {
+            Some(duration) => tokio::time::timeout(duration, fix_fut)
+                .await
+                .map_err(|_| {
+                    anyhow::anyhow!(
+                        "Conflict fix iteration timed out after {}s",
+                        duration.as_secs()
+                    )
+                })??,
+            None => fix_fut.await?,
+        };
+
+        let unresolved = files_with_conflict_markers(&worktree.path, &conflicted);
+        if !unresolved.is_empty() {
+            anyhow::bail!("unresolved conflicts in {}", unresolved.join(", "));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1079,6 +2072,29 @@ mod tests {
         assert!(json.contains("\"task_count\":3"));
     }
 
+    #[test]
+    fn test_preflight_trigger_targets_planning_iteration() {
+        let decision = PreflightDecision {
+            estimate: crate::decomposition::SizeEstimate {
+                estimated_iterations: 14,
+                ..Default::default()
+            },
+            budget: 8,
+            threshold_percent: 100,
+            decompose: true,
+        };
+
+        let trigger = preflight_trigger(&decision);
+        assert!(trigger.should_decompose());
+        assert_eq!(
+            trigger.reason(),
+            &TriggerReason::PreflightEstimate {
+                estimated_iterations: 14,
+                budget: 8,
+            }
+        );
+    }
+
     #[test]
     fn test_phase_result_can_proceed() {
         let result = PhaseResult::success(
@@ -1196,7 +2212,7 @@ mod tests {
 
     #[test]
     fn test_dag_config_serialization() {
-        use crate::dag::scheduler::{ReviewConfig, ReviewMode, SwarmBackend};
+        use crate::dag::scheduler::{ReviewConfig, ReviewMode, SchedulingPolicy, SwarmBackend};
 
         // Create a fully configured DagConfig
         let config = DagConfig {
@@ -1210,10 +2226,13 @@ mod tests {
                 mode: ReviewMode::Auto,
                 max_fix_attempts: 3,
                 arbiter_confidence: 0.8,
+                arbiter_model: None,
             },
             decomposition_enabled: true,
             decomposition_threshold: 60,
             escalation_types: vec!["critical".to_string(), "security".to_string()],
+            isolation: IsolationMode::Worktree,
+            scheduling: SchedulingPolicy::Fifo,
         };
 
         // Serialize to JSON
@@ -1228,6 +2247,8 @@ mod tests {
         assert!(json.contains("\"auto\""));
         assert!(json.contains("\"decomposition_enabled\":true"));
         assert!(json.contains("\"decomposition_threshold\":60"));
+        assert!(json.contains("\"isolation\":\"worktree\""));
+        assert!(json.contains("\"scheduling\":\"fifo\""));
 
         // Deserialize back
         let deserialized: DagConfig =
@@ -1248,6 +2269,7 @@ mod tests {
         assert!((deserialized.review.arbiter_confidence - 0.8).abs() < f64::EPSILON);
         assert!(deserialized.decomposition_enabled);
         assert_eq!(deserialized.decomposition_threshold, 60);
+        assert_eq!(deserialized.isolation, IsolationMode::Worktree);
         assert_eq!(
             deserialized.escalation_types,
             vec!["critical".to_string(), "security".to_string()]
@@ -1314,6 +2336,17 @@ mod tests {
         assert!(json.contains("\"passed\":true"));
         assert!(json.contains("\"findings_count\":2"));
 
+        // ScopeConflict
+        let scope_conflict = PhaseEvent::ScopeConflict {
+            phase: "05".to_string(),
+            other: "06".to_string(),
+            files: vec![PathBuf::from("src/lib.rs")],
+        };
+        let json = serde_json::to_string(&scope_conflict).unwrap();
+        assert!(json.contains("\"type\":\"scope_conflict\""));
+        assert!(json.contains("\"other\":\"06\""));
+        assert!(json.contains("\"files\":[\"src/lib.rs\"]"));
+
         // WaveStarted
         let wave_started = PhaseEvent::WaveStarted {
             wave: 1,
@@ -1442,4 +2475,187 @@ mod tests {
         assert!(failure_msg.contains("600"));
         assert!(failure_msg.contains("Iteration 3"));
     }
+
+    #[cfg(target_os = "linux")]
+    fn is_running(pid: &str) -> bool {
+        // A killed child may linger as a zombie until it is reaped
+        std::fs::read_to_string(format!("/proc/{pid}/stat"))
+            .map(|stat| !stat.contains(") Z "))
+            .unwrap_or(false)
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {args:?} failed");
+    }
+
+    /// A committed repository with `lib.rs` and `.forge/` ignored.
+    fn test_repo() -> tempfile::TempDir {
+        let repo = tempfile::tempdir().unwrap();
+        git(repo.path(), &["init"]);
+        git(repo.path(), &["config", "user.email", "test@test.com"]);
+        git(repo.path(), &["config", "user.name", "Test"]);
+        std::fs::write(repo.path().join(".gitignore"), ".forge/\n").unwrap();
+        std::fs::write(repo.path().join("lib.rs"), "one\ntwo\nthree\n").unwrap();
+        git(repo.path(), &["add", "."]);
+        git(repo.path(), &["commit", "-m", "initial"]);
+        repo
+    }
+
+    /// Configure the repository to run `script` as the agent.
+    fn install_agent(repo: &Path, script: &str) -> ExecutorConfig {
+        use std::os::unix::fs::PermissionsExt;
+
+        let forge_dir = repo.join(".forge");
+        std::fs::create_dir_all(forge_dir.join("logs")).unwrap();
+        std::fs::write(forge_dir.join("spec.md"), "# Spec\n").unwrap();
+        let agent = forge_dir.join("claude");
+        std::fs::write(&agent, format!("#!/bin/sh\n{script}")).unwrap();
+        std::fs::set_permissions(&agent, std::fs::Permissions::from_mode(0o755)).unwrap();
+        std::fs::write(
+            forge_dir.join("forge.toml"),
+            format!("[project]\nclaude_cmd = \"{}\"\n", agent.display()),
+        )
+        .unwrap();
+
+        ExecutorConfig {
+            project_dir: repo.to_path_buf(),
+            claude_cmd: agent.display().to_string(),
+            skip_permissions: true,
+            auto_approve: false,
+            verbose: false,
+            review_config: ReviewIntegrationConfig::default(),
+            decomposition_config: DecompositionConfig::disabled(),
+        }
+    }
+
+    async fn wait_for(path: &Path) -> String {
+        for _ in 0..200 {
+            let content = std::fs::read_to_string(path).unwrap_or_default();
+            if !content.trim().is_empty() {
+                return content.trim().to_string();
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        panic!("{} was never written", path.display());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_cancel_phase_kills_agent_and_removes_worktree() {
+        use crate::dag::control::{ControlClient, ControlServer};
+
+        // An agent that records its PID and never finishes
+        let repo = test_repo();
+        let bin = tempfile::tempdir().unwrap();
+        let pid_file = bin.path().join("agent.pid");
+        let config = install_agent(
+            repo.path(),
+            &format!("echo $$ > {}\nexec sleep 30\n", pid_file.display()),
+        );
+        let forge_dir = repo.path().join(".forge");
+
+        let (mut server, control) = ControlServer::new();
+        let url = server.start().await.unwrap();
+        let executor = DagExecutor::new(
+            config,
+            DagConfig::default()
+                .with_decomposition(false)
+                .with_isolation(IsolationMode::Worktree),
+        )
+        .with_control(control);
+        let phases = vec![Phase::new("01", "Slow", "DONE", 3, "", vec![])];
+        let run = tokio::spawn(async move { executor.execute(&phases).await });
+
+        let pid = wait_for(&pid_file).await;
+        assert!(is_running(&pid), "agent never started");
+        let worktree = forge_dir.join("swarm-worktrees").join("phase-01");
+        assert!(worktree.exists());
+
+        ControlClient::new(&url)
+            .with_token(server.token())
+            .send(&ControlCommand::CancelPhase {
+                phase: "01".to_string(),
+            })
+            .await
+            .unwrap();
+        let result = tokio::time::timeout(Duration::from_secs(10), run)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        server.stop();
+
+        assert!(!result.success);
+        for _ in 0..100 {
+            if !is_running(&pid) {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        assert!(!is_running(&pid), "agent outlived its cancelled phase");
+        assert!(!worktree.exists());
+    }
+
+    async fn merge_clean(isolation: &WorktreeIsolation, worktree: &PhaseWorktree, phase: &str) {
+        let patch = isolation.phase_patch(worktree).unwrap();
+        let mut ledger = isolation.lock().await;
+        match isolation.merge(&ledger, worktree, &patch).unwrap() {
+            MergeOutcome::Clean(applied) => ledger.push(phase, applied),
+            other => panic!("expected clean merge, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merge_back_releases_ledger_during_conflict_fix() {
+        let repo = test_repo();
+        let signals = tempfile::tempdir().unwrap();
+        let (started, go) = (signals.path().join("started"), signals.path().join("go"));
+        // Resolves the conflict once the test lets it
+        let config = install_agent(
+            repo.path(),
+            &format!(
+                "cat >/dev/null\necho started > {}\n\
+                 while [ ! -f {} ]; do sleep 0.05; done\n\
+                 printf 'one\\nboth\\nthree\\n' > lib.rs\n",
+                started.display(),
+                go.display()
+            ),
+        );
+        let isolation = WorktreeIsolation::new(repo.path());
+        let first = isolation.branch("01").await.unwrap();
+        let mut second = isolation.branch("02").await.unwrap();
+        let third = isolation.branch("03").await.unwrap();
+        std::fs::write(first.path.join("lib.rs"), "one\nfirst\nthree\n").unwrap();
+        std::fs::write(second.path.join("lib.rs"), "one\nsecond\nthree\n").unwrap();
+        std::fs::write(third.path.join("new.rs"), "fn new() {}\n").unwrap();
+        merge_clean(&isolation, &first, "01").await;
+
+        let phase = Phase::new("02", "Second", "DONE", 3, "", vec![]);
+        let merging = merge_back(&phase, &config, &isolation, &mut second, 1);
+        let other_merge = async {
+            wait_for(&started).await;
+            // Another phase merges while the fix iteration runs
+            tokio::time::timeout(
+                Duration::from_secs(5),
+                merge_clean(&isolation, &third, "03"),
+            )
+            .await
+            .expect("ledger held during the fix iteration");
+            std::fs::write(&go, "").unwrap();
+        };
+        let (fix_iterations, ()) = tokio::join!(merging, other_merge);
+
+        assert_eq!(fix_iterations.unwrap(), 1);
+        assert_eq!(
+            std::fs::read_to_string(repo.path().join("lib.rs")).unwrap(),
+            "one\nboth\nthree\n"
+        );
+        assert!(repo.path().join("new.rs").exists());
+        assert_eq!(isolation.lock().await.len(), 3);
+    }
 }
@@ -0,0 +1,557 @@
+//! Rendering of the phase DAG for inspection outside of a run.
+//!
+//! [`DagGraph`] combines the dependency graph, the execution waves and the
+//! progress recorded in `.forge/state` into one annotated view. Each node
+//! carries its budget, last recorded status and iteration count, and an
+//! *expected* iteration count used to find the critical path:
+//!
+//! - completed phases count the iterations they actually took
+//! - phases with sub-phases count the sum of their sub-phases (which run
+//!   sequentially)
+//! - everything else counts its full budget
+//!
+//! The critical path is the dependency chain with the largest total expected
+//! iterations. When it accounts for most of the total, the plan is heavily
+//! serialized and adding parallel capacity will not help.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use super::scheduler::{DagConfig, DagScheduler};
+use crate::orchestrator::state::StateEntry;
+use crate::phase::{Phase, SubPhase, SubPhaseStatus};
+
+/// Output format for `forge graph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum GraphFormat {
+    /// Graphviz DOT.
+    Dot,
+    /// Mermaid flowchart.
+    Mermaid,
+    /// Annotated JSON.
+    Json,
+}
+
+/// Last recorded progress for a phase or sub-phase.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PhaseHistory {
+    /// Status of the most recent state entry (e.g. "completed", "started").
+    pub status: String,
+    /// Iteration recorded with that entry.
+    pub iterations: u32,
+}
+
+impl PhaseHistory {
+    /// Collect the latest entry per phase and sub-phase from the state file.
+    ///
+    /// Keys are full phase ids, so sub-phases are keyed as e.g. `"05.1"`.
+    pub fn from_entries(entries: &[StateEntry]) -> HashMap<String, PhaseHistory> {
+        entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.full_phase_id(),
+                    PhaseHistory {
+                        status: entry.status.clone(),
+                        iterations: entry.iteration,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn is_completed(&self) -> bool {
+        self.status == "completed"
+    }
+}
+
+/// A single phase or sub-phase in the rendered graph.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphNode {
+    pub number: String,
+    pub name: String,
+    pub budget: u32,
+    /// Last recorded status from the state file, if the phase has run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Iterations recorded with that status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iterations: Option<u32>,
+    /// Iterations used for critical path analysis.
+    pub expected_iterations: u32,
+    /// Execution wave (top-level phases only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wave: Option<usize>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    /// Whether this node lies on the critical path.
+    pub critical: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sub_phases: Vec<GraphNode>,
+}
+
+impl GraphNode {
+    fn from_sub_phase(sub: &SubPhase, history: &HashMap<String, PhaseHistory>) -> Self {
+        let recorded = history.get(&sub.number);
+        let status = recorded
+            .map(|h| h.status.clone())
+            .or_else(|| sub_phase_status(&sub.status).map(str::to_string));
+        let expected_iterations = match recorded {
+            Some(h) if h.is_completed() => h.iterations,
+            _ => sub.budget,
+        };
+
+        Self {
+            number: sub.number.clone(),
+            name: sub.name.clone(),
+            budget: sub.budget,
+            status,
+            iterations: recorded.map(|h| h.iterations),
+            expected_iterations,
+            wave: None,
+            depends_on: Vec::new(),
+            critical: false,
+            sub_phases: Vec::new(),
+        }
+    }
+
+    fn from_phase(phase: &Phase, history: &HashMap<String, PhaseHistory>) -> Self {
+        let recorded = history.get(&phase.number);
+
+        let mut subs: Vec<&SubPhase> = phase.sub_phases.iter().collect();
+        subs.sort_by_key(|sp| sp.order);
+        let sub_phases: Vec<GraphNode> = subs
+            .into_iter()
+            .map(|sp| Self::from_sub_phase(sp, history))
+            .collect();
+
+        let expected_iterations = match recorded {
+            Some(h) if h.is_completed() => h.iterations,
+            _ if !sub_phases.is_empty() => sub_phases.iter().map(|s| s.expected_iterations).sum(),
+            _ => phase.budget,
+        };
+
+        Self {
+            number: phase.number.clone(),
+            name: phase.name.clone(),
+            budget: phase.budget,
+            status: recorded.map(|h| h.status.clone()),
+            iterations: recorded.map(|h| h.iterations),
+            expected_iterations,
+            wave: None,
+            depends_on: phase.depends_on.clone(),
+            critical: false,
+            sub_phases,
+        }
+    }
+}
+
+fn sub_phase_status(status: &SubPhaseStatus) -> Option<&'static str> {
+    match status {
+        SubPhaseStatus::Pending => None,
+        SubPhaseStatus::InProgress => Some("in_progress"),
+        SubPhaseStatus::Completed => Some("completed"),
+        SubPhaseStatus::Failed => Some("failed"),
+        SubPhaseStatus::Skipped => Some("skipped"),
+    }
+}
+
+/// The annotated phase DAG with its critical path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DagGraph {
+    /// Top-level phases in phases.json order, each with its sub-phases.
+    pub nodes: Vec<GraphNode>,
+    pub waves: Vec<Vec<String>>,
+    /// Phase numbers along the critical path, first to last.
+    pub critical_path: Vec<String>,
+    /// Total expected iterations along the critical path.
+    pub critical_iterations: u32,
+    /// Total expected iterations across all phases.
+    pub total_iterations: u32,
+}
+
+impl DagGraph {
+    /// Build the annotated graph, validating dependencies like the scheduler does.
+    pub fn build(phases: &[Phase], history: &HashMap<String, PhaseHistory>) -> Result<Self> {
+        let scheduler = DagScheduler::from_phases(phases, DagConfig::default())?;
+        let graph = scheduler.graph();
+        let waves = scheduler.compute_waves();
+
+        let mut nodes: Vec<GraphNode> = graph
+            .phases()
+            .iter()
+            .map(|p| GraphNode::from_phase(p, history))
+            .collect();
+        for (wave, numbers) in waves.iter().enumerate() {
+            for number in numbers {
+                if let Some(idx) = graph.get_index(number) {
+                    nodes[idx].wave = Some(wave);
+                }
+            }
+        }
+
+        // Longest path over the topological order given by the waves.
+        let mut distance = vec![0u32; nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; nodes.len()];
+        for number in waves.iter().flatten() {
+            let Some(idx) = graph.get_index(number) else {
+                continue;
+            };
+            let best = graph
+                .dependencies(idx)
+                .iter()
+                .copied()
+                .max_by_key(|&dep| (distance[dep], std::cmp::Reverse(dep)));
+            distance[idx] = nodes[idx].expected_iterations + best.map_or(0, |dep| distance[dep]);
+            previous[idx] = best;
+        }
+
+        let mut critical_path = Vec::new();
+        let end = (0..nodes.len()).max_by_key(|&i| (distance[i], std::cmp::Reverse(i)));
+        let critical_iterations = end.map_or(0, |i| distance[i]);
+        let mut cursor = end;
+        while let Some(idx) = cursor {
+            nodes[idx].critical = true;
+            critical_path.push(nodes[idx].number.clone());
+            cursor = previous[idx];
+        }
+        critical_path.reverse();
+
+        let total_iterations = nodes.iter().map(|n| n.expected_iterations).sum();
+
+        Ok(Self {
+            nodes,
+            waves,
+            critical_path,
+            critical_iterations,
+            total_iterations,
+        })
+    }
+
+    /// Render the graph in the given format.
+    pub fn render(&self, format: GraphFormat) -> Result<String> {
+        Ok(match format {
+            GraphFormat::Dot => self.to_dot(),
+            GraphFormat::Mermaid => self.to_mermaid(),
+            GraphFormat::Json => serde_json::to_string_pretty(self)?,
+        })
+    }
+
+    /// One-line summary of the critical path.
+    pub fn summary(&self) -> String {
+        format!(
+            "critical path: {} ({} of {} expected iterations)",
+            if self.critical_path.is_empty() {
+                "-".to_string()
+            } else {
+                self.critical_path.join(" -> ")
+            },
+            self.critical_iterations,
+            self.total_iterations
+        )
+    }
+
+    fn is_critical_edge(&self, from: &str, to: &str) -> bool {
+        self.critical_path
+            .windows(2)
+            .any(|pair| pair[0] == from && pair[1] == to)
+    }
+
+    /// Render as a Graphviz DOT digraph.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "// {}", self.summary());
+        out.push_str("digraph phases {\n");
+        out.push_str("  rankdir=LR;\n");
+        out.push_str("  node [shape=box, style=\"rounded,filled\", fillcolor=white];\n");
+
+        for node in &self.nodes {
+            write_dot_node(&mut out, node, node.critical);
+            for sub in &node.sub_phases {
+                write_dot_node(&mut out, sub, false);
+            }
+        }
+
+        for node in &self.nodes {
+            for dep in &node.depends_on {
+                let attrs = if self.is_critical_edge(dep, &node.number) {
+                    " [color=red, penwidth=2.5]"
+                } else {
+                    ""
+                };
+                let _ = writeln!(
+                    out,
+                    "  \"{}\" -> \"{}\"{};",
+                    dot_escape(dep),
+                    dot_escape(&node.number),
+                    attrs
+                );
+            }
+            let mut from = &node.number;
+            for sub in &node.sub_phases {
+                let _ = writeln!(
+                    out,
+                    "  \"{}\" -> \"{}\" [style=dashed, arrowhead=open];",
+                    dot_escape(from),
+                    dot_escape(&sub.number)
+                );
+                from = &sub.number;
+            }
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Render as a Mermaid flowchart.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "%% {}", self.summary());
+        out.push_str("flowchart LR\n");
+
+        let mut classes: Vec<(String, &'static str)> = Vec::new();
+        for node in &self.nodes {
+            write_mermaid_node(&mut out, node, &mut classes);
+            for sub in &node.sub_phases {
+                write_mermaid_node(&mut out, sub, &mut classes);
+            }
+        }
+
+        let mut link = 0;
+        let mut critical_links = Vec::new();
+        for node in &self.nodes {
+            for dep in &node.depends_on {
+                let _ = writeln!(
+                    out,
+                    "  {} --> {}",
+                    mermaid_id(dep),
+                    mermaid_id(&node.number)
+                );
+                if self.is_critical_edge(dep, &node.number) {
+                    critical_links.push(link.to_string());
+                }
+                link += 1;
+            }
+            let mut from = &node.number;
+            for sub in &node.sub_phases {
+                let _ = writeln!(
+                    out,
+                    "  {} -.-> {}",
+                    mermaid_id(from),
+                    mermaid_id(&sub.number)
+                );
+                from = &sub.number;
+                link += 1;
+            }
+        }
+
+        out.push_str("  classDef completed fill:#d4edda,stroke:#28a745\n");
+        out.push_str("  classDef running fill:#fff3cd,stroke:#ffc107\n");
+        out.push_str("  classDef failed fill:#f8d7da,stroke:#dc3545\n");
+        out.push_str("  classDef critical stroke:#d33,stroke-width:3px\n");
+        for (id, class) in &classes {
+            let _ = writeln!(out, "  class {} {}", id, class);
+        }
+        if !critical_links.is_empty() {
+            let _ = writeln!(
+                out,
+                "  linkStyle {} stroke:#d33,stroke-width:3px",
+                critical_links.join(",")
+            );
+        }
+        out
+    }
+}
+
+/// Colour bucket for a recorded status.
+fn status_class(status: Option<&str>) -> Option<&'static str> {
+    match status? {
+        "completed" => Some("completed"),
+        "started" | "in_progress" => Some("running"),
+        "failed" | "max_iterations" => Some("failed"),
+        _ => None,
+    }
+}
+
+fn node_label(node: &GraphNode) -> (String, String) {
+    let mut details = format!("budget {}", node.budget);
+    if let Some(iterations) = node.iterations {
+        let _ = write!(details, ", {} iter", iterations);
+    }
+    if let Some(status) = &node.status {
+        let _ = write!(details, ", {}", status);
+    }
+    (format!("{} {}", node.number, node.name), details)
+}
+
+fn write_dot_node(out: &mut String, node: &GraphNode, critical: bool) {
+    let (title, details) = node_label(node);
+    let mut attrs = format!(
+        "label=\"{}\\n{}\"",
+        dot_escape(&title),
+        dot_escape(&details)
+    );
+    match status_class(node.status.as_deref()) {
+        Some("completed") => attrs.push_str(", fillcolor=\"#d4edda\""),
+        Some("running") => attrs.push_str(", fillcolor=\"#fff3cd\""),
+        Some("failed") => attrs.push_str(", fillcolor=\"#f8d7da\""),
+        _ => {}
+    }
+    if critical {
+        attrs.push_str(", color=red, penwidth=2.5");
+    }
+    if node.number.contains('.') {
+        attrs.push_str(", fontsize=10");
+    }
+    let _ = writeln!(out, "  \"{}\" [{}];", dot_escape(&node.number), attrs);
+}
+
+fn write_mermaid_node(
+    out: &mut String,
+    node: &GraphNode,
+    classes: &mut Vec<(String, &'static str)>,
+) {
+    let (title, details) = node_label(node);
+    let id = mermaid_id(&node.number);
+    let _ = writeln!(
+        out,
+        "  {}[\"{}<br/>{}\"]",
+        id,
+        mermaid_escape(&title),
+        mermaid_escape(&details)
+    );
+    if let Some(class) = status_class(node.status.as_deref()) {
+        classes.push((id.clone(), class));
+    }
+    if node.critical {
+        classes.push((id, "critical"));
+    }
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn mermaid_escape(s: &str) -> String {
+    s.replace('"', "#quot;")
+}
+
+/// Mermaid node ids must be plain identifiers.
+fn mermaid_id(number: &str) -> String {
+    let sanitized: String = number
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("p{}", sanitized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn phases() -> Vec<Phase> {
+        vec![
+            Phase::new("01", "Setup", "SETUP DONE", 5, "", vec![]),
+            Phase::new("02", "Core", "CORE DONE", 20, "", vec!["01".into()]),
+            Phase::new("03", "Docs", "DOCS DONE", 4, "", vec!["01".into()]),
+            Phase::new(
+                "04",
+                "Ship",
+                "SHIP DONE",
+                3,
+                "",
+                vec!["02".into(), "03".into()],
+            ),
+        ]
+    }
+
+    fn entry(phase: &str, sub_phase: Option<&str>, iteration: u32, status: &str) -> StateEntry {
+        StateEntry {
+            phase: phase.to_string(),
+            sub_phase: sub_phase.map(str::to_string),
+            iteration,
+            status: status.to_string(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_critical_path_uses_budgets() {
+        let graph = DagGraph::build(&phases(), &HashMap::new()).unwrap();
+
+        assert_eq!(graph.critical_path, vec!["01", "02", "04"]);
+        assert_eq!(graph.critical_iterations, 28);
+        assert_eq!(graph.total_iterations, 32);
+        assert_eq!(graph.nodes[3].wave, Some(2));
+        assert!(!graph.nodes[2].critical);
+    }
+
+    #[test]
+    fn test_history_overrides_budget() {
+        let history = PhaseHistory::from_entries(&[
+            entry("01", None, 0, "started"),
+            entry("01", None, 2, "completed"),
+            entry("02", None, 3, "completed"),
+        ]);
+        let graph = DagGraph::build(&phases(), &history).unwrap();
+
+        assert_eq!(graph.nodes[0].status.as_deref(), Some("completed"));
+        assert_eq!(graph.nodes[0].expected_iterations, 2);
+        // 02 finished in 3 iterations, so the docs branch is now longer.
+        assert_eq!(graph.critical_path, vec!["01", "03", "04"]);
+        assert_eq!(graph.critical_iterations, 9);
+    }
+
+    #[test]
+    fn test_sub_phases_sum_expected_iterations() {
+        let mut phases = phases();
+        phases[2].add_sub_phase("Guide", "GUIDE DONE", 10, "");
+        phases[2].add_sub_phase("Reference", "REF DONE", 15, "");
+        let history = PhaseHistory::from_entries(&[entry("03", Some("03.1"), 6, "completed")]);
+
+        let graph = DagGraph::build(&phases, &history).unwrap();
+        let docs = &graph.nodes[2];
+
+        assert_eq!(docs.sub_phases.len(), 2);
+        assert_eq!(docs.sub_phases[0].status.as_deref(), Some("completed"));
+        assert_eq!(docs.expected_iterations, 21);
+        assert_eq!(graph.critical_path, vec!["01", "03", "04"]);
+    }
+
+    #[test]
+    fn test_render_dot_and_mermaid() {
+        let mut phases = phases();
+        phases[1].add_sub_phase("Parser \"v2\"", "P DONE", 5, "");
+        let graph = DagGraph::build(&phases, &HashMap::new()).unwrap();
+
+        let dot = graph.render(GraphFormat::Dot).unwrap();
+        assert!(dot.starts_with("// critical path: 01 -> 02 -> 04"));
+        assert!(dot.contains("\"01\" -> \"02\" [color=red, penwidth=2.5];"));
+        assert!(dot.contains("\"01\" -> \"03\";"));
+        assert!(dot.contains("\"02\" -> \"02.1\" [style=dashed"));
+        assert!(dot.contains("Parser \\\"v2\\\""));
+
+        let mermaid = graph.render(GraphFormat::Mermaid).unwrap();
+        assert!(mermaid.contains("flowchart LR"));
+        assert!(mermaid.contains("p02 -.-> p02_1"));
+        assert!(mermaid.contains("Parser #quot;v2#quot;"));
+        assert!(mermaid.contains("class p01 critical"));
+        assert!(mermaid.contains("linkStyle 0,3 stroke:#d33"));
+    }
+
+    #[test]
+    fn test_render_json() {
+        let graph = DagGraph::build(&phases(), &HashMap::new()).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&graph.render(GraphFormat::Json).unwrap()).unwrap();
+
+        assert_eq!(json["critical_iterations"], 28);
+        assert_eq!(json["nodes"][1]["critical"], true);
+        assert_eq!(json["waves"][1], serde_json::json!(["02", "03"]));
+    }
+}
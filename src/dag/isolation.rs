@@ -0,0 +1,392 @@
+//! Per-phase git worktree isolation.
+//!
+//! With `forge swarm --isolation worktree`, each phase runs in its own git
+//! worktree under `.forge/swarm-worktrees/` instead of the shared project
+//! directory. A worktree is branched from the merged state of the swarm:
+//! `HEAD` plus every phase patch merged so far, which always includes the
+//! phase's dependencies.
+//!
+//! When a phase succeeds, its patch is merged back into the project
+//! directory before any dependent is scheduled:
+//!
+//! 1. The patch is checked with [`detect_conflicts`] against the patches
+//!    merged since the worktree was branched, then applied.
+//! 2. If it conflicts or does not apply, the worktree is rebased: reset to
+//!    its base, brought up to date with the newer patches, and the phase's
+//!    patch is re-applied with a three-way merge.
+//! 3. Conflict markers left by the rebase are handed to the phase for a fix
+//!    iteration before the merge is retried.
+//!
+//! Merges are serialized through the [`MergeLedger`], so each one sees the
+//! project directory exactly as the previous merge left it. The ledger is
+//! not held during a fix iteration; a phase merged meanwhile sends the fixed
+//! worktree through another rebase.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, MutexGuard};
+
+use crate::council::merge::{git_error_message, run_git_with_input};
+use crate::council::{MergeOutcome, WorktreeManager, apply_patch, detect_conflicts};
+use crate::tracker::GitTracker;
+
+/// Directory inside `.forge/` holding the phase worktrees.
+pub const WORKTREE_DIR: &str = "swarm-worktrees";
+
+/// Where swarm phases do their work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum IsolationMode {
+    /// All phases share the project directory
+    #[default]
+    Shared,
+    /// Each phase runs in its own git worktree and is merged back on success
+    Worktree,
+}
+
+/// A phase patch merged into the project directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergedPatch {
+    pub phase: String,
+    pub patch: String,
+}
+
+/// Phase patches merged into the project directory, in merge order.
+#[derive(Debug, Default)]
+pub struct MergeLedger {
+    merged: Vec<MergedPatch>,
+}
+
+impl MergeLedger {
+    /// Number of merged patches.
+    pub fn len(&self) -> usize {
+        self.merged.len()
+    }
+
+    /// Whether nothing has been merged yet.
+    pub fn is_empty(&self) -> bool {
+        self.merged.is_empty()
+    }
+
+    /// Patches merged after the first `base` ones.
+    pub fn since(&self, base: usize) -> &[MergedPatch] {
+        self.merged.get(base..).unwrap_or_default()
+    }
+
+    /// Record a merged patch. Empty patches change nothing and are skipped.
+    pub fn push(&mut self, phase: &str, patch: String) {
+        if patch.trim().is_empty() {
+            return;
+        }
+        self.merged.push(MergedPatch {
+            phase: phase.to_string(),
+            patch,
+        });
+    }
+}
+
+/// The worktree a single phase runs in.
+#[derive(Debug, Clone)]
+pub struct PhaseWorktree {
+    pub phase: String,
+    pub name: String,
+    pub path: PathBuf,
+    /// Commit holding the merged state the phase works on top of.
+    pub base_sha: String,
+    /// Number of ledger entries included in the base.
+    pub base_len: usize,
+}
+
+/// Creates phase worktrees and merges them back into the project directory.
+#[derive(Debug)]
+pub struct WorktreeIsolation {
+    repo_path: PathBuf,
+    ledger: Mutex<MergeLedger>,
+}
+
+impl WorktreeIsolation {
+    pub fn new(repo_path: &Path) -> Self {
+        Self {
+            repo_path: repo_path.to_path_buf(),
+            ledger: Mutex::new(MergeLedger::default()),
+        }
+    }
+
+    fn manager(&self) -> Result<WorktreeManager> {
+        Ok(WorktreeManager::new(&self.repo_path)?.with_dir_name(WORKTREE_DIR))
+    }
+
+    /// Create a worktree for the phase at the current merged state.
+    pub async fn branch(&self, phase: &str) -> Result<PhaseWorktree> {
+        let ledger = self.ledger.lock().await;
+        let name = worktree_name(phase);
+        let manager = self.manager()?;
+
+        // A worktree left behind by an interrupted run would block `git worktree add`
+        manager.remove_worktree(&name)?;
+        let path = manager.create_worktree(&name)?;
+
+        for merged in ledger.since(0) {
+            apply_cleanly(&path, &merged.patch)
+                .with_context(|| format!("Failed to replay phase {} in worktree", merged.phase))?;
+        }
+        let base_sha = GitTracker::new(&path)?.snapshot_before(phase)?;
+
+        Ok(PhaseWorktree {
+            phase: phase.to_string(),
+            name,
+            path,
+            base_sha,
+            base_len: ledger.len(),
+        })
+    }
+
+    /// Lock the ledger. Hold the guard from checking a patch until it is recorded.
+    pub async fn lock(&self) -> MutexGuard<'_, MergeLedger> {
+        self.ledger.lock().await
+    }
+
+    /// The phase's changes relative to its base, including new files.
+    pub fn phase_patch(&self, worktree: &PhaseWorktree) -> Result<String> {
+        git(&worktree.path, ["add", "-A"])?;
+        git(&worktree.path, ["diff", "--binary", &worktree.base_sha])
+    }
+
+    /// Apply the patch to the project directory unless it overlaps patches
+    /// merged since the worktree was branched.
+    ///
+    /// The caller records a [`MergeOutcome::Clean`] patch in the ledger.
+    pub fn merge(
+        &self,
+        ledger: &MergeLedger,
+        worktree: &PhaseWorktree,
+        patch: &str,
+    ) -> Result<MergeOutcome> {
+        let mut conflicts: Vec<String> = Vec::new();
+        for merged in ledger.since(worktree.base_len) {
+            for file in detect_conflicts(&merged.patch, patch) {
+                if !conflicts.contains(&file) {
+                    conflicts.push(file);
+                }
+            }
+        }
+        if !conflicts.is_empty() {
+            return Ok(MergeOutcome::Conflict(conflicts));
+        }
+        apply_patch(&self.repo_path, patch)
+    }
+
+    /// Move the phase's changes on top of the current merged state.
+    ///
+    /// Returns the files left with conflict markers; empty when the
+    /// three-way merge was clean.
+    pub fn rebase(
+        &self,
+        ledger: &MergeLedger,
+        worktree: &mut PhaseWorktree,
+        patch: &str,
+    ) -> Result<Vec<String>> {
+        let path = worktree.path.clone();
+        git(&path, ["reset", "--hard", &worktree.base_sha])?;
+        git(&path, ["clean", "-fd"])?;
+
+        for merged in ledger.since(worktree.base_len) {
+            apply_cleanly(&path, &merged.patch)
+                .with_context(|| format!("Failed to replay phase {} in worktree", merged.phase))?;
+        }
+        worktree.base_sha = GitTracker::new(&path)?.snapshot_before(&worktree.phase)?;
+        worktree.base_len = ledger.len();
+
+        let output = run_git_with_input(&path, ["apply", "--3way", "-"], patch)
+            .context("Failed to re-apply phase patch")?;
+        if output.status.success() {
+            return Ok(Vec::new());
+        }
+
+        let unmerged = git(&path, ["diff", "--name-only", "--diff-filter=U"])?;
+        let files: Vec<String> = unmerged.lines().map(str::to_string).collect();
+        if files.is_empty() {
+            bail!("{}", git_error_message("git apply --3way failed", &output));
+        }
+        Ok(files)
+    }
+
+    /// Remove the phase's worktree.
+    pub fn remove(&self, worktree: &PhaseWorktree) -> Result<()> {
+        self.manager()?.remove_worktree(&worktree.name)
+    }
+}
+
+/// Files among `files` that still contain conflict markers.
+pub fn files_with_conflict_markers(dir: &Path, files: &[String]) -> Vec<String> {
+    files
+        .iter()
+        .filter(|file| {
+            std::fs::read_to_string(dir.join(file))
+                .map(|content| {
+                    content
+                        .lines()
+                        .any(|l| l.starts_with("<<<<<<< ") || l.starts_with(">>>>>>> "))
+                })
+                .unwrap_or(false)
+        })
+        .cloned()
+        .collect()
+}
+
+fn worktree_name(phase: &str) -> String {
+    let safe: String = phase
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    format!("phase-{safe}")
+}
+
+fn apply_cleanly(dir: &Path, patch: &str) -> Result<()> {
+    match apply_patch(dir, patch)? {
+        MergeOutcome::Clean(_) => Ok(()),
+        MergeOutcome::Conflict(files) => bail!("Conflicts in {}", files.join(", ")),
+        MergeOutcome::Failure(message) => bail!("{message}"),
+    }
+}
+
+fn git<const N: usize>(dir: &Path, args: [&str; N]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .with_context(|| format!("Failed to run git {:?}", args))?;
+    if !output.status.success() {
+        bail!(
+            "{}",
+            git_error_message(&format!("git {} failed", args.join(" ")), &output)
+        );
+    }
+    String::from_utf8(output.stdout).context("git produced invalid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_test_repo() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path();
+        git(path, ["init"]).unwrap();
+        git(path, ["config", "user.email", "test@test.com"]).unwrap();
+        git(path, ["config", "user.name", "Test"]).unwrap();
+        fs::write(path.join(".gitignore"), ".forge/\n").unwrap();
+        fs::write(path.join("lib.rs"), "one\ntwo\nthree\n").unwrap();
+        git(path, ["add", "."]).unwrap();
+        git(path, ["commit", "-m", "initial"]).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_worktree_name_is_path_safe() {
+        assert_eq!(worktree_name("01"), "phase-01");
+        assert_eq!(worktree_name("02.1/a"), "phase-02-1-a");
+    }
+
+    #[test]
+    fn test_ledger_since_and_skips_empty() {
+        let mut ledger = MergeLedger::default();
+        ledger.push("01", "diff a".to_string());
+        ledger.push("02", "  \n".to_string());
+        ledger.push("03", "diff c".to_string());
+
+        assert_eq!(ledger.len(), 2);
+        assert_eq!(ledger.since(1)[0].phase, "03");
+        assert!(ledger.since(2).is_empty());
+        assert!(ledger.since(5).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_branch_includes_merged_phases_and_merges_back() {
+        let repo = create_test_repo();
+        let isolation = WorktreeIsolation::new(repo.path());
+
+        let first = isolation.branch("01").await.unwrap();
+        fs::write(first.path.join("new.rs"), "fn new() {}\n").unwrap();
+        let patch = isolation.phase_patch(&first).unwrap();
+        assert!(patch.contains("new.rs"));
+        {
+            let mut ledger = isolation.lock().await;
+            match isolation.merge(&ledger, &first, &patch).unwrap() {
+                MergeOutcome::Clean(applied) => ledger.push("01", applied),
+                other => panic!("expected clean merge, got {other:?}"),
+            }
+        }
+        isolation.remove(&first).unwrap();
+        assert!(repo.path().join("new.rs").exists());
+        assert!(!first.path.exists());
+
+        // A dependent starts from the merged state and only reports its own changes
+        let second = isolation.branch("02").await.unwrap();
+        assert!(second.path.join("new.rs").exists());
+        assert_eq!(second.base_len, 1);
+        assert!(isolation.phase_patch(&second).unwrap().is_empty());
+        isolation.remove(&second).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_overlapping_phases_conflict_then_rebase() {
+        let repo = create_test_repo();
+        let isolation = WorktreeIsolation::new(repo.path());
+
+        let a = isolation.branch("a").await.unwrap();
+        let mut b = isolation.branch("b").await.unwrap();
+        fs::write(a.path.join("lib.rs"), "ONE\ntwo\nthree\n").unwrap();
+        fs::write(b.path.join("lib.rs"), "one\ntwo\nTHREE\n").unwrap();
+
+        let mut ledger = isolation.lock().await;
+        let patch_a = isolation.phase_patch(&a).unwrap();
+        match isolation.merge(&ledger, &a, &patch_a).unwrap() {
+            MergeOutcome::Clean(applied) => ledger.push("a", applied),
+            other => panic!("expected clean merge, got {other:?}"),
+        }
+
+        let patch_b = isolation.phase_patch(&b).unwrap();
+        let outcome = isolation.merge(&ledger, &b, &patch_b).unwrap();
+        assert!(matches!(outcome, MergeOutcome::Conflict(ref files) if files == &["lib.rs"]));
+
+        // Non-overlapping lines merge cleanly on top of the newer state
+        let markers = isolation.rebase(&ledger, &mut b, &patch_b).unwrap();
+        assert!(markers.is_empty());
+        assert_eq!(b.base_len, 1);
+        let rebased = isolation.phase_patch(&b).unwrap();
+        match isolation.merge(&ledger, &b, &rebased).unwrap() {
+            MergeOutcome::Clean(applied) => ledger.push("b", applied),
+            other => panic!("expected clean merge, got {other:?}"),
+        }
+        assert_eq!(
+            fs::read_to_string(repo.path().join("lib.rs")).unwrap(),
+            "ONE\ntwo\nTHREE\n"
+        );
+    }
+
+    #[test]
+    fn test_files_with_conflict_markers() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("a.rs"),
+            "<<<<<<< ours\nx\n=======\ny\n>>>>>>> theirs\n",
+        )
+        .unwrap();
+        fs::write(dir.path().join("b.rs"), "resolved\n").unwrap();
+        let files = vec![
+            "a.rs".to_string(),
+            "b.rs".to_string(),
+            "gone.rs".to_string(),
+        ];
+        assert_eq!(
+            files_with_conflict_markers(dir.path(), &files),
+            vec!["a.rs".to_string()]
+        );
+    }
+}
@@ -6,11 +6,20 @@
 //!
 //! ## Architecture
 //!
-//! The DAG scheduler has three main components:
+//! The DAG scheduler has four main components:
 //!
 //! 1. **Builder** - Constructs a DAG from phases with their dependencies
 //! 2. **Scheduler** - Computes execution waves and manages ready states
 //! 3. **Executor** - Runs phases in parallel with review integration
+//! 4. **Graph** - Renders the DAG with progress and its critical path
+//!
+//! Independent phases that edit the same files are kept apart by the
+//! scheduler; see the `scope` module. Alternatively each phase can run in
+//! its own git worktree and be merged back on success; see the `isolation`
+//! module.
+//!
+//! Execution state is checkpointed after every event so an interrupted swarm
+//! can be resumed; see the `checkpoint` module.
 //!
 //! ## Example
 //!
@@ -39,15 +48,28 @@
 //! ```
 
 mod builder;
+mod checkpoint;
+mod control;
 mod executor;
+mod graph;
+mod isolation;
 mod scheduler;
+mod scope;
 mod state;
 
 pub use builder::DagBuilder;
+pub use checkpoint::{DagCheckpoint, DecompositionProgress, ResumePolicy, SubTaskProgress};
+pub use control::{
+    AbortMode, ControlClient, ControlCommand, ControlServer, ControlStatus, SwarmControl,
+};
 pub use executor::{DagExecutor, ExecutionResult, ExecutorConfig, PhaseEvent};
+pub use graph::{DagGraph, GraphFormat, GraphNode, PhaseHistory};
+pub use isolation::{IsolationMode, MergeLedger, PhaseWorktree, WorktreeIsolation};
 pub use scheduler::{
-    DagConfig, DagScheduler, PhaseNode, PhaseStatus, ReviewConfig, ReviewMode, SwarmBackend,
+    DagConfig, DagScheduler, PhaseNode, PhaseStatus, ReviewConfig, ReviewMode, SchedulingPolicy,
+    SwarmBackend,
 };
+pub use scope::{ConflictLog, PhaseChanges, PhaseScope, ScopeConflict};
 pub use state::{DagState, DagSummary, PhaseResult};
 
 #[cfg(test)]
@@ -2,11 +2,20 @@
 //!
 //! The scheduler computes execution waves - groups of phases that can run in parallel
 //! because their dependencies are satisfied.
+//!
+//! Ready phases are handed out in priority order: an explicit `priority` on the
+//! phase first, then (with [`SchedulingPolicy::CriticalPath`]) the phase with
+//! the longest remaining path of expected iterations through its dependents.
+//! Starting those first keeps the longest chain moving and shortens the run.
 
 use crate::dag::builder::{DagBuilder, PhaseGraph, PhaseIndex};
+use crate::dag::isolation::IsolationMode;
+use crate::dag::scope::PhaseScope;
 use crate::phase::Phase;
+use crate::review::ResolutionMode;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
 use std::collections::HashSet;
 
 /// Configuration for the DAG scheduler.
@@ -28,6 +37,12 @@ pub struct DagConfig {
     pub decomposition_threshold: u32,
     /// Finding types to always escalate
     pub escalation_types: Vec<String>,
+    /// Where phases do their work
+    #[serde(default)]
+    pub isolation: IsolationMode,
+    /// Order in which ready phases are started
+    #[serde(default)]
+    pub scheduling: SchedulingPolicy,
 }
 
 impl Default for DagConfig {
@@ -41,6 +56,8 @@ impl Default for DagConfig {
             decomposition_enabled: true,
             decomposition_threshold: 50,
             escalation_types: Vec::new(),
+            isolation: IsolationMode::default(),
+            scheduling: SchedulingPolicy::default(),
         }
     }
 }
@@ -93,6 +110,31 @@ impl DagConfig {
         self.escalation_types = types;
         self
     }
+
+    /// Set where phases do their work.
+    pub fn with_isolation(mut self, isolation: IsolationMode) -> Self {
+        self.isolation = isolation;
+        self
+    }
+
+    /// Set the order in which ready phases are started.
+    pub fn with_scheduling(mut self, scheduling: SchedulingPolicy) -> Self {
+        self.scheduling = scheduling;
+        self
+    }
+}
+
+/// Order in which ready phases are started.
+///
+/// Explicit phase priorities always come first; the policy breaks ties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SchedulingPolicy {
+    /// Prefer phases with the longest remaining path of expected iterations
+    #[default]
+    CriticalPath,
+    /// Start phases in phases.json order
+    Fifo,
 }
 
 /// Backend for swarm execution.
@@ -123,6 +165,9 @@ pub struct ReviewConfig {
     pub max_fix_attempts: u32,
     /// Arbiter confidence threshold
     pub arbiter_confidence: f64,
+    /// Model the LLM arbiter runs with, when not the default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arbiter_model: Option<String>,
 }
 
 impl Default for ReviewConfig {
@@ -133,6 +178,7 @@ impl Default for ReviewConfig {
             mode: ReviewMode::Manual,
             max_fix_attempts: 2,
             arbiter_confidence: 0.7,
+            arbiter_model: None,
         }
     }
 }
@@ -152,6 +198,21 @@ impl ReviewConfig {
         self.mode = mode;
         self
     }
+
+    /// The arbiter resolution mode for this config.
+    pub fn resolution_mode(&self) -> ResolutionMode {
+        match self.mode {
+            ReviewMode::Manual => ResolutionMode::manual(self.max_fix_attempts),
+            ReviewMode::Auto => ResolutionMode::auto(self.max_fix_attempts),
+            ReviewMode::Arbiter => ResolutionMode::auto_with_llm(
+                self.max_fix_attempts,
+                self.arbiter_model
+                    .as_deref()
+                    .unwrap_or(crate::review::arbiter::DEFAULT_ARBITER_MODEL),
+                self.arbiter_confidence,
+            ),
+        }
+    }
 }
 
 /// Review resolution mode.
@@ -256,6 +317,14 @@ pub struct DagScheduler {
     completed: HashSet<PhaseIndex>,
     /// Set of failed phase indices
     failed: HashSet<PhaseIndex>,
+    /// Declared file scopes and locks, by phase index
+    scopes: Vec<PhaseScope>,
+    /// Pairs found to conflict at runtime (lower index first)
+    learned_conflicts: HashSet<(PhaseIndex, PhaseIndex)>,
+    /// Expected iterations per phase, by phase index
+    expected: Vec<u32>,
+    /// Expected iterations along the longest path from each phase to a leaf
+    remaining: Vec<u32>,
 }
 
 impl DagScheduler {
@@ -271,14 +340,98 @@ impl DagScheduler {
             .enumerate()
             .map(|(i, p)| PhaseNode::new(p.clone(), i))
             .collect();
+        let scopes = graph.phases().iter().map(PhaseScope::of).collect();
+        let expected = graph.phases().iter().map(|p| p.budget).collect();
 
-        Ok(Self {
+        let mut scheduler = Self {
             graph,
             nodes,
             config,
             completed: HashSet::new(),
             failed: HashSet::new(),
-        })
+            scopes,
+            learned_conflicts: HashSet::new(),
+            expected,
+            remaining: Vec::new(),
+        };
+        scheduler.compute_remaining();
+        Ok(scheduler)
+    }
+
+    /// Use a better estimate than the budget for a phase's iterations,
+    /// e.g. the average learned from earlier projects.
+    pub fn set_expected_iterations(&mut self, number: &str, iterations: u32) {
+        if let Some(idx) = self.graph.get_index(number) {
+            self.expected[idx] = iterations;
+            self.compute_remaining();
+        }
+    }
+
+    /// Add phases spawned by a running phase as new nodes.
+    ///
+    /// The children are scheduled like any other phase, and phases that
+    /// depend on `parent` wait for them too. Returns the numbers of the
+    /// added phases.
+    pub fn insert_phases(&mut self, parent: &str, children: Vec<Phase>) -> Result<Vec<String>> {
+        let parent_idx = self
+            .graph
+            .get_index(parent)
+            .with_context(|| format!("Unknown parent phase '{}'", parent))?;
+        let added = self
+            .graph
+            .insert_phases(parent_idx, children)
+            .with_context(|| format!("Failed to insert sub-phases of phase '{}'", parent))?;
+
+        // Dependents of the parent gained dependencies on the children
+        for (node, phase) in self.nodes.iter_mut().zip(self.graph.phases()) {
+            node.phase.depends_on = phase.depends_on.clone();
+        }
+
+        let mut numbers = Vec::with_capacity(added.len());
+        for idx in added {
+            let phase = &self.graph.phases()[idx];
+            self.nodes.push(PhaseNode::new(phase.clone(), idx));
+            self.scopes.push(PhaseScope::of(phase));
+            self.expected.push(phase.budget);
+            numbers.push(phase.number.clone());
+        }
+        self.compute_remaining();
+        Ok(numbers)
+    }
+
+    /// Expected iterations along the longest path from a phase to a leaf,
+    /// counting the phase itself.
+    pub fn remaining_path(&self, number: &str) -> Option<u32> {
+        self.graph.get_index(number).map(|i| self.remaining[i])
+    }
+
+    fn compute_remaining(&mut self) {
+        let mut remaining = vec![0u32; self.nodes.len()];
+        // Waves are a topological order; walk it backwards so dependents come first.
+        for number in self.compute_waves().iter().rev().flatten() {
+            let Some(idx) = self.graph.get_index(number) else {
+                continue;
+            };
+            let longest_tail = self
+                .graph
+                .dependents(idx)
+                .iter()
+                .map(|&dep| remaining[dep])
+                .max()
+                .unwrap_or(0);
+            remaining[idx] = self.expected[idx].saturating_add(longest_tail);
+        }
+        self.remaining = remaining;
+    }
+
+    /// Sort key for ready phases; smaller sorts first.
+    fn rank(&self, idx: PhaseIndex) -> (Reverse<i32>, Reverse<u32>, PhaseIndex) {
+        let priority = self.nodes[idx].phase.priority.unwrap_or(0);
+        let remaining = match self.config.scheduling {
+            SchedulingPolicy::CriticalPath => self.remaining[idx],
+            SchedulingPolicy::Fifo => 0,
+        };
+        (Reverse(priority), Reverse(remaining), idx)
     }
 
     /// Get the number of phases in the DAG.
@@ -297,6 +450,11 @@ impl DagScheduler {
         self.nodes.get_mut(index)
     }
 
+    /// Get the underlying phase graph.
+    pub fn graph(&self) -> &PhaseGraph {
+        &self.graph
+    }
+
     /// Get all phase nodes.
     pub fn nodes(&self) -> &[PhaseNode] {
         &self.nodes
@@ -351,18 +509,73 @@ impl DagScheduler {
         waves
     }
 
-    /// Get phases that are ready to run (dependencies satisfied, not started).
+    /// Get phases that are ready to run (dependencies satisfied, not started),
+    /// highest priority first.
+    ///
+    /// Phases that conflict with a running phase, or with a phase earlier in
+    /// the returned list, are held back so the whole list can be started
+    /// together.
     pub fn get_ready_phases(&self) -> Vec<&PhaseNode> {
-        self.nodes
+        let mut claimed: Vec<PhaseIndex> = self
+            .nodes
+            .iter()
+            .filter(|node| node.status.is_running())
+            .map(|node| node.index)
+            .collect();
+
+        let mut candidates: Vec<&PhaseNode> = self
+            .nodes
             .iter()
             .filter(|node| {
-                if !matches!(node.status, PhaseStatus::Pending) {
-                    return false;
-                }
-                self.graph
-                    .dependencies_satisfied(node.index, &self.completed)
+                matches!(node.status, PhaseStatus::Pending)
+                    && self
+                        .graph
+                        .dependencies_satisfied(node.index, &self.completed)
             })
-            .collect()
+            .collect();
+        candidates.sort_by_key(|node| self.rank(node.index));
+
+        let mut ready = Vec::new();
+        for node in candidates {
+            if claimed
+                .iter()
+                .any(|&other| self.conflicts_at(node.index, other))
+            {
+                continue;
+            }
+            claimed.push(node.index);
+            ready.push(node);
+        }
+        ready
+    }
+
+    /// Record that two phases must not run at the same time.
+    pub fn add_conflict(&mut self, a: &str, b: &str) {
+        if let (Some(a), Some(b)) = (self.graph.get_index(a), self.graph.get_index(b))
+            && a != b
+        {
+            self.learned_conflicts.insert((a.min(b), a.max(b)));
+        }
+    }
+
+    /// Check whether two phases conflict, by declared scope or a recorded conflict.
+    pub fn conflicts(&self, a: &str, b: &str) -> bool {
+        match (self.graph.get_index(a), self.graph.get_index(b)) {
+            (Some(a), Some(b)) => a != b && self.conflicts_at(a, b),
+            _ => false,
+        }
+    }
+
+    /// Check whether a phase declared its file scope or locks.
+    pub fn has_declared_scope(&self, number: &str) -> bool {
+        self.graph
+            .get_index(number)
+            .is_some_and(|i| self.scopes[i].is_declared())
+    }
+
+    fn conflicts_at(&self, a: PhaseIndex, b: PhaseIndex) -> bool {
+        self.learned_conflicts.contains(&(a.min(b), a.max(b)))
+            || self.scopes[a].overlaps(&self.scopes[b])
     }
 
     /// Mark a phase as running.
@@ -404,6 +617,54 @@ impl DagScheduler {
         }
     }
 
+    /// Mark a pending or running phase as cancelled.
+    ///
+    /// Unlike [`mark_failed`](Self::mark_failed) this never skips dependents,
+    /// so they can still run if the phase is retried. Returns false if the
+    /// phase has already finished.
+    pub fn mark_cancelled(&mut self, number: &str) -> bool {
+        let Some(idx) = self.graph.get_index(number) else {
+            return false;
+        };
+        let node = &mut self.nodes[idx];
+        if node.status.is_terminal() {
+            return false;
+        }
+        node.status = PhaseStatus::Failed {
+            error: "cancelled".to_string(),
+        };
+        self.failed.insert(idx);
+        true
+    }
+
+    /// Make a failed or cancelled phase pending again.
+    ///
+    /// Dependents skipped because of the failure become pending too.
+    /// Returns false if the phase has not failed.
+    pub fn reset_failed(&mut self, number: &str) -> bool {
+        let Some(idx) = self.graph.get_index(number) else {
+            return false;
+        };
+        if !matches!(self.nodes[idx].status, PhaseStatus::Failed { .. }) {
+            return false;
+        }
+        self.nodes[idx].status = PhaseStatus::Pending;
+        self.failed.remove(&idx);
+        self.unskip_dependents(idx);
+        true
+    }
+
+    fn unskip_dependents(&mut self, idx: PhaseIndex) {
+        let dependents: Vec<PhaseIndex> = self.graph.dependents(idx).to_vec();
+        for dep_idx in dependents {
+            if matches!(self.nodes[dep_idx].status, PhaseStatus::Skipped) {
+                self.nodes[dep_idx].status = PhaseStatus::Pending;
+                self.failed.remove(&dep_idx);
+                self.unskip_dependents(dep_idx);
+            }
+        }
+    }
+
     /// Mark a phase as skipped.
     pub fn mark_skipped(&mut self, number: &str) {
         if let Some(idx) = self.graph.get_index(number) {
@@ -553,6 +814,160 @@ mod tests {
         assert_eq!(ready.len(), 2);
     }
 
+    #[test]
+    fn test_ready_phases_hold_back_overlapping_scopes() {
+        let mut phases = vec![
+            phase("01", vec![]),
+            phase("02", vec![]),
+            phase("03", vec![]),
+            phase("04", vec![]),
+        ];
+        phases[0].scope = vec!["src/lib.rs".to_string()];
+        phases[1].scope = vec!["src/*.rs".to_string()];
+        phases[2].locks = vec!["database".to_string()];
+        phases[3].locks = vec!["database".to_string()];
+
+        let mut scheduler = DagScheduler::from_phases(&phases, DagConfig::default()).unwrap();
+        let ready: Vec<_> = scheduler
+            .get_ready_phases()
+            .iter()
+            .map(|n| n.phase.number.clone())
+            .collect();
+        assert_eq!(ready, vec!["01", "03"]);
+
+        // Held back while the conflicting phase runs, released once it completes.
+        scheduler.mark_running("01");
+        scheduler.mark_running("03");
+        assert!(scheduler.get_ready_phases().is_empty());
+        scheduler.mark_completed("01", 2);
+        let ready: Vec<_> = scheduler
+            .get_ready_phases()
+            .iter()
+            .map(|n| n.phase.number.clone())
+            .collect();
+        assert_eq!(ready, vec!["02"]);
+    }
+
+    fn ready_numbers(scheduler: &DagScheduler) -> Vec<String> {
+        scheduler
+            .get_ready_phases()
+            .iter()
+            .map(|n| n.phase.number.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_ready_phases_prefer_longest_remaining_path() {
+        // 01 is a leaf; 02 -> 04 is the longer chain
+        let phases = vec![
+            phase("01", vec![]),
+            phase("02", vec![]),
+            phase("03", vec![]),
+            phase("04", vec!["02"]),
+        ];
+        let mut scheduler = DagScheduler::from_phases(&phases, DagConfig::default()).unwrap();
+        assert_eq!(scheduler.remaining_path("02"), Some(10));
+        assert_eq!(scheduler.remaining_path("01"), Some(5));
+        assert_eq!(ready_numbers(&scheduler), vec!["02", "01", "03"]);
+
+        // Learned history says 03 takes far longer than its siblings
+        scheduler.set_expected_iterations("03", 20);
+        assert_eq!(ready_numbers(&scheduler), vec!["03", "02", "01"]);
+
+        let fifo = DagConfig::default().with_scheduling(SchedulingPolicy::Fifo);
+        let scheduler = DagScheduler::from_phases(&phases, fifo).unwrap();
+        assert_eq!(ready_numbers(&scheduler), vec!["01", "02", "03"]);
+    }
+
+    #[test]
+    fn test_explicit_priority_wins() {
+        let mut phases = vec![
+            phase("01", vec![]),
+            phase("02", vec![]),
+            phase("03", vec!["02"]),
+        ];
+        phases[0].priority = Some(10);
+        let scheduler = DagScheduler::from_phases(&phases, DagConfig::default()).unwrap();
+        assert_eq!(ready_numbers(&scheduler), vec!["01", "02"]);
+
+        phases[0].priority = Some(-1);
+        let fifo = DagConfig::default().with_scheduling(SchedulingPolicy::Fifo);
+        let scheduler = DagScheduler::from_phases(&phases, fifo).unwrap();
+        assert_eq!(ready_numbers(&scheduler), vec!["02", "01"]);
+    }
+
+    #[test]
+    fn test_inserted_phases_run_before_dependents() {
+        let phases = vec![
+            phase("01", vec![]),
+            phase("02", vec!["01"]),
+            phase("03", vec![]),
+        ];
+        let mut scheduler = DagScheduler::from_phases(&phases, DagConfig::default()).unwrap();
+        scheduler.mark_running("01");
+
+        let added = scheduler
+            .insert_phases(
+                "01",
+                vec![phase("01.1", vec!["01"]), phase("01.2", vec!["01"])],
+            )
+            .unwrap();
+        assert_eq!(added, vec!["01.1", "01.2"]);
+        assert_eq!(scheduler.phase_count(), 5);
+        assert_eq!(scheduler.remaining_path("01"), Some(15));
+        assert_eq!(
+            scheduler.get_node("02").unwrap().phase.depends_on,
+            vec!["01", "01.1", "01.2"]
+        );
+
+        // The children run alongside other phases; 02 waits for them
+        scheduler.mark_completed("01", 3);
+        assert_eq!(ready_numbers(&scheduler), vec!["01.1", "01.2", "03"]);
+        scheduler.mark_completed("01.1", 2);
+        assert_eq!(ready_numbers(&scheduler), vec!["01.2", "03"]);
+        scheduler.mark_completed("01.2", 2);
+        assert_eq!(ready_numbers(&scheduler), vec!["02", "03"]);
+
+        assert!(
+            scheduler
+                .insert_phases("99", vec![phase("99.1", vec![])])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_learned_conflicts_serialize_phases() {
+        let phases = vec![phase("01", vec![]), phase("02", vec![])];
+        let mut scheduler = DagScheduler::from_phases(&phases, DagConfig::default()).unwrap();
+        assert!(!scheduler.conflicts("01", "02"));
+
+        scheduler.add_conflict("02", "01");
+        assert!(scheduler.conflicts("01", "02"));
+        assert_eq!(scheduler.get_ready_phases().len(), 1);
+    }
+
+    #[test]
+    fn test_cancel_and_retry_phase() {
+        let phases = vec![phase("01", vec![]), phase("02", vec!["01"])];
+        let mut scheduler = DagScheduler::from_phases(&phases, DagConfig::default()).unwrap();
+
+        scheduler.mark_running("01");
+        assert!(scheduler.mark_cancelled("01"));
+        assert!(!scheduler.mark_cancelled("01"));
+        assert_eq!(scheduler.failed_count(), 1);
+        assert!(scheduler.get_ready_phases().is_empty());
+        // Dependents are left pending so a retry can unblock them
+        assert!(matches!(
+            scheduler.get_node("02").unwrap().status,
+            PhaseStatus::Pending
+        ));
+
+        assert!(scheduler.reset_failed("01"));
+        assert!(!scheduler.reset_failed("02"));
+        assert_eq!(scheduler.failed_count(), 0);
+        assert_eq!(scheduler.get_ready_phases()[0].phase.number, "01");
+    }
+
     #[test]
     fn test_fail_fast() {
         let phases = vec![
@@ -589,4 +1004,22 @@ mod tests {
         assert!(scheduler.all_complete());
         assert!(scheduler.all_success());
     }
+
+    #[test]
+    fn test_review_config_resolution_mode() {
+        let config = ReviewConfig::default();
+        assert!(config.resolution_mode().is_manual());
+
+        let config = ReviewConfig::default().with_mode(ReviewMode::Arbiter);
+        let mode = config.resolution_mode();
+        assert!(mode.is_auto());
+        assert!(mode.has_llm());
+        assert_eq!(mode.max_attempts, 2);
+
+        let config = ReviewConfig {
+            arbiter_model: Some("opus".to_string()),
+            ..config
+        };
+        assert_eq!(config.resolution_mode().model(), Some("opus"));
+    }
 }
@@ -0,0 +1,338 @@
+//! File-scope and resource conflicts between phases.
+//!
+//! All phases of a swarm share one working tree, so two independent phases
+//! that edit the same files must not run at the same time. Conflicts come
+//! from two places:
+//!
+//! - **Declared scopes**: a phase may list the files it edits (`scope`, as
+//!   globs) and named resources it needs exclusively (`locks`). The scheduler
+//!   refuses to co-schedule phases whose scopes overlap.
+//! - **Detected conflicts**: when at least one of two concurrent phases has
+//!   no declared scope, their changes are compared after both finish.
+//!   Files changed by both are recorded in `.forge/scope-conflicts.json`,
+//!   and the pair is serialized from then on, including when the phases are
+//!   retried in a later run of the same plan.
+//!
+//! Phases share the working tree, so each phase's git diff also contains
+//! edits made by its concurrent peers. The diffs are therefore attributed
+//! using the files each agent edited through its own file tools: a file one
+//! phase claims and the other does not belongs to the claiming phase alone.
+//! Files neither phase claims (for example, written by a shell command) are
+//! still counted for both, so detection stays conservative for them.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::audit::FileChangeSummary;
+use crate::phase::Phase;
+
+/// File name of the detected conflict log inside `.forge/`.
+pub const CONFLICTS_FILE: &str = "scope-conflicts.json";
+
+/// The files and resources a phase declares it will touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PhaseScope {
+    pub files: Vec<String>,
+    pub locks: Vec<String>,
+}
+
+impl PhaseScope {
+    /// Read the declared scope of a phase.
+    pub fn of(phase: &Phase) -> Self {
+        Self {
+            files: phase.scope.clone(),
+            locks: phase.locks.clone(),
+        }
+    }
+
+    /// Whether the phase declared any files or locks.
+    pub fn is_declared(&self) -> bool {
+        !self.files.is_empty() || !self.locks.is_empty()
+    }
+
+    /// Whether the two scopes may touch the same file or resource.
+    pub fn overlaps(&self, other: &PhaseScope) -> bool {
+        self.locks.iter().any(|lock| other.locks.contains(lock))
+            || self
+                .files
+                .iter()
+                .any(|a| other.files.iter().any(|b| globs_overlap(a, b)))
+    }
+}
+
+/// Conservatively decide whether two globs can match a common path.
+///
+/// Literal paths are compared exactly or matched against the other glob.
+/// For two wildcard patterns, the literal prefixes before the first wildcard
+/// are compared: `src/api/*.rs` and `src/db/**` are disjoint, while
+/// `src/**` and `src/api/*.rs` overlap.
+pub fn globs_overlap(a: &str, b: &str) -> bool {
+    let a = a.trim_start_matches("./");
+    let b = b.trim_start_matches("./");
+
+    match (is_literal(a), is_literal(b)) {
+        (true, true) => a == b,
+        (true, false) => glob_matches(b, a),
+        (false, true) => glob_matches(a, b),
+        (false, false) => {
+            let (pa, pb) = (literal_prefix(a), literal_prefix(b));
+            pa.starts_with(pb) || pb.starts_with(pa)
+        }
+    }
+}
+
+fn is_literal(pattern: &str) -> bool {
+    !pattern.contains(['*', '?', '['])
+}
+
+fn literal_prefix(pattern: &str) -> &str {
+    let end = pattern.find(['*', '?', '[']).unwrap_or(pattern.len());
+    &pattern[..end]
+}
+
+fn glob_matches(pattern: &str, path: &str) -> bool {
+    match glob::Pattern::new(pattern) {
+        Ok(p) => p.matches_path(Path::new(path)),
+        // An unparsable glob could match anything; assume it does.
+        Err(_) => true,
+    }
+}
+
+/// All paths touched according to a change summary.
+pub fn touched_files(changes: &FileChangeSummary) -> BTreeSet<PathBuf> {
+    changes
+        .files_added
+        .iter()
+        .chain(&changes.files_modified)
+        .chain(&changes.files_deleted)
+        .cloned()
+        .collect()
+}
+
+/// The changes a finished phase left in the shared working tree.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PhaseChanges {
+    /// Every path in the phase's git diff, including peers' edits.
+    pub diff: BTreeSet<PathBuf>,
+    /// Paths the phase's agent edited itself.
+    pub edited: BTreeSet<PathBuf>,
+}
+
+impl PhaseChanges {
+    pub fn new(changes: &FileChangeSummary, edited: &BTreeSet<PathBuf>) -> Self {
+        Self {
+            diff: touched_files(changes),
+            edited: edited.clone(),
+        }
+    }
+
+    /// Files changed by both phases.
+    ///
+    /// A file in both diffs that only one of the phases edited belongs to
+    /// that phase; the other diff merely picked it up from the shared tree.
+    pub fn shared_with(&self, other: &PhaseChanges) -> Vec<PathBuf> {
+        self.diff
+            .intersection(&other.diff)
+            .filter(|file| self.edited.contains(*file) == other.edited.contains(*file))
+            .cloned()
+            .collect()
+    }
+}
+
+/// A pair of phases found to touch the same files while running concurrently.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopeConflict {
+    /// The two phase numbers, in sorted order.
+    pub phases: [String; 2],
+    /// The names of the two phases, in the same order as `phases`.
+    ///
+    /// A conflict only applies to phases that still carry these names, so
+    /// a different plan reusing the numbers is not serialized by it.
+    #[serde(default)]
+    pub names: [String; 2],
+    pub files: Vec<PathBuf>,
+    pub detected_at: DateTime<Utc>,
+}
+
+impl ScopeConflict {
+    pub fn new(a: &Phase, b: &Phase, files: Vec<PathBuf>) -> Self {
+        let (a, b) = if a.number <= b.number { (a, b) } else { (b, a) };
+        Self {
+            phases: [a.number.clone(), b.number.clone()],
+            names: [a.name.clone(), b.name.clone()],
+            files,
+            detected_at: Utc::now(),
+        }
+    }
+
+    /// Whether this conflict is between the given phase numbers.
+    pub fn involves(&self, a: &str, b: &str) -> bool {
+        (self.phases[0] == a && self.phases[1] == b) || (self.phases[0] == b && self.phases[1] == a)
+    }
+
+    /// Whether this conflict was recorded for these phases of the current plan.
+    pub fn applies_to(&self, a: &Phase, b: &Phase) -> bool {
+        let matches =
+            |i: usize, phase: &Phase| self.phases[i] == phase.number && self.names[i] == phase.name;
+        (matches(0, a) && matches(1, b)) || (matches(0, b) && matches(1, a))
+    }
+}
+
+/// Detected conflicts persisted in `.forge/scope-conflicts.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConflictLog {
+    #[serde(default)]
+    pub conflicts: Vec<ScopeConflict>,
+}
+
+impl ConflictLog {
+    /// Path of the conflict log inside the given `.forge` directory.
+    pub fn path(forge_dir: &Path) -> PathBuf {
+        forge_dir.join(CONFLICTS_FILE)
+    }
+
+    /// Load the log, returning an empty one if the file does not exist.
+    pub fn load(forge_dir: &Path) -> Result<Self> {
+        let path = Self::path(forge_dir);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Write the log to disk.
+    pub fn save(&self, forge_dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(forge_dir)?;
+        let path = Self::path(forge_dir);
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Add a conflict, replacing any earlier record for the same pair.
+    pub fn record(&mut self, conflict: ScopeConflict) {
+        self.conflicts
+            .retain(|c| !c.involves(&conflict.phases[0], &conflict.phases[1]));
+        self.conflicts.push(conflict);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn scope(files: &[&str], locks: &[&str]) -> PhaseScope {
+        PhaseScope {
+            files: files.iter().map(|s| s.to_string()).collect(),
+            locks: locks.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_globs_overlap() {
+        assert!(globs_overlap("src/lib.rs", "src/lib.rs"));
+        assert!(globs_overlap("./src/lib.rs", "src/lib.rs"));
+        assert!(!globs_overlap("src/lib.rs", "src/main.rs"));
+        assert!(globs_overlap("src/*.rs", "src/lib.rs"));
+        assert!(!globs_overlap("src/api/*.rs", "src/lib.rs"));
+        assert!(globs_overlap("src/**", "src/api/*.rs"));
+        assert!(!globs_overlap("src/api/*.rs", "src/db/**"));
+        assert!(globs_overlap("*.md", "docs/**"));
+    }
+
+    #[test]
+    fn test_scope_overlaps() {
+        let api = scope(&["src/api/**"], &[]);
+        let db = scope(&["src/db/**"], &["database"]);
+        let migrations = scope(&["migrations/*.sql"], &["database"]);
+
+        assert!(!api.overlaps(&db));
+        assert!(db.overlaps(&migrations));
+        assert!(api.overlaps(&scope(&["src/api/routes.rs"], &[])));
+        assert!(!api.overlaps(&PhaseScope::default()));
+        assert!(!PhaseScope::default().is_declared());
+    }
+
+    #[test]
+    fn test_touched_files() {
+        let changes = FileChangeSummary {
+            files_added: vec![PathBuf::from("b.rs")],
+            files_modified: vec![PathBuf::from("a.rs"), PathBuf::from("b.rs")],
+            ..Default::default()
+        };
+        let files: Vec<_> = touched_files(&changes).into_iter().collect();
+        assert_eq!(files, vec![PathBuf::from("a.rs"), PathBuf::from("b.rs")]);
+    }
+
+    fn changes(diff: &[&str], edited: &[&str]) -> PhaseChanges {
+        PhaseChanges {
+            diff: diff.iter().map(PathBuf::from).collect(),
+            edited: edited.iter().map(PathBuf::from).collect(),
+        }
+    }
+
+    #[test]
+    fn test_concurrent_phases_with_disjoint_edits_do_not_conflict() {
+        // Both diffs see both files because the phases share the working tree
+        let api = changes(&["src/api.rs", "src/db.rs"], &["src/api.rs"]);
+        let db = changes(&["src/api.rs", "src/db.rs"], &["src/db.rs"]);
+
+        assert!(api.shared_with(&db).is_empty());
+        assert!(db.shared_with(&api).is_empty());
+    }
+
+    #[test]
+    fn test_shared_changes() {
+        let a = changes(&["lib.rs", "Cargo.lock", "a.rs"], &["lib.rs", "a.rs"]);
+        let b = changes(&["lib.rs", "Cargo.lock", "a.rs"], &["lib.rs"]);
+
+        // Edited by both, or by neither (e.g. through a shell command)
+        assert_eq!(
+            a.shared_with(&b),
+            vec![PathBuf::from("Cargo.lock"), PathBuf::from("lib.rs")]
+        );
+    }
+
+    fn phase(number: &str, name: &str) -> Phase {
+        Phase::new(number, name, "DONE", 5, "", vec![])
+    }
+
+    #[test]
+    fn test_conflict_applies_only_to_same_plan() {
+        let (api, db) = (phase("02", "API"), phase("03", "Database"));
+        let conflict = ScopeConflict::new(&db, &api, vec![PathBuf::from("a.rs")]);
+
+        assert_eq!(conflict.phases, ["02".to_string(), "03".to_string()]);
+        assert!(conflict.applies_to(&api, &db));
+        assert!(conflict.applies_to(&db, &api));
+        assert!(!conflict.applies_to(&phase("02", "Docs"), &db));
+    }
+
+    #[test]
+    fn test_conflict_log_roundtrip() {
+        let dir = tempdir().unwrap();
+        assert!(ConflictLog::load(dir.path()).unwrap().conflicts.is_empty());
+
+        let (a, b) = (phase("02", "API"), phase("03", "Database"));
+        let mut log = ConflictLog::default();
+        log.record(ScopeConflict::new(&b, &a, vec![PathBuf::from("a.rs")]));
+        log.record(ScopeConflict::new(&a, &b, vec![PathBuf::from("b.rs")]));
+        log.save(dir.path()).unwrap();
+
+        let loaded = ConflictLog::load(dir.path()).unwrap();
+        assert_eq!(loaded.conflicts.len(), 1);
+        assert_eq!(
+            loaded.conflicts[0].phases,
+            ["02".to_string(), "03".to_string()]
+        );
+        assert_eq!(loaded.conflicts[0].files, vec![PathBuf::from("b.rs")]);
+        assert!(loaded.conflicts[0].involves("03", "02"));
+    }
+}
@@ -4,9 +4,13 @@
 //! including individual phase results and overall DAG state.
 
 use crate::audit::FileChangeSummary;
-use crate::review::DispatchResult;
+use crate::decomposition::DecomposedPhase;
+use crate::orchestrator::FailureClass;
+use crate::phase::Phase;
+use crate::review::{DispatchResult, FixItem};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 /// Overall state of DAG execution.
@@ -49,12 +53,19 @@ pub struct PhaseResult {
     pub iterations: u32,
     /// Files changed during the phase
     pub files_changed: FileChangeSummary,
+    /// Files the phase's agent edited itself, as opposed to changes made
+    /// by other phases sharing the working tree
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub edited_files: BTreeSet<PathBuf>,
     /// Review result if reviews were run
     #[serde(skip_serializing_if = "Option::is_none")]
     pub review_result: Option<DispatchResult>,
     /// Error message if the phase failed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) error: Option<String>,
+    /// Why the phase failed, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_class: Option<FailureClass>,
     /// Duration of the phase execution
     #[serde(with = "duration_serde")]
     pub duration: Duration,
@@ -64,6 +75,15 @@ pub struct PhaseResult {
     /// Whether the phase was decomposed into sub-tasks
     #[serde(default)]
     pub decomposed: bool,
+    /// The sub-task plan, when the phase was decomposed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decomposition: Option<DecomposedPhase>,
+    /// Sub-phases and decomposed tasks to schedule after this phase
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spawned_phases: Vec<Phase>,
+    /// Review fix items tracked by the fix loop, with their final status
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub review_fixes: Vec<FixItem>,
 }
 
 impl PhaseResult {
@@ -79,11 +99,16 @@ impl PhaseResult {
             success: true,
             iterations,
             files_changed,
+            edited_files: BTreeSet::new(),
             review_result: None,
             error: None,
+            failure_class: None,
             duration,
             note: None,
             decomposed: false,
+            decomposition: None,
+            spawned_phases: Vec::new(),
+            review_fixes: Vec::new(),
         }
     }
 
@@ -94,11 +119,16 @@ impl PhaseResult {
             success: false,
             iterations,
             files_changed: FileChangeSummary::default(),
+            edited_files: BTreeSet::new(),
             review_result: None,
             error: Some(error.to_string()),
+            failure_class: None,
             duration,
             note: None,
             decomposed: false,
+            decomposition: None,
+            spawned_phases: Vec::new(),
+            review_fixes: Vec::new(),
         }
     }
 
@@ -108,6 +138,24 @@ impl PhaseResult {
         self
     }
 
+    /// Record the files the phase's agent edited itself.
+    pub fn with_edited_files(mut self, files: BTreeSet<PathBuf>) -> Self {
+        self.edited_files = files;
+        self
+    }
+
+    /// Record the review fix items tracked for this phase.
+    pub fn with_review_fixes(mut self, items: Vec<FixItem>) -> Self {
+        self.review_fixes = items;
+        self
+    }
+
+    /// Record why the phase failed.
+    pub fn with_failure_class(mut self, class: FailureClass) -> Self {
+        self.failure_class = Some(class);
+        self
+    }
+
     /// Add a note to this phase result.
     pub fn with_note(mut self, note: &str) -> Self {
         self.note = Some(note.to_string());
@@ -120,6 +168,19 @@ impl PhaseResult {
         self
     }
 
+    /// Attach the sub-task plan of a decomposed phase.
+    pub fn with_decomposed_phase(mut self, decomposed: DecomposedPhase) -> Self {
+        self.decomposed = true;
+        self.decomposition = Some(decomposed);
+        self
+    }
+
+    /// Attach phases spawned by this phase, to be inserted into the DAG.
+    pub fn with_spawned_phases(mut self, phases: Vec<Phase>) -> Self {
+        self.spawned_phases = phases;
+        self
+    }
+
     /// Check if the phase can proceed (success and reviews pass).
     pub fn can_proceed(&self) -> bool {
         if !self.success {
@@ -156,6 +217,7 @@ impl PhaseResult {
     pub(crate) fn mark_review_gate_failure(&mut self) {
         self.success = false;
         self.error = Some("Review gate failed".to_string());
+        self.failure_class = Some(FailureClass::ReviewBlocked);
     }
 }
 
@@ -197,6 +259,17 @@ impl DagSummary {
         self.phase_results.insert(result.phase.clone(), result);
     }
 
+    /// Forget the result of a phase that is about to run again.
+    pub fn remove_result(&mut self, phase: &str) -> Option<PhaseResult> {
+        let result = self.phase_results.remove(phase)?;
+        if result.is_success() {
+            self.completed = self.completed.saturating_sub(1);
+        } else {
+            self.failed = self.failed.saturating_sub(1);
+        }
+        Some(result)
+    }
+
     /// Mark a phase as skipped.
     pub fn mark_skipped(&mut self, phase: &str) {
         self.skipped += 1;
@@ -299,6 +372,19 @@ mod tests {
         assert_eq!(result.error(), Some("Budget exhausted"));
     }
 
+    #[test]
+    fn test_phase_result_failure_class() {
+        let result = PhaseResult::failure("01", "Budget exhausted", 10, Duration::ZERO)
+            .with_failure_class(FailureClass::BudgetExhausted);
+        assert_eq!(result.failure_class, Some(FailureClass::BudgetExhausted));
+
+        let mut result =
+            PhaseResult::success("02", 3, FileChangeSummary::default(), Duration::ZERO);
+        assert!(result.failure_class.is_none());
+        result.mark_review_gate_failure();
+        assert_eq!(result.failure_class, Some(FailureClass::ReviewBlocked));
+    }
+
     #[test]
     fn test_phase_result_decomposition() {
         let result = PhaseResult::success(
@@ -371,5 +457,11 @@ mod tests {
         assert_eq!(summary.skipped, 1);
         assert!(!summary.all_success());
         assert_eq!(summary.completion_percentage(), 50.0);
+
+        // A retried phase no longer counts as failed
+        assert!(summary.remove_result("03").is_some());
+        assert!(summary.remove_result("03").is_none());
+        assert_eq!(summary.failed, 0);
+        assert_eq!(summary.completed, 2);
     }
 }
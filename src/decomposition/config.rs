@@ -49,6 +49,18 @@ pub struct DecompositionConfig {
     /// Budget buffer percentage to reserve for unexpected issues.
     /// The decomposed tasks' total budget should leave this percentage free.
     pub budget_buffer_percent: u32,
+    /// Whether to estimate phase size before execution and decompose
+    /// oversized phases up-front.
+    #[serde(default)]
+    pub preflight_enabled: bool,
+    /// Estimated iterations, as a percentage of the budget, above which a
+    /// phase is decomposed up-front.
+    #[serde(default = "default_preflight_threshold_percent")]
+    pub preflight_threshold_percent: u32,
+}
+
+fn default_preflight_threshold_percent() -> u32 {
+    100
 }
 
 impl Default for DecompositionConfig {
@@ -64,6 +76,8 @@ impl Default for DecompositionConfig {
             max_tasks: 10,
             require_integration_task: false,
             budget_buffer_percent: 10,
+            preflight_enabled: false,
+            preflight_threshold_percent: default_preflight_threshold_percent(),
         }
     }
 }
@@ -110,6 +124,13 @@ impl DecompositionConfig {
         self
     }
 
+    /// Enable or disable pre-flight decomposition with its threshold.
+    pub fn set_preflight(mut self, enabled: bool, threshold_percent: u32) -> Self {
+        self.preflight_enabled = enabled;
+        self.preflight_threshold_percent = threshold_percent;
+        self
+    }
+
     /// Add complexity keywords.
     pub fn add_complexity_keywords(mut self, keywords: Vec<String>) -> Self {
         self.complexity_keywords.extend(keywords);
@@ -183,6 +204,8 @@ mod tests {
         assert_eq!(config.progress_threshold_percent, 30);
         assert!(config.allow_explicit_request);
         assert!(config.detect_complexity_signals);
+        assert!(!config.preflight_enabled);
+        assert_eq!(config.preflight_threshold_percent, 100);
     }
 
     #[test]
@@ -267,12 +290,15 @@ mod tests {
             .set_enabled(true)
             .set_budget_threshold(70)
             .set_progress_threshold(25)
-            .set_task_limits(3, 8);
+            .set_task_limits(3, 8)
+            .set_preflight(true, 150);
 
         assert!(config.enabled);
         assert_eq!(config.budget_threshold_percent, 70);
         assert_eq!(config.progress_threshold_percent, 25);
         assert_eq!(config.min_tasks, 3);
         assert_eq!(config.max_tasks, 8);
+        assert!(config.preflight_enabled);
+        assert_eq!(config.preflight_threshold_percent, 150);
     }
 }
@@ -100,6 +100,11 @@ pub enum TriggerReason {
     ExplicitRequest,
     /// Multiple blockers indicate need for decomposition.
     MultipleBlockers { count: usize },
+    /// Pre-flight size estimate exceeded the budget before the phase started.
+    PreflightEstimate {
+        estimated_iterations: u32,
+        budget: u32,
+    },
     /// No trigger condition met.
     None,
 }
@@ -129,6 +134,13 @@ impl TriggerReason {
                     count
                 )
             }
+            Self::PreflightEstimate {
+                estimated_iterations,
+                budget,
+            } => format!(
+                "Pre-flight estimate of {} iterations exceeds budget {}",
+                estimated_iterations, budget
+            ),
             Self::None => "No decomposition trigger".to_string(),
         }
     }
@@ -424,6 +436,16 @@ mod tests {
         let blockers = TriggerReason::MultipleBlockers { count: 5 };
         assert!(blockers.description().contains("5"));
 
+        let preflight = TriggerReason::PreflightEstimate {
+            estimated_iterations: 14,
+            budget: 8,
+        };
+        assert!(
+            preflight
+                .description()
+                .contains("14 iterations exceeds budget 8")
+        );
+
         let none = TriggerReason::None;
         assert!(none.description().contains("No"));
     }
@@ -0,0 +1,555 @@
+//! Pre-flight size estimation for phases.
+//!
+//! The [`DecompositionDetector`](super::DecompositionDetector) reacts once a
+//! phase has already burned budget. The [`SizeEstimator`] looks at a phase
+//! before it starts instead: it counts the requirements in its description,
+//! measures the spec sections it references and finds the repository files it
+//! is likely to touch, then turns that into a rough iteration estimate.
+//!
+//! When the estimate exceeds the phase budget by more than the configured
+//! threshold, the phase is decomposed up-front: its first iteration asks for a
+//! decomposition plan instead of an implementation.
+
+use crate::decomposition::config::DecompositionConfig;
+use crate::phase::Phase;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Iterations every phase needs regardless of size.
+const BASE_ITERATIONS: u32 = 1;
+/// Lines of referenced spec one iteration is expected to cover.
+const SPEC_LINES_PER_ITERATION: usize = 30;
+/// Files one iteration is expected to touch.
+const FILES_PER_ITERATION: usize = 3;
+/// Extra iterations per complexity keyword in the description.
+const ITERATIONS_PER_COMPLEXITY_SIGNAL: u32 = 2;
+/// Upper bound on the likely files kept in an estimate.
+const MAX_LIKELY_FILES: usize = 50;
+/// Shortest description word matched against file names.
+const MIN_KEYWORD_LEN: usize = 5;
+/// Shortest heading title matched against the phase description.
+const MIN_TITLE_LEN: usize = 6;
+
+/// Estimated size of a phase, derived before it runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeEstimate {
+    /// Requirements listed in the phase description.
+    pub requirements: usize,
+    /// Headings of the spec sections the phase references.
+    #[serde(default)]
+    pub spec_sections: Vec<String>,
+    /// Non-empty lines in the referenced spec sections.
+    pub spec_lines: usize,
+    /// Repository files the phase is likely to touch.
+    #[serde(default)]
+    pub likely_files: Vec<String>,
+    /// Complexity keywords found in the description.
+    #[serde(default)]
+    pub complexity_signals: Vec<String>,
+    /// Estimated iterations needed to finish the phase.
+    pub estimated_iterations: u32,
+}
+
+impl SizeEstimate {
+    /// One-line summary of what the estimate is based on.
+    pub fn summary(&self) -> String {
+        format!(
+            "~{} iterations ({} requirements, {} spec sections / {} lines, {} likely files, {} complexity signals)",
+            self.estimated_iterations,
+            self.requirements,
+            self.spec_sections.len(),
+            self.spec_lines,
+            self.likely_files.len(),
+            self.complexity_signals.len()
+        )
+    }
+}
+
+/// Whether a phase should be decomposed before it starts, and why.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreflightDecision {
+    /// The size estimate the decision is based on.
+    pub estimate: SizeEstimate,
+    /// The phase budget.
+    pub budget: u32,
+    /// Estimate-to-budget percentage above which the phase is decomposed.
+    pub threshold_percent: u32,
+    /// Whether the phase is decomposed up-front.
+    pub decompose: bool,
+}
+
+impl PreflightDecision {
+    /// Estimated iterations as a percentage of the budget.
+    pub fn budget_percent(&self) -> u32 {
+        self.estimate.estimated_iterations.saturating_mul(100) / self.budget.max(1)
+    }
+
+    /// Human-readable description of the decision.
+    pub fn description(&self) -> String {
+        let verdict = if self.decompose {
+            "decomposing up-front"
+        } else {
+            "within threshold"
+        };
+        format!(
+            "Pre-flight estimate {} is {}% of budget {} (threshold {}%): {}",
+            self.estimate.summary(),
+            self.budget_percent(),
+            self.budget,
+            self.threshold_percent,
+            verdict
+        )
+    }
+
+    /// Prompt section asking the first iteration for a decomposition plan.
+    ///
+    /// `task_budget` is the total budget the tasks may use.
+    pub fn planning_prompt(&self, task_budget: u32) -> String {
+        let mut prompt = format!(
+            "## PRE-FLIGHT DECOMPOSITION\n\
+             This phase is estimated at {} iterations against a budget of {}, so it is \
+             split into sub-tasks before implementation starts. Do not implement anything \
+             in this iteration. Break the phase into independent sub-tasks and output the \
+             plan as JSON inside <decomposition></decomposition> tags, with a `tasks` array \
+             (each with `id`, `name`, `description`, `files`, `depends_on` and `budget`) and \
+             an optional `integration_task`. Keep the total budget of all tasks within {} \
+             iterations.\n",
+            self.estimate.estimated_iterations, self.budget, task_budget
+        );
+        if !self.estimate.spec_sections.is_empty() {
+            prompt.push_str(&format!(
+                "Referenced spec sections: {}\n",
+                self.estimate.spec_sections.join(", ")
+            ));
+        }
+        if !self.estimate.likely_files.is_empty() {
+            prompt.push_str(&format!(
+                "Likely files: {}\n",
+                self.estimate.likely_files.join(", ")
+            ));
+        }
+        prompt
+    }
+}
+
+/// Estimates phase size before execution.
+#[derive(Debug, Clone)]
+pub struct SizeEstimator {
+    config: DecompositionConfig,
+}
+
+impl SizeEstimator {
+    /// Create a new estimator with the given configuration.
+    pub fn new(config: DecompositionConfig) -> Self {
+        Self { config }
+    }
+
+    /// Whether pre-flight estimation applies to a phase.
+    ///
+    /// Sub-phases and decomposed tasks are never decomposed again up-front.
+    pub fn applies_to(&self, phase: &Phase) -> bool {
+        self.config.enabled && self.config.preflight_enabled && phase.parent_phase.is_none()
+    }
+
+    /// Estimate a phase's size from its description, the spec and the
+    /// repository's files.
+    pub fn estimate(&self, phase: &Phase, spec: &str, files: &[String]) -> SizeEstimate {
+        let text = format!("{}\n{}", phase.name, phase.reasoning).to_lowercase();
+
+        let requirements = count_requirements(&phase.reasoning);
+        let (spec_sections, spec_lines) = referenced_sections(spec, &text);
+        let likely_files = likely_files(phase, &text, files);
+        let complexity_signals: Vec<String> = self
+            .config
+            .complexity_keywords
+            .iter()
+            .filter(|k| text.contains(&k.to_lowercase()))
+            .cloned()
+            .collect();
+
+        let estimated_iterations = BASE_ITERATIONS
+            + requirements as u32
+            + spec_lines.div_ceil(SPEC_LINES_PER_ITERATION) as u32
+            + likely_files.len().div_ceil(FILES_PER_ITERATION) as u32
+            + complexity_signals.len() as u32 * ITERATIONS_PER_COMPLEXITY_SIGNAL;
+
+        SizeEstimate {
+            requirements,
+            spec_sections,
+            spec_lines,
+            likely_files,
+            complexity_signals,
+            estimated_iterations,
+        }
+    }
+
+    /// Estimate a phase and decide whether to decompose it up-front.
+    pub fn decide(&self, phase: &Phase, spec: &str, files: &[String]) -> PreflightDecision {
+        let estimate = self.estimate(phase, spec, files);
+        let limit = phase.budget as u64 * self.config.preflight_threshold_percent as u64;
+        let decompose =
+            self.applies_to(phase) && estimate.estimated_iterations as u64 * 100 > limit;
+        PreflightDecision {
+            estimate,
+            budget: phase.budget,
+            threshold_percent: self.config.preflight_threshold_percent,
+            decompose,
+        }
+    }
+
+    /// Estimate a phase against the spec file and the files tracked in
+    /// `project_dir`.
+    ///
+    /// A missing spec or repository only makes the estimate smaller.
+    pub fn assess(&self, phase: &Phase, spec_file: &Path, project_dir: &Path) -> PreflightDecision {
+        let spec = std::fs::read_to_string(spec_file).unwrap_or_default();
+        self.decide(phase, &spec, &tracked_files(project_dir))
+    }
+}
+
+/// Count the requirements in a phase description.
+///
+/// List items count one each; without a list, each sentence of three or more
+/// words counts as one.
+fn count_requirements(description: &str) -> usize {
+    let items = description
+        .lines()
+        .map(str::trim_start)
+        .filter(|line| {
+            line.starts_with("- ")
+                || line.starts_with("* ")
+                || line.starts_with("+ ")
+                || line
+                    .split_once(['.', ')'])
+                    .is_some_and(|(n, _)| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+        })
+        .count();
+    if items > 0 {
+        return items;
+    }
+
+    description
+        .split(['.', ';', '\n'])
+        .filter(|sentence| sentence.split_whitespace().count() >= 3)
+        .count()
+}
+
+/// A markdown section of the spec.
+struct SpecSection {
+    /// Heading level (number of `#`).
+    level: usize,
+    /// Section number such as `3.2`, if the heading is numbered.
+    number: Option<String>,
+    /// Heading text without the number.
+    title: String,
+    /// Line range covered by the section, including subsections.
+    lines: std::ops::Range<usize>,
+}
+
+/// Split a markdown spec into sections, ignoring headings in code fences.
+fn spec_sections(spec: &str) -> Vec<SpecSection> {
+    let lines: Vec<&str> = spec.lines().collect();
+    let mut headings = Vec::new();
+    let mut in_fence = false;
+    for (idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        if level == 0 || !trimmed[level..].starts_with(' ') {
+            continue;
+        }
+        let heading = trimmed[level..].trim();
+        let (number, title) = match heading.split_once(' ') {
+            Some((first, rest))
+                if first
+                    .trim_end_matches('.')
+                    .split('.')
+                    .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit())) =>
+            {
+                (
+                    Some(first.trim_end_matches('.').to_string()),
+                    rest.trim().to_string(),
+                )
+            }
+            _ => (None, heading.to_string()),
+        };
+        headings.push((idx, level, number, title));
+    }
+
+    headings
+        .iter()
+        .enumerate()
+        .map(|(i, (start, level, number, title))| {
+            let end = headings[i + 1..]
+                .iter()
+                .find(|(_, next_level, _, _)| next_level <= level)
+                .map(|(idx, _, _, _)| *idx)
+                .unwrap_or(lines.len());
+            SpecSection {
+                level: *level,
+                number: number.clone(),
+                title: title.clone(),
+                lines: start + 1..end,
+            }
+        })
+        .collect()
+}
+
+/// Find the spec sections a phase references and count their non-empty lines.
+///
+/// A section is referenced when the phase mentions its number (`section 3.2`,
+/// `§3.2`) or its title. A lone top-level heading is the document title and
+/// never counts. Lines shared by nested referenced sections count once.
+fn referenced_sections(spec: &str, text: &str) -> (Vec<String>, usize) {
+    static SECTION_REF: OnceLock<Regex> = OnceLock::new();
+    let section_ref = SECTION_REF
+        .get_or_init(|| Regex::new(r"(?:section|§)\s*(\d+(?:\.\d+)*)").expect("valid regex"));
+    let numbers: BTreeSet<&str> = section_ref
+        .captures_iter(text)
+        .filter_map(|c| c.get(1).map(|m| m.as_str()))
+        .collect();
+
+    let lines: Vec<&str> = spec.lines().collect();
+    let mut covered = vec![false; lines.len()];
+    let sections = spec_sections(spec);
+    let top_level = sections.iter().filter(|s| s.level == 1).count();
+    let mut headings = Vec::new();
+    for section in sections {
+        let title = section.title.to_lowercase();
+        let by_number = section
+            .number
+            .as_deref()
+            .is_some_and(|n| numbers.contains(n));
+        let by_title = title.len() >= MIN_TITLE_LEN && text.contains(&title);
+        if !(by_number || by_title) || (section.level == 1 && top_level == 1) {
+            continue;
+        }
+        headings.push(match &section.number {
+            Some(n) => format!("{} {}", n, section.title),
+            None => section.title.clone(),
+        });
+        for idx in section.lines {
+            covered[idx] = true;
+        }
+    }
+
+    let spec_lines = covered
+        .iter()
+        .zip(&lines)
+        .filter(|(covered, line)| **covered && !line.trim().is_empty())
+        .count();
+    (headings, spec_lines)
+}
+
+/// Find the files a phase is likely to touch.
+///
+/// Files matching the phase scope, paths mentioned in the description and
+/// files whose name matches a word of the description all count.
+fn likely_files(phase: &Phase, text: &str, files: &[String]) -> Vec<String> {
+    let scope: Vec<glob::Pattern> = phase
+        .scope
+        .iter()
+        .filter_map(|g| glob::Pattern::new(g).ok())
+        .collect();
+    let words: BTreeSet<&str> = text
+        .split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '/' || c == '.' || c == '-'))
+        .map(|w| w.trim_matches('.'))
+        .filter(|w| w.len() >= MIN_KEYWORD_LEN)
+        .collect();
+
+    let mut matched = BTreeSet::new();
+    for file in files {
+        let lower = file.to_lowercase();
+        let stem = Path::new(&lower)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default();
+        let in_scope = scope.iter().any(|p| p.matches(file));
+        let mentioned = words.iter().any(|w| {
+            (w.contains('/') && lower.ends_with(w)) || (stem.len() >= MIN_KEYWORD_LEN && *w == stem)
+        });
+        if in_scope || mentioned {
+            matched.insert(file.clone());
+        }
+        if matched.len() >= MAX_LIKELY_FILES {
+            break;
+        }
+    }
+    matched.into_iter().collect()
+}
+
+/// Files tracked in the git index of `project_dir`, or none outside a repository.
+fn tracked_files(project_dir: &Path) -> Vec<String> {
+    let Ok(repo) = git2::Repository::open(project_dir) else {
+        return Vec::new();
+    };
+    let Ok(index) = repo.index() else {
+        return Vec::new();
+    };
+    index
+        .iter()
+        .filter_map(|entry| String::from_utf8(entry.path).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = "# Project\n\
+                        \n\
+                        ## 1 Overview\n\
+                        Short intro.\n\
+                        \n\
+                        ## 2 Authentication\n\
+                        Users sign in with OAuth.\n\
+                        Google and GitHub are supported.\n\
+                        \n\
+                        ### 2.1 Sessions\n\
+                        Sessions expire after an hour.\n\
+                        ```\n\
+                        # not a heading\n\
+                        ```\n\
+                        \n\
+                        ## 3 Billing\n\
+                        Stripe integration.\n";
+
+    fn phase(budget: u32, reasoning: &str) -> Phase {
+        Phase::new("05", "OAuth", "OAUTH DONE", budget, reasoning, vec![])
+    }
+
+    fn files() -> Vec<String> {
+        vec![
+            "src/auth/oauth.rs".to_string(),
+            "src/auth/session.rs".to_string(),
+            "src/billing.rs".to_string(),
+            "README.md".to_string(),
+        ]
+    }
+
+    fn preflight() -> DecompositionConfig {
+        DecompositionConfig::default().set_preflight(true, 100)
+    }
+
+    #[test]
+    fn test_count_requirements() {
+        assert_eq!(
+            count_requirements("- Google login\n- GitHub login\n1. Tests"),
+            3
+        );
+        assert_eq!(
+            count_requirements("Add Google login. Add GitHub login; write the tests"),
+            3
+        );
+        assert_eq!(count_requirements("OAuth"), 0);
+    }
+
+    #[test]
+    fn test_referenced_sections_by_number_and_title() {
+        let (sections, lines) =
+            referenced_sections(SPEC, "implement section 2 and the payment flow");
+
+        assert_eq!(sections, vec!["2 Authentication".to_string()]);
+        // Section 2 includes its subsection and the fenced block, but not 3
+        assert_eq!(lines, 7);
+
+        let (sections, _) = referenced_sections(SPEC, "§2.1 and sessions");
+        assert_eq!(sections, vec!["2.1 Sessions".to_string()]);
+    }
+
+    #[test]
+    fn test_likely_files_from_scope_paths_and_names() {
+        let mut p = phase(10, "Update the oauth flow and src/billing.rs");
+        p.scope = vec!["src/auth/session*".to_string()];
+        let text = format!("{}\n{}", p.name, p.reasoning).to_lowercase();
+
+        assert_eq!(
+            likely_files(&p, &text, &files()),
+            vec![
+                "src/auth/oauth.rs".to_string(),
+                "src/auth/session.rs".to_string(),
+                "src/billing.rs".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_estimate_combines_signals() {
+        let estimator = SizeEstimator::new(preflight());
+        let estimate = estimator.estimate(
+            &phase(
+                10,
+                "- Implement section 2\n- Support multiple oauth providers",
+            ),
+            SPEC,
+            &files(),
+        );
+
+        assert_eq!(estimate.requirements, 2);
+        assert_eq!(estimate.spec_sections, vec!["2 Authentication".to_string()]);
+        assert_eq!(estimate.likely_files, vec!["src/auth/oauth.rs".to_string()]);
+        assert_eq!(estimate.complexity_signals, vec!["multiple".to_string()]);
+        // 1 base + 2 requirements + 1 spec + 1 file + 2 complexity
+        assert_eq!(estimate.estimated_iterations, 7);
+    }
+
+    #[test]
+    fn test_decide_against_threshold() {
+        let estimator = SizeEstimator::new(preflight());
+        let reasoning = "- Implement section 2\n- Support multiple oauth providers";
+
+        let small_budget = estimator.decide(&phase(5, reasoning), SPEC, &files());
+        assert!(small_budget.decompose);
+        assert_eq!(small_budget.budget_percent(), 140);
+        assert!(small_budget.description().contains("decomposing up-front"));
+
+        let large_budget = estimator.decide(&phase(10, reasoning), SPEC, &files());
+        assert!(!large_budget.decompose);
+        assert!(large_budget.description().contains("within threshold"));
+    }
+
+    #[test]
+    fn test_decide_skips_when_disabled_or_sub_phase() {
+        let reasoning = "- Implement section 2\n- Support multiple oauth providers";
+
+        let disabled = SizeEstimator::new(DecompositionConfig::default());
+        assert!(
+            !disabled
+                .decide(&phase(2, reasoning), SPEC, &files())
+                .decompose
+        );
+
+        let mut sub_phase = phase(2, reasoning);
+        sub_phase.parent_phase = Some("04".to_string());
+        let estimator = SizeEstimator::new(preflight());
+        assert!(!estimator.decide(&sub_phase, SPEC, &files()).decompose);
+    }
+
+    #[test]
+    fn test_planning_prompt() {
+        let estimator = SizeEstimator::new(preflight());
+        let decision = estimator.decide(
+            &phase(
+                5,
+                "- Implement section 2\n- Support multiple oauth providers",
+            ),
+            SPEC,
+            &files(),
+        );
+        let prompt = decision.planning_prompt(4);
+
+        assert!(prompt.contains("<decomposition>"));
+        assert!(prompt.contains("within 4 iterations"));
+        assert!(prompt.contains("2 Authentication"));
+        assert!(prompt.contains("src/auth/oauth.rs"));
+    }
+}
@@ -13,7 +13,7 @@ use crate::decomposition::types::{
 };
 use crate::phase::{Phase, SubPhase, SubPhaseStatus};
 use anyhow::{Context, Result};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 /// Executor for decomposed phases.
 ///
@@ -89,13 +89,15 @@ impl DecompositionExecutor {
 
     /// Create SubPhase objects from a DecomposedPhase.
     ///
-    /// Returns SubPhase objects ready to be added to the parent Phase.
+    /// Returns SubPhase objects ready to be added to the parent Phase,
+    /// numbered after any sub-phases the parent already has.
     pub fn create_subphases(&self, decomposed: &DecomposedPhase, parent: &Phase) -> Vec<SubPhase> {
         let mut subphases = Vec::new();
         let all_tasks = decomposed.decomposition.all_tasks();
+        let offset = parent.sub_phases.len();
 
         for (idx, task) in all_tasks.iter().enumerate() {
-            let order = (idx + 1) as u32;
+            let order = (offset + idx + 1) as u32;
             let promise = format!("{} SUBTASK {} COMPLETE", parent.promise, order);
 
             let mut subphase = SubPhase::new(
@@ -116,6 +118,59 @@ impl DecompositionExecutor {
         subphases
     }
 
+    /// Create Phase objects for the tasks of a DecomposedPhase, so they can
+    /// be scheduled as phases of their own.
+    ///
+    /// Task dependencies become dependencies on the matching phases; tasks
+    /// without any depend on the parent, and an integration task without
+    /// any waits for all other tasks. Tasks that list files get them as
+    /// their scope, the others inherit the parent's.
+    pub fn create_phases(&self, decomposed: &DecomposedPhase, parent: &Phase) -> Vec<Phase> {
+        let tasks = decomposed.decomposition.all_tasks();
+        let subphases = self.create_subphases(decomposed, parent);
+        let numbers: HashMap<&str, &str> = tasks
+            .iter()
+            .zip(&subphases)
+            .map(|(task, sub)| (task.id.as_str(), sub.number.as_str()))
+            .collect();
+        let integration_id = decomposed
+            .decomposition
+            .integration_task
+            .as_ref()
+            .map(|t| t.id.as_str());
+
+        tasks
+            .iter()
+            .zip(&subphases)
+            .map(|(task, sub)| {
+                let mut phase = sub.to_phase(parent);
+                let depends_on: Vec<String> =
+                    if task.depends_on.is_empty() && integration_id == Some(task.id.as_str()) {
+                        decomposed
+                            .decomposition
+                            .tasks
+                            .iter()
+                            .filter_map(|t| numbers.get(t.id.as_str()))
+                            .map(|n| n.to_string())
+                            .collect()
+                    } else {
+                        task.depends_on
+                            .iter()
+                            .filter_map(|dep| numbers.get(dep.as_str()))
+                            .map(|n| n.to_string())
+                            .collect()
+                    };
+                if !depends_on.is_empty() {
+                    phase.depends_on = depends_on;
+                }
+                if !task.files.is_empty() {
+                    phase.scope = task.files.clone();
+                }
+                phase
+            })
+            .collect()
+    }
+
     /// Apply SubPhases to a Phase, modifying it in place.
     ///
     /// This adds all SubPhases to the parent phase and sets up the
@@ -390,6 +445,7 @@ impl ExecutionSummary {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::decomposition::types::IntegrationTask;
 
     fn test_phase() -> Phase {
         Phase::new(
@@ -479,6 +535,44 @@ mod tests {
         assert_eq!(subphases[2].number, "05.3");
     }
 
+    #[test]
+    fn test_create_phases_maps_task_dependencies() {
+        let executor = test_executor();
+        let mut phase = test_phase();
+        phase.scope = vec!["src/auth/**".to_string()];
+        let mut decomposition = test_decomposition();
+        decomposition.tasks[0].files = vec!["src/auth/google.rs".to_string()];
+        let decomposition = decomposition.with_integration(IntegrationTask::new(
+            "final",
+            "Final check",
+            "Check everything",
+            2,
+        ));
+
+        let decomposed = executor
+            .convert_to_subphases(&phase, decomposition, 0, "Complex")
+            .unwrap();
+        let phases = executor.create_phases(&decomposed, &phase);
+
+        assert_eq!(phases.len(), 4);
+        assert_eq!(phases[0].number, "05.1");
+        assert_eq!(phases[0].depends_on, vec!["05"]);
+        assert_eq!(phases[0].scope, vec!["src/auth/google.rs"]);
+        assert_eq!(phases[1].scope, vec!["src/auth/**"]);
+        assert_eq!(phases[2].depends_on, vec!["05.1", "05.2"]);
+        // The integration task waits for every other task
+        assert_eq!(phases[3].depends_on, vec!["05.1", "05.2", "05.3"]);
+        assert_eq!(phases[3].parent_phase.as_deref(), Some("05"));
+
+        // Numbering continues after sub-phases the parent already spawned
+        phase
+            .sub_phases
+            .push(SubPhase::new("05", 1, "Spawned", "SPAWNED", 2, ""));
+        let phases = executor.create_phases(&decomposed, &phase);
+        assert_eq!(phases[0].number, "05.2");
+        assert_eq!(phases[2].depends_on, vec!["05.2", "05.3"]);
+    }
+
     // =========================================
     // get_ready_tasks tests
     // =========================================
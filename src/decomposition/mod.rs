@@ -5,7 +5,11 @@
 //!
 //! - **Detection**: Identifies when a phase needs decomposition based on budget
 //!   usage and complexity signals from Claude output
+//! - **Estimation**: Sizes a phase before it starts so oversized phases can be
+//!   decomposed up-front
 //! - **Parsing**: Extracts decomposition requests from Claude's XML output
+//! - **Review**: Lets an operator, or an autonomy policy, approve, edit,
+//!   re-request or reject a plan before it runs
 //! - **Conversion**: Transforms decomposed tasks into SubPhase objects
 //! - **Execution**: Coordinates sub-task execution with progress tracking
 //!
@@ -15,6 +19,8 @@
 //! 1. Worker emits `<blocker>` with complexity signal
 //! 2. Iterations > threshold% of budget with progress < 30%
 //! 3. Worker explicitly requests: `<request-decomposition/>`
+//! 4. Pre-flight estimate exceeds the budget threshold (opt-in), in which case
+//!    the first iteration is asked for a plan
 //!
 //! ## Example
 //!
@@ -36,17 +42,24 @@
 
 mod config;
 mod detector;
+mod estimator;
 mod executor;
 mod parser;
+mod review;
 mod types;
 
 pub use config::{DEFAULT_COMPLEXITY_KEYWORDS, DecompositionConfig, default_complexity_keywords};
 pub use detector::{DecompositionDetector, DecompositionTrigger, ExecutionSignals, TriggerReason};
+pub use estimator::{PreflightDecision, SizeEstimate, SizeEstimator};
 pub use executor::{DecompositionExecutor, ExecutionSummary};
 pub use parser::{
     extract_decomposition_reason, parse_decomposition_output, parse_decomposition_request,
     validate_decomposition,
 };
+pub use review::{
+    PendingPlan, PlanDecision, PlanFormat, PlanPolicy, PlanReviewHandler, TerminalPlanReviewer,
+    replan_prompt,
+};
 pub use types::{
     DecomposedPhase, DecompositionResult, DecompositionTask, IntegrationTask, TaskStatus,
 };
@@ -0,0 +1,582 @@
+//! Review of decomposition plans before they run.
+//!
+//! A parsed [`DecompositionResult`] is wrapped in a [`PendingPlan`] and handed
+//! to a [`PlanReviewHandler`], which returns a [`PlanDecision`]: run the plan,
+//! run an edited plan instead, ask the worker for a new plan, or reject
+//! decomposition and keep working on the phase as a whole.
+//!
+//! Two handlers are provided:
+//!
+//! - [`TerminalPlanReviewer`]: shows the plan and lets the operator edit it
+//!   in `$EDITOR` as JSON or YAML.
+//! - [`PlanPolicy`]: decides without a human for autonomous runs, rejecting
+//!   plans with too many tasks, too large a budget or tasks that touch
+//!   forbidden files.
+
+use std::fmt;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::decomposition::parser::validate_decomposition;
+use crate::decomposition::types::DecompositionResult;
+use crate::phase::Phase;
+
+/// Runtime fields of a task that are not part of an editable plan.
+const RUNTIME_TASK_FIELDS: &[&str] = &["status", "error", "iterations_used"];
+
+/// A decomposition plan waiting for a decision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingPlan {
+    /// Phase number.
+    pub phase: String,
+    /// Phase name.
+    pub phase_name: String,
+    /// Why the phase is being decomposed.
+    pub reason: String,
+    /// Budget the plan's tasks may use.
+    pub available_budget: u32,
+    /// The proposed plan.
+    pub plan: DecompositionResult,
+}
+
+impl PendingPlan {
+    /// Create a pending plan for a phase.
+    pub fn new(
+        phase: &Phase,
+        reason: &str,
+        available_budget: u32,
+        plan: DecompositionResult,
+    ) -> Self {
+        Self {
+            phase: phase.number.clone(),
+            phase_name: phase.name.clone(),
+            reason: reason.to_string(),
+            available_budget,
+            plan,
+        }
+    }
+}
+
+/// What the reviewer decided for a plan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "decision", rename_all = "snake_case")]
+pub enum PlanDecision {
+    /// Run the plan as proposed.
+    Approve,
+    /// Run this edited plan instead.
+    Edit { plan: DecompositionResult },
+    /// Ask the worker for a new plan in the next iteration.
+    Rerequest {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        feedback: Option<String>,
+    },
+    /// Do not decompose; keep working on the phase as a whole.
+    Reject {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
+}
+
+impl PlanDecision {
+    /// The plan to run, if the decision runs one.
+    pub fn into_plan(self, proposed: DecompositionResult) -> Option<DecompositionResult> {
+        match self {
+            Self::Approve => Some(proposed),
+            Self::Edit { plan } => Some(plan),
+            Self::Rerequest { .. } | Self::Reject { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for PlanDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Approve => write!(f, "approved"),
+            Self::Edit { plan } => write!(f, "edited to {} tasks", plan.task_count()),
+            Self::Rerequest { feedback: None } => write!(f, "new plan requested"),
+            Self::Rerequest {
+                feedback: Some(feedback),
+            } => write!(f, "new plan requested: {}", feedback),
+            Self::Reject { reason: None } => write!(f, "rejected"),
+            Self::Reject {
+                reason: Some(reason),
+            } => write!(f, "rejected: {}", reason),
+        }
+    }
+}
+
+/// Decides on a decomposition plan before it runs.
+#[async_trait::async_trait]
+pub trait PlanReviewHandler: Send + Sync {
+    /// Block until the plan has been decided on.
+    async fn review(&self, plan: &PendingPlan) -> Result<PlanDecision>;
+}
+
+/// Prompt section asking the worker for a new plan after a re-request.
+pub fn replan_prompt(feedback: Option<&str>) -> String {
+    let mut prompt = "## DECOMPOSITION PLAN NOT ACCEPTED\n\
+                      The operator asked for a new decomposition plan. Do not implement \
+                      anything in this iteration. Output a revised plan as JSON inside \
+                      <decomposition></decomposition> tags.\n"
+        .to_string();
+    if let Some(feedback) = feedback {
+        prompt.push_str(&format!("Operator feedback: {}\n", feedback));
+    }
+    prompt
+}
+
+/// Autonomous approval policy for decomposition plans.
+///
+/// A plan is approved unless it breaks one of the limits.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlanPolicy {
+    /// Most tasks a plan may have, integration task included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tasks: Option<usize>,
+    /// Largest total budget a plan may use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_budget: Option<u32>,
+    /// Globs of files no task may touch.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forbidden_files: Vec<String>,
+}
+
+impl PlanPolicy {
+    /// Check that every forbidden file glob parses.
+    pub fn validate(&self) -> Result<()> {
+        for pattern in &self.forbidden_files {
+            glob::Pattern::new(pattern)
+                .with_context(|| format!("invalid forbidden_files glob `{pattern}`"))?;
+        }
+        Ok(())
+    }
+
+    /// The limits a plan breaks.
+    ///
+    /// A forbidden file glob that does not parse rejects every plan, rather
+    /// than letting tasks touch the files it was meant to protect.
+    pub fn violations(&self, plan: &DecompositionResult) -> Vec<String> {
+        let mut violations = Vec::new();
+        if let Some(max) = self.max_tasks
+            && plan.task_count() > max
+        {
+            violations.push(format!(
+                "{} tasks exceed the limit of {}",
+                plan.task_count(),
+                max
+            ));
+        }
+        if let Some(max) = self.max_total_budget
+            && plan.total_budget() > max
+        {
+            violations.push(format!(
+                "total budget {} exceeds the limit of {}",
+                plan.total_budget(),
+                max
+            ));
+        }
+
+        let mut forbidden = Vec::new();
+        for pattern in &self.forbidden_files {
+            match glob::Pattern::new(pattern) {
+                Ok(p) => forbidden.push(p),
+                Err(_) => violations.push(format!("forbidden_files glob `{pattern}` is invalid")),
+            }
+        }
+        for task in &plan.tasks {
+            for file in &task.files {
+                let file = file.trim_start_matches("./");
+                if forbidden.iter().any(|p| p.matches(file)) {
+                    violations.push(format!(
+                        "task '{}' touches forbidden file {}",
+                        task.id, file
+                    ));
+                }
+            }
+        }
+        violations
+    }
+
+    /// Approve the plan, or reject it listing the limits it breaks.
+    pub fn decide(&self, plan: &DecompositionResult) -> PlanDecision {
+        let violations = self.violations(plan);
+        if violations.is_empty() {
+            PlanDecision::Approve
+        } else {
+            PlanDecision::Reject {
+                reason: Some(violations.join("; ")),
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl PlanReviewHandler for PlanPolicy {
+    async fn review(&self, plan: &PendingPlan) -> Result<PlanDecision> {
+        Ok(self.decide(&plan.plan))
+    }
+}
+
+/// Format a plan is edited in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanFormat {
+    Json,
+    Yaml,
+}
+
+impl PlanFormat {
+    /// File extension for the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+        }
+    }
+
+    /// Render a plan for editing, without the tasks' runtime fields.
+    pub fn render(&self, plan: &DecompositionResult) -> Result<String> {
+        let mut value = serde_json::to_value(plan)?;
+        if let Some(tasks) = value.get_mut("tasks").and_then(|t| t.as_array_mut()) {
+            for task in tasks.iter_mut().filter_map(|t| t.as_object_mut()) {
+                for field in RUNTIME_TASK_FIELDS {
+                    task.remove(*field);
+                }
+            }
+        }
+        Ok(match self {
+            Self::Json => serde_json::to_string_pretty(&value)?,
+            Self::Yaml => serde_yaml::to_string(&value)?,
+        })
+    }
+
+    /// Parse and validate an edited plan.
+    pub fn parse(&self, content: &str) -> Result<DecompositionResult> {
+        let plan: DecompositionResult = match self {
+            Self::Json => serde_json::from_str(content).context("Invalid plan JSON")?,
+            Self::Yaml => serde_yaml::from_str(content).context("Invalid plan YAML")?,
+        };
+        validate_decomposition(&plan)?;
+        Ok(plan)
+    }
+}
+
+/// Interactive plan review on the terminal.
+///
+/// Reviews are serialized so parallel phases never share the prompt.
+#[derive(Debug, Default)]
+pub struct TerminalPlanReviewer {
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl TerminalPlanReviewer {
+    /// Create a terminal plan reviewer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl PlanReviewHandler for TerminalPlanReviewer {
+    async fn review(&self, plan: &PendingPlan) -> Result<PlanDecision> {
+        let _guard = self.lock.lock().await;
+        let plan = plan.clone();
+        tokio::task::spawn_blocking(move || browse(&plan))
+            .await
+            .context("Plan review prompt panicked")?
+    }
+}
+
+/// Show the plan and ask until the operator decides.
+fn browse(pending: &PendingPlan) -> Result<PlanDecision> {
+    use console::style;
+    use dialoguer::{Input, Select, theme::ColorfulTheme};
+
+    let theme = ColorfulTheme::default();
+    let mut plan = pending.plan.clone();
+    let mut edited = false;
+
+    eprintln!();
+    eprintln!(
+        "{} Phase {} - {}: {}",
+        style("Decomposition plan").bold().yellow(),
+        pending.phase,
+        pending.phase_name,
+        pending.reason
+    );
+
+    loop {
+        print_plan(&plan, pending.available_budget);
+
+        let options = [
+            "Approve",
+            "Edit as YAML",
+            "Edit as JSON",
+            "Request a new plan",
+            "Reject (keep the phase whole)",
+        ];
+        let choice = Select::with_theme(&theme)
+            .with_prompt("Run this plan?")
+            .items(options)
+            .default(0)
+            .interact()?;
+
+        match choice {
+            0 if edited => return Ok(PlanDecision::Edit { plan }),
+            0 => return Ok(PlanDecision::Approve),
+            1 | 2 => {
+                let format = if choice == 1 {
+                    PlanFormat::Yaml
+                } else {
+                    PlanFormat::Json
+                };
+                match edit_in_editor(&plan, format) {
+                    Ok(updated) => {
+                        edited |= updated != plan;
+                        plan = updated;
+                    }
+                    Err(e) => eprintln!("  {} {:#}", style("Edit discarded:").red(), e),
+                }
+            }
+            _ => {
+                let note: String = Input::with_theme(&theme)
+                    .with_prompt(if choice == 3 { "Feedback" } else { "Reason" })
+                    .allow_empty(true)
+                    .interact_text()?;
+                let note = Some(note).filter(|note| !note.trim().is_empty());
+                return Ok(if choice == 3 {
+                    PlanDecision::Rerequest { feedback: note }
+                } else {
+                    PlanDecision::Reject { reason: note }
+                });
+            }
+        }
+    }
+}
+
+/// Print the tasks of a plan.
+fn print_plan(plan: &DecompositionResult, available_budget: u32) {
+    use console::style;
+
+    eprintln!();
+    if let Some(analysis) = &plan.analysis {
+        eprintln!("  {}", style(analysis).dim());
+    }
+    for task in &plan.tasks {
+        eprintln!(
+            "  {} {} (budget {})",
+            style(&task.id).bold(),
+            task.name,
+            task.budget
+        );
+        if !task.depends_on.is_empty() {
+            eprintln!("      depends on: {}", task.depends_on.join(", "));
+        }
+        if !task.files.is_empty() {
+            eprintln!("      files: {}", task.files.join(", "));
+        }
+    }
+    if let Some(integration) = &plan.integration_task {
+        eprintln!(
+            "  {} {} (budget {}, integration)",
+            style(&integration.id).bold(),
+            integration.name,
+            integration.budget
+        );
+        if !integration.depends_on.is_empty() {
+            eprintln!("      depends on: {}", integration.depends_on.join(", "));
+        }
+    }
+    eprintln!(
+        "  {} tasks, total budget {} of {} available",
+        plan.task_count(),
+        plan.total_budget(),
+        available_budget
+    );
+    eprintln!();
+}
+
+/// Open the plan in `$VISUAL` or `$EDITOR` and read back the edited plan.
+fn edit_in_editor(plan: &DecompositionResult, format: PlanFormat) -> Result<DecompositionResult> {
+    let path = std::env::temp_dir().join(format!(
+        "forge-plan-{}.{}",
+        &Uuid::new_v4().simple().to_string()[..8],
+        format.extension()
+    ));
+    std::fs::write(&path, format.render(plan)?)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let result = run_editor(&path).and_then(|()| {
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        format.parse(&content)
+    });
+    let _ = std::fs::remove_file(&path);
+    result
+}
+
+/// Run the configured editor on a file and wait for it to exit.
+fn run_editor(path: &Path) -> Result<()> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+        .with_context(|| format!("Failed to start editor '{}'", editor))?;
+    if !status.success() {
+        bail!("Editor '{}' exited with {}", editor, status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decomposition::types::{DecompositionTask, IntegrationTask};
+
+    fn plan() -> DecompositionResult {
+        DecompositionResult::new(vec![
+            DecompositionTask::new("google", "Google", "Google OAuth", 4)
+                .with_files(vec!["src/auth/google.rs".to_string()]),
+            DecompositionTask::new("github", "GitHub", "GitHub OAuth", 4)
+                .with_files(vec!["./migrations/001.sql".to_string()]),
+        ])
+        .with_integration(
+            IntegrationTask::new("wire", "Wire up", "Register providers", 2)
+                .with_depends_on(vec!["google".to_string(), "github".to_string()]),
+        )
+    }
+
+    #[test]
+    fn test_policy_approves_plan_within_limits() {
+        let policy = PlanPolicy {
+            max_tasks: Some(3),
+            max_total_budget: Some(10),
+            forbidden_files: vec!["Cargo.lock".to_string()],
+        };
+
+        assert!(policy.violations(&plan()).is_empty());
+        assert_eq!(policy.decide(&plan()), PlanDecision::Approve);
+        assert_eq!(PlanPolicy::default().decide(&plan()), PlanDecision::Approve);
+    }
+
+    #[test]
+    fn test_policy_rejects_plan_breaking_limits() {
+        let policy = PlanPolicy {
+            max_tasks: Some(2),
+            max_total_budget: Some(8),
+            forbidden_files: vec!["migrations/**".to_string()],
+        };
+
+        let violations = policy.violations(&plan());
+        assert_eq!(
+            violations,
+            vec![
+                "3 tasks exceed the limit of 2".to_string(),
+                "total budget 10 exceeds the limit of 8".to_string(),
+                "task 'github' touches forbidden file migrations/001.sql".to_string(),
+            ]
+        );
+        match policy.decide(&plan()) {
+            PlanDecision::Reject {
+                reason: Some(reason),
+            } => {
+                assert!(reason.contains("forbidden file"))
+            }
+            other => panic!("expected rejection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_policy_with_invalid_glob() {
+        let policy = PlanPolicy {
+            forbidden_files: vec!["migrations/[".to_string()],
+            ..Default::default()
+        };
+
+        assert!(policy.validate().is_err());
+        assert!(PlanPolicy::default().validate().is_ok());
+        assert_eq!(
+            policy.violations(&plan()),
+            vec!["forbidden_files glob `migrations/[` is invalid".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_decision_into_plan() {
+        let edited = DecompositionResult::new(vec![DecompositionTask::new("a", "A", "A", 1)]);
+
+        assert_eq!(PlanDecision::Approve.into_plan(plan()), Some(plan()));
+        assert_eq!(
+            PlanDecision::Edit {
+                plan: edited.clone()
+            }
+            .into_plan(plan()),
+            Some(edited)
+        );
+        assert_eq!(
+            PlanDecision::Rerequest { feedback: None }.into_plan(plan()),
+            None
+        );
+        assert_eq!(
+            PlanDecision::Reject { reason: None }.into_plan(plan()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_decision_serialization() {
+        let json = serde_json::to_string(&PlanDecision::Rerequest {
+            feedback: Some("Split by provider".to_string()),
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            r#"{"decision":"rerequest","feedback":"Split by provider"}"#
+        );
+
+        let decision: PlanDecision = serde_json::from_str(r#"{"decision":"approve"}"#).unwrap();
+        assert_eq!(decision, PlanDecision::Approve);
+        assert_eq!(
+            PlanDecision::Reject {
+                reason: Some("too big".to_string())
+            }
+            .to_string(),
+            "rejected: too big"
+        );
+    }
+
+    #[test]
+    fn test_plan_format_roundtrip() {
+        for format in [PlanFormat::Json, PlanFormat::Yaml] {
+            let rendered = format.render(&plan()).unwrap();
+            assert!(!rendered.contains("iterations_used"));
+            assert_eq!(format.parse(&rendered).unwrap(), plan());
+        }
+    }
+
+    #[test]
+    fn test_plan_format_rejects_invalid_edit() {
+        let rendered = PlanFormat::Yaml
+            .render(&plan())
+            .unwrap()
+            .replace("- google", "- missing");
+
+        let err = PlanFormat::Yaml.parse(&rendered).unwrap_err();
+        assert!(err.to_string().contains("missing"));
+    }
+
+    #[test]
+    fn test_replan_prompt() {
+        let prompt = replan_prompt(Some("One task per provider"));
+        assert!(prompt.contains("<decomposition>"));
+        assert!(prompt.contains("Operator feedback: One task per provider"));
+        assert!(!replan_prompt(None).contains("Operator feedback"));
+    }
+}
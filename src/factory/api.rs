@@ -227,6 +227,11 @@ pub fn api_router() -> Router<SharedState> {
         .route("/api/runs/{id}/cancel", post(cancel_pipeline_run))
         .route("/api/runs/{id}/team", get(get_run_team))
         .route("/api/runs/{id}/phases", get(get_run_phases))
+        .route("/api/reviews/pending", get(list_pending_reviews))
+        .route(
+            "/api/reviews/{review_id}/decision",
+            post(decide_pending_review),
+        )
         .route("/api/tasks/{id}/events", get(get_task_events))
         .route("/api/github/status", get(github_status))
         .route("/api/github/device-code", post(github_device_code))
@@ -1176,6 +1181,79 @@ async fn get_run_phases(
     Ok(Json(phases))
 }
 
+#[derive(Deserialize)]
+pub struct PendingReviewsQuery {
+    pub run_id: Option<i64>,
+}
+
+/// `GET /api/reviews/pending` — list manual reviews waiting for a decision.
+///
+/// Supports optional `?run_id=N` to restrict the list to one run.
+///
+/// **Response:** `200 OK` with a JSON array of `PendingReviewRecord` objects.
+async fn list_pending_reviews(
+    State(state): State<SharedState>,
+    Query(query): Query<PendingReviewsQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let reviews = state
+        .db
+        .list_pending_reviews(query.run_id.map(RunId))
+        .await
+        .map_err(|e| ApiError::Internal(e.to_string()))?;
+    Ok(Json(reviews))
+}
+
+/// `POST /api/reviews/:review_id/decision` — decide on a pending manual review.
+///
+/// The body is a `ManualResolution` (per-finding accept/fix/dismiss, optional
+/// notes, or `fail_phase`). The decision is handed to the waiting swarm
+/// through the project's `.forge/pending-reviews/` directory.
+///
+/// **Response:** `200 OK` with the updated `PendingReviewRecord`.
+///
+/// **Errors:**
+/// - `404 Not Found` if no such review exists
+/// - `400 Bad Request` if the review was already decided or names unknown findings
+async fn decide_pending_review(
+    State(state): State<SharedState>,
+    Path(review_id): Path<String>,
+    Json(resolution): Json<crate::review::ManualResolution>,
+) -> Result<impl IntoResponse, ApiError> {
+    let record = state
+        .db
+        .get_pending_review(&review_id)
+        .await
+        .map_err(|e| ApiError::Internal(e.to_string()))?
+        .ok_or_else(|| ApiError::NotFound(format!("Review {} not found", review_id)))?;
+    if record.resolution.is_some() {
+        return Err(ApiError::BadRequest(format!(
+            "Review {} was already decided",
+            review_id
+        )));
+    }
+
+    let forge_dir = crate::init::get_forge_dir(std::path::Path::new(&record.project_path));
+    crate::review::FileReviewQueue::new(&forge_dir)
+        .decide(&review_id, &resolution)
+        .map_err(|e| ApiError::BadRequest(format!("{:#}", e)))?;
+
+    let record = state
+        .db
+        .resolve_pending_review(&review_id, &resolution)
+        .await
+        .map_err(|e| ApiError::Internal(e.to_string()))?;
+    broadcast_message(
+        &state.ws_tx,
+        &WsMessage::PipelineReviewDecided {
+            run_id: record.run_id,
+            phase_number: record.review.phase.clone(),
+            review_id,
+            resolution,
+        },
+    );
+    Ok(Json(record))
+}
+
 /// `GET /api/tasks/:id/events` — list events emitted by an agent task.
 ///
 /// Supports optional `?limit=N` query parameter (max 500, default 100).
@@ -2701,6 +2779,39 @@ mod tests {
         assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
+    #[tokio::test]
+    async fn test_list_pending_reviews_empty() {
+        let app = test_app().await;
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/api/reviews/pending?run_id=1")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let reviews: Vec<serde_json::Value> = body_json(response.into_body()).await;
+        assert!(reviews.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_decide_pending_review_not_found() {
+        let app = test_app().await;
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/api/reviews/missing/decision")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::json!({"findings": [{"id": "F1", "action": "dismiss"}]}).to_string(),
+            ))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn test_get_task_events_not_found() {
         let app = test_app().await;
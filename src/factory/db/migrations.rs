@@ -14,6 +14,7 @@ const MIGRATIONS: &[(i64, &str)] = &[
     ),
     (7, include_str!("migrations/007_metrics.sql")),
     (8, include_str!("migrations/008_pipeline_stalled.sql")),
+    (9, include_str!("migrations/009_pending_reviews.sql")),
 ];
 
 /// Run all pending migrations.
@@ -168,7 +169,7 @@ mod tests {
         let (_db, conn) = test_db().await;
         run_migrations(&conn).await.unwrap();
         let version = get_schema_version(&conn).await.unwrap();
-        assert_eq!(version, 8);
+        assert_eq!(version, 9);
     }
 
     #[tokio::test]
@@ -178,7 +179,7 @@ mod tests {
         // Running again should be a no-op
         run_migrations(&conn).await.unwrap();
         let version = get_schema_version(&conn).await.unwrap();
-        assert_eq!(version, 8);
+        assert_eq!(version, 9);
     }
 
     #[tokio::test]
@@ -194,7 +195,7 @@ mod tests {
         // Now run_migrations should pick up from 3
         run_migrations(&conn).await.unwrap();
         let version = get_schema_version(&conn).await.unwrap();
-        assert_eq!(version, 8);
+        assert_eq!(version, 9);
     }
 
     #[tokio::test]
@@ -278,7 +279,7 @@ mod tests {
 
         // Verify final version
         let version = get_schema_version(&conn).await.unwrap();
-        assert_eq!(version, 8);
+        assert_eq!(version, 9);
 
         // Verify tables from migrations 3-6 exist and are usable
         // First, set up FK prerequisites: insert a project, issue, and pipeline_run
@@ -330,5 +331,13 @@ mod tests {
         )
         .await
         .unwrap();
+
+        // Migration 9: pending_reviews table
+        conn.execute(
+            "INSERT INTO pending_reviews (review_id, run_id, phase_number, project_path, review) VALUES ('abc', 1, '01', '/tmp/test', '{}')",
+            (),
+        )
+        .await
+        .unwrap();
     }
 }
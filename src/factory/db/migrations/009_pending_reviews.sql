@@ -0,0 +1,14 @@
+-- Manual review decisions requested by `forge swarm` in manual review mode.
+CREATE TABLE IF NOT EXISTS pending_reviews (
+    id INTEGER PRIMARY KEY,
+    review_id TEXT NOT NULL UNIQUE,
+    run_id INTEGER NOT NULL REFERENCES pipeline_runs(id),
+    phase_number TEXT NOT NULL,
+    project_path TEXT NOT NULL,
+    review TEXT NOT NULL,
+    resolution TEXT,
+    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+    decided_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_pending_reviews_run ON pending_reviews(run_id);
@@ -388,6 +388,41 @@ impl DbHandle {
         pipeline::get_pipeline_phases(conn, run_id).await
     }
 
+    pub async fn insert_pending_review(
+        &self,
+        run_id: super::models::RunId,
+        project_path: &str,
+        review: &crate::review::PendingReview,
+    ) -> Result<()> {
+        let conn = self.conn();
+        pipeline::insert_pending_review(conn, run_id, project_path, review).await
+    }
+
+    pub async fn list_pending_reviews(
+        &self,
+        run_id: Option<super::models::RunId>,
+    ) -> Result<Vec<super::models::PendingReviewRecord>> {
+        let conn = self.conn();
+        pipeline::list_pending_reviews(conn, run_id).await
+    }
+
+    pub async fn get_pending_review(
+        &self,
+        review_id: &str,
+    ) -> Result<Option<super::models::PendingReviewRecord>> {
+        let conn = self.conn();
+        pipeline::get_pending_review(conn, review_id).await
+    }
+
+    pub async fn resolve_pending_review(
+        &self,
+        review_id: &str,
+        resolution: &crate::review::ManualResolution,
+    ) -> Result<super::models::PendingReviewRecord> {
+        let conn = self.conn();
+        pipeline::resolve_pending_review(conn, review_id, resolution).await
+    }
+
     // ── Agent convenience methods ────────────────────────────────────
 
     pub async fn create_agent_team(
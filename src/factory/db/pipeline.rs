@@ -309,6 +309,107 @@ pub async fn get_pipeline_phases(conn: &Connection, run_id: RunId) -> Result<Vec
     Ok(phases)
 }
 
+fn row_to_pending_review(row: &Row) -> Result<PendingReviewRecord> {
+    let review: String = row.get(2)?;
+    let resolution: Option<String> = row.get(3)?;
+    Ok(PendingReviewRecord {
+        run_id: RunId(row.get::<i64>(0)?),
+        project_path: row.get(1)?,
+        review: serde_json::from_str(&review).context("Failed to parse pending review")?,
+        resolution: resolution
+            .map(|resolution| serde_json::from_str(&resolution))
+            .transpose()
+            .context("Failed to parse review resolution")?,
+        decided_at: row.get(4)?,
+    })
+}
+
+const PENDING_REVIEW_COLS: &str = "run_id, project_path, review, resolution, decided_at";
+
+/// Record a manual review requested by a run.
+pub async fn insert_pending_review(
+    conn: &Connection,
+    run_id: RunId,
+    project_path: &str,
+    review: &crate::review::PendingReview,
+) -> Result<()> {
+    let review_json = serde_json::to_string(review)?;
+    conn.execute(
+        "INSERT OR IGNORE INTO pending_reviews (review_id, run_id, phase_number, project_path, review)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        libsql::params![
+            review.id.as_str(),
+            run_id.0,
+            review.phase.as_str(),
+            project_path,
+            review_json
+        ],
+    )
+    .await
+    .context("Failed to insert pending review")?;
+    Ok(())
+}
+
+/// List undecided manual reviews, optionally for one run, oldest first.
+pub async fn list_pending_reviews(
+    conn: &Connection,
+    run_id: Option<RunId>,
+) -> Result<Vec<PendingReviewRecord>> {
+    let sql = format!(
+        "SELECT {} FROM pending_reviews
+         WHERE resolution IS NULL AND (?1 IS NULL OR run_id = ?1)
+         ORDER BY id ASC",
+        PENDING_REVIEW_COLS
+    );
+    let mut rows = conn
+        .query(&sql, libsql::params![run_id.map(|id| id.0)])
+        .await
+        .context("Failed to query pending reviews")?;
+    let mut reviews = Vec::new();
+    while let Some(row) = rows.next().await? {
+        reviews.push(row_to_pending_review(&row)?);
+    }
+    Ok(reviews)
+}
+
+/// Get a manual review by its review id.
+pub async fn get_pending_review(
+    conn: &Connection,
+    review_id: &str,
+) -> Result<Option<PendingReviewRecord>> {
+    let sql = format!(
+        "SELECT {} FROM pending_reviews WHERE review_id = ?1",
+        PENDING_REVIEW_COLS
+    );
+    let mut rows = conn
+        .query(&sql, [review_id])
+        .await
+        .context("Failed to query pending review")?;
+    match rows.next().await? {
+        Some(row) => Ok(Some(row_to_pending_review(&row)?)),
+        None => Ok(None),
+    }
+}
+
+/// Store the human decision for a manual review.
+pub async fn resolve_pending_review(
+    conn: &Connection,
+    review_id: &str,
+    resolution: &crate::review::ManualResolution,
+) -> Result<PendingReviewRecord> {
+    let resolution_json = serde_json::to_string(resolution)?;
+    conn.execute(
+        "UPDATE pending_reviews SET resolution = ?1, decided_at = datetime('now')
+         WHERE review_id = ?2",
+        libsql::params![resolution_json, review_id],
+    )
+    .await
+    .context("Failed to resolve pending review")?;
+    get_pending_review(conn, review_id)
+        .await?
+        .context("Pending review not found after update")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -831,4 +932,46 @@ mod tests {
             "completed_at should be set for Completed status"
         );
     }
+
+    #[tokio::test]
+    async fn test_pending_review_lifecycle() {
+        use crate::review::{
+            FindingAction, FindingSeverity, ManualResolution, PendingReview, ReviewAggregation,
+            ReviewFinding, ReviewReport, ReviewVerdict,
+        };
+
+        let db = DbHandle::new_in_memory().await.unwrap();
+        let conn = db.conn();
+        let project = super::super::projects::create_project(conn, "test", "/tmp/test")
+            .await
+            .unwrap();
+        let issue =
+            super::super::issues::create_issue(conn, project.id, "Test", "", &IssueColumn::Backlog)
+                .await
+                .unwrap();
+        let run = create_pipeline_run(conn, issue.id).await.unwrap();
+        let aggregation = ReviewAggregation::new("01").add_report(
+            ReviewReport::new("01", "security-sentinel", ReviewVerdict::Fail).add_finding(
+                ReviewFinding::new(FindingSeverity::Error, "src/db.rs", "SQL injection"),
+            ),
+        );
+        let review = PendingReview::new(&aggregation, "Schema", 0);
+
+        insert_pending_review(conn, run.id, "/tmp/test", &review)
+            .await
+            .unwrap();
+        let pending = list_pending_reviews(conn, Some(run.id)).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].review, review);
+        assert_eq!(pending[0].project_path, "/tmp/test");
+
+        let resolution =
+            ManualResolution::default().with_finding("F1", FindingAction::Dismiss, None);
+        let record = resolve_pending_review(conn, &review.id, &resolution)
+            .await
+            .unwrap();
+        assert_eq!(record.resolution, Some(resolution));
+        assert!(record.decided_at.is_some());
+        assert!(list_pending_reviews(conn, None).await.unwrap().is_empty());
+    }
 }
@@ -367,6 +367,27 @@ pub struct PipelinePhase {
     pub error: Option<String>,
 }
 
+/// A manual review decision requested by a pipeline run.
+///
+/// Created when `forge swarm` runs in manual review mode and a phase's gating
+/// reviews fail. The swarm waits until a human posts a decision through
+/// `POST /api/reviews/:review_id/decision`, which is written back to the
+/// project's `.forge/pending-reviews/` directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingReviewRecord {
+    /// Foreign key to the owning [`PipelineRun`].
+    pub run_id: RunId,
+    /// Project directory the swarm runs in.
+    #[serde(skip_serializing, default)]
+    pub project_path: String,
+    /// The findings waiting for a decision.
+    pub review: crate::review::PendingReview,
+    /// The human decision, once made.
+    pub resolution: Option<crate::review::ManualResolution>,
+    /// ISO 8601 timestamp of when the decision was made.
+    pub decided_at: Option<String>,
+}
+
 // API view types
 
 /// Full Kanban board view for a project, returned by the `GET /api/projects/:id/board` endpoint.
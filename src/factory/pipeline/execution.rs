@@ -172,7 +172,7 @@ pub(crate) async fn execute_pipeline_streaming(
 
         // Try to parse as DAG executor PhaseEvent first (from forge swarm)
         if let Some(event) = try_parse_phase_event(&line) {
-            process_phase_event(&event, run_id, db, tx).await;
+            process_phase_event(&event, run_id, project_path, db, tx).await;
             continue;
         }
 
@@ -454,7 +454,7 @@ pub(crate) async fn execute_pipeline_docker(
 
             // Parse progress — same logic as local
             if let Some(event) = try_parse_phase_event(&line) {
-                process_phase_event(&event, run_id, db, tx).await;
+                process_phase_event(&event, run_id, project_path, db, tx).await;
                 continue;
             }
             if let Some(progress) = try_parse_progress(&line) {
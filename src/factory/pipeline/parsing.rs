@@ -267,6 +267,15 @@ pub(crate) enum PhaseEventJson {
     ReviewStarted {
         phase: String,
     },
+    ReviewPending {
+        phase: String,
+        review: crate::review::PendingReview,
+    },
+    ReviewResolved {
+        phase: String,
+        review_id: String,
+        resolution: crate::review::ManualResolution,
+    },
     ReviewCompleted {
         phase: String,
         passed: bool,
@@ -315,9 +324,13 @@ pub(crate) fn try_parse_phase_event(line: &str) -> Option<PhaseEventJson> {
 }
 
 /// Process a PhaseEvent and emit corresponding WsMessages + DB updates.
+///
+/// `project_path` is the directory the swarm runs in; pending manual reviews
+/// are decided by writing into its `.forge/pending-reviews/`.
 pub(crate) async fn process_phase_event(
     event: &PhaseEventJson,
     run_id: RunId,
+    project_path: &str,
     db: &DbHandle,
     tx: &broadcast::Sender<String>,
 ) {
@@ -432,6 +445,64 @@ pub(crate) async fn process_phase_event(
             )
             .await;
         }
+        PhaseEventJson::ReviewPending { phase, review } => {
+            if let Err(e) = db.insert_pending_review(run_id, project_path, review).await {
+                broadcast_message(
+                    tx,
+                    &WsMessage::PipelineError {
+                        run_id,
+                        message: format!("Failed to record pending review: {:#}", e),
+                    },
+                );
+            }
+            emit_run_event(
+                db,
+                tx,
+                run_id,
+                &WsMessage::PipelineReviewPending {
+                    run_id,
+                    phase_number: phase.clone(),
+                    review: review.clone(),
+                },
+            )
+            .await;
+        }
+        PhaseEventJson::ReviewResolved {
+            phase,
+            review_id,
+            resolution,
+        } => {
+            // Reviews decided through the Factory API are already recorded;
+            // this covers decisions made in the terminal or with
+            // `forge review resolve`.
+            let recorded = match db.get_pending_review(review_id).await {
+                Ok(record) => record.is_some_and(|r| r.resolution.is_some()),
+                Err(_) => false,
+            };
+            if !recorded {
+                if let Err(e) = db.resolve_pending_review(review_id, resolution).await {
+                    broadcast_message(
+                        tx,
+                        &WsMessage::PipelineError {
+                            run_id,
+                            message: format!("Failed to resolve pending review: {:#}", e),
+                        },
+                    );
+                }
+                emit_run_event(
+                    db,
+                    tx,
+                    run_id,
+                    &WsMessage::PipelineReviewDecided {
+                        run_id,
+                        phase_number: phase.clone(),
+                        review_id: review_id.clone(),
+                        resolution: resolution.clone(),
+                    },
+                )
+                .await;
+            }
+        }
         PhaseEventJson::ReviewCompleted {
             phase,
             passed,
@@ -637,6 +708,88 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_try_parse_phase_event_review_pending() {
+        use crate::review::{
+            FindingSeverity, PendingReview, ReviewAggregation, ReviewFinding, ReviewReport,
+            ReviewVerdict,
+        };
+
+        let aggregation = ReviewAggregation::new("02").add_report(
+            ReviewReport::new("02", "security-sentinel", ReviewVerdict::Fail).add_finding(
+                ReviewFinding::new(FindingSeverity::Error, "src/db.rs", "SQL injection"),
+            ),
+        );
+        let review = PendingReview::new(&aggregation, "Schema", 0);
+        let line = serde_json::to_string(&crate::dag::PhaseEvent::ReviewPending {
+            phase: "02".to_string(),
+            review: Box::new(review.clone()),
+        })
+        .unwrap();
+
+        match try_parse_phase_event(&line).expect("should parse ReviewPending event") {
+            PhaseEventJson::ReviewPending {
+                phase,
+                review: parsed,
+            } => {
+                assert_eq!(phase, "02");
+                assert_eq!(parsed, review);
+            }
+            other => panic!("Expected ReviewPending, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_process_review_resolved_marks_pending_review_decided() {
+        use crate::review::{
+            FindingAction, FindingSeverity, ManualResolution, PendingReview, ReviewAggregation,
+            ReviewFinding, ReviewReport, ReviewVerdict,
+        };
+
+        let db = DbHandle::new_in_memory().await.unwrap();
+        let project = db.create_project("test", "/tmp/test").await.unwrap();
+        let issue = db
+            .create_issue(project.id, "Review", "", &IssueColumn::Backlog)
+            .await
+            .unwrap();
+        let run = db.create_pipeline_run(issue.id).await.unwrap();
+        let (tx, _rx) = broadcast::channel(16);
+
+        let aggregation = ReviewAggregation::new("02").add_report(
+            ReviewReport::new("02", "security-sentinel", ReviewVerdict::Fail).add_finding(
+                ReviewFinding::new(FindingSeverity::Error, "src/db.rs", "SQL injection"),
+            ),
+        );
+        let review = PendingReview::new(&aggregation, "Schema", 0);
+        let resolution =
+            ManualResolution::default().with_finding("F1", FindingAction::Dismiss, None);
+
+        for event in [
+            crate::dag::PhaseEvent::ReviewPending {
+                phase: "02".to_string(),
+                review: Box::new(review.clone()),
+            },
+            crate::dag::PhaseEvent::ReviewResolved {
+                phase: "02".to_string(),
+                review_id: review.id.clone(),
+                resolution: Box::new(resolution.clone()),
+            },
+        ] {
+            let line = serde_json::to_string(&event).unwrap();
+            let event = try_parse_phase_event(&line).expect("should parse phase event");
+            process_phase_event(&event, run.id, "/tmp/test", &db, &tx).await;
+        }
+
+        let record = db.get_pending_review(&review.id).await.unwrap().unwrap();
+        assert_eq!(record.resolution, Some(resolution));
+        assert!(
+            db.list_pending_reviews(Some(run.id))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_parse_text_delta() {
         let line = r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hello world"}}"#;
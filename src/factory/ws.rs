@@ -92,6 +92,17 @@ pub enum WsMessage {
         passed: bool,
         findings_count: usize,
     },
+    PipelineReviewPending {
+        run_id: RunId,
+        phase_number: String,
+        review: crate::review::PendingReview,
+    },
+    PipelineReviewDecided {
+        run_id: RunId,
+        phase_number: String,
+        review_id: String,
+        resolution: crate::review::ManualResolution,
+    },
 
     // Agent team lifecycle
     TeamCreated {
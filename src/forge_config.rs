@@ -19,6 +19,11 @@
 //! permission_mode = "standard"
 //! context_limit = "80%"
 //!
+//! [defaults.retry]
+//! max_retries = 3
+//! backoff_secs = 5
+//! retry_on = ["transient"]
+//!
 //! [phases.overrides."database-*"]
 //! permission_mode = "strict"
 //! budget = 12
@@ -28,6 +33,9 @@
 //! parallel = true
 //! mode = "arbiter"
 //! confidence_threshold = 0.7
+//! arbiter_model = "sonnet"
+//! manual_timeout_secs = 3600
+//! export = ["sarif", "junit"]
 //!
 //! [[reviews.specialists]]
 //! type = "security"
@@ -46,6 +54,13 @@
 //! min_tasks = 2
 //! max_tasks = 10
 //! budget_buffer = 10
+//! preflight = false
+//! preflight_threshold = 100
+//!
+//! [decomposition.policy]
+//! max_tasks = 6
+//! max_total_budget = 40
+//! forbidden_files = ["migrations/**", "Cargo.lock"]
 //! ```
 
 use anyhow::{Context, Result};
@@ -55,6 +70,7 @@ use std::path::{Path, PathBuf};
 use tracing::warn;
 
 use crate::council::config::CouncilConfig;
+use crate::orchestrator::retry::RetryPolicy;
 
 /// Permission modes for phase execution.
 ///
@@ -167,6 +183,9 @@ pub struct DefaultsConfig {
     /// Per-iteration timeout in seconds. None means no timeout.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub iteration_timeout_secs: Option<u64>,
+    /// Retry policy for failed iterations
+    #[serde(default)]
+    pub retry: RetryPolicy,
 }
 
 fn default_budget() -> u32 {
@@ -194,6 +213,7 @@ impl Default for DefaultsConfig {
             context_limit: default_context_limit(),
             skip_permissions: default_skip_permissions(),
             iteration_timeout_secs: None,
+            retry: RetryPolicy::default(),
         }
     }
 }
@@ -219,6 +239,9 @@ pub struct PhaseOverride {
     /// Per-phase iteration timeout in seconds. Overrides defaults.iteration_timeout_secs.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub iteration_timeout_secs: Option<u64>,
+    /// Per-phase retry policy. Replaces defaults.retry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
 }
 
 /// Phase override configuration section.
@@ -273,6 +296,16 @@ pub struct ReviewsSection {
     /// Confidence threshold for arbiter mode.
     #[serde(default = "default_confidence_threshold")]
     pub confidence_threshold: f64,
+    /// Model the LLM arbiter runs with in arbiter mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arbiter_model: Option<String>,
+    /// How long an unattended manual review waits for a decision before the
+    /// arbiter decides instead; 0 waits indefinitely.
+    #[serde(default = "default_manual_timeout_secs")]
+    pub manual_timeout_secs: u64,
+    /// Formats written to `.forge/reviews/` after every review dispatch.
+    #[serde(default)]
+    pub export: Vec<crate::review::ReviewExportFormat>,
 }
 
 fn default_review_parallel() -> bool {
@@ -283,6 +316,10 @@ fn default_confidence_threshold() -> f64 {
     crate::review::arbiter::DEFAULT_CONFIDENCE_THRESHOLD
 }
 
+fn default_manual_timeout_secs() -> u64 {
+    3600
+}
+
 impl Default for ReviewsSection {
     fn default() -> Self {
         Self {
@@ -291,21 +328,73 @@ impl Default for ReviewsSection {
             parallel: true,
             mode: ReviewMode::default(),
             confidence_threshold: default_confidence_threshold(),
+            arbiter_model: None,
+            manual_timeout_secs: default_manual_timeout_secs(),
+            export: Vec::new(),
         }
     }
 }
 
+impl ReviewsSection {
+    /// The arbiter resolution mode with the configured arbiter model and
+    /// confidence threshold.
+    pub fn resolution_mode(&self) -> crate::review::ResolutionMode {
+        let mut mode = self.mode.to_resolution_mode();
+        if mode.has_llm()
+            && let Some(model) = &self.arbiter_model
+        {
+            mode.model = Some(model.clone());
+        }
+        mode.confidence_threshold = self.confidence_threshold;
+        mode
+    }
+
+    /// How long an unattended manual review waits, `None` for indefinitely.
+    pub fn manual_timeout(&self) -> Option<std::time::Duration> {
+        (self.manual_timeout_secs > 0)
+            .then(|| std::time::Duration::from_secs(self.manual_timeout_secs))
+    }
+
+    /// Resolve the configured specialists.
+    ///
+    /// A specialist without an explicit `gate` takes it from its
+    /// `.forge/reviewers/` definition, otherwise it is advisory.
+    pub fn resolve_specialists(
+        &self,
+        registry: &crate::review::ReviewerRegistry,
+    ) -> Vec<crate::phase::PhaseSpecialistConfig> {
+        use std::str::FromStr;
+
+        self.specialists
+            .iter()
+            .map(|specialist| {
+                let specialist_type =
+                    crate::review::SpecialistType::from_str(&specialist.specialist_type)
+                        .unwrap_or_else(|never| match never {});
+                let definition_gate = registry
+                    .get(&specialist_type.agent_name())
+                    .and_then(|definition| definition.gate);
+                crate::phase::PhaseSpecialistConfig {
+                    specialist_type,
+                    gate: specialist.gate.or(definition_gate).unwrap_or(false),
+                    focus_areas: specialist.focus_areas.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
 /// Resolution mode for review failures (config-side).
 ///
-/// All modes map to Auto now. "manual" and "arbiter" are accepted for backward
-/// compatibility but mapped to Auto with appropriate settings.
+/// "manual" pauses for a human decision on gating failures; "arbiter" is
+/// accepted for backward compatibility and mapped to Auto with an LLM model.
 #[derive(Debug, Clone, Default, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ReviewMode {
-    /// Auto-fix mode (deprecated "manual" also maps here).
+    /// Auto-fix mode.
     #[default]
     Auto,
-    /// Backward compat: accepted but treated as Auto.
+    /// Pause for a human decision on gating failures.
     Manual,
     /// Backward compat: accepted but treated as Auto with LLM.
     Arbiter,
@@ -315,14 +404,11 @@ impl ReviewMode {
     /// Convert to ResolutionMode for use with the arbiter.
     pub fn to_resolution_mode(self) -> crate::review::ResolutionMode {
         match self {
-            ReviewMode::Manual => {
-                warn!("'manual' review mode is deprecated, using auto");
-                crate::review::ResolutionMode::default()
-            }
+            ReviewMode::Manual => crate::review::ResolutionMode::manual(2),
             ReviewMode::Auto => crate::review::ResolutionMode::auto(2),
             ReviewMode::Arbiter => crate::review::ResolutionMode::auto_with_llm(
                 2,
-                "claude-3-sonnet",
+                crate::review::arbiter::DEFAULT_ARBITER_MODEL,
                 crate::review::arbiter::DEFAULT_CONFIDENCE_THRESHOLD,
             ),
         }
@@ -336,8 +422,11 @@ pub struct ReviewSpecialistConfig {
     #[serde(rename = "type")]
     pub specialist_type: String,
     /// Whether this review gates phase completion.
+    ///
+    /// When unset, a custom reviewer's `.forge/reviewers/` definition decides,
+    /// otherwise the review is advisory.
     #[serde(default)]
-    pub gate: bool,
+    pub gate: Option<bool>,
     /// Custom focus areas (optional).
     #[serde(default)]
     pub focus_areas: Vec<String>,
@@ -370,6 +459,18 @@ pub struct DecompositionSection {
     /// Budget buffer percentage to reserve for unexpected issues.
     #[serde(default = "default_budget_buffer")]
     pub budget_buffer: u32,
+    /// Whether to estimate phase size before execution and decompose
+    /// oversized phases up-front.
+    #[serde(default)]
+    pub preflight: bool,
+    /// Estimated iterations, as a percentage of the budget, above which a
+    /// phase is decomposed up-front.
+    #[serde(default = "default_preflight_threshold")]
+    pub preflight_threshold: u32,
+    /// Policy that approves or rejects decomposition plans when nobody is
+    /// there to review them.
+    #[serde(default)]
+    pub policy: crate::decomposition::PlanPolicy,
 }
 
 fn default_decomposition_enabled() -> bool {
@@ -404,6 +505,10 @@ fn default_budget_buffer() -> u32 {
     10
 }
 
+fn default_preflight_threshold() -> u32 {
+    100
+}
+
 impl Default for DecompositionSection {
     fn default() -> Self {
         Self {
@@ -415,6 +520,9 @@ impl Default for DecompositionSection {
             min_tasks: default_min_tasks(),
             max_tasks: default_max_tasks(),
             budget_buffer: default_budget_buffer(),
+            preflight: false,
+            preflight_threshold: default_preflight_threshold(),
+            policy: crate::decomposition::PlanPolicy::default(),
         }
     }
 }
@@ -433,6 +541,8 @@ impl DecompositionSection {
             max_tasks: self.max_tasks,
             require_integration_task: false,
             budget_buffer_percent: self.budget_buffer,
+            preflight_enabled: self.preflight,
+            preflight_threshold_percent: self.preflight_threshold,
         }
     }
 }
@@ -718,7 +828,16 @@ impl ForgeToml {
 
     /// Parse configuration from a TOML string.
     pub fn parse(content: &str) -> Result<Self> {
-        toml::from_str(content).context("Failed to parse forge.toml")
+        let config: Self = toml::from_str(content).context("Failed to parse forge.toml")?;
+        if let Some(council) = &config.council {
+            council.validate().context("Invalid [council] section")?;
+        }
+        config
+            .decomposition
+            .policy
+            .validate()
+            .context("Invalid [decomposition.policy] section")?;
+        Ok(config)
     }
 
     /// Load configuration from the default location (.forge/forge.toml).
@@ -770,6 +889,7 @@ impl ForgeToml {
             skills: Vec::new(),
             council: None,
             iteration_timeout_secs: self.defaults.iteration_timeout_secs,
+            retry: self.defaults.retry.clone(),
         };
 
         // Apply matching overrides
@@ -796,6 +916,9 @@ impl ForgeToml {
                 if let Some(timeout) = override_cfg.iteration_timeout_secs {
                     settings.iteration_timeout_secs = Some(timeout);
                 }
+                if let Some(ref retry) = override_cfg.retry {
+                    settings.retry = retry.clone();
+                }
             }
         }
 
@@ -846,6 +969,8 @@ pub struct PhaseSettings {
     pub council: Option<bool>,
     /// Per-iteration timeout in seconds (phase override > defaults > None).
     pub iteration_timeout_secs: Option<u64>,
+    /// Retry policy for failed iterations (phase override > defaults).
+    pub retry: RetryPolicy,
 }
 
 /// Check if a pattern matches a phase name.
@@ -1260,6 +1385,21 @@ sandbox = "workspace-write"
         assert_eq!(codex.sandbox.as_deref(), Some("workspace-write"));
     }
 
+    #[test]
+    fn test_forge_toml_council_rejects_duplicate_worker_names() {
+        let content = r#"
+[council.workers.Claude]
+cmd = "claude"
+
+[council.workers.claude]
+cmd = "claude"
+model = "opus"
+"#;
+
+        let error = ForgeToml::parse(content).unwrap_err();
+        assert!(format!("{error:#}").contains("share a name"));
+    }
+
     #[test]
     fn test_forge_toml_council_defaults_when_partial() {
         let content = r#"
@@ -1598,6 +1738,7 @@ enabled = true
 parallel = true
 mode = "arbiter"
 confidence_threshold = 0.8
+export = ["sarif", "junit"]
 
 [[reviews.specialists]]
 type = "security"
@@ -1614,16 +1755,23 @@ focus_areas = ["N+1 queries", "memory"]
         assert!(toml.reviews.parallel);
         assert_eq!(toml.reviews.mode, ReviewMode::Arbiter);
         assert!((toml.reviews.confidence_threshold - 0.8).abs() < f64::EPSILON);
+        assert_eq!(
+            toml.reviews.export,
+            vec![
+                crate::review::ReviewExportFormat::Sarif,
+                crate::review::ReviewExportFormat::Junit
+            ]
+        );
         assert_eq!(toml.reviews.specialists.len(), 2);
 
         let security = &toml.reviews.specialists[0];
         assert_eq!(security.specialist_type, "security");
-        assert!(security.gate);
+        assert_eq!(security.gate, Some(true));
         assert!(security.focus_areas.is_empty());
 
         let performance = &toml.reviews.specialists[1];
         assert_eq!(performance.specialist_type, "performance");
-        assert!(!performance.gate);
+        assert_eq!(performance.gate, Some(false));
         assert_eq!(performance.focus_areas.len(), 2);
     }
 
@@ -1659,6 +1807,23 @@ focus_areas = ["N+1 queries", "memory"]
         );
     }
 
+    #[test]
+    fn test_review_mode_to_resolution_mode() {
+        assert!(ReviewMode::Manual.to_resolution_mode().is_manual());
+        assert!(ReviewMode::Auto.to_resolution_mode().is_auto());
+        assert!(ReviewMode::Arbiter.to_resolution_mode().has_llm());
+
+        let section = ReviewsSection {
+            mode: ReviewMode::Arbiter,
+            arbiter_model: Some("opus".to_string()),
+            confidence_threshold: 0.9,
+            ..ReviewsSection::default()
+        };
+        let mode = section.resolution_mode();
+        assert_eq!(mode.model(), Some("opus"));
+        assert_eq!(mode.confidence_threshold, 0.9);
+    }
+
     #[test]
     fn test_reviews_section_disabled_by_default() {
         let toml = ForgeToml::default();
@@ -1691,6 +1856,12 @@ enabled = true
         assert_eq!(decomposition.min_tasks, 2);
         assert_eq!(decomposition.max_tasks, 10);
         assert_eq!(decomposition.budget_buffer, 10);
+        assert!(!decomposition.preflight);
+        assert_eq!(decomposition.preflight_threshold, 100);
+        assert_eq!(
+            decomposition.policy,
+            crate::decomposition::PlanPolicy::default()
+        );
     }
 
     #[test]
@@ -1705,6 +1876,13 @@ detect_complexity_signals = false
 min_tasks = 3
 max_tasks = 8
 budget_buffer = 15
+preflight = true
+preflight_threshold = 120
+
+[decomposition.policy]
+max_tasks = 5
+max_total_budget = 30
+forbidden_files = ["migrations/**"]
 "#;
         let toml = ForgeToml::parse(content).unwrap();
 
@@ -1716,6 +1894,25 @@ budget_buffer = 15
         assert_eq!(toml.decomposition.min_tasks, 3);
         assert_eq!(toml.decomposition.max_tasks, 8);
         assert_eq!(toml.decomposition.budget_buffer, 15);
+        assert!(toml.decomposition.preflight);
+        assert_eq!(toml.decomposition.preflight_threshold, 120);
+        assert_eq!(toml.decomposition.policy.max_tasks, Some(5));
+        assert_eq!(toml.decomposition.policy.max_total_budget, Some(30));
+        assert_eq!(
+            toml.decomposition.policy.forbidden_files,
+            vec!["migrations/**"]
+        );
+    }
+
+    #[test]
+    fn test_decomposition_policy_rejects_invalid_glob() {
+        let content = r#"
+[decomposition.policy]
+forbidden_files = ["migrations/["]
+"#;
+
+        let error = ForgeToml::parse(content).unwrap_err();
+        assert!(format!("{error:#}").contains("invalid forbidden_files glob `migrations/[`"));
     }
 
     #[test]
@@ -1745,6 +1942,9 @@ budget_threshold = 70
             min_tasks: 2,
             max_tasks: 10,
             budget_buffer: 10,
+            preflight: true,
+            preflight_threshold: 150,
+            policy: Default::default(),
         };
 
         let config = section.to_decomposition_config();
@@ -1757,6 +1957,8 @@ budget_threshold = 70
         assert_eq!(config.min_tasks, 2);
         assert_eq!(config.max_tasks, 10);
         assert_eq!(config.budget_buffer_percent, 10);
+        assert!(config.preflight_enabled);
+        assert_eq!(config.preflight_threshold_percent, 150);
     }
 
     #[test]
@@ -2025,6 +2227,37 @@ budget = 20
         assert!(settings.iteration_timeout_secs.is_none());
     }
 
+    // =========================================
+    // retry policy config tests
+    // =========================================
+
+    #[test]
+    fn test_retry_policy_defaults_when_no_config() {
+        let settings = ForgeToml::default().phase_settings("any-phase");
+        assert_eq!(settings.retry, RetryPolicy::default());
+    }
+
+    #[test]
+    fn test_retry_policy_phase_override_replaces_defaults() {
+        let content = r#"
+[defaults.retry]
+max_retries = 5
+backoff_secs = 2
+
+[phases.overrides."deploy-*".retry]
+max_retries = 0
+"#;
+        let toml = ForgeToml::parse(content).unwrap();
+
+        let regular = toml.phase_settings("build");
+        assert_eq!(regular.retry.max_retries, 5);
+        assert_eq!(regular.retry.backoff_secs, 2);
+
+        let deploy = toml.phase_settings("deploy-prod");
+        assert_eq!(deploy.retry.max_retries, 0);
+        assert_eq!(deploy.retry.backoff_secs, 5);
+    }
+
     // =========================================
     // Factory section tests
     // =========================================
@@ -1,4 +1,5 @@
 use crate::audit::FileChangeSummary;
+use crate::decomposition::PreflightDecision;
 use crate::forge_config::PermissionMode;
 use crate::phase::{Phase, SubPhase};
 use crate::signals::SubPhaseSpawnSignal;
@@ -106,10 +107,14 @@ impl ApprovalGate {
 
     /// Check whether a phase should proceed (called at phase start).
     /// This is the main entry point for phase-level approval.
+    ///
+    /// A pre-flight estimate, when given, is shown with the phase; a phase
+    /// estimated above the threshold is never auto-approved by file count.
     pub fn check_phase(
         &mut self,
         phase: &Phase,
         previous_changes: Option<&FileChangeSummary>,
+        preflight: Option<&PreflightDecision>,
         ui: &OrchestratorUI,
     ) -> Result<GateDecision> {
         // Display phase header
@@ -120,6 +125,10 @@ impl ApprovalGate {
             ui.print_previous_changes(changes);
         }
 
+        if let Some(decision) = preflight {
+            print_preflight(decision);
+        }
+
         // Show permission mode if not standard
         if phase.permission_mode != PermissionMode::Standard {
             println!(
@@ -163,6 +172,7 @@ impl ApprovalGate {
             PermissionMode::Standard => {
                 // Standard: use threshold-based auto-approval for phase start
                 if let Some(changes) = previous_changes
+                    && !preflight.is_some_and(|d| d.decompose)
                     && changes.total_files() <= self.auto_threshold
                     && changes.total_files() > 0
                 {
@@ -434,6 +444,23 @@ impl ApprovalGate {
     }
 }
 
+/// Print a phase's pre-flight estimate below its header.
+fn print_preflight(decision: &PreflightDecision) {
+    println!(
+        "  {} {}",
+        console::style("Estimate:").dim(),
+        decision.estimate.summary()
+    );
+    if decision.decompose {
+        println!(
+            "  {} {}% of budget exceeds the {}% pre-flight threshold; consider splitting this phase",
+            console::style("⚠").yellow(),
+            decision.budget_percent(),
+            decision.threshold_percent
+        );
+    }
+}
+
 /// Decision result from a sub-phase spawn gate check.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SubPhaseSpawnDecision {
@@ -48,6 +48,10 @@ Guidelines:
 - Early phases (scaffold, config) should have smaller budgets (5-10)
 - Complex phases (auth, integrations) should have larger budgets (15-25)
 - Identify natural dependencies - what must be done before what
+- Optionally add "scope": a list of globs for the files a phase will edit, so
+  independent phases that touch the same files are not run at the same time
+- Optionally add "weight": 2 for unusually heavy phases that should count as two
+  parallel slots
 - Include all major features from the spec
 - Order phases logically with dependencies"#;
 
@@ -400,6 +400,8 @@ Respond ONLY with the JSON object, no other text."#,
                 success = result.success,
                 tasks_completed = result.tasks_completed.len(),
                 tasks_failed = result.tasks_failed.len(),
+                rejected_events = result.rejected_events.total(),
+                source = ?result.source,
                 "Swarm completed"
             );
         }
@@ -421,6 +423,19 @@ Respond ONLY with the JSON object, no other text."#,
                 "duration_secs".to_string(),
                 serde_json::json!(result.duration.as_secs()),
             );
+            metadata.insert(
+                "rejected_events".to_string(),
+                serde_json::json!(result.rejected_events),
+            );
+            metadata.insert(
+                "result_source".to_string(),
+                serde_json::json!(result.source),
+            );
+            metadata.insert("tests".to_string(), serde_json::json!(result.tests));
+            metadata.insert(
+                "reconciliation".to_string(),
+                serde_json::json!(result.reconciliation),
+            );
 
             Ok(HookResult {
                 action: HookAction::Continue,
@@ -446,6 +461,19 @@ Respond ONLY with the JSON object, no other text."#,
                 "tasks_failed".to_string(),
                 serde_json::json!(result.tasks_failed),
             );
+            metadata.insert(
+                "rejected_events".to_string(),
+                serde_json::json!(result.rejected_events),
+            );
+            metadata.insert(
+                "result_source".to_string(),
+                serde_json::json!(result.source),
+            );
+            metadata.insert("tests".to_string(), serde_json::json!(result.tests));
+            metadata.insert(
+                "reconciliation".to_string(),
+                serde_json::json!(result.reconciliation),
+            );
 
             Ok(HookResult {
                 action: HookAction::Block,
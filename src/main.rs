@@ -70,6 +70,12 @@ pub enum Commands {
     },
     List,
     Status,
+    /// Render the phase DAG with progress and its critical path
+    Graph {
+        /// Output format
+        #[arg(long, value_enum, default_value = "dot")]
+        format: forge::dag::GraphFormat,
+    },
     Reset {
         #[arg(long)]
         force: bool,
@@ -99,6 +105,20 @@ pub enum Commands {
         #[command(subcommand)]
         command: Option<SkillsCommands>,
     },
+    /// Inspect custom review specialists defined in .forge/reviewers/
+    Reviewers {
+        #[command(subcommand)]
+        command: Option<ReviewersCommands>,
+    },
+    /// Run review specialists on a diff, or work with recorded review runs
+    #[command(args_conflicts_with_subcommands = true)]
+    Review {
+        #[command(subcommand)]
+        command: Option<ReviewCommands>,
+
+        #[command(flatten)]
+        args: ReviewArgs,
+    },
     /// Manually trigger context compaction for a phase
     Compact {
         /// Phase number to compact (defaults to current running phase)
@@ -173,7 +193,7 @@ pub enum Commands {
         review: Option<String>,
 
         /// Review mode: manual, auto, arbiter
-        #[arg(long, default_value = "manual")]
+        #[arg(long, default_value = "auto")]
         review_mode: String,
 
         /// Maximum auto-fix attempts
@@ -211,6 +231,22 @@ pub enum Commands {
         /// Stop all phases on first failure
         #[arg(long)]
         fail_fast: bool,
+
+        /// Phase isolation: shared, worktree
+        #[arg(long, default_value = "shared")]
+        isolation: String,
+
+        /// Order to start ready phases: critical-path, fifo
+        #[arg(long, default_value = "critical-path")]
+        schedule: String,
+
+        /// Resume the last interrupted swarm from its checkpoint
+        #[arg(long, conflicts_with_all = ["from", "only"])]
+        resume: bool,
+
+        /// Attempts a failed phase gets before --resume stops retrying it
+        #[arg(long, default_value = "3")]
+        max_attempts: u32,
     },
     /// Check for updates and self-update the binary
     Update {
@@ -232,9 +268,27 @@ pub enum Commands {
 #[derive(Subcommand, Clone)]
 pub enum SwarmCommands {
     /// Show current swarm execution status
-    Status,
-    /// Gracefully abort the running swarm
-    Abort,
+    Status {
+        /// Stream phase events until the swarm finishes
+        #[arg(long)]
+        watch: bool,
+    },
+    /// Abort the running swarm after the current iterations
+    Abort {
+        /// Kill running phases immediately instead
+        #[arg(long)]
+        now: bool,
+    },
+    /// Stop starting new phases; running phases continue
+    Pause,
+    /// Resume starting phases after a pause
+    Resume,
+    /// Cancel a running or pending phase
+    Cancel { phase: String },
+    /// Run a failed or cancelled phase again
+    Retry { phase: String },
+    /// Change how many phases may run at once
+    MaxParallel { max_parallel: usize },
 }
 
 #[derive(Subcommand)]
@@ -275,6 +329,123 @@ pub enum ConfigCommands {
     Init,
 }
 
+#[derive(Subcommand, Clone)]
+pub enum ReviewersCommands {
+    /// List all reviewer definitions
+    List,
+    /// Show a reviewer definition
+    Show { name: String },
+}
+
+/// Diff selection and options for `forge review`.
+#[derive(clap::Args, Clone, Debug, Default)]
+pub struct ReviewArgs {
+    /// Git revision range to review (e.g. origin/main..HEAD); defaults to uncommitted changes
+    pub range: Option<String>,
+
+    /// Review only staged changes
+    #[arg(long, conflicts_with_all = ["range", "pr"])]
+    pub staged: bool,
+
+    /// Review a patch file, e.g. a downloaded pull request diff
+    #[arg(long, value_name = "PATCH_FILE", conflicts_with = "range")]
+    pub pr: Option<PathBuf>,
+
+    /// Only run these specialists, keeping their [reviews] gating from forge.toml (comma-separated: security,performance,architecture,simplicity,all)
+    #[arg(long)]
+    pub specialists: Option<String>,
+
+    /// Let the arbiter decide whether gating failures block
+    #[arg(long)]
+    pub arbiter: bool,
+}
+
+#[derive(Subcommand, Clone)]
+pub enum ReviewCommands {
+    /// Export recorded review runs as SARIF, JUnit XML or Markdown
+    Export {
+        /// Output format
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: forge::review::ReviewExportFormat,
+        /// Only export runs for this phase
+        #[arg(long)]
+        phase: Option<String>,
+        /// Only export the most recent run of each phase
+        #[arg(long)]
+        latest: bool,
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Manage accepted findings in .forge/review-baseline.json
+    Baseline {
+        #[command(subcommand)]
+        command: BaselineCommands,
+    },
+    /// List recorded arbiter decisions and human overrides
+    History {
+        /// Only show decisions for this phase
+        #[arg(long)]
+        phase: Option<String>,
+        /// Show at most this many of the most recent decisions
+        #[arg(long, default_value = "20")]
+        limit: usize,
+        /// Only show overridden decisions
+        #[arg(long)]
+        overrides: bool,
+    },
+    /// Override a recorded arbiter decision so future decisions learn from it
+    Override {
+        /// Decision id from `forge review history`
+        id: String,
+        /// The verdict the arbiter should have made
+        #[arg(long, value_enum)]
+        verdict: forge::review::ArbiterVerdict,
+        /// Why the decision was wrong
+        #[arg(long)]
+        reason: Option<String>,
+    },
+    /// List reviews waiting for a manual decision
+    Pending,
+    /// Decide on a pending manual review; unlisted findings are fixed
+    Resolve {
+        /// Review id from `forge review pending`
+        id: String,
+        /// Findings to fix (comma-separated ids, e.g. F1,F3)
+        #[arg(long, value_delimiter = ',')]
+        fix: Vec<String>,
+        /// Findings to accept as they are
+        #[arg(long, value_delimiter = ',')]
+        accept: Vec<String>,
+        /// Findings to dismiss as false positives
+        #[arg(long, value_delimiter = ',')]
+        dismiss: Vec<String>,
+        /// Fail the phase instead
+        #[arg(long, conflicts_with_all = ["fix", "accept", "dismiss"])]
+        fail: bool,
+        /// Note passed on with the decision
+        #[arg(long)]
+        note: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Clone)]
+pub enum BaselineCommands {
+    /// Accept the findings of the latest recorded review run of each phase
+    Update {
+        /// Only accept findings from this phase
+        #[arg(long)]
+        phase: Option<String>,
+        /// Only accept findings from this specialist
+        #[arg(long)]
+        specialist: Option<String>,
+    },
+    /// List accepted findings
+    Show,
+    /// Remove all accepted findings
+    Clear,
+}
+
 #[derive(Subcommand, Clone)]
 pub enum SkillsCommands {
     /// List all available skills
@@ -381,12 +552,18 @@ async fn main() -> Result<()> {
         }
         Commands::List => cmd::cmd_list(&project_dir)?,
         Commands::Status => cmd::cmd_status(&project_dir)?,
+        Commands::Graph { format } => cmd::cmd_graph(&project_dir, *format)?,
         Commands::Reset { force } => cmd::cmd_reset(&project_dir, &cli, *force)?,
         Commands::Audit { command } => cmd::cmd_audit(&project_dir, command)?,
         Commands::Learn { name } => cmd::cmd_learn(&project_dir, name.as_deref())?,
         Commands::Patterns { command } => cmd::cmd_patterns(command.clone())?,
         Commands::Config { command } => cmd::cmd_config(&project_dir, command.clone())?,
         Commands::Skills { command } => cmd::cmd_skills(&project_dir, command.clone())?,
+        Commands::Reviewers { command } => cmd::cmd_reviewers(&project_dir, command.clone())?,
+        Commands::Review { command, args } => match command {
+            Some(command) => cmd::cmd_review(&project_dir, command.clone())?,
+            None => cmd::cmd_review_diff(&cli, &project_dir, args).await?,
+        },
         Commands::Compact { phase, status } => {
             cmd::cmd_compact(&project_dir, &cli, phase.as_deref(), *status)?
         }
@@ -437,21 +614,42 @@ async fn main() -> Result<()> {
             permission_mode,
             ui,
             fail_fast,
+            isolation,
+            schedule,
+            resume,
+            max_attempts,
         } => {
             // Handle subcommands first
             if let Some(subcmd) = command {
-                match subcmd {
-                    SwarmCommands::Status => {
-                        cmd::cmd_swarm_status(&project_dir)?;
+                use forge::dag::{AbortMode, ControlCommand};
+                let command = match subcmd {
+                    SwarmCommands::Status { watch } => {
+                        cmd::cmd_swarm_status(&project_dir, *watch).await?;
                         let _ = update_handle.await;
                         return Ok(());
                     }
-                    SwarmCommands::Abort => {
-                        cmd::cmd_swarm_abort(&project_dir)?;
-                        let _ = update_handle.await;
-                        return Ok(());
-                    }
-                }
+                    SwarmCommands::Abort { now } => ControlCommand::Abort {
+                        mode: if *now {
+                            AbortMode::Immediate
+                        } else {
+                            AbortMode::Graceful
+                        },
+                    },
+                    SwarmCommands::Pause => ControlCommand::Pause,
+                    SwarmCommands::Resume => ControlCommand::Resume,
+                    SwarmCommands::Cancel { phase } => ControlCommand::CancelPhase {
+                        phase: phase.clone(),
+                    },
+                    SwarmCommands::Retry { phase } => ControlCommand::RetryPhase {
+                        phase: phase.clone(),
+                    },
+                    SwarmCommands::MaxParallel { max_parallel } => ControlCommand::SetMaxParallel {
+                        max_parallel: *max_parallel,
+                    },
+                };
+                cmd::cmd_swarm_control(&project_dir, command).await?;
+                let _ = update_handle.await;
+                return Ok(());
             }
 
             // Main swarm execution
@@ -472,6 +670,11 @@ async fn main() -> Result<()> {
                 permission_mode,
                 ui,
                 *fail_fast,
+                isolation,
+                schedule,
+                resume.then_some(forge::dag::ResumePolicy {
+                    max_attempts: *max_attempts,
+                }),
             )
             .await?;
         }
@@ -22,10 +22,12 @@
 //! The audit logger and factory DB are append-only observation layers; they do
 //! not drive control flow.
 
+pub mod retry;
 pub mod review_integration;
 pub mod runner;
 pub mod state;
 
+pub use retry::{FailureClass, RetryPolicy};
 pub use review_integration::{
     DefaultSpecialist, PhaseWithReviewResult, ReviewIntegration, ReviewIntegrationConfig,
 };
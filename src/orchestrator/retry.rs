@@ -0,0 +1,429 @@
+//! Failure taxonomy and retry policies for phase iterations.
+//!
+//! An iteration can fail for reasons that say nothing about the phase itself:
+//! the Claude CLI could not be spawned, the API was overloaded, the iteration
+//! hit its timeout. [`FailureClass`] separates those transient infrastructure
+//! failures from model errors and from the phase-level outcomes (budget
+//! exhausted, blocked by review). A [`RetryPolicy`] decides which classes are
+//! retried in place, with exponential backoff, without spending an iteration
+//! of the phase budget.
+//!
+//! Policies are configured in `forge.toml`:
+//!
+//! ```toml
+//! [defaults.retry]
+//! max_retries = 3
+//! backoff_secs = 5
+//! max_backoff_secs = 120
+//! retry_on = ["transient"]
+//!
+//! [phases.overrides."deploy-*".retry]
+//! max_retries = 0
+//! ```
+
+use std::future::Future;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::errors::OrchestratorError;
+use crate::orchestrator::runner::IterationResult;
+
+/// Phrases in a Claude error result that point at the API rather than the model.
+const TRANSIENT_ERROR_MARKERS: &[&str] = &[
+    "overloaded",
+    "rate limit",
+    "rate_limit",
+    "too many requests",
+    "internal server error",
+    "service unavailable",
+    "bad gateway",
+    "gateway timeout",
+    "connection reset",
+    "connection refused",
+    "econnreset",
+    "etimedout",
+    "network error",
+    "api error: 5",
+    "529",
+];
+
+/// Why a phase or one of its iterations failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureClass {
+    /// Infrastructure failure: spawn error, API outage, timeout, I/O error
+    Transient,
+    /// Claude ran but reported an error for the request
+    ModelError,
+    /// The phase used its whole budget without emitting its promise
+    BudgetExhausted,
+    /// The phase finished but its review gate did not pass
+    ReviewBlocked,
+}
+
+impl FailureClass {
+    /// Classify an error returned instead of an iteration result.
+    ///
+    /// Errors at this level come from running the CLI, not from the model,
+    /// so they are all treated as transient.
+    pub fn of_error(_error: &anyhow::Error) -> Self {
+        FailureClass::Transient
+    }
+
+    /// Classify a finished iteration from its exit code and error text.
+    ///
+    /// Only the result event's error or the CLI's stderr is inspected: the
+    /// transcript may mention rate limits or outages without being one.
+    /// Returns `None` when the iteration ran cleanly, whether or not it
+    /// emitted the promise.
+    pub fn of_iteration(result: &IterationResult) -> Option<Self> {
+        let exit_code = result.session.exit_code;
+        if !result.is_error && exit_code == 0 {
+            return None;
+        }
+        // Killed by a signal, or an API failure reported as the error
+        if exit_code < 0 || result.error.as_deref().is_some_and(is_transient_message) {
+            return Some(FailureClass::Transient);
+        }
+        Some(FailureClass::ModelError)
+    }
+
+    /// Whether a failure of this class counts against the iteration budget.
+    pub fn consumes_budget(&self) -> bool {
+        !matches!(self, FailureClass::Transient)
+    }
+
+    /// Short human-readable name.
+    pub fn label(&self) -> &'static str {
+        match self {
+            FailureClass::Transient => "transient",
+            FailureClass::ModelError => "model error",
+            FailureClass::BudgetExhausted => "budget exhausted",
+            FailureClass::ReviewBlocked => "review blocked",
+        }
+    }
+}
+
+impl std::fmt::Display for FailureClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Whether an error message looks like an API or network failure.
+fn is_transient_message(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    TRANSIENT_ERROR_MARKERS
+        .iter()
+        .any(|marker| lower.contains(marker))
+}
+
+/// How failed iterations of a phase are retried.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Retries per iteration before the failure is reported
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on every further retry
+    #[serde(default = "default_backoff_secs")]
+    pub backoff_secs: u64,
+    /// Upper bound on the delay between retries
+    #[serde(default = "default_max_backoff_secs")]
+    pub max_backoff_secs: u64,
+    /// Failure classes that are retried
+    #[serde(default = "default_retry_on")]
+    pub retry_on: Vec<FailureClass>,
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_backoff_secs() -> u64 {
+    5
+}
+
+fn default_max_backoff_secs() -> u64 {
+    120
+}
+
+fn default_retry_on() -> Vec<FailureClass> {
+    vec![FailureClass::Transient]
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: default_max_retries(),
+            backoff_secs: default_backoff_secs(),
+            max_backoff_secs: default_max_backoff_secs(),
+            retry_on: default_retry_on(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Whether a failure of `class` is retried after `retries` earlier retries.
+    pub fn should_retry(&self, class: FailureClass, retries: u32) -> bool {
+        retries < self.max_retries && self.retry_on.contains(&class)
+    }
+
+    /// Delay before retry number `retry` (starting at 0).
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u64.checked_shl(retry).unwrap_or(u64::MAX);
+        let secs = self
+            .backoff_secs
+            .saturating_mul(factor)
+            .min(self.max_backoff_secs);
+        Duration::from_secs(secs)
+    }
+}
+
+/// Run an iteration, retrying it while the policy allows.
+///
+/// Retries reuse the same iteration number, so they do not spend the phase
+/// budget. The last outcome is returned once it succeeds, fails with a class
+/// the policy does not retry, or runs out of retries.
+pub async fn run_with_retry<F, Fut>(
+    policy: &RetryPolicy,
+    phase: &str,
+    mut run: F,
+) -> Result<IterationResult>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<IterationResult>>,
+{
+    let mut retries = 0;
+    loop {
+        let outcome = run().await;
+        let class = match &outcome {
+            Ok(result) => FailureClass::of_iteration(result),
+            Err(e) => Some(FailureClass::of_error(e)),
+        };
+        let Some(class) = class.filter(|c| policy.should_retry(*c, retries)) else {
+            return outcome;
+        };
+
+        let delay = policy.backoff(retries);
+        retries += 1;
+        let reason = match &outcome {
+            Ok(result) => format!("exit code {}", result.session.exit_code),
+            Err(e) => format!("{:#}", e),
+        };
+        warn!(
+            phase = %phase,
+            class = %class,
+            retry = retries,
+            max_retries = policy.max_retries,
+            delay_secs = delay.as_secs(),
+            "Iteration failed ({}), retrying",
+            reason
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Whether an error is an iteration timeout.
+pub fn is_timeout(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<OrchestratorError>(),
+        Some(OrchestratorError::IterationTimeout { .. })
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::ClaudeSession;
+    use crate::signals::IterationSignals;
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// An iteration whose output is `output`, which is also its error text
+    /// when it failed, as for an error result event.
+    fn iteration(exit_code: i32, is_error: bool, output: &str) -> IterationResult {
+        IterationResult {
+            session: ClaudeSession {
+                prompt_file: PathBuf::new(),
+                prompt_chars: 0,
+                output_file: PathBuf::new(),
+                output_chars: output.len(),
+                exit_code,
+                token_usage: None,
+                session_id: None,
+            },
+            promise_found: false,
+            is_error,
+            output: output.to_string(),
+            signals: IterationSignals::default(),
+            council_data: None,
+            edited_files: Default::default(),
+            error: (is_error || exit_code != 0).then(|| output.to_string()),
+        }
+    }
+
+    fn instant_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            backoff_secs: 0,
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn test_classify_iteration() {
+        assert_eq!(FailureClass::of_iteration(&iteration(0, false, "ok")), None);
+        assert_eq!(
+            FailureClass::of_iteration(&iteration(1, true, "API Error: 529 Overloaded")),
+            Some(FailureClass::Transient)
+        );
+        assert_eq!(
+            FailureClass::of_iteration(&iteration(1, true, "Rate limit reached")),
+            Some(FailureClass::Transient)
+        );
+        assert_eq!(
+            FailureClass::of_iteration(&iteration(-1, false, "")),
+            Some(FailureClass::Transient)
+        );
+        assert_eq!(
+            FailureClass::of_iteration(&iteration(1, true, "Prompt is too long")),
+            Some(FailureClass::ModelError)
+        );
+        assert_eq!(
+            FailureClass::of_iteration(&iteration(0, true, "max turns reached")),
+            Some(FailureClass::ModelError)
+        );
+    }
+
+    #[test]
+    fn test_classify_iteration_ignores_the_transcript() {
+        let mut result = iteration(1, true, "Prompt is too long");
+        result.output = "Added a rate limit to the API client\nPrompt is too long".to_string();
+        assert_eq!(
+            FailureClass::of_iteration(&result),
+            Some(FailureClass::ModelError)
+        );
+
+        let mut result = iteration(1, false, "");
+        result.output = "Retrying after 529 overloaded responses".to_string();
+        result.error = None;
+        assert_eq!(
+            FailureClass::of_iteration(&result),
+            Some(FailureClass::ModelError)
+        );
+    }
+
+    #[test]
+    fn test_classify_errors_as_transient() {
+        let spawn = anyhow::Error::from(OrchestratorError::SpawnFailed(std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "claude not found",
+        )));
+        let timeout = anyhow::Error::from(OrchestratorError::IterationTimeout {
+            phase: "01".into(),
+            timeout_secs: 30,
+        });
+        assert_eq!(FailureClass::of_error(&spawn), FailureClass::Transient);
+        assert_eq!(FailureClass::of_error(&timeout), FailureClass::Transient);
+        assert!(is_timeout(&timeout));
+        assert!(!is_timeout(&spawn));
+    }
+
+    #[test]
+    fn test_only_transient_failures_spare_the_budget() {
+        assert!(!FailureClass::Transient.consumes_budget());
+        assert!(FailureClass::ModelError.consumes_budget());
+        assert!(FailureClass::BudgetExhausted.consumes_budget());
+        assert!(FailureClass::ReviewBlocked.consumes_budget());
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = RetryPolicy::default();
+        assert!(policy.should_retry(FailureClass::Transient, 0));
+        assert!(policy.should_retry(FailureClass::Transient, 2));
+        assert!(!policy.should_retry(FailureClass::Transient, 3));
+        assert!(!policy.should_retry(FailureClass::ModelError, 0));
+        assert!(!RetryPolicy::none().should_retry(FailureClass::Transient, 0));
+    }
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy {
+            backoff_secs: 5,
+            max_backoff_secs: 30,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.backoff(0), Duration::from_secs(5));
+        assert_eq!(policy.backoff(1), Duration::from_secs(10));
+        assert_eq!(policy.backoff(2), Duration::from_secs(20));
+        assert_eq!(policy.backoff(3), Duration::from_secs(30));
+        assert_eq!(policy.backoff(70), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_policy_parses_from_toml() {
+        let policy: RetryPolicy = toml::from_str(
+            r#"
+max_retries = 1
+retry_on = ["transient", "model_error"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(policy.max_retries, 1);
+        assert_eq!(policy.backoff_secs, 5);
+        assert!(policy.should_retry(FailureClass::ModelError, 0));
+    }
+
+    #[tokio::test]
+    async fn test_run_with_retry_retries_transient_failures() {
+        let calls = AtomicU32::new(0);
+        let result = run_with_retry(&instant_policy(3), "01", || async {
+            if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                anyhow::bail!("spawn failed")
+            }
+            Ok(iteration(0, false, "done"))
+        })
+        .await
+        .unwrap();
+        assert_eq!(result.output, "done");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_retry_gives_up_after_max_retries() {
+        let calls = AtomicU32::new(0);
+        let result = run_with_retry(&instant_policy(2), "01", || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(iteration(1, true, "overloaded_error"))
+        })
+        .await
+        .unwrap();
+        assert!(result.is_error);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_retry_returns_model_errors_immediately() {
+        let calls = AtomicU32::new(0);
+        let result = run_with_retry(&instant_policy(3), "01", || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(iteration(1, true, "invalid tool input"))
+        })
+        .await
+        .unwrap();
+        assert!(result.is_error);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -16,7 +16,9 @@
 //! let phase = Phase::new("05", "OAuth", "OAUTH DONE", 10, "reason", vec![]);
 //! let files_changed = vec!["src/auth.rs".to_string()];
 //!
-//! let result = integration.run_phase_reviews(&phase, 5, &files_changed).await?;
+//! let result = integration
+//!     .run_phase_reviews(&phase, 5, "HEAD", &files_changed)
+//!     .await?;
 //!
 //! if result.can_proceed() {
 //!     println!("Phase can proceed");
@@ -25,15 +27,19 @@
 //! # }
 //! ```
 
+use crate::orchestrator::runner::IterationResult;
 use crate::phase::{Phase, PhaseSpecialistConfig};
 use crate::review::{
-    DispatchResult, DispatcherConfig, PhaseReviewConfig, ReviewDispatcher, ReviewSpecialist,
-    SpecialistType,
+    DecisionSource, DispatchResult, DispatcherConfig, FixPlan, ManualReviewHandler,
+    PhaseReviewConfig, ReviewDispatcher, ReviewSpecialist, SpecialistType,
 };
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::future::Future;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
 
 /// Configuration for review integration with the orchestrator.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -142,6 +148,12 @@ impl ReviewIntegration {
         Self { config, dispatcher }
     }
 
+    /// Set the handler asked for decisions in manual review mode.
+    pub fn with_manual_handler(mut self, handler: Arc<dyn ManualReviewHandler>) -> Self {
+        self.dispatcher = self.dispatcher.with_manual_handler(handler);
+        self
+    }
+
     /// Check if review integration is enabled.
     pub fn is_enabled(&self) -> bool {
         self.config.enabled
@@ -149,11 +161,13 @@ impl ReviewIntegration {
 
     /// Run reviews for a completed phase.
     ///
+    /// `base_revision` is the snapshot the phase started from.
     /// Returns a DispatchResult indicating whether the phase can proceed.
     pub async fn run_phase_reviews(
         &self,
         phase: &Phase,
         iterations_used: u32,
+        base_revision: &str,
         files_changed: &[String],
     ) -> Result<DispatchResult> {
         if !self.config.enabled {
@@ -179,12 +193,93 @@ impl ReviewIntegration {
         let review_config = PhaseReviewConfig::new(&phase.number, &phase.name)
             .add_specialists(specialists)
             .with_budget(phase.budget, iterations_used)
+            .with_base_revision(base_revision)
             .with_files_changed(files_changed.to_vec());
 
         // Run the dispatch
         self.dispatcher.dispatch(review_config).await
     }
 
+    /// Drive the structured fix loop after a dispatch that needs fixes.
+    ///
+    /// Every actionable finding of the failed gating reviews becomes a
+    /// tracked [`FixItem`](crate::review::FixItem). While the arbiter asks
+    /// for fixes (at most `max_fix_attempts` rounds, and only while the
+    /// phase budget has iterations left), `run_fix` runs fix round `n` with
+    /// the item prompt, then the reporting specialists re-check only their
+    /// items against the changed files plus those the fixes edited, and the
+    /// arbiter decides over the unresolved ones. When a human reviewer
+    /// decided, only the findings they marked for fixing are tracked.
+    pub async fn run_fix_loop<F, Fut>(
+        &self,
+        phase: &Phase,
+        iterations_used: u32,
+        base_revision: &str,
+        files_changed: &[String],
+        initial: DispatchResult,
+        mut run_fix: F,
+    ) -> Result<FixLoopOutcome>
+    where
+        F: FnMut(u32, String) -> Fut,
+        Fut: Future<Output = Result<IterationResult>>,
+    {
+        let mut plan = FixPlan::from_aggregation(&initial.aggregation);
+        let mut result = initial;
+        let mut fix_iterations = 0;
+        let mut edited_files = BTreeSet::new();
+        let mut files_changed = files_changed.to_vec();
+        let max_attempts = self.config.dispatcher.arbiter.max_fix_attempts;
+        let review_config = PhaseReviewConfig::new(&phase.number, &phase.name)
+            .add_specialists(self.build_specialists(phase))
+            .with_base_revision(base_revision);
+
+        while result.needs_fix()
+            && !plan.is_empty()
+            && fix_iterations < max_attempts
+            && iterations_used + fix_iterations < phase.budget
+        {
+            if let Some(arbiter_result) = &result.arbiter_result
+                && arbiter_result.source == DecisionSource::Human
+            {
+                plan.retain_labels(&arbiter_result.decision.addressed_findings);
+            }
+            if plan.pending().is_empty() {
+                plan.reopen_unresolved();
+            }
+            fix_iterations += 1;
+
+            let prompt = plan.fix_prompt(
+                self.config.dispatcher.working_dir.as_deref(),
+                result.fix_instructions(),
+            );
+            let output = run_fix(fix_iterations, prompt).await?;
+            plan.apply_fix_response(&output.output);
+            for path in &output.edited_files {
+                let file = path.to_string_lossy().to_string();
+                if !files_changed.contains(&file) {
+                    files_changed.push(file);
+                }
+            }
+            edited_files.extend(output.edited_files);
+
+            let review_config = review_config
+                .clone()
+                .with_budget(phase.budget, iterations_used + fix_iterations)
+                .with_files_changed(files_changed.clone());
+            result = self
+                .dispatcher
+                .recheck(&mut plan, &review_config, fix_iterations)
+                .await?;
+        }
+
+        Ok(FixLoopOutcome {
+            result,
+            plan,
+            fix_iterations,
+            edited_files,
+        })
+    }
+
     /// Build the list of specialists for a phase.
     fn build_specialists(&self, phase: &Phase) -> Vec<ReviewSpecialist> {
         // Check if phase has explicit review configuration
@@ -218,6 +313,19 @@ impl ReviewIntegration {
     }
 }
 
+/// Outcome of [`ReviewIntegration::run_fix_loop`].
+#[derive(Debug, Clone)]
+pub struct FixLoopOutcome {
+    /// The last dispatch result (the initial one if no fix round ran).
+    pub result: DispatchResult,
+    /// Tracked fix items with their final status.
+    pub plan: FixPlan,
+    /// Fix iterations that ran.
+    pub fix_iterations: u32,
+    /// Files the fix iterations edited, relative to the project directory.
+    pub edited_files: BTreeSet<PathBuf>,
+}
+
 /// Result of a phase with reviews.
 #[derive(Debug, Clone)]
 pub struct PhaseWithReviewResult {
@@ -463,20 +571,113 @@ mod tests {
         let integration = ReviewIntegration::new(config);
 
         let phase = Phase::new("05", "OAuth", "DONE", 10, "reason", vec![]);
-        let result = integration.run_phase_reviews(&phase, 5, &[]).await.unwrap();
+        let result = integration
+            .run_phase_reviews(&phase, 5, "HEAD", &[])
+            .await
+            .unwrap();
 
         // Should succeed immediately when disabled
         assert!(!result.has_gating_failures);
         assert!(result.can_proceed());
     }
 
+    #[tokio::test]
+    async fn test_run_fix_loop_rechecks_items() {
+        use crate::review::{
+            ArbiterDecision, ArbiterResult, FindingSeverity, FixStatus, ReviewAggregation,
+            ReviewFinding, ReviewReport, ReviewVerdict,
+        };
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let claude = temp_dir.path().join("fake-claude");
+        std::fs::write(
+            &claude,
+            "#!/bin/sh\ncat > /dev/null\necho '{\"items\": [{\"id\": \"F1\", \"status\": \"fixed\"}]}'\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&claude, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let config = ReviewIntegrationConfig::enabled()
+            .with_claude_cmd(claude.to_str().unwrap())
+            .with_default_specialists(vec![DefaultSpecialist::gating("security")]);
+        let integration = ReviewIntegration::new(config);
+        let phase = Phase::new("05", "OAuth", "DONE", 10, "reason", vec![]);
+
+        let aggregation = ReviewAggregation::new("05").add_report(
+            ReviewReport::new("05", "security-sentinel", ReviewVerdict::Fail).add_finding(
+                ReviewFinding::new(FindingSeverity::Error, "src/auth.rs", "Token logged"),
+            ),
+        );
+        let initial = DispatchResult::with_arbiter(
+            aggregation,
+            ArbiterResult::rule_based(ArbiterDecision::fix("Fixable", 0.9, "Stop logging")),
+            std::time::Duration::from_secs(0),
+        );
+
+        let mut prompts = Vec::new();
+        let outcome = integration
+            .run_fix_loop(&phase, 5, "HEAD", &[], initial.clone(), |round, prompt| {
+                assert_eq!(round, 1);
+                prompts.push(prompt);
+                async { Ok(fix_result("Removed the log line", &["src/auth.rs"])) }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.fix_iterations, 1);
+        assert_eq!(
+            outcome.edited_files,
+            BTreeSet::from([PathBuf::from("src/auth.rs")])
+        );
+        assert_eq!(prompts.len(), 1);
+        assert!(prompts[0].contains("### F1 [error] src/auth.rs (security-sentinel)"));
+        assert!(prompts[0].contains("Arbiter guidance: Stop logging"));
+        assert_eq!(outcome.plan.items[0].status, FixStatus::Fixed);
+        assert!(outcome.result.can_proceed());
+
+        // No fix round runs once the phase budget is spent
+        let outcome = integration
+            .run_fix_loop(&phase, 10, "HEAD", &[], initial, |_, _| async {
+                panic!("fix round ran past the phase budget")
+            })
+            .await
+            .unwrap();
+        assert_eq!(outcome.fix_iterations, 0);
+        assert!(outcome.result.needs_fix());
+    }
+
+    fn fix_result(output: &str, edited: &[&str]) -> IterationResult {
+        IterationResult {
+            session: crate::audit::ClaudeSession {
+                prompt_file: PathBuf::new(),
+                prompt_chars: 0,
+                output_file: PathBuf::new(),
+                output_chars: output.len(),
+                exit_code: 0,
+                token_usage: None,
+                session_id: None,
+            },
+            promise_found: false,
+            is_error: false,
+            output: output.to_string(),
+            signals: Default::default(),
+            council_data: None,
+            edited_files: edited.iter().map(PathBuf::from).collect(),
+            error: None,
+        }
+    }
+
     #[tokio::test]
     async fn test_run_phase_reviews_no_specialists() {
         let config = ReviewIntegrationConfig::enabled();
         let integration = ReviewIntegration::new(config);
 
         let phase = Phase::new("05", "OAuth", "DONE", 10, "reason", vec![]);
-        let result = integration.run_phase_reviews(&phase, 5, &[]).await.unwrap();
+        let result = integration
+            .run_phase_reviews(&phase, 5, "HEAD", &[])
+            .await
+            .unwrap();
 
         // Should succeed when no specialists configured
         assert!(!result.has_gating_failures);
@@ -1,19 +1,23 @@
 use crate::audit::{ClaudeSession, FileChangeSummary, TokenUsage};
 use crate::config::Config;
 use crate::council::worker::{create_chairman_worker, create_workers};
-use crate::council::{CouncilEngine, CouncilPhaseResult};
+use crate::council::{CouncilAuditData, CouncilEngine, CouncilPhaseResult};
 use crate::errors::OrchestratorError;
 use crate::forge_config::tools_for_permission_mode;
+use crate::orchestrator::retry::RetryPolicy;
 use crate::phase::Phase;
 use crate::signals::{IterationSignals, extract_signals};
 use crate::skills::SkillsLoader;
-use crate::stream::{ContentBlock, StreamEvent, describe_tool_use, tool_emoji, truncate_thinking};
+use crate::stream::{
+    ContentBlock, StreamEvent, describe_tool_use, edited_file, tool_emoji, truncate_thinking,
+};
 use crate::ui::OrchestratorUI;
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
 use tokio::process::Command;
 use tracing::{info, warn};
 
@@ -253,6 +257,7 @@ fn council_result_to_iteration_result(
     phase: &Phase,
 ) -> IterationResult {
     let token_usage = aggregate_council_token_usage(&council_result);
+    let council_data = council_result.to_audit_data();
     let output = council_result.winning_diff;
     let signal_text = council_result
         .worker_results
@@ -275,8 +280,12 @@ fn council_result_to_iteration_result(
             session_id: None,
         },
         promise_found,
+        is_error: false,
         output,
         signals,
+        council_data: Some(council_data),
+        edited_files: BTreeSet::new(),
+        error: None,
     }
 }
 
@@ -287,9 +296,19 @@ pub struct ClaudeRunner {
 pub struct IterationResult {
     pub session: ClaudeSession,
     pub promise_found: bool,
+    /// Whether the result event reported an error
+    pub is_error: bool,
     pub output: String,
     /// Signals extracted from Claude's output (progress, blockers, pivots)
     pub signals: IterationSignals,
+    /// Council scores and decision when the iteration ran in council mode
+    pub council_data: Option<CouncilAuditData>,
+    /// Files the agent edited through its own file tools, relative to the
+    /// project directory
+    pub edited_files: BTreeSet<PathBuf>,
+    /// Error text of an error result event, or the CLI's stderr when it
+    /// failed without one; failures are classified from this, not the output
+    pub error: Option<String>,
 }
 
 impl ClaudeRunner {
@@ -297,6 +316,11 @@ impl ClaudeRunner {
         Self { config }
     }
 
+    /// Path of the spec file prompts are built from.
+    pub fn spec_file(&self) -> &Path {
+        &self.config.spec_file
+    }
+
     pub fn should_use_council(&self, phase: &Phase) -> bool {
         let global_enabled = self
             .config
@@ -352,6 +376,16 @@ impl ClaudeRunner {
             .map(Duration::from_secs)
     }
 
+    /// Resolve the retry policy for a phase from config.
+    ///
+    /// Resolution order: PhaseOverride > DefaultsConfig > built-in default.
+    pub fn resolve_retry_policy(&self, phase: &Phase) -> RetryPolicy {
+        self.config
+            .forge_config()
+            .map(|fc| fc.phase_settings(&phase.name).retry)
+            .unwrap_or_default()
+    }
+
     /// Unified iteration dispatch for sequential execution.
     ///
     /// This is the single enforcement point for per-iteration timeouts.
@@ -596,12 +630,14 @@ impl ClaudeRunner {
             ui.log_step(&format!("Spawning: {}", display));
         }
 
-        // Run Claude with prompt via stdin
+        // Run Claude with prompt via stdin. Killed if the iteration is
+        // dropped, e.g. when a swarm phase is cancelled or times out.
         let mut child = cmd
             .stdin(std::process::Stdio::piped())
             .stdout(std::process::Stdio::piped())
             .stderr(std::process::Stdio::piped())
             .current_dir(&self.config.project_dir)
+            .kill_on_drop(true)
             .spawn()
             .map_err(OrchestratorError::SpawnFailed)?;
 
@@ -620,9 +656,16 @@ impl ClaudeRunner {
             stdin.shutdown().await.context("Failed to close stdin")?;
         }
 
-        // Take stdout for streaming
+        // Take stdout for streaming; stderr is drained alongside so a chatty
+        // CLI cannot block on a full pipe
         let stdout = child.stdout.take().context("Failed to get stdout")?;
         let mut reader = BufReader::new(stdout).lines();
+        let stderr = child.stderr.take().context("Failed to get stderr")?;
+        let stderr_task = tokio::spawn(async move {
+            let mut text = String::new();
+            let _ = BufReader::new(stderr).read_to_string(&mut text).await;
+            text
+        });
 
         // Accumulate output and final result
         let mut accumulated_text = String::new();
@@ -630,6 +673,7 @@ impl ClaudeRunner {
         let mut is_error = false;
         let mut captured_session_id: Option<String> = None;
         let mut extracted_token_usage: Option<TokenUsage> = None;
+        let mut edited_files = BTreeSet::new();
 
         // Spawn elapsed time updater
         let ui_clone = ui.clone();
@@ -667,6 +711,14 @@ impl ClaudeRunner {
                             for content in message.content {
                                 match content {
                                     ContentBlock::ToolUse { name, input, .. } => {
+                                        if let Some(path) = edited_file(&name, &input) {
+                                            let path = Path::new(path);
+                                            edited_files.insert(
+                                                path.strip_prefix(&self.config.project_dir)
+                                                    .unwrap_or(path)
+                                                    .to_path_buf(),
+                                            );
+                                        }
                                         let desc = describe_tool_use(&name, &input);
                                         let emoji = tool_emoji(&name);
                                         if let Some(ref ui) = ui {
@@ -720,6 +772,7 @@ impl ClaudeRunner {
         // Wait for process to finish
         let status = child.wait().await?;
         elapsed_task.abort();
+        let stderr = stderr_task.await.unwrap_or_default();
 
         let duration = start.elapsed();
         let exit_code = status.code().unwrap_or(-1);
@@ -749,6 +802,14 @@ impl ClaudeRunner {
             .await;
         }
 
+        let error = match &final_result {
+            Some(text) if is_error => Some(text.clone()),
+            _ if (is_error || exit_code != 0) && !stderr.trim().is_empty() => {
+                Some(stderr.trim().to_string())
+            }
+            _ => None,
+        };
+
         // Use final_result if available, otherwise accumulated text
         let combined_output = final_result.unwrap_or(accumulated_text);
 
@@ -807,8 +868,12 @@ impl ClaudeRunner {
         Ok(IterationResult {
             session,
             promise_found,
+            is_error,
             output: combined_output,
             signals,
+            council_data: None,
+            edited_files,
+            error,
         })
     }
 
@@ -1758,6 +1823,11 @@ enabled = true
                 winning_diff: "merged output\n<promise>DONE</promise>".to_string(),
                 worker_results: vec![worker_result(&[])],
                 review_results: Vec::new(),
+                score_matrix: Vec::new(),
+                rankings: Vec::new(),
+                hybrid_selections: Vec::new(),
+                worker_runs: Vec::new(),
+                early_exit: false,
                 merge_outcome: MergeOutcome::Clean("merged output".to_string()),
                 merge_attempts: 1,
             },
@@ -1775,6 +1845,11 @@ enabled = true
                 winning_diff: "merged diff output".to_string(),
                 worker_results: vec![worker_result(&[])],
                 review_results: Vec::new(),
+                score_matrix: Vec::new(),
+                rankings: Vec::new(),
+                hybrid_selections: Vec::new(),
+                worker_runs: Vec::new(),
+                early_exit: false,
                 merge_outcome: MergeOutcome::Clean("merged diff output".to_string()),
                 merge_attempts: 1,
             },
@@ -1795,6 +1870,11 @@ enabled = true
                     "<blocker>Need migration fix</blocker>",
                 ])],
                 review_results: Vec::new(),
+                score_matrix: Vec::new(),
+                rankings: Vec::new(),
+                hybrid_selections: Vec::new(),
+                worker_runs: Vec::new(),
+                early_exit: false,
                 merge_outcome: MergeOutcome::Clean("merged diff output".to_string()),
                 merge_attempts: 1,
             },
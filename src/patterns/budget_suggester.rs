@@ -275,6 +275,33 @@ pub fn suggest_budgets(
     suggestions
 }
 
+/// Expected iterations per phase based on similar patterns.
+///
+/// Uses the average iterations of phases of the same type, capped at the
+/// phase's budget. Phases without historical data are left out, so callers
+/// can fall back to the budget.
+pub fn expected_iterations(
+    patterns: &[&Pattern],
+    phases: &[crate::phase::Phase],
+) -> HashMap<String, u32> {
+    let mut type_data: HashMap<PhaseType, Vec<&PhaseStat>> = HashMap::new();
+    for pattern in patterns {
+        for stat in &pattern.phase_stats {
+            type_data.entry(stat.phase_type).or_default().push(stat);
+        }
+    }
+
+    phases
+        .iter()
+        .filter_map(|phase| {
+            let stats = type_data.get(&PhaseType::classify(&phase.name))?;
+            let avg = PhaseTypeStats::from_phases(stats).avg_iterations;
+            let expected = (avg.round() as u32).clamp(1, phase.budget.max(1));
+            Some((phase.number.clone(), expected))
+        })
+        .collect()
+}
+
 /// Display pattern match results.
 pub fn display_pattern_matches(matches: &[PatternMatch]) {
     if matches.is_empty() {
@@ -12,7 +12,7 @@ pub mod stats_aggregator;
 
 pub use budget_suggester::{
     BudgetSuggestion, PatternMatch, display_budget_suggestions, display_pattern_matches,
-    match_patterns, recommend_skills_for_phase, suggest_budgets,
+    expected_iterations, match_patterns, recommend_skills_for_phase, suggest_budgets,
 };
 pub use learning::{
     GLOBAL_FORGE_DIR, Pattern, PhaseStat, PhaseType, PhaseTypeStats, display_pattern,
@@ -735,6 +735,33 @@ mod tests {
         assert!(suggestions[0].suggested_budget <= 10);
     }
 
+    #[test]
+    fn test_expected_iterations_from_history() {
+        let mut pattern = Pattern::new("test");
+        pattern.phase_stats = vec![PhaseStat {
+            name: "Project scaffold".to_string(),
+            promise: "DONE".to_string(),
+            actual_iterations: 4,
+            original_budget: 10,
+            phase_type: PhaseType::Scaffold,
+            file_patterns: vec![],
+            common_errors: vec![],
+        }];
+
+        let phases = vec![
+            crate::phase::Phase::new("01", "Initial scaffold", "DONE", 10, "Setup", vec![]),
+            crate::phase::Phase::new("02", "Repo setup", "DONE", 2, "Setup", vec![]),
+            crate::phase::Phase::new("03", "Api", "DONE", 10, "Api", vec![]),
+        ];
+
+        let expected = expected_iterations(&[&pattern], &phases);
+        assert_eq!(expected.get("01"), Some(&4));
+        // Capped at the phase budget
+        assert_eq!(expected.get("02"), Some(&2));
+        // No implement phases in history
+        assert!(!expected.contains_key("03"));
+    }
+
     #[test]
     fn test_budget_suggestion_is_significant() {
         let not_significant = BudgetSuggestion {
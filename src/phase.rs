@@ -91,6 +91,20 @@ pub struct Phase {
     /// List of phase numbers that this phase depends on
     #[serde(default)]
     pub depends_on: Vec<String>,
+    /// Files this phase edits, as globs relative to the project root.
+    /// Phases with overlapping scopes are never run at the same time.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scope: Vec<String>,
+    /// Named resources this phase needs exclusive access to (e.g. "database").
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locks: Vec<String>,
+    /// Scheduling priority. Among ready phases, higher priorities start first.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    /// Parallel slots the phase occupies while running (default 1).
+    /// A heavy phase with weight 2 counts twice against `max_parallel`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u32>,
     /// List of skill names to load for this phase
     #[serde(default)]
     pub skills: Vec<String>,
@@ -202,6 +216,10 @@ impl SubPhase {
             budget: self.budget,
             reasoning: self.reasoning.clone(),
             depends_on: vec![parent.number.clone()],
+            scope: parent.scope.clone(),
+            locks: parent.locks.clone(),
+            priority: parent.priority,
+            weight: parent.weight,
             skills: if self.skills.is_empty() {
                 parent.skills.clone()
             } else {
@@ -251,6 +269,10 @@ impl Phase {
             budget,
             reasoning: reasoning.to_string(),
             depends_on,
+            scope: Vec::new(),
+            locks: Vec::new(),
+            priority: None,
+            weight: None,
             skills: Vec::new(),
             permission_mode: PermissionMode::default(),
             parent_phase: None,
@@ -278,6 +300,10 @@ impl Phase {
             budget,
             reasoning: reasoning.to_string(),
             depends_on,
+            scope: Vec::new(),
+            locks: Vec::new(),
+            priority: None,
+            weight: None,
             skills,
             permission_mode: PermissionMode::default(),
             parent_phase: None,
@@ -305,6 +331,10 @@ impl Phase {
             budget,
             reasoning: reasoning.to_string(),
             depends_on,
+            scope: Vec::new(),
+            locks: Vec::new(),
+            priority: None,
+            weight: None,
             skills: Vec::new(),
             permission_mode,
             parent_phase: None,
@@ -315,6 +345,11 @@ impl Phase {
         }
     }
 
+    /// Number of parallel slots the phase occupies while running.
+    pub fn slots(&self) -> usize {
+        self.weight.unwrap_or(1).max(1) as usize
+    }
+
     /// Backward-compatible accessor for max_iterations (now called budget).
     #[inline]
     pub fn max_iterations(&self) -> u32 {
@@ -1,7 +1,8 @@
 //! LLM-based arbiter for resolving review failures.
 //!
 //! When a gating review fails, the arbiter decides how to proceed using a unified
-//! Auto resolution mode with optional LLM support.
+//! Auto resolution mode with optional LLM support. In manual mode the decision
+//! is handed to a human instead (see [`crate::review::manual`]).
 //!
 //! ## Usage
 //!
@@ -33,6 +34,7 @@
 //! - **FIX**: Clear fix path exists, security/correctness issues, within remaining budget
 //! - **FAIL_PHASE**: Out of budget, unresolvable issues, max fix attempts exceeded
 
+use crate::review::history::ArbiterPrecedent;
 use crate::review::{FindingSeverity, ReviewAggregation, ReviewFinding, ReviewReport};
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -40,10 +42,14 @@ use tracing::{debug, warn};
 
 /// Resolution mode for handling review failures.
 ///
-/// Simplified to Auto-only. Deprecated "manual" and "arbiter" modes are
-/// accepted during deserialization and mapped to Auto with appropriate defaults.
+/// Auto mode with optional LLM support, or manual mode where a human decides.
+/// The legacy "arbiter" mode is accepted during deserialization and mapped to
+/// Auto with an LLM model.
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct ResolutionMode {
+    /// Whether gating failures pause for a human decision.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub manual: bool,
     /// Maximum fix attempts before failing the phase.
     pub max_attempts: u32,
     /// Optional LLM model for arbiter decisions.
@@ -57,6 +63,9 @@ pub struct ResolutionMode {
 /// Default confidence threshold for arbiter decisions.
 pub const DEFAULT_CONFIDENCE_THRESHOLD: f64 = 0.7;
 
+/// Model the LLM arbiter runs with unless `[reviews] arbiter_model` is set.
+pub const DEFAULT_ARBITER_MODEL: &str = "sonnet";
+
 fn default_confidence_threshold() -> f64 {
     DEFAULT_CONFIDENCE_THRESHOLD
 }
@@ -68,6 +77,7 @@ fn default_max_attempts() -> u32 {
 impl Default for ResolutionMode {
     fn default() -> Self {
         Self {
+            manual: false,
             max_attempts: default_max_attempts(),
             model: None,
             confidence_threshold: default_confidence_threshold(),
@@ -87,14 +97,11 @@ impl<'de> serde::Deserialize<'de> for ResolutionMode {
 
         match &value {
             serde_json::Value::String(s) => match s.as_str() {
-                "manual" => {
-                    warn!("'manual' resolution mode is deprecated, using auto");
-                    Ok(ResolutionMode::default())
-                }
+                "manual" => Ok(ResolutionMode::manual(default_max_attempts())),
                 "auto" => Ok(ResolutionMode::default()),
                 "arbiter" => Ok(ResolutionMode::auto_with_llm(
                     default_max_attempts(),
-                    "claude-3-sonnet",
+                    DEFAULT_ARBITER_MODEL,
                     default_confidence_threshold(),
                 )),
                 other => Err(de::Error::custom(format!(
@@ -108,14 +115,17 @@ impl<'de> serde::Deserialize<'de> for ResolutionMode {
                 {
                     match mode_str {
                         "manual" => {
-                            warn!("'manual' resolution mode is deprecated, using auto");
-                            return Ok(ResolutionMode::default());
+                            let max = map
+                                .get("max_attempts")
+                                .and_then(|v| v.as_u64())
+                                .unwrap_or(default_max_attempts() as u64);
+                            return Ok(ResolutionMode::manual(max as u32));
                         }
                         "arbiter" => {
                             let model = map
                                 .get("model")
                                 .and_then(|v| v.as_str())
-                                .unwrap_or("claude-3-sonnet");
+                                .unwrap_or(DEFAULT_ARBITER_MODEL);
                             let threshold = map
                                 .get("confidence_threshold")
                                 .and_then(|v| v.as_f64())
@@ -142,7 +152,9 @@ impl<'de> serde::Deserialize<'de> for ResolutionMode {
                     .get("confidence_threshold")
                     .and_then(|v| v.as_f64())
                     .unwrap_or(default_confidence_threshold());
+                let manual = map.get("manual").and_then(|v| v.as_bool()).unwrap_or(false);
                 Ok(Self {
+                    manual,
                     max_attempts: max as u32,
                     model,
                     confidence_threshold: threshold,
@@ -159,6 +171,7 @@ impl ResolutionMode {
     /// Create an auto resolution mode with given max attempts.
     pub fn auto(max_attempts: u32) -> Self {
         Self {
+            manual: false,
             max_attempts,
             model: None,
             confidence_threshold: default_confidence_threshold(),
@@ -168,15 +181,29 @@ impl ResolutionMode {
     /// Create an auto resolution mode with LLM arbiter support.
     pub fn auto_with_llm(max_attempts: u32, model: &str, confidence_threshold: f64) -> Self {
         Self {
+            manual: false,
             max_attempts,
             model: Some(model.to_string()),
             confidence_threshold,
         }
     }
 
-    /// Check if this is auto mode (always true now).
+    /// Create a manual resolution mode where a human decides on failures.
+    pub fn manual(max_attempts: u32) -> Self {
+        Self {
+            manual: true,
+            ..Self::auto(max_attempts)
+        }
+    }
+
+    /// Check if this is auto mode.
     pub fn is_auto(&self) -> bool {
-        true
+        !self.manual
+    }
+
+    /// Check if this is manual mode.
+    pub fn is_manual(&self) -> bool {
+        self.manual
     }
 
     /// Get the max attempts.
@@ -202,7 +229,9 @@ impl ResolutionMode {
 
 impl fmt::Display for ResolutionMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(ref model) = self.model {
+        if self.manual {
+            write!(f, "manual (max {} attempts)", self.max_attempts)
+        } else if let Some(ref model) = self.model {
             write!(
                 f,
                 "auto+llm ({}, confidence >= {:.0}%, max {} attempts)",
@@ -277,13 +306,19 @@ impl ArbiterConfig {
         Self {
             mode: ResolutionMode::auto_with_llm(
                 default_max_fix_attempts(),
-                "claude-3-sonnet",
+                DEFAULT_ARBITER_MODEL,
                 DEFAULT_CONFIDENCE_THRESHOLD,
             ),
             ..Default::default()
         }
     }
 
+    /// Set the resolution mode.
+    pub fn with_mode(mut self, mode: ResolutionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     /// Set the confidence threshold.
     pub fn with_confidence_threshold(mut self, threshold: f64) -> Self {
         self.mode.confidence_threshold = threshold;
@@ -346,6 +381,9 @@ pub struct ArbiterInput {
     /// Additional context (e.g., phase reasoning, recent changes).
     #[serde(default)]
     pub additional_context: Option<String>,
+    /// Past decisions for the same project, shown as few-shot examples.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub precedents: Vec<ArbiterPrecedent>,
 }
 
 impl ArbiterInput {
@@ -361,6 +399,7 @@ impl ArbiterInput {
             failed_specialists: Vec::new(),
             fix_attempts: 0,
             additional_context: None,
+            precedents: Vec::new(),
         }
     }
 
@@ -397,6 +436,7 @@ impl ArbiterInput {
             failed_specialists,
             fix_attempts: 0,
             additional_context: None,
+            precedents: Vec::new(),
         }
     }
 
@@ -430,6 +470,12 @@ impl ArbiterInput {
         self
     }
 
+    /// Add past decisions to learn from.
+    pub fn with_precedents(mut self, precedents: Vec<ArbiterPrecedent>) -> Self {
+        self.precedents = precedents;
+        self
+    }
+
     /// Get remaining budget.
     pub fn remaining_budget(&self) -> u32 {
         self.budget.saturating_sub(self.iterations_used)
@@ -460,7 +506,9 @@ impl ArbiterInput {
 }
 
 /// The arbiter's decision on how to proceed.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, clap::ValueEnum,
+)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum ArbiterVerdict {
     /// Continue despite findings (acceptable trade-offs, false positives).
@@ -674,6 +722,8 @@ pub enum DecisionSource {
     Config,
     /// Decision due to error/fallback.
     Fallback,
+    /// Decision made by a human reviewer in manual mode.
+    Human,
 }
 
 impl fmt::Display for DecisionSource {
@@ -683,6 +733,7 @@ impl fmt::Display for DecisionSource {
             Self::RuleBased => write!(f, "rule-based"),
             Self::Config => write!(f, "config"),
             Self::Fallback => write!(f, "fallback"),
+            Self::Human => write!(f, "human"),
         }
     }
 }
@@ -725,6 +776,17 @@ impl ArbiterResult {
         }
     }
 
+    /// Create a result with a human decision.
+    pub fn human(decision: ArbiterDecision) -> Self {
+        Self {
+            decision,
+            source: DecisionSource::Human,
+            raw_response: None,
+            duration_ms: None,
+            error: None,
+        }
+    }
+
     /// Create a fallback result due to an error.
     pub fn fallback(decision: ArbiterDecision, error: &str) -> Self {
         Self {
@@ -770,6 +832,8 @@ pub fn build_arbiter_prompt(input: &ArbiterInput) -> String {
         .map(|ctx| format!("\n## Additional Context\n{}\n", ctx))
         .unwrap_or_default();
 
+    let precedents_section = precedents_section(&input.precedents);
+
     format!(
         r#"# Review Arbiter
 
@@ -780,7 +844,7 @@ You are deciding how to handle review findings that would block progress.
 - Budget: {iterations_used}/{budget} iterations used ({remaining} remaining)
 - Fix attempts so far: {fix_attempts}
 - Failed specialists: {failed_specialists}
-{context_section}
+{context_section}{precedents_section}
 ## Blocking Findings
 ```json
 {findings_json}
@@ -830,10 +894,47 @@ Respond with ONLY a JSON object in this exact format (no markdown, no explanatio
         fix_attempts = input.fix_attempts,
         failed_specialists = failed_specialists,
         context_section = context_section,
+        precedents_section = precedents_section,
         findings_json = findings_json,
     )
 }
 
+/// Render past decisions as few-shot examples, or nothing if there are none.
+fn precedents_section(precedents: &[ArbiterPrecedent]) -> String {
+    if precedents.is_empty() {
+        return String::new();
+    }
+
+    let mut section = String::from(
+        "\n## Past Decisions in This Project\n\
+         Match the team's risk tolerance shown below. Where a human overrode the \
+         arbiter, the human verdict is the correct one.\n",
+    );
+    for (index, precedent) in precedents.iter().enumerate() {
+        section.push_str(&format!(
+            "\n### Example {} (phase {}; failed: {})\n",
+            index + 1,
+            precedent.phase,
+            precedent.failed_specialists.join(", ")
+        ));
+        for finding in &precedent.findings {
+            section.push_str(&format!("- {}\n", finding));
+        }
+        section.push_str(&format!(
+            "Arbiter decided {}: {}\n",
+            precedent.arbiter_verdict, precedent.arbiter_reasoning
+        ));
+        if let Some(verdict) = precedent.human_verdict {
+            section.push_str(&format!("Human override: {}", verdict));
+            if let Some(reason) = &precedent.human_reason {
+                section.push_str(&format!(" ({})", reason));
+            }
+            section.push('\n');
+        }
+    }
+    section
+}
+
 /// Parse an arbiter decision from LLM response.
 pub fn parse_arbiter_response(response: &str) -> Option<ArbiterDecision> {
     // Try to find JSON in the response
@@ -1115,6 +1216,9 @@ impl ArbiterExecutor {
         // Build command
         let mut cmd = Command::new(&self.config.claude_cmd);
         cmd.arg("--print");
+        if let Some(model) = self.config.mode.model() {
+            cmd.arg("--model").arg(model);
+        }
 
         if self.config.skip_permissions {
             cmd.arg("--dangerously-skip-permissions");
@@ -1276,10 +1380,19 @@ mod tests {
     }
 
     #[test]
-    fn test_deprecated_manual_deserializes_as_auto() {
+    fn test_manual_deserializes_as_manual() {
         let json = r#"{"mode":"manual"}"#;
         let config: ArbiterConfig = serde_json::from_str(json).unwrap();
-        assert!(config.mode.is_auto());
+        assert!(config.mode.is_manual());
+        assert!(!config.mode.is_auto());
+
+        let mode: ResolutionMode = serde_json::from_str(r#""manual""#).unwrap();
+        assert_eq!(mode, ResolutionMode::manual(2));
+        assert_eq!(format!("{}", mode), "manual (max 2 attempts)");
+
+        let round_trip: ResolutionMode =
+            serde_json::from_str(&serde_json::to_string(&mode).unwrap()).unwrap();
+        assert_eq!(round_trip, mode);
     }
 
     #[test]
@@ -1595,6 +1708,30 @@ mod tests {
         assert!(prompt.contains("PROCEED"));
         assert!(prompt.contains("FIX"));
         assert!(prompt.contains("FAIL_PHASE"));
+        assert!(!prompt.contains("Past Decisions"));
+    }
+
+    #[test]
+    fn test_build_arbiter_prompt_with_precedents() {
+        let precedent = ArbiterPrecedent {
+            phase: "03 - Storage".to_string(),
+            failed_specialists: vec!["security-sentinel".to_string()],
+            findings: vec!["[warning] src/db.rs:3: Token logged".to_string()],
+            arbiter_verdict: ArbiterVerdict::Proceed,
+            arbiter_reasoning: "Minor warning".to_string(),
+            human_verdict: Some(ArbiterVerdict::Fix),
+            human_reason: Some("Never log tokens".to_string()),
+        };
+        let input =
+            ArbiterInput::new("05", "OAuth Integration", 20, 8).with_precedents(vec![precedent]);
+
+        let prompt = build_arbiter_prompt(&input);
+
+        assert!(prompt.contains("## Past Decisions in This Project"));
+        assert!(prompt.contains("### Example 1 (phase 03 - Storage; failed: security-sentinel)"));
+        assert!(prompt.contains("- [warning] src/db.rs:3: Token logged"));
+        assert!(prompt.contains("Arbiter decided PROCEED: Minor warning"));
+        assert!(prompt.contains("Human override: FIX (Never log tokens)"));
     }
 
     #[test]
@@ -0,0 +1,643 @@
+//! Accepted-findings baseline and inline suppressions.
+//!
+//! A gating specialist that keeps flagging a finding the team has consciously
+//! accepted would otherwise burn fix attempts every phase. Two mechanisms
+//! take such findings out of gating while still reporting them as
+//! [`SuppressedFinding`]s:
+//!
+//! - `.forge/review-baseline.json`, written by `forge review baseline update`.
+//!   Entries are matched on specialist, file and normalized issue text, with
+//!   a fuzzy line match so unrelated edits above a finding do not revive it.
+//! - Inline comments on the finding's line or the line above it:
+//!
+//! ```text
+//! // forge-review: ignore security-sentinel
+//! let query = format!("SELECT * FROM {table}");
+//! ```
+//!
+//! At least one specialist must be named; names may be aliases such as
+//! `security`. `forge-review: ignore-file <names>` anywhere in a file
+//! silences the named specialists for the whole file.
+//!
+//! The agent under review writes the files these comments are read from, so
+//! a directive is only honored when it already exists in the base revision
+//! the reviewed diff starts from, and never for a gating specialist: only a
+//! baseline entry takes a gating finding out of gating.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{ReviewFinding, ReviewReport, ReviewVerdict, SpecialistType};
+use super::{SuppressedFinding, SuppressionReason};
+
+/// Baseline file name under the forge dir.
+const BASELINE_FILE: &str = "review-baseline.json";
+
+/// How far a finding may move and still match its baseline entry.
+const LINE_TOLERANCE: u32 = 10;
+
+/// Marker that introduces an inline suppression comment.
+const INLINE_MARKER: &str = "forge-review:";
+
+/// One accepted finding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Stable hash of specialist, file and normalized issue.
+    pub fingerprint: String,
+    /// Agent name of the specialist that reported the finding.
+    pub specialist: String,
+    /// File the finding was reported in.
+    pub file: String,
+    /// Normalized issue text.
+    pub issue: String,
+    /// Line the finding was reported at, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// When the finding was accepted.
+    pub accepted_at: DateTime<Utc>,
+}
+
+impl BaselineEntry {
+    /// Create an entry accepting `finding` as reported by `specialist`.
+    pub fn from_finding(specialist: &str, finding: &ReviewFinding) -> Self {
+        let issue = normalize_issue(finding.issue());
+        Self {
+            fingerprint: fingerprint(specialist, finding.file(), &issue),
+            specialist: specialist.to_string(),
+            file: finding.file().to_string(),
+            issue,
+            line: finding.line(),
+            accepted_at: Utc::now(),
+        }
+    }
+
+    /// Whether this entry covers `finding` reported by `specialist`.
+    pub fn matches(&self, specialist: &str, finding: &ReviewFinding) -> bool {
+        let issue = normalize_issue(finding.issue());
+        if self.fingerprint != fingerprint(specialist, finding.file(), &issue) {
+            return false;
+        }
+        match (self.line, finding.line()) {
+            (Some(accepted), Some(line)) => accepted.abs_diff(line) <= LINE_TOLERANCE,
+            _ => true,
+        }
+    }
+}
+
+/// The set of accepted findings in `.forge/review-baseline.json`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReviewBaseline {
+    /// Accepted findings.
+    #[serde(default)]
+    pub entries: Vec<BaselineEntry>,
+}
+
+impl ReviewBaseline {
+    /// Path of the baseline file for a forge directory.
+    pub fn path(forge_dir: &Path) -> PathBuf {
+        forge_dir.join(BASELINE_FILE)
+    }
+
+    /// Load the baseline; a missing file is an empty baseline.
+    pub fn load(forge_dir: &Path) -> Result<Self> {
+        let path = Self::path(forge_dir);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Write the baseline to the forge directory.
+    pub fn save(&self, forge_dir: &Path) -> Result<PathBuf> {
+        let path = Self::path(forge_dir);
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, json)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(path)
+    }
+
+    /// Accept a finding; returns false if it was already covered.
+    pub fn add(&mut self, specialist: &str, finding: &ReviewFinding) -> bool {
+        if self.contains(specialist, finding) {
+            return false;
+        }
+        self.entries
+            .push(BaselineEntry::from_finding(specialist, finding));
+        true
+    }
+
+    /// Whether a finding is covered by the baseline.
+    pub fn contains(&self, specialist: &str, finding: &ReviewFinding) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.matches(specialist, finding))
+    }
+
+    /// Number of accepted findings.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the baseline is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Moves baselined and inline-suppressed findings out of review reports.
+pub struct Suppressor {
+    baseline: ReviewBaseline,
+    working_dir: Option<PathBuf>,
+    base_revision: Option<String>,
+    sources: HashMap<String, Option<(String, String)>>,
+}
+
+impl Suppressor {
+    /// Create a suppressor reading sources relative to `working_dir`.
+    ///
+    /// Inline directives are checked against `base_revision`; without one
+    /// only baseline entries suppress findings.
+    pub fn new(
+        baseline: ReviewBaseline,
+        working_dir: Option<PathBuf>,
+        base_revision: Option<String>,
+    ) -> Self {
+        Self {
+            baseline,
+            working_dir,
+            base_revision,
+            sources: HashMap::new(),
+        }
+    }
+
+    /// Suppress findings in `report` and re-derive its verdict.
+    ///
+    /// When anything was suppressed, the verdict follows the remaining
+    /// findings and whether the report gates; otherwise it is kept.
+    pub fn apply(&mut self, mut report: ReviewReport, is_gating: bool) -> ReviewReport {
+        let findings = std::mem::take(&mut report.findings);
+        for finding in findings {
+            match self.reason(&report.reviewer, &finding, is_gating) {
+                Some(reason) => report
+                    .suppressed
+                    .push(SuppressedFinding { finding, reason }),
+                None => report.findings.push(finding),
+            }
+        }
+
+        if !report.suppressed.is_empty() {
+            report.verdict = ReviewVerdict::from_findings(&report.findings, is_gating);
+        }
+
+        report
+    }
+
+    fn reason(
+        &mut self,
+        specialist: &str,
+        finding: &ReviewFinding,
+        is_gating: bool,
+    ) -> Option<SuppressionReason> {
+        if self.baseline.contains(specialist, finding) {
+            return Some(SuppressionReason::Baseline);
+        }
+        if is_gating {
+            return None;
+        }
+        let (source, base) = self.sources(finding.file())?;
+        is_inline_suppressed(source, base, finding.line(), specialist)
+            .then_some(SuppressionReason::InlineComment)
+    }
+
+    /// The reviewed and base-revision contents of `file`.
+    fn sources(&mut self, file: &str) -> Option<(&str, &str)> {
+        let working_dir = self.working_dir.as_deref();
+        let base_revision = self.base_revision.as_deref();
+        self.sources
+            .entry(file.to_string())
+            .or_insert_with(|| {
+                let path = match working_dir {
+                    Some(dir) => dir.join(file),
+                    None => PathBuf::from(file),
+                };
+                let source = std::fs::read_to_string(path).ok()?;
+                let base = read_at_revision(working_dir, base_revision?, file)?;
+                Some((source, base))
+            })
+            .as_ref()
+            .map(|(source, base)| (source.as_str(), base.as_str()))
+    }
+}
+
+/// Contents of `file` (relative to `dir`) at `revision`, if it existed there.
+fn read_at_revision(dir: Option<&Path>, revision: &str, file: &str) -> Option<String> {
+    if Path::new(file).is_absolute() {
+        return None;
+    }
+    let mut command = Command::new("git");
+    command.args([
+        "show",
+        &format!("{}:./{}", revision, file.trim_start_matches("./")),
+    ]);
+    if let Some(dir) = dir {
+        command.current_dir(dir);
+    }
+    let output = command.output().ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Normalize issue text so rewordings in whitespace, case or numbers match.
+pub fn normalize_issue(issue: &str) -> String {
+    let mut normalized = String::with_capacity(issue.len());
+    let mut pending_space = false;
+    let mut in_number = false;
+    for c in issue.chars() {
+        if c.is_ascii_digit() {
+            if !in_number {
+                if pending_space && !normalized.is_empty() {
+                    normalized.push(' ');
+                }
+                normalized.push('#');
+                pending_space = false;
+            }
+            in_number = true;
+        } else if c.is_alphanumeric() {
+            if pending_space && !normalized.is_empty() {
+                normalized.push(' ');
+            }
+            normalized.extend(c.to_lowercase());
+            pending_space = false;
+            in_number = false;
+        } else {
+            pending_space = true;
+            in_number = false;
+        }
+    }
+    normalized
+}
+
+/// Stable fingerprint of a finding, independent of its line.
+pub fn fingerprint(specialist: &str, file: &str, normalized_issue: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(specialist.as_bytes());
+    hasher.update([0]);
+    hasher.update(file.trim_start_matches("./").as_bytes());
+    hasher.update([0]);
+    hasher.update(normalized_issue.as_bytes());
+    let digest = hasher.finalize();
+    digest[..8]
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Whether `source` silences `specialist` at `line` (1-based) or file-wide.
+///
+/// Only directives whose line also appears in `base`, the file as it was
+/// before the reviewed diff, are honored.
+pub fn is_inline_suppressed(source: &str, base: &str, line: Option<u32>, specialist: &str) -> bool {
+    let in_base = |text: &str| base.lines().any(|line| line.trim() == text.trim());
+    let file_wide = source
+        .lines()
+        .filter(|text| in_base(text))
+        .filter_map(parse_directive)
+        .any(|(file_level, names)| file_level && names_cover(&names, specialist));
+    if file_wide {
+        return true;
+    }
+
+    let Some(line) = line.filter(|line| *line > 0) else {
+        return false;
+    };
+    let index = line as usize - 1;
+    let lines: Vec<&str> = source.lines().collect();
+    [index.checked_sub(1), Some(index)]
+        .into_iter()
+        .flatten()
+        .filter_map(|index| lines.get(index))
+        .filter(|text| in_base(text))
+        .filter_map(|text| parse_directive(text))
+        .any(|(file_level, names)| !file_level && names_cover(&names, specialist))
+}
+
+/// Parse `forge-review: ignore[-file] <names>` into (file-level, names).
+fn parse_directive(text: &str) -> Option<(bool, Vec<String>)> {
+    let rest = text[text.find(INLINE_MARKER)? + INLINE_MARKER.len()..].trim_start();
+    let (file_level, names) = if let Some(names) = rest.strip_prefix("ignore-file") {
+        (true, names)
+    } else {
+        (false, rest.strip_prefix("ignore")?)
+    };
+    if names.starts_with(|c: char| !c.is_whitespace() && c != ',') {
+        return None;
+    }
+
+    let names = names
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .take_while(|name| !matches!(*name, "--" | "*/" | "-->"))
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            SpecialistType::from_str(name)
+                .map(|specialist| specialist.agent_name())
+                .unwrap_or_else(|_| name.to_string())
+        })
+        .collect();
+    Some((file_level, names))
+}
+
+fn names_cover(names: &[String], specialist: &str) -> bool {
+    names.iter().any(|name| name == specialist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::review::FindingSeverity;
+    use tempfile::TempDir;
+
+    fn finding(line: u32, issue: &str) -> ReviewFinding {
+        ReviewFinding::new(FindingSeverity::Error, "src/db.rs", issue).with_line(line)
+    }
+
+    #[test]
+    fn test_normalize_issue() {
+        assert_eq!(
+            normalize_issue("  SQL injection in `query()` at line 42!"),
+            "sql injection in query at line #"
+        );
+        assert_eq!(
+            normalize_issue("sql   injection in query at line 7"),
+            "sql injection in query at line #"
+        );
+    }
+
+    #[test]
+    fn test_baseline_matches_with_fuzzy_line() {
+        let mut baseline = ReviewBaseline::default();
+        assert!(baseline.add(
+            "security-sentinel",
+            &finding(40, "SQL injection at line 40")
+        ));
+
+        assert!(baseline.contains(
+            "security-sentinel",
+            &finding(45, "sql injection at line 45")
+        ));
+        assert!(!baseline.contains(
+            "security-sentinel",
+            &finding(80, "SQL injection at line 40")
+        ));
+        assert!(!baseline.contains(
+            "performance-oracle",
+            &finding(40, "SQL injection at line 40")
+        ));
+        assert!(!baseline.add(
+            "security-sentinel",
+            &finding(41, "SQL injection at line 40")
+        ));
+        assert_eq!(baseline.len(), 1);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut baseline = ReviewBaseline::default();
+        baseline.add("security-sentinel", &finding(1, "Issue"));
+
+        baseline.save(temp_dir.path()).unwrap();
+
+        assert_eq!(ReviewBaseline::load(temp_dir.path()).unwrap(), baseline);
+    }
+
+    #[test]
+    fn test_inline_suppression_on_line_or_line_above() {
+        let source = "fn a() {}\n// forge-review: ignore security\nlet q = raw;\nlet r = raw; // forge-review: ignore performance-oracle, security-sentinel\n";
+
+        assert!(is_inline_suppressed(
+            source,
+            source,
+            Some(3),
+            "security-sentinel"
+        ));
+        assert!(!is_inline_suppressed(
+            source,
+            source,
+            Some(3),
+            "performance-oracle"
+        ));
+        assert!(is_inline_suppressed(
+            source,
+            source,
+            Some(4),
+            "performance-oracle"
+        ));
+        assert!(!is_inline_suppressed(
+            source,
+            source,
+            Some(1),
+            "security-sentinel"
+        ));
+        assert!(!is_inline_suppressed(
+            source,
+            source,
+            None,
+            "security-sentinel"
+        ));
+    }
+
+    #[test]
+    fn test_inline_suppression_requires_names_and_file_level() {
+        let bare = "x // forge-review: ignore\n";
+        assert!(!is_inline_suppressed(bare, bare, Some(1), "anything"));
+        let bare_file = "# forge-review: ignore-file\nDROP TABLE users;\n";
+        assert!(!is_inline_suppressed(
+            bare_file, bare_file, None, "anything"
+        ));
+
+        let file_level = "# forge-review: ignore-file sql-migrations\nDROP TABLE users;\n";
+        assert!(is_inline_suppressed(
+            file_level,
+            file_level,
+            None,
+            "sql-migrations"
+        ));
+        let ignored = "// forge-review: ignored anything\n";
+        assert!(!is_inline_suppressed(ignored, ignored, Some(1), "anything"));
+    }
+
+    #[test]
+    fn test_inline_suppression_ignores_directives_missing_from_base() {
+        let base = "let q = raw;\n";
+        let source = "// forge-review: ignore security\nlet q = raw;\n";
+        assert!(!is_inline_suppressed(
+            source,
+            base,
+            Some(2),
+            "security-sentinel"
+        ));
+
+        let source = "// forge-review: ignore-file security\nlet q = raw;\n";
+        assert!(!is_inline_suppressed(
+            source,
+            base,
+            Some(2),
+            "security-sentinel"
+        ));
+    }
+
+    fn git_repo(files: &[(&str, &str)]) -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let run = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(temp_dir.path())
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        run(&["init", "-q"]);
+        run(&["config", "user.email", "test@example.com"]);
+        run(&["config", "user.name", "Test"]);
+        for (file, content) in files {
+            let path = temp_dir.path().join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        run(&["add", "-A"]);
+        run(&["commit", "-q", "-m", "base"]);
+        temp_dir
+    }
+
+    #[test]
+    fn test_suppressor_moves_findings_and_downgrades_verdict() {
+        let source = "// forge-review: ignore performance\nlet q = raw;\n";
+        let temp_dir = git_repo(&[("src/db.rs", source)]);
+        let mut baseline = ReviewBaseline::default();
+        baseline.add("security-sentinel", &finding(10, "Accepted risk"));
+        let mut suppressor = Suppressor::new(
+            baseline,
+            Some(temp_dir.path().to_path_buf()),
+            Some("HEAD".to_string()),
+        );
+
+        let report = ReviewReport::new("05", "security-sentinel", ReviewVerdict::Fail)
+            .add_finding(finding(10, "Accepted risk"));
+        let report = suppressor.apply(report, true);
+        assert!(report.findings.is_empty());
+        assert_eq!(report.suppressed[0].reason, SuppressionReason::Baseline);
+        assert_eq!(report.verdict, ReviewVerdict::Pass);
+
+        let report = ReviewReport::new("05", "performance-oracle", ReviewVerdict::Warn)
+            .add_finding(
+                ReviewFinding::new(FindingSeverity::Warning, "src/db.rs", "Slow query")
+                    .with_line(2),
+            );
+        let report = suppressor.apply(report, false);
+        assert!(report.findings.is_empty());
+        assert_eq!(
+            report.suppressed[0].reason,
+            SuppressionReason::InlineComment
+        );
+        assert_eq!(report.verdict, ReviewVerdict::Pass);
+    }
+
+    #[test]
+    fn test_suppressor_ignores_directives_added_by_the_reviewed_diff() {
+        let temp_dir = git_repo(&[("src/db.rs", "let q = raw;\n")]);
+        std::fs::write(
+            temp_dir.path().join("src/db.rs"),
+            "// forge-review: ignore security performance\nlet q = raw;\n",
+        )
+        .unwrap();
+        let mut suppressor = Suppressor::new(
+            ReviewBaseline::default(),
+            Some(temp_dir.path().to_path_buf()),
+            Some("HEAD".to_string()),
+        );
+
+        let report = ReviewReport::new("05", "security-sentinel", ReviewVerdict::Fail)
+            .add_finding(finding(2, "Raw query"));
+        let report = suppressor.apply(report, true);
+        assert_eq!(report.findings.len(), 1);
+        assert!(report.suppressed.is_empty());
+        assert_eq!(report.verdict, ReviewVerdict::Fail);
+
+        let report = ReviewReport::new("05", "performance-oracle", ReviewVerdict::Warn)
+            .add_finding(finding(2, "Slow query"));
+        let report = suppressor.apply(report, false);
+        assert_eq!(report.findings.len(), 1);
+    }
+
+    #[test]
+    fn test_suppressor_never_inline_suppresses_gating_findings() {
+        let temp_dir = git_repo(&[(
+            "src/db.rs",
+            "// forge-review: ignore security\nlet q = raw;\n",
+        )]);
+        let mut suppressor = Suppressor::new(
+            ReviewBaseline::default(),
+            Some(temp_dir.path().to_path_buf()),
+            Some("HEAD".to_string()),
+        );
+
+        let report = ReviewReport::new("05", "security-sentinel", ReviewVerdict::Fail)
+            .add_finding(finding(2, "Raw query"));
+        let report = suppressor.apply(report, true);
+
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.verdict, ReviewVerdict::Fail);
+    }
+
+    #[test]
+    fn test_suppressor_keeps_verdict_with_remaining_findings() {
+        let report = ReviewReport::new("05", "security-sentinel", ReviewVerdict::Fail)
+            .add_finding(finding(2, "Raw query"));
+
+        let mut suppressor = Suppressor::new(ReviewBaseline::default(), None, None);
+        let report = suppressor.apply(report, true);
+
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.verdict, ReviewVerdict::Fail);
+    }
+
+    #[test]
+    fn test_suppressor_rederives_verdict_from_remaining_findings() {
+        let mut baseline = ReviewBaseline::default();
+        baseline.add("security-sentinel", &finding(10, "Accepted risk"));
+        let mixed = || {
+            ReviewReport::new("05", "security-sentinel", ReviewVerdict::Fail)
+                .add_finding(finding(10, "Accepted risk"))
+                .add_finding(
+                    ReviewFinding::new(FindingSeverity::Warning, "src/db.rs", "Slow query")
+                        .with_line(20),
+                )
+        };
+
+        let mut suppressor = Suppressor::new(baseline.clone(), None, None);
+        let report = suppressor.apply(mixed(), true);
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.suppressed.len(), 1);
+        assert_eq!(report.verdict, ReviewVerdict::Warn);
+
+        let still_failing = mixed().add_finding(finding(30, "Unescaped input"));
+        let report = suppressor.apply(still_failing, true);
+        assert_eq!(report.verdict, ReviewVerdict::Fail);
+
+        let report = suppressor.apply(mixed().add_finding(finding(30, "Unescaped input")), false);
+        assert_eq!(report.verdict, ReviewVerdict::Warn);
+    }
+}
@@ -0,0 +1,425 @@
+//! File-defined custom review specialists.
+//!
+//! Custom specialists referenced from `[reviews]` (or a phase's review
+//! settings) can be described in `.forge/reviewers/<name>.toml` or
+//! `.forge/reviewers/<name>.md`. A definition carries the full prompt
+//! template, focus areas, a severity mapping, the default gating behaviour,
+//! file-glob applicability and an optional model override.
+//!
+//! ## TOML Format
+//!
+//! ```toml
+//! description = "Reviews SQL migrations"
+//! gate = true
+//! applies_to = ["**/*.sql"]
+//! model = "opus"
+//! focus_areas = ["Destructive migrations", "Missing indexes"]
+//! prompt = """
+//! Review the migrations changed in phase {phase} ({phase_name}):
+//! {files_changed}
+//! """
+//!
+//! [severity_map]
+//! critical = "error"
+//! minor = "info"
+//! ```
+//!
+//! ## Markdown Format
+//!
+//! The same fields go in YAML frontmatter and the body is the prompt template:
+//!
+//! ```markdown
+//! ---
+//! gate: true
+//! applies_to: ["**/*.sql"]
+//! ---
+//! Review the migrations changed in phase {phase}.
+//! ```
+//!
+//! Templates may reference `{phase}`, `{phase_name}`, `{files_changed}`,
+//! `{focus_areas}` and `{additional_context}`.
+//!
+//! A definition with a `command` instead of a prompt is a deterministic tool
+//! reviewer; see [`super::tools`].
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use super::FindingSeverity;
+use super::prompt_loader::split_frontmatter;
+use super::tools::ToolOutputFormat;
+
+/// Directory under the forge dir that holds reviewer definitions.
+const REVIEWERS_DIR: &str = "reviewers";
+
+/// A custom review specialist loaded from `.forge/reviewers/`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ReviewerDefinition {
+    /// Reviewer name; always the file stem.
+    #[serde(default)]
+    pub name: String,
+    /// One-line description shown by `forge reviewers list`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Prompt template sent to the reviewer (LLM reviewers only).
+    #[serde(default)]
+    pub prompt: String,
+    /// Shell command to run instead of an LLM; makes this a tool reviewer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Output format of `command`.
+    #[serde(default)]
+    pub format: ToolOutputFormat,
+    /// Keep tool findings in files the phase did not change.
+    #[serde(default)]
+    pub include_unchanged_files: bool,
+    /// Focus areas used when `[reviews]` does not override them.
+    #[serde(default)]
+    pub focus_areas: Vec<String>,
+    /// Maps severities emitted by the reviewer (e.g. `critical`) to forge severities.
+    #[serde(default)]
+    pub severity_map: BTreeMap<String, FindingSeverity>,
+    /// Gating default when `[reviews]` does not set `gate`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gate: Option<bool>,
+    /// Only review when a changed file matches one of these globs.
+    #[serde(default)]
+    pub applies_to: Vec<String>,
+    /// Model override passed to the review CLI.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// File the definition was loaded from.
+    #[serde(skip)]
+    pub source: PathBuf,
+}
+
+impl ReviewerDefinition {
+    /// Parse a definition from a `.toml` or `.md` file.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read reviewer definition {}", path.display()))?;
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .with_context(|| format!("Invalid reviewer file name: {}", path.display()))?;
+
+        let mut definition = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str::<Self>(&content)
+                .with_context(|| format!("Failed to parse {}", path.display()))?,
+            Some("md") => {
+                let (frontmatter, body) = split_frontmatter(&content)
+                    .map_err(|reason| anyhow::anyhow!("{}: {reason}", path.display()))?;
+                let mut definition: Self = serde_yaml::from_str(frontmatter)
+                    .with_context(|| format!("Failed to parse {}", path.display()))?;
+                definition.prompt = body;
+                definition
+            }
+            _ => bail!("Unsupported reviewer definition: {}", path.display()),
+        };
+
+        definition.name = name.to_string();
+        definition.source = path.to_path_buf();
+        definition.validate()?;
+        Ok(definition)
+    }
+
+    fn validate(&self) -> Result<()> {
+        match &self.command {
+            Some(command) if command.trim().is_empty() => bail!(
+                "Reviewer `{}` has an empty command ({})",
+                self.name,
+                self.source.display()
+            ),
+            None if self.prompt.trim().is_empty() => bail!(
+                "Reviewer `{}` has an empty prompt ({})",
+                self.name,
+                self.source.display()
+            ),
+            _ => {}
+        }
+        for pattern in &self.applies_to {
+            glob::Pattern::new(pattern).with_context(|| {
+                format!(
+                    "Reviewer `{}` has an invalid applies_to glob `{pattern}`",
+                    self.name
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Whether this reviewer runs a command rather than an LLM.
+    pub fn is_tool(&self) -> bool {
+        self.command.is_some()
+    }
+
+    /// Whether this reviewer should run for the given changed files.
+    ///
+    /// Reviewers without `applies_to` always run, as do all reviewers when
+    /// the changed files are unknown.
+    pub fn applies_to_files(&self, files_changed: &[String]) -> bool {
+        if self.applies_to.is_empty() || files_changed.is_empty() {
+            return true;
+        }
+
+        let patterns = self
+            .applies_to
+            .iter()
+            .filter_map(|pattern| glob::Pattern::new(pattern).ok())
+            .collect::<Vec<_>>();
+        files_changed
+            .iter()
+            .any(|file| patterns.iter().any(|pattern| pattern.matches(file)))
+    }
+
+    /// Translate a reviewer-emitted severity label through `severity_map`.
+    pub fn map_severity(&self, label: &str) -> Option<FindingSeverity> {
+        self.severity_map
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(label))
+            .map(|(_, severity)| *severity)
+    }
+
+    /// Substitute the phase context into the prompt template.
+    pub fn render_prompt(
+        &self,
+        phase: &str,
+        phase_name: &str,
+        files_changed: &[String],
+        focus_areas: &[&str],
+        additional_context: Option<&str>,
+    ) -> String {
+        let bullet_list = |items: &mut dyn Iterator<Item = &str>| {
+            items
+                .map(|item| format!("- {item}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let files = if files_changed.is_empty() {
+            "No specific files listed - review the entire phase output.".to_string()
+        } else {
+            bullet_list(&mut files_changed.iter().map(String::as_str))
+        };
+
+        self.prompt
+            .replace("{phase}", phase)
+            .replace("{phase_name}", phase_name)
+            .replace("{files_changed}", &files)
+            .replace(
+                "{focus_areas}",
+                &bullet_list(&mut focus_areas.iter().copied()),
+            )
+            .replace("{additional_context}", additional_context.unwrap_or(""))
+    }
+}
+
+/// All reviewer definitions found under `.forge/reviewers/`.
+#[derive(Debug, Clone, Default)]
+pub struct ReviewerRegistry {
+    definitions: BTreeMap<String, ReviewerDefinition>,
+}
+
+impl ReviewerRegistry {
+    /// Directory holding reviewer definitions for the given forge dir.
+    pub fn reviewers_dir(forge_dir: &Path) -> PathBuf {
+        forge_dir.join(REVIEWERS_DIR)
+    }
+
+    /// Load every `.toml` and `.md` definition; a missing directory yields an empty registry.
+    pub fn load(forge_dir: &Path) -> Result<Self> {
+        let dir = Self::reviewers_dir(forge_dir);
+        if !dir.is_dir() {
+            return Ok(Self::default());
+        }
+
+        let mut paths = std::fs::read_dir(&dir)
+            .with_context(|| format!("Failed to read {}", dir.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.is_file()
+                    && matches!(
+                        path.extension().and_then(|ext| ext.to_str()),
+                        Some("toml" | "md")
+                    )
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        let mut definitions = BTreeMap::new();
+        for path in paths {
+            let definition = ReviewerDefinition::from_file(&path)?;
+            if let Some(existing) = definitions.get(&definition.name) {
+                let existing: &ReviewerDefinition = existing;
+                bail!(
+                    "Reviewer `{}` is defined twice: {} and {}",
+                    definition.name,
+                    existing.source.display(),
+                    path.display()
+                );
+            }
+            definitions.insert(definition.name.clone(), definition);
+        }
+
+        Ok(Self { definitions })
+    }
+
+    /// Look up a definition by specialist agent name (e.g. `sql-migrations`).
+    pub fn get(&self, agent_name: &str) -> Option<&ReviewerDefinition> {
+        self.definitions.get(agent_name)
+    }
+
+    /// Definitions in name order.
+    pub fn definitions(&self) -> impl Iterator<Item = &ReviewerDefinition> {
+        self.definitions.values()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_reviewer(forge_dir: &Path, file_name: &str, content: &str) -> PathBuf {
+        let dir = ReviewerRegistry::reviewers_dir(forge_dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(file_name);
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_load_toml_definition() {
+        let temp_dir = TempDir::new().unwrap();
+        write_reviewer(
+            temp_dir.path(),
+            "sql-migrations.toml",
+            r#"
+description = "Reviews SQL migrations"
+gate = true
+applies_to = ["**/*.sql"]
+model = "opus"
+focus_areas = ["Destructive migrations"]
+prompt = "Review phase {phase}:\n{files_changed}"
+
+[severity_map]
+critical = "error"
+minor = "info"
+"#,
+        );
+
+        let registry = ReviewerRegistry::load(temp_dir.path()).unwrap();
+        let definition = registry.get("sql-migrations").unwrap();
+
+        assert_eq!(definition.name, "sql-migrations");
+        assert_eq!(definition.gate, Some(true));
+        assert_eq!(definition.model.as_deref(), Some("opus"));
+        assert_eq!(definition.focus_areas, vec!["Destructive migrations"]);
+        assert_eq!(
+            definition.map_severity("CRITICAL"),
+            Some(FindingSeverity::Error)
+        );
+        assert_eq!(definition.map_severity("unknown"), None);
+    }
+
+    #[test]
+    fn test_load_markdown_definition_uses_body_as_prompt() {
+        let temp_dir = TempDir::new().unwrap();
+        write_reviewer(
+            temp_dir.path(),
+            "api-compliance.md",
+            "---\ngate: false\nfocus_areas:\n  - REST conventions\n---\nCheck {phase_name} for API drift.\n",
+        );
+
+        let registry = ReviewerRegistry::load(temp_dir.path()).unwrap();
+        let definition = registry.get("api-compliance").unwrap();
+
+        assert_eq!(definition.gate, Some(false));
+        assert_eq!(definition.prompt, "Check {phase_name} for API drift.\n");
+        assert_eq!(definition.focus_areas, vec!["REST conventions"]);
+    }
+
+    #[test]
+    fn test_load_tool_definition_without_prompt() {
+        let temp_dir = TempDir::new().unwrap();
+        write_reviewer(
+            temp_dir.path(),
+            "clippy.toml",
+            "command = \"cargo clippy --message-format=json\"\nformat = \"cargo\"\n",
+        );
+
+        let registry = ReviewerRegistry::load(temp_dir.path()).unwrap();
+        let definition = registry.get("clippy").unwrap();
+
+        assert!(definition.is_tool());
+        assert_eq!(definition.format, ToolOutputFormat::Cargo);
+        assert!(!definition.include_unchanged_files);
+    }
+
+    #[test]
+    fn test_load_missing_directory_is_empty() {
+        let temp_dir = TempDir::new().unwrap();
+
+        assert!(ReviewerRegistry::load(temp_dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_load_rejects_duplicate_and_invalid_definitions() {
+        let temp_dir = TempDir::new().unwrap();
+        write_reviewer(temp_dir.path(), "dup.toml", "prompt = \"a\"");
+        write_reviewer(temp_dir.path(), "dup.md", "---\ngate: true\n---\nb");
+
+        let error = ReviewerRegistry::load(temp_dir.path()).unwrap_err();
+        assert!(error.to_string().contains("defined twice"));
+
+        let temp_dir = TempDir::new().unwrap();
+        write_reviewer(temp_dir.path(), "empty.toml", "gate = true");
+        assert!(ReviewerRegistry::load(temp_dir.path()).is_err());
+
+        let temp_dir = TempDir::new().unwrap();
+        write_reviewer(
+            temp_dir.path(),
+            "bad-glob.toml",
+            "prompt = \"x\"\napplies_to = [\"[\"]",
+        );
+        assert!(ReviewerRegistry::load(temp_dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_applies_to_files() {
+        let definition = ReviewerDefinition {
+            applies_to: vec!["**/*.sql".to_string()],
+            ..ReviewerDefinition::default()
+        };
+
+        assert!(definition.applies_to_files(&["db/migrations/001_init.sql".to_string()]));
+        assert!(!definition.applies_to_files(&["src/main.rs".to_string()]));
+        assert!(definition.applies_to_files(&[]));
+        assert!(ReviewerDefinition::default().applies_to_files(&["src/main.rs".to_string()]));
+    }
+
+    #[test]
+    fn test_render_prompt_substitutes_placeholders() {
+        let definition = ReviewerDefinition {
+            prompt: "{phase} {phase_name}\n{files_changed}\n{focus_areas}\n{additional_context}"
+                .to_string(),
+            ..ReviewerDefinition::default()
+        };
+
+        let prompt = definition.render_prompt(
+            "05",
+            "Migrations",
+            &["db/001.sql".to_string()],
+            &["Locks"],
+            Some("extra"),
+        );
+
+        assert_eq!(prompt, "05 Migrations\n- db/001.sql\n- Locks\nextra");
+    }
+}
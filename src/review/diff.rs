@@ -0,0 +1,469 @@
+//! Diff sources for standalone reviews (`forge review`).
+//!
+//! Review specialists normally run after a phase with the files it changed.
+//! A [`DiffSource`] lets them run on any diff instead: a git revision range,
+//! the staged changes, a patch file (e.g. a downloaded PR), or by default
+//! all uncommitted changes.
+//!
+//! Tool reviewers and inline suppressions read files, so a range, the staged
+//! changes or a patch is reviewed in a [`ReviewCheckout`] of the reviewed
+//! revision rather than in the working tree.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result, bail};
+
+/// Diffs longer than this are truncated before being sent to specialists.
+const MAX_DIFF_CHARS: usize = 100_000;
+
+/// Where the diff under review comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffSource {
+    /// Uncommitted changes (staged and unstaged) against `HEAD`.
+    WorkingTree,
+    /// Staged changes only.
+    Staged,
+    /// A git revision range such as `origin/main..HEAD`.
+    Range(String),
+    /// A unified diff read from a file.
+    Patch(PathBuf),
+}
+
+impl DiffSource {
+    /// Short description used as the review's phase name.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::WorkingTree => "uncommitted changes".to_string(),
+            Self::Staged => "staged changes".to_string(),
+            Self::Range(range) => range.clone(),
+            Self::Patch(path) => path.display().to_string(),
+        }
+    }
+
+    /// Load the diff text and changed files.
+    pub fn load(&self, repo_dir: &Path) -> Result<ReviewDiff> {
+        let text = match self {
+            Self::Patch(path) => std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read patch {}", path.display()))?,
+            _ => git(repo_dir, &self.git_args(&[]))?,
+        };
+        let files = match self {
+            Self::Patch(_) => files_from_patch(&text),
+            _ => git(
+                repo_dir,
+                &self.git_args(&["--name-only", "--diff-filter=d"]),
+            )?
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect(),
+        };
+
+        Ok(ReviewDiff {
+            description: self.describe(),
+            text,
+            files,
+        })
+    }
+
+    /// Check out the reviewed revision when it differs from the working tree.
+    ///
+    /// A range is checked out at its end; staged changes and a patch are
+    /// applied on top of `HEAD`, so unstaged edits are not reviewed. The
+    /// working tree needs no checkout.
+    pub fn checkout(&self, repo_dir: &Path) -> Result<Option<ReviewCheckout>> {
+        match self {
+            Self::WorkingTree => Ok(None),
+            Self::Staged => {
+                let staged = git(repo_dir, &["diff", "--cached", "--binary"])?;
+                let checkout = ReviewCheckout::create(repo_dir, "HEAD")?;
+                if !staged.trim().is_empty() {
+                    git_with_input(checkout.path(), &["apply", "--binary", "-"], &staged)
+                        .context("Staged changes do not apply to HEAD")?;
+                }
+                Ok(Some(checkout))
+            }
+            Self::Range(range) => match range_end(range) {
+                Some(revision) => ReviewCheckout::create(repo_dir, revision).map(Some),
+                None => Ok(None),
+            },
+            Self::Patch(patch) => {
+                let patch = std::path::absolute(patch)
+                    .with_context(|| format!("Failed to resolve patch {}", patch.display()))?;
+                let checkout = ReviewCheckout::create(repo_dir, "HEAD")?;
+                git(checkout.path(), &["apply", &patch.to_string_lossy()]).with_context(|| {
+                    format!(
+                        "Patch {} does not apply to HEAD; check out its base first",
+                        patch.display()
+                    )
+                })?;
+                Ok(Some(checkout))
+            }
+        }
+    }
+
+    /// Resolve the revision the diff starts from.
+    ///
+    /// Inline suppressions are only honored when they already exist there.
+    /// A symmetric range (`a...b`) starts at the merge base of its ends.
+    pub fn base(&self, repo_dir: &Path) -> Result<String> {
+        let revision = match self {
+            Self::WorkingTree | Self::Staged | Self::Patch(_) => "HEAD".to_string(),
+            Self::Range(range) => match range_end(range) {
+                Some(end) if range.contains("...") => {
+                    git(repo_dir, &["merge-base", range_start(range), end])?
+                }
+                _ => range_start(range).to_string(),
+            },
+        };
+        let revision = git(repo_dir, &["rev-parse", "--verify", revision.trim()])?;
+        Ok(revision.trim().to_string())
+    }
+
+    fn git_args<'a>(&'a self, extra: &[&'a str]) -> Vec<&'a str> {
+        let mut args = vec!["diff"];
+        args.extend_from_slice(extra);
+        match self {
+            Self::WorkingTree => args.push("HEAD"),
+            Self::Staged => args.push("--cached"),
+            Self::Range(range) => args.push(range),
+            Self::Patch(_) => {}
+        }
+        args
+    }
+}
+
+/// A loaded diff ready for review.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewDiff {
+    /// What was diffed.
+    pub description: String,
+    /// Unified diff text.
+    pub text: String,
+    /// Files added or modified by the diff.
+    pub files: Vec<String>,
+}
+
+impl ReviewDiff {
+    /// Whether the diff has no changes.
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Review context handed to specialists, truncated to a sane size.
+    pub fn review_context(&self) -> String {
+        let mut diff = self.text.as_str();
+        let mut note = "";
+        if diff.len() > MAX_DIFF_CHARS {
+            let mut end = MAX_DIFF_CHARS;
+            while !diff.is_char_boundary(end) {
+                end -= 1;
+            }
+            diff = &diff[..end];
+            note = "\n[diff truncated]";
+        }
+        format!(
+            "You are reviewing {} rather than a completed phase. \
+             Only report issues introduced by this diff.\n\n```diff\n{}\n```{}",
+            self.description,
+            diff.trim_end(),
+            note
+        )
+    }
+}
+
+/// A temporary worktree holding the reviewed revision, removed on drop.
+#[derive(Debug)]
+pub struct ReviewCheckout {
+    repo_dir: PathBuf,
+    path: PathBuf,
+}
+
+impl ReviewCheckout {
+    fn create(repo_dir: &Path, revision: &str) -> Result<Self> {
+        let path = std::env::temp_dir().join(format!("forge-review-{}", uuid::Uuid::new_v4()));
+        git(
+            repo_dir,
+            &[
+                "worktree",
+                "add",
+                "--detach",
+                "--quiet",
+                &path.to_string_lossy(),
+                revision,
+            ],
+        )
+        .with_context(|| format!("Failed to check out {} for review", revision))?;
+        Ok(Self {
+            repo_dir: repo_dir.to_path_buf(),
+            path,
+        })
+    }
+
+    /// Directory holding the reviewed files.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ReviewCheckout {
+    fn drop(&mut self) {
+        let _ = git(
+            &self.repo_dir,
+            &[
+                "worktree",
+                "remove",
+                "--force",
+                &self.path.to_string_lossy(),
+            ],
+        );
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// The revision at the end of a range, or `None` when the range ends at
+/// the working tree (`git diff <rev>`).
+fn range_end(range: &str) -> Option<&str> {
+    let (_, end) = range.split_once("...").or_else(|| range.split_once(".."))?;
+    Some(if end.is_empty() { "HEAD" } else { end })
+}
+
+/// The revision at the start of a range; a bare revision is its own start.
+fn range_start(range: &str) -> &str {
+    let start = range
+        .split_once("...")
+        .or_else(|| range.split_once(".."))
+        .map_or(range, |(start, _)| start);
+    if start.is_empty() { "HEAD" } else { start }
+}
+
+/// Files added or modified by a unified diff (deleted files are skipped).
+pub fn files_from_patch(patch: &str) -> Vec<String> {
+    let mut files: Vec<String> = Vec::new();
+    for line in patch.lines() {
+        let Some(path) = line.strip_prefix("+++ ") else {
+            continue;
+        };
+        let path = path.split('\t').next().unwrap_or(path).trim();
+        if path == "/dev/null" {
+            continue;
+        }
+        let path = path.strip_prefix("b/").unwrap_or(path).to_string();
+        if !files.contains(&path) {
+            files.push(path);
+        }
+    }
+    files
+}
+
+fn git(repo_dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(repo_dir)
+        .output()
+        .context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn git_with_input(repo_dir: &Path, args: &[&str], input: &str) -> Result<String> {
+    let mut child = Command::new("git")
+        .args(args)
+        .current_dir(repo_dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run git")?;
+    child
+        .stdin
+        .take()
+        .context("Failed to open git stdin")?
+        .write_all(input.as_bytes())
+        .context("Failed to write to git")?;
+    let output = child.wait_with_output().context("Failed to run git")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const PATCH: &str = "\
+diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1 +1 @@
+-old
++new
+diff --git a/src/gone.rs b/src/gone.rs
+--- a/src/gone.rs
++++ /dev/null
+@@ -1 +0,0 @@
+-gone
+diff --git a/docs/new.md b/docs/new.md
+--- /dev/null
++++ b/docs/new.md\t2026-01-01
+@@ -0,0 +1 @@
++hello
+";
+
+    #[test]
+    fn test_files_from_patch_skips_deleted_files() {
+        assert_eq!(files_from_patch(PATCH), vec!["src/lib.rs", "docs/new.md"]);
+    }
+
+    #[test]
+    fn test_load_patch_source() {
+        let temp_dir = TempDir::new().unwrap();
+        let patch = temp_dir.path().join("pr.patch");
+        std::fs::write(&patch, PATCH).unwrap();
+
+        let diff = DiffSource::Patch(patch).load(temp_dir.path()).unwrap();
+
+        assert!(!diff.is_empty());
+        assert_eq!(diff.files.len(), 2);
+        assert!(diff.review_context().contains("```diff\ndiff --git"));
+    }
+
+    #[test]
+    fn test_load_staged_changes_from_git() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        let run = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        run(&["init", "-q"]);
+        run(&["config", "user.email", "test@example.com"]);
+        run(&["config", "user.name", "Test"]);
+        std::fs::write(dir.join("a.txt"), "one\n").unwrap();
+        run(&["add", "a.txt"]);
+        run(&["commit", "-q", "-m", "init"]);
+        std::fs::write(dir.join("a.txt"), "two\n").unwrap();
+        std::fs::write(dir.join("b.txt"), "new\n").unwrap();
+        run(&["add", "b.txt"]);
+
+        let staged = DiffSource::Staged.load(dir).unwrap();
+        let working = DiffSource::WorkingTree.load(dir).unwrap();
+
+        assert_eq!(staged.files, vec!["b.txt"]);
+        assert_eq!(working.files, vec!["a.txt", "b.txt"]);
+        assert!(working.text.contains("+two"));
+    }
+
+    #[test]
+    fn test_range_end() {
+        assert_eq!(range_end("origin/main..HEAD~1"), Some("HEAD~1"));
+        assert_eq!(range_end("main...feature"), Some("feature"));
+        assert_eq!(range_end("main.."), Some("HEAD"));
+        assert_eq!(range_end("HEAD~2"), None);
+    }
+
+    #[test]
+    fn test_range_start() {
+        assert_eq!(range_start("origin/main..HEAD~1"), "origin/main");
+        assert_eq!(range_start("main...feature"), "main");
+        assert_eq!(range_start("..feature"), "HEAD");
+        assert_eq!(range_start("HEAD~2"), "HEAD~2");
+    }
+
+    #[test]
+    fn test_checkout_reads_reviewed_revision() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        let run = |args: &[&str]| {
+            let status = Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        run(&["init", "-q"]);
+        run(&["config", "user.email", "test@example.com"]);
+        run(&["config", "user.name", "Test"]);
+        std::fs::write(dir.join("a.txt"), "one\n").unwrap();
+        run(&["add", "a.txt"]);
+        run(&["commit", "-q", "-m", "one"]);
+        std::fs::write(dir.join("a.txt"), "two\n").unwrap();
+        run(&["commit", "-q", "-am", "two"]);
+        std::fs::write(dir.join("a.txt"), "uncommitted\n").unwrap();
+
+        let range = DiffSource::Range("HEAD~1..HEAD~1".to_string());
+        let checkout = range.checkout(dir).unwrap().unwrap();
+        assert_eq!(
+            std::fs::read_to_string(checkout.path().join("a.txt")).unwrap(),
+            "one\n"
+        );
+        let checkout_path = checkout.path().to_path_buf();
+        drop(checkout);
+        assert!(!checkout_path.exists());
+
+        let patch = dir.join("pr.patch");
+        std::fs::write(
+            &patch,
+            "--- a/a.txt\n+++ b/a.txt\n@@ -1 +1 @@\n-two\n+patched\n",
+        )
+        .unwrap();
+        let checkout = DiffSource::Patch(patch).checkout(dir).unwrap().unwrap();
+        assert_eq!(
+            std::fs::read_to_string(checkout.path().join("a.txt")).unwrap(),
+            "patched\n"
+        );
+
+        run(&["checkout", "-q", "a.txt"]);
+        std::fs::write(dir.join("a.txt"), "staged\n").unwrap();
+        std::fs::write(dir.join("b.txt"), "new\n").unwrap();
+        run(&["add", "a.txt", "b.txt"]);
+        std::fs::write(dir.join("a.txt"), "unstaged\n").unwrap();
+        let checkout = DiffSource::Staged.checkout(dir).unwrap().unwrap();
+        assert_eq!(
+            std::fs::read_to_string(checkout.path().join("a.txt")).unwrap(),
+            "staged\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(checkout.path().join("b.txt")).unwrap(),
+            "new\n"
+        );
+
+        assert!(DiffSource::WorkingTree.checkout(dir).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_review_context_truncates_large_diffs() {
+        let diff = ReviewDiff {
+            description: "HEAD~1..HEAD".to_string(),
+            text: "+".repeat(MAX_DIFF_CHARS + 10),
+            files: Vec::new(),
+        };
+
+        let context = diff.review_context();
+
+        assert!(context.starts_with("You are reviewing HEAD~1..HEAD"));
+        assert!(context.ends_with("[diff truncated]"));
+    }
+}
@@ -3,7 +3,11 @@
 //! The dispatcher coordinates review specialist execution:
 //! - Spawns review specialists (optionally in parallel)
 //! - Collects findings into a ReviewAggregation
-//! - Handles gating reviews via the arbiter
+//! - Runs deterministic tool reviewers before LLM reviewers
+//! - Suppresses baselined findings and `forge-review: ignore` comments
+//! - Handles gating reviews via the arbiter, recording each decision
+//! - Re-checks tracked fix items after a fix iteration
+//! - Saves each run (and configured exports) to `.forge/reviews/`
 //!
 //! ## Usage
 //!
@@ -28,14 +32,19 @@
 //! # }
 //! ```
 
+use crate::review::history::DEFAULT_PRECEDENT_LIMIT;
 use crate::review::{
-    ArbiterConfig, ArbiterExecutor, ArbiterInput, ArbiterResult, FindingSeverity, PromptLoader,
-    ReviewAggregation, ReviewFinding, ReviewReport, ReviewSpecialist, ReviewVerdict,
+    ArbiterConfig, ArbiterExecutor, ArbiterHistory, ArbiterInput, ArbiterResult, FindingSeverity,
+    FixPlan, ManualReviewHandler, PendingReview, PromptLoader, ReviewAggregation, ReviewBaseline,
+    ReviewExportFormat, ReviewFinding, ReviewRecord, ReviewReport, ReviewSpecialist, ReviewStore,
+    ReviewTimedOut, ReviewVerdict, ReviewerDefinition, ReviewerRegistry, SpecialistType,
+    Suppressor, tools,
 };
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::process::Command;
@@ -64,8 +73,18 @@ pub struct DispatcherConfig {
     pub verbose: bool,
     /// Arbiter configuration for handling failures.
     pub arbiter: ArbiterConfig,
+    /// Whether gating failures are handed to the arbiter.
+    #[serde(default = "default_arbiter_enabled")]
+    pub arbiter_enabled: bool,
     /// Optional forge directory for loading file-based review prompts.
     pub forge_dir: Option<PathBuf>,
+    /// Formats written to `.forge/reviews/` after each dispatch.
+    #[serde(default)]
+    pub export_formats: Vec<ReviewExportFormat>,
+}
+
+fn default_arbiter_enabled() -> bool {
+    true
 }
 
 impl Default for DispatcherConfig {
@@ -78,7 +97,9 @@ impl Default for DispatcherConfig {
             skip_permissions: true,
             verbose: false,
             arbiter: ArbiterConfig::default(),
+            arbiter_enabled: true,
             forge_dir: None,
+            export_formats: Vec::new(),
         }
     }
 }
@@ -126,11 +147,26 @@ impl DispatcherConfig {
         self
     }
 
+    /// Enable or disable the arbiter for gating failures.
+    ///
+    /// Without the arbiter a gating failure is reported as-is and the
+    /// dispatch result cannot proceed.
+    pub fn with_arbiter_enabled(mut self, enabled: bool) -> Self {
+        self.arbiter_enabled = enabled;
+        self
+    }
+
     /// Set the forge directory for file-based review prompts.
     pub fn with_forge_dir(mut self, dir: PathBuf) -> Self {
         self.forge_dir = Some(dir);
         self
     }
+
+    /// Set the export formats written alongside each persisted review run.
+    pub fn with_export_formats(mut self, formats: Vec<ReviewExportFormat>) -> Self {
+        self.export_formats = formats;
+        self
+    }
 }
 
 /// Configuration for reviewing a specific phase.
@@ -152,6 +188,10 @@ pub struct PhaseReviewConfig {
     /// Additional context to provide to reviewers.
     #[serde(default)]
     pub additional_context: Option<String>,
+    /// Revision the reviewed changes start from; inline suppressions are
+    /// only honored when they already exist there.
+    #[serde(default)]
+    pub base_revision: Option<String>,
 }
 
 impl PhaseReviewConfig {
@@ -165,6 +205,7 @@ impl PhaseReviewConfig {
             iterations_used: 0,
             files_changed: Vec::new(),
             additional_context: None,
+            base_revision: None,
         }
     }
 
@@ -196,6 +237,12 @@ impl PhaseReviewConfig {
         self
     }
 
+    /// Set the revision the reviewed changes start from.
+    pub fn with_base_revision(mut self, revision: &str) -> Self {
+        self.base_revision = Some(revision.to_string());
+        self
+    }
+
     /// Add additional context for reviewers.
     pub fn with_additional_context(mut self, context: &str) -> Self {
         self.additional_context = Some(context.to_string());
@@ -306,12 +353,22 @@ impl DispatchResult {
 /// The review dispatcher coordinates review specialist execution.
 pub struct ReviewDispatcher {
     config: DispatcherConfig,
+    manual_handler: Option<Arc<dyn ManualReviewHandler>>,
 }
 
 impl ReviewDispatcher {
     /// Create a new review dispatcher with the given configuration.
     pub fn new(config: DispatcherConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            manual_handler: None,
+        }
+    }
+
+    /// Set the handler asked for decisions when the arbiter is in manual mode.
+    pub fn with_manual_handler(mut self, handler: Arc<dyn ManualReviewHandler>) -> Self {
+        self.manual_handler = Some(handler);
+        self
     }
 
     /// Create a dispatcher with default configuration.
@@ -350,13 +407,81 @@ impl ReviewDispatcher {
             );
         }
 
-        // Run review specialists
-        let reports = if self.config.parallel {
-            self.run_parallel_reviews(&review_config).await?
-        } else {
-            self.run_sequential_reviews(&review_config).await?
+        // Pair custom specialists with their definitions and drop the ones
+        // whose `applies_to` globs match none of the changed files
+        let registry = self.load_reviewer_registry()?;
+        let specialists: Vec<_> = review_config
+            .specialists
+            .iter()
+            .filter_map(|specialist| {
+                let definition = custom_definition(&registry, specialist);
+                if definition.is_some_and(|definition| {
+                    !definition.applies_to_files(&review_config.files_changed)
+                }) {
+                    debug!(
+                        specialist = %specialist.display_name(),
+                        "Skipping reviewer: no changed files match applies_to"
+                    );
+                    return None;
+                }
+                Some((specialist, definition))
+            })
+            .collect();
+
+        // Tool reviewers are cheap and deterministic, so they run first; a
+        // gating tool failure skips the LLM reviewers entirely
+        let (tool_specialists, llm_specialists): (Vec<_>, Vec<_>) = specialists
+            .into_iter()
+            .partition(|(_, definition)| definition.is_some_and(ReviewerDefinition::is_tool));
+
+        // Move baselined and inline-suppressed findings out of gating before
+        // the tool gate is judged, so accepted findings cannot block
+        let mut suppressor = Suppressor::new(
+            self.load_baseline(),
+            self.config.working_dir.clone(),
+            review_config.base_revision.clone(),
+        );
+        let mut suppress = |reports: Vec<ReviewReport>| -> Vec<ReviewReport> {
+            reports
+                .into_iter()
+                .map(|report| {
+                    let is_gating = review_config
+                        .specialists
+                        .iter()
+                        .any(|s| s.agent_name() == report.reviewer && s.is_gating());
+                    suppressor.apply(report, is_gating)
+                })
+                .collect()
         };
 
+        let mut reports = suppress(
+            self.run_sequential_reviews(&tool_specialists, &review_config)
+                .await?,
+        );
+        let tool_gate_failed = reports.iter().any(|report| {
+            report.verdict == ReviewVerdict::Fail
+                && tool_specialists
+                    .iter()
+                    .any(|(s, _)| s.agent_name() == report.reviewer && s.is_gating())
+        });
+
+        if tool_gate_failed && !llm_specialists.is_empty() {
+            info!(
+                skipped = llm_specialists.len(),
+                "Tool reviewer gate failed, skipping LLM reviewers"
+            );
+        } else if self.config.parallel {
+            reports.extend(suppress(
+                self.run_parallel_reviews(&llm_specialists, &review_config)
+                    .await?,
+            ));
+        } else {
+            reports.extend(suppress(
+                self.run_sequential_reviews(&llm_specialists, &review_config)
+                    .await?,
+            ));
+        }
+
         // Build aggregation
         let aggregation = ReviewAggregation::new(&review_config.phase)
             .add_reports(reports)
@@ -379,9 +504,101 @@ impl ReviewDispatcher {
             );
         }
 
+        self.persist_aggregation(&aggregation);
+
         // Handle gating failures via arbiter
-        if aggregation.has_gating_failures() && review_config.has_gating_specialists() {
-            let arbiter_result = self.invoke_arbiter(&aggregation, &review_config).await?;
+        if self.config.arbiter_enabled
+            && aggregation.has_gating_failures()
+            && review_config.has_gating_specialists()
+        {
+            let arbiter_result = self
+                .invoke_arbiter(&aggregation, &review_config, 0, None)
+                .await?;
+            Ok(DispatchResult::with_arbiter(
+                aggregation,
+                arbiter_result,
+                start.elapsed(),
+            ))
+        } else {
+            Ok(DispatchResult::success(aggregation, start.elapsed()))
+        }
+    }
+
+    /// Re-check the pending items of a fix plan after a fix iteration.
+    ///
+    /// Each specialist with pending items checks only those items: tool
+    /// reviewers re-run their command, LLM reviewers answer a re-check prompt.
+    /// A failed re-check leaves its items pending. The arbiter then decides
+    /// over the unresolved items only.
+    pub async fn recheck(
+        &self,
+        plan: &mut FixPlan,
+        review_config: &PhaseReviewConfig,
+        fix_attempts: u32,
+    ) -> Result<DispatchResult> {
+        let start = Instant::now();
+        let registry = self.load_reviewer_registry()?;
+        let names: Vec<String> = plan
+            .specialists_with_pending()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+
+        for name in names {
+            let Some(specialist) = review_config
+                .specialists
+                .iter()
+                .find(|specialist| specialist.agent_name() == name)
+            else {
+                warn!("No specialist {} configured to re-check its findings", name);
+                continue;
+            };
+            let definition = custom_definition(&registry, specialist);
+
+            let rechecked = match definition.filter(|definition| definition.is_tool()) {
+                Some(definition) => self
+                    .run_tool_review(specialist, definition, review_config)
+                    .await
+                    .map(|report| plan.apply_tool_findings(&name, &report.findings)),
+                None => {
+                    let model = definition.and_then(|definition| definition.model.as_deref());
+                    self.run_claude_review(&plan.recheck_prompt(specialist), model)
+                        .await
+                        .map(|output| {
+                            if !plan.apply_recheck_response(&name, &output) {
+                                warn!("Could not parse re-check output from {}", name);
+                            }
+                        })
+                }
+            };
+            if let Err(e) = rechecked {
+                warn!("Re-check by {} failed: {}", name, e);
+            }
+        }
+
+        let aggregation = plan
+            .unresolved_aggregation()
+            .with_total_duration_ms(start.elapsed().as_millis() as u64);
+
+        info!(
+            phase = %review_config.phase,
+            fix_attempts,
+            status = %plan.summary(),
+            "Fix items re-checked"
+        );
+
+        self.persist_aggregation(&aggregation);
+
+        if self.config.arbiter_enabled && aggregation.has_gating_failures() {
+            let context = plan.arbiter_context();
+            let arbiter_result = self
+                .invoke_arbiter(
+                    &aggregation,
+                    review_config,
+                    fix_attempts,
+                    context.as_deref(),
+                )
+                .await?;
             Ok(DispatchResult::with_arbiter(
                 aggregation,
                 arbiter_result,
@@ -392,17 +609,55 @@ impl ReviewDispatcher {
         }
     }
 
+    /// Save the aggregation and configured exports to `.forge/reviews/`.
+    ///
+    /// Persistence is best-effort: failures are logged, never fatal.
+    fn persist_aggregation(&self, aggregation: &ReviewAggregation) {
+        let Some(forge_dir) = self.config.forge_dir.as_deref() else {
+            return;
+        };
+        let record = ReviewRecord::new(aggregation.clone());
+        match ReviewStore::new(forge_dir).save(&record, &self.config.export_formats) {
+            Ok(path) => debug!(path = %path.display(), "Review results saved"),
+            Err(e) => warn!("Failed to save review results: {:#}", e),
+        }
+    }
+
+    /// Load `.forge/review-baseline.json`, falling back to empty on error.
+    fn load_baseline(&self) -> ReviewBaseline {
+        let Some(forge_dir) = self.config.forge_dir.as_deref() else {
+            return ReviewBaseline::default();
+        };
+        ReviewBaseline::load(forge_dir).unwrap_or_else(|e| {
+            warn!("Ignoring review baseline: {:#}", e);
+            ReviewBaseline::default()
+        })
+    }
+
+    /// Load `.forge/reviewers/` definitions.
+    ///
+    /// An invalid definition is an error here as in `forge run`, rather than
+    /// a reviewer that silently disappears.
+    fn load_reviewer_registry(&self) -> Result<ReviewerRegistry> {
+        let Some(forge_dir) = self.config.forge_dir.as_deref() else {
+            return Ok(ReviewerRegistry::default());
+        };
+        ReviewerRegistry::load(forge_dir).context("Failed to load reviewer definitions")
+    }
+
     /// Run review specialists in parallel.
     async fn run_parallel_reviews(
         &self,
+        specialists: &[(&ReviewSpecialist, Option<&ReviewerDefinition>)],
         review_config: &PhaseReviewConfig,
     ) -> Result<Vec<ReviewReport>> {
         use futures::future::join_all;
 
-        let futures: Vec<_> = review_config
-            .specialists
+        let futures: Vec<_> = specialists
             .iter()
-            .map(|specialist| self.run_single_review(specialist, review_config))
+            .map(|(specialist, definition)| {
+                self.run_single_review(specialist, *definition, review_config)
+            })
             .collect();
 
         let results = join_all(futures).await;
@@ -424,12 +679,16 @@ impl ReviewDispatcher {
     /// Run review specialists sequentially.
     async fn run_sequential_reviews(
         &self,
+        specialists: &[(&ReviewSpecialist, Option<&ReviewerDefinition>)],
         review_config: &PhaseReviewConfig,
     ) -> Result<Vec<ReviewReport>> {
         let mut reports = Vec::new();
 
-        for specialist in &review_config.specialists {
-            match self.run_single_review(specialist, review_config).await {
+        for (specialist, definition) in specialists {
+            match self
+                .run_single_review(specialist, *definition, review_config)
+                .await
+            {
                 Ok(report) => reports.push(report),
                 Err(e) => {
                     warn!("Specialist {} failed: {}", specialist.display_name(), e);
@@ -445,6 +704,7 @@ impl ReviewDispatcher {
     async fn run_single_review(
         &self,
         specialist: &ReviewSpecialist,
+        definition: Option<&ReviewerDefinition>,
         review_config: &PhaseReviewConfig,
     ) -> Result<ReviewReport> {
         let start = Instant::now();
@@ -457,19 +717,32 @@ impl ReviewDispatcher {
             );
         }
 
+        if let Some(definition) = definition.filter(|definition| definition.is_tool()) {
+            return self
+                .run_tool_review(specialist, definition, review_config)
+                .await
+                .map(|report| report.with_duration_ms(start.elapsed().as_millis() as u64));
+        }
+
         // Build the review prompt
-        let prompt =
-            build_review_prompt(specialist, review_config, self.config.forge_dir.as_deref());
+        let prompt = match definition {
+            Some(definition) => build_definition_prompt(definition, specialist, review_config),
+            None => {
+                build_review_prompt(specialist, review_config, self.config.forge_dir.as_deref())
+            }
+        };
 
         // Run Claude with the review prompt
-        let output = self.run_claude_review(&prompt).await?;
+        let model = definition.and_then(|definition| definition.model.as_deref());
+        let output = self.run_claude_review(&prompt, model).await?;
 
         // Parse the review output
-        let report = parse_review_output(
+        let report = parse_review_output_for(
             &output,
             &review_config.phase,
             &specialist.agent_name(),
             specialist.is_gating(),
+            definition,
         )
         .with_duration_ms(start.elapsed().as_millis() as u64);
 
@@ -485,11 +758,70 @@ impl ReviewDispatcher {
         Ok(report)
     }
 
+    /// Run a tool reviewer's command and convert its diagnostics to a report.
+    async fn run_tool_review(
+        &self,
+        specialist: &ReviewSpecialist,
+        definition: &ReviewerDefinition,
+        review_config: &PhaseReviewConfig,
+    ) -> Result<ReviewReport> {
+        let working_dir = self.config.working_dir.as_deref();
+        let findings = tools::run_tool_command(
+            definition,
+            &review_config.files_changed,
+            working_dir,
+            self.config.review_timeout,
+        )
+        .await
+        .and_then(|output| {
+            tools::parse_tool_output(
+                &output,
+                definition,
+                &review_config.files_changed,
+                working_dir,
+            )
+            .with_context(|| format!("Tool reviewer `{}` produced no results", definition.name))
+        });
+
+        let report = match findings {
+            Ok(findings) => tools::build_tool_report(
+                &review_config.phase,
+                &specialist.agent_name(),
+                specialist.is_gating(),
+                findings,
+            ),
+            Err(error) => {
+                warn!("{} (tool) failed: {error:#}", specialist.display_name());
+                tools::build_tool_error_report(
+                    &review_config.phase,
+                    &specialist.agent_name(),
+                    specialist.is_gating(),
+                    &error,
+                )
+            }
+        };
+
+        if self.config.verbose {
+            debug!(
+                "{} (tool) completed: {} ({} findings)",
+                specialist.display_name(),
+                report.verdict,
+                report.findings_count()
+            );
+        }
+
+        Ok(report)
+    }
+
     /// Run Claude to perform a review.
-    async fn run_claude_review(&self, prompt: &str) -> Result<String> {
+    async fn run_claude_review(&self, prompt: &str, model: Option<&str>) -> Result<String> {
         let mut cmd = Command::new(&self.config.claude_cmd);
         cmd.arg("--print");
 
+        if let Some(model) = model {
+            cmd.arg("--model").arg(model);
+        }
+
         if self.config.skip_permissions {
             cmd.arg("--dangerously-skip-permissions");
         }
@@ -549,6 +881,8 @@ impl ReviewDispatcher {
         &self,
         aggregation: &ReviewAggregation,
         review_config: &PhaseReviewConfig,
+        fix_attempts: u32,
+        context: Option<&str>,
     ) -> Result<ArbiterResult> {
         if self.config.verbose {
             debug!(
@@ -564,9 +898,59 @@ impl ReviewDispatcher {
             review_config.budget,
             review_config.iterations_used,
         )
-        .with_phase_name(&review_config.phase_name);
+        .with_phase_name(&review_config.phase_name)
+        .with_fix_attempts(fix_attempts);
+        let input = match context {
+            Some(context) => input.with_additional_context(context),
+            None => input,
+        };
 
-        executor.decide_with_quick_check(input).await
+        // Past decisions and human overrides are best-effort learning signal
+        let history = self.config.forge_dir.as_deref().map(ArbiterHistory::new);
+        let input = match history
+            .as_ref()
+            .map(|history| history.precedents(DEFAULT_PRECEDENT_LIMIT))
+        {
+            Some(Ok(precedents)) => input.with_precedents(precedents),
+            Some(Err(e)) => {
+                warn!("Ignoring arbiter history: {:#}", e);
+                input
+            }
+            None => input,
+        };
+
+        let manual = self.config.arbiter.mode.is_manual();
+        if manual && self.manual_handler.is_none() {
+            warn!("Manual review mode has no reviewer attached, using the arbiter");
+        }
+        let result = match self.manual_handler.as_ref().filter(|_| manual) {
+            Some(handler) => {
+                let review =
+                    PendingReview::new(aggregation, &review_config.phase_name, fix_attempts);
+                info!(
+                    phase = %review.phase,
+                    review_id = %review.id,
+                    findings = review.items.len(),
+                    "Waiting for manual review decision"
+                );
+                match handler.resolve(&review).await {
+                    Ok(resolution) => ArbiterResult::human(resolution.to_decision(&review)),
+                    Err(e) if e.downcast_ref::<ReviewTimedOut>().is_some() => {
+                        warn!("{:#}, using the arbiter", e);
+                        executor.decide_with_quick_check(input.clone()).await?
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+            None => executor.decide_with_quick_check(input.clone()).await?,
+        };
+        if let Some(history) = &history {
+            match history.record(&input, &result) {
+                Ok(entry) => debug!(id = %entry.id, "Arbiter decision recorded"),
+                Err(e) => warn!("Failed to record arbiter decision: {:#}", e),
+            }
+        }
+        Ok(result)
     }
 }
 
@@ -653,7 +1037,23 @@ Examine the code for these specific concerns:
    - Suggest how to fix it
    - Classify severity: error (critical), warning (should fix), info (nice to fix), note (observation)
 
-## Output Format
+{output_format}
+"#,
+        display_name = specialist.display_name(),
+        phase = config.phase,
+        phase_name = config.phase_name,
+        context_section = context_section,
+        gating_note = gating_note,
+        focus_list = focus_list,
+        files_section = files_section,
+        output_format = output_format_section(specialist.display_name()),
+    )
+}
+
+/// The JSON output contract every review prompt ends with.
+fn output_format_section(display_name: &str) -> String {
+    format!(
+        r#"## Output Format
 
 Respond with a JSON object containing your review findings:
 
@@ -682,18 +1082,56 @@ If no issues are found, return:
 }}
 ```
 
-Begin your review now.
-"#,
-        display_name = specialist.display_name(),
-        phase = config.phase,
-        phase_name = config.phase_name,
-        context_section = context_section,
-        gating_note = gating_note,
-        focus_list = focus_list,
-        files_section = files_section,
+Begin your review now."#
+    )
+}
+
+/// Build the prompt for a custom specialist defined in `.forge/reviewers/`.
+///
+/// The definition's template is rendered with the phase context, followed by
+/// the gating note and the standard output format so findings stay parseable.
+fn build_definition_prompt(
+    definition: &ReviewerDefinition,
+    specialist: &ReviewSpecialist,
+    config: &PhaseReviewConfig,
+) -> String {
+    let focus_areas = if specialist.custom_focus_areas.is_empty() {
+        definition.focus_areas.iter().map(String::as_str).collect()
+    } else {
+        specialist.focus_areas()
+    };
+    let body = definition.render_prompt(
+        &config.phase,
+        &config.phase_name,
+        &config.files_changed,
+        &focus_areas,
+        config.additional_context.as_deref(),
+    );
+
+    let gating_note = if specialist.is_gating() {
+        "**This is a GATING review.** If you find critical issues (error severity), the phase cannot proceed until they are resolved."
+    } else {
+        "This is an advisory review. Issues will be reported but won't block phase progression."
+    };
+
+    format!(
+        "{body}\n\n{gating_note}\n\n{output_format}\n",
+        body = body.trim(),
+        output_format = output_format_section(specialist.display_name()),
     )
 }
 
+/// Find the file definition for a custom specialist, if any.
+fn custom_definition<'a>(
+    registry: &'a ReviewerRegistry,
+    specialist: &ReviewSpecialist,
+) -> Option<&'a ReviewerDefinition> {
+    match specialist.specialist_type {
+        SpecialistType::Custom(_) => registry.get(&specialist.agent_name()),
+        _ => None,
+    }
+}
+
 /// Build a review prompt from a file-based [`PromptConfig`].
 ///
 /// Wraps the file's body with the dispatcher's dynamic context sections
@@ -757,8 +1195,14 @@ fn build_prompt_from_config(
     )
 }
 
-/// Parse review output from Claude into a ReviewReport.
-fn parse_review_output(output: &str, phase: &str, reviewer: &str, is_gating: bool) -> ReviewReport {
+/// Parse review output, applying a custom reviewer's severity mapping if given.
+fn parse_review_output_for(
+    output: &str,
+    phase: &str,
+    reviewer: &str,
+    is_gating: bool,
+    definition: Option<&ReviewerDefinition>,
+) -> ReviewReport {
     // Try to extract JSON from the output
     if let Some(json_str) = extract_json(output)
         && let Ok(value) = serde_json::from_str::<serde_json::Value>(&json_str)
@@ -785,7 +1229,7 @@ fn parse_review_output(output: &str, phase: &str, reviewer: &str, is_gating: boo
         let mut findings = Vec::new();
         if let Some(findings_array) = value.get("findings").and_then(|v| v.as_array()) {
             for finding_value in findings_array {
-                if let Some(finding) = parse_finding(finding_value) {
+                if let Some(finding) = parse_finding_for(finding_value, definition) {
                     findings.push(finding);
                 }
             }
@@ -812,16 +1256,20 @@ fn parse_review_output(output: &str, phase: &str, reviewer: &str, is_gating: boo
         .with_timestamp(chrono::Utc::now())
 }
 
-/// Parse a single finding from JSON.
-fn parse_finding(value: &serde_json::Value) -> Option<ReviewFinding> {
+/// Parse a single finding from JSON, consulting the definition's severity map first.
+fn parse_finding_for(
+    value: &serde_json::Value,
+    definition: Option<&ReviewerDefinition>,
+) -> Option<ReviewFinding> {
     let severity_str = value.get("severity").and_then(|v| v.as_str())?;
-    let severity = match severity_str.to_lowercase().as_str() {
+    let mapped = definition.and_then(|definition| definition.map_severity(severity_str));
+    let severity = mapped.unwrap_or_else(|| match severity_str.to_lowercase().as_str() {
         "error" => FindingSeverity::Error,
         "warning" => FindingSeverity::Warning,
         "info" => FindingSeverity::Info,
         "note" => FindingSeverity::Note,
         _ => FindingSeverity::Warning,
-    };
+    });
 
     let file = value.get("file").and_then(|v| v.as_str())?;
     let issue = value.get("issue").and_then(|v| v.as_str())?;
@@ -870,7 +1318,7 @@ fn determine_verdict(
 }
 
 /// Extract JSON from output that may contain markdown or other text.
-fn extract_json(output: &str) -> Option<String> {
+pub(crate) fn extract_json(output: &str) -> Option<String> {
     // Try to find JSON in a code block
     if let Some(start) = output.find("```json") {
         let after_marker = &output[start + 7..];
@@ -1206,7 +1654,7 @@ I found {"verdict": "warn", "summary": "Issues", "findings": []} in the code.
 ```
 "#;
 
-        let report = parse_review_output(output, "05", "security-sentinel", true);
+        let report = parse_review_output_for(output, "05", "security-sentinel", true, None);
 
         assert_eq!(report.verdict, ReviewVerdict::Pass);
         assert_eq!(report.summary, "No issues found");
@@ -1233,7 +1681,7 @@ I found {"verdict": "warn", "summary": "Issues", "findings": []} in the code.
 ```
 "#;
 
-        let report = parse_review_output(output, "05", "security-sentinel", true);
+        let report = parse_review_output_for(output, "05", "security-sentinel", true, None);
 
         assert_eq!(report.verdict, ReviewVerdict::Warn);
         assert_eq!(report.findings.len(), 1);
@@ -1262,7 +1710,7 @@ I found {"verdict": "warn", "summary": "Issues", "findings": []} in the code.
 }
 "#;
 
-        let report = parse_review_output(output, "05", "security-sentinel", true);
+        let report = parse_review_output_for(output, "05", "security-sentinel", true, None);
 
         // Gating review with critical findings should be Fail
         assert_eq!(report.verdict, ReviewVerdict::Fail);
@@ -1272,7 +1720,7 @@ I found {"verdict": "warn", "summary": "Issues", "findings": []} in the code.
     fn test_parse_review_output_unparseable() {
         let output = "This is not JSON at all";
 
-        let report = parse_review_output(output, "05", "security-sentinel", false);
+        let report = parse_review_output_for(output, "05", "security-sentinel", false, None);
 
         // Should fall back to warn (not pass) so unparseable output doesn't silently pass
         assert_eq!(report.verdict, ReviewVerdict::Warn);
@@ -1296,7 +1744,7 @@ I found {"verdict": "warn", "summary": "Issues", "findings": []} in the code.
             "category": "security/sql"
         });
 
-        let finding = parse_finding(&value).unwrap();
+        let finding = parse_finding_for(&value, None).unwrap();
 
         assert_eq!(finding.severity(), FindingSeverity::Warning);
         assert_eq!(finding.file(), "src/main.rs");
@@ -1315,7 +1763,7 @@ I found {"verdict": "warn", "summary": "Issues", "findings": []} in the code.
             "issue": "Problem"
         });
 
-        let finding = parse_finding(&value).unwrap();
+        let finding = parse_finding_for(&value, None).unwrap();
 
         assert_eq!(finding.severity(), FindingSeverity::Error);
         assert_eq!(finding.file(), "a.rs");
@@ -1331,14 +1779,14 @@ I found {"verdict": "warn", "summary": "Issues", "findings": []} in the code.
             "severity": "warning",
             "issue": "Problem"
         });
-        assert!(parse_finding(&value).is_none());
+        assert!(parse_finding_for(&value, None).is_none());
 
         // Missing issue
         let value = serde_json::json!({
             "severity": "warning",
             "file": "a.rs"
         });
-        assert!(parse_finding(&value).is_none());
+        assert!(parse_finding_for(&value, None).is_none());
     }
 
     // =========================================
@@ -1565,4 +2013,433 @@ JSON.
         let config = DispatcherConfig::default().with_forge_dir(PathBuf::from("/tmp/test/.forge"));
         assert_eq!(config.forge_dir, Some(PathBuf::from("/tmp/test/.forge")));
     }
+
+    // =========================================
+    // File-defined reviewer tests
+    // =========================================
+
+    fn sql_reviewer() -> ReviewerDefinition {
+        ReviewerDefinition {
+            name: "sql-migrations".to_string(),
+            prompt: "Inspect migrations in phase {phase}:\n{files_changed}\n{focus_areas}"
+                .to_string(),
+            focus_areas: vec!["Destructive migrations".to_string()],
+            severity_map: [("critical".to_string(), FindingSeverity::Error)]
+                .into_iter()
+                .collect(),
+            applies_to: vec!["**/*.sql".to_string()],
+            ..ReviewerDefinition::default()
+        }
+    }
+
+    #[test]
+    fn test_build_definition_prompt_renders_template() {
+        let specialist =
+            ReviewSpecialist::gating(SpecialistType::Custom("sql-migrations".to_string()));
+        let config = PhaseReviewConfig::new("07", "Schema")
+            .with_files_changed(vec!["db/001.sql".to_string()]);
+
+        let prompt = build_definition_prompt(&sql_reviewer(), &specialist, &config);
+
+        assert!(prompt.starts_with("Inspect migrations in phase 07:\n- db/001.sql"));
+        assert!(prompt.contains("- Destructive migrations"));
+        assert!(prompt.contains("GATING review"));
+        assert!(prompt.contains("## Output Format"));
+    }
+
+    #[test]
+    fn test_parse_review_output_applies_severity_map() {
+        let output = r#"{"verdict": "warn", "findings": [{"severity": "critical", "file": "db/001.sql", "issue": "Drops a column"}]}"#;
+        let definition = sql_reviewer();
+
+        let report =
+            parse_review_output_for(output, "07", "sql-migrations", true, Some(&definition));
+
+        assert_eq!(report.findings[0].severity(), FindingSeverity::Error);
+        assert_eq!(report.verdict, ReviewVerdict::Fail);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_skips_reviewer_outside_applies_to() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reviewers_dir = ReviewerRegistry::reviewers_dir(temp_dir.path());
+        std::fs::create_dir_all(&reviewers_dir).unwrap();
+        std::fs::write(
+            reviewers_dir.join("sql-migrations.toml"),
+            "prompt = \"Review\"\napplies_to = [\"**/*.sql\"]\n",
+        )
+        .unwrap();
+
+        let dispatcher = ReviewDispatcher::new(
+            DispatcherConfig::default()
+                .with_claude_cmd("false")
+                .with_forge_dir(temp_dir.path().to_path_buf()),
+        );
+        let review_config = PhaseReviewConfig::new("07", "Schema")
+            .add_specialist(ReviewSpecialist::gating(SpecialistType::Custom(
+                "sql-migrations".to_string(),
+            )))
+            .with_files_changed(vec!["src/main.rs".to_string()]);
+
+        let result = dispatcher.dispatch(review_config).await.unwrap();
+
+        assert_eq!(result.aggregation.reports_count(), 0);
+        assert!(result.can_proceed());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_fails_on_invalid_reviewer_definition() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reviewers_dir = ReviewerRegistry::reviewers_dir(temp_dir.path());
+        std::fs::create_dir_all(&reviewers_dir).unwrap();
+        std::fs::write(reviewers_dir.join("broken.toml"), "gate = true\n").unwrap();
+
+        let dispatcher = ReviewDispatcher::new(
+            DispatcherConfig::default()
+                .with_claude_cmd("false")
+                .with_forge_dir(temp_dir.path().to_path_buf()),
+        );
+        let review_config = PhaseReviewConfig::new("07", "Schema")
+            .add_specialist(ReviewSpecialist::gating(SpecialistType::Custom(
+                "broken".to_string(),
+            )))
+            .with_files_changed(vec!["src/main.rs".to_string()]);
+
+        let error = dispatcher.dispatch(review_config).await.unwrap_err();
+
+        assert!(format!("{:#}", error).contains("reviewer definitions"));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_gating_tool_failure_skips_llm_reviewers() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reviewers_dir = ReviewerRegistry::reviewers_dir(temp_dir.path());
+        std::fs::create_dir_all(&reviewers_dir).unwrap();
+        std::fs::write(
+            reviewers_dir.join("lint.toml"),
+            "command = \"cat lint.sarif\"\nformat = \"sarif\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("lint.sarif"),
+            r#"{"runs": [{"results": [{"ruleId": "no-unwrap", "level": "error",
+                "message": {"text": "unwrap on user input"},
+                "locations": [{"physicalLocation": {
+                    "artifactLocation": {"uri": "src/main.rs"},
+                    "region": {"startLine": 7}}}]}]}]}"#,
+        )
+        .unwrap();
+
+        // `echo` would produce an (unparseable) LLM report if it ran
+        let dispatcher = ReviewDispatcher::new(
+            DispatcherConfig::default()
+                .with_claude_cmd("echo")
+                .with_arbiter_enabled(false)
+                .with_working_dir(temp_dir.path().to_path_buf())
+                .with_forge_dir(temp_dir.path().to_path_buf()),
+        );
+        let review_config = PhaseReviewConfig::new("07", "Schema")
+            .add_specialist(ReviewSpecialist::advisory(
+                SpecialistType::PerformanceOracle,
+            ))
+            .add_specialist(ReviewSpecialist::gating(SpecialistType::Custom(
+                "lint".to_string(),
+            )))
+            .with_files_changed(vec!["src/main.rs".to_string()]);
+
+        let result = dispatcher.dispatch(review_config).await.unwrap();
+
+        assert_eq!(result.aggregation.reports_count(), 1);
+        assert!(result.aggregation.has_gating_failures());
+        assert!(result.arbiter_result.is_none());
+        assert!(!result.can_proceed());
+        let finding = &result.aggregation.reports[0].findings[0];
+        assert_eq!(finding.location(), "src/main.rs:7");
+        assert_eq!(finding.category(), Some("no-unwrap"));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_failing_advisory_tool_does_not_skip_llm_reviewers() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reviewers_dir = ReviewerRegistry::reviewers_dir(temp_dir.path());
+        std::fs::create_dir_all(&reviewers_dir).unwrap();
+        std::fs::write(
+            reviewers_dir.join("lint.toml"),
+            "command = \"echo 'lint: bad config' >&2; exit 2\"\n",
+        )
+        .unwrap();
+        // Reads the whole prompt, then produces an (unparseable) LLM report
+        let claude = temp_dir.path().join("claude");
+        std::fs::write(&claude, "#!/bin/sh\ncat >/dev/null\necho reviewed\n").unwrap();
+        std::fs::set_permissions(&claude, std::os::unix::fs::PermissionsExt::from_mode(0o755))
+            .unwrap();
+
+        let dispatcher = ReviewDispatcher::new(
+            DispatcherConfig::default()
+                .with_claude_cmd(claude.to_str().unwrap())
+                .with_arbiter_enabled(false)
+                .with_working_dir(temp_dir.path().to_path_buf())
+                .with_forge_dir(temp_dir.path().to_path_buf()),
+        );
+        let review_config = PhaseReviewConfig::new("07", "Schema")
+            .add_specialist(ReviewSpecialist::advisory(
+                SpecialistType::PerformanceOracle,
+            ))
+            .add_specialist(ReviewSpecialist::advisory(SpecialistType::Custom(
+                "lint".to_string(),
+            )));
+
+        let result = dispatcher.dispatch(review_config).await.unwrap();
+
+        assert_eq!(result.aggregation.reports_count(), 2);
+        let report = &result.aggregation.reports[0];
+        assert_eq!(report.reviewer, "lint");
+        assert_eq!(report.verdict, ReviewVerdict::Warn);
+        assert!(report.summary.contains("lint: bad config"));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_baselined_finding_does_not_gate() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reviewers_dir = ReviewerRegistry::reviewers_dir(temp_dir.path());
+        std::fs::create_dir_all(&reviewers_dir).unwrap();
+        std::fs::write(
+            reviewers_dir.join("lint.toml"),
+            "command = \"cat lint.sarif\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("lint.sarif"),
+            r#"{"runs": [{"results": [{"ruleId": "no-unwrap", "level": "error",
+                "message": {"text": "unwrap on user input"},
+                "locations": [{"physicalLocation": {
+                    "artifactLocation": {"uri": "src/main.rs"},
+                    "region": {"startLine": 7}}}]}]}]}"#,
+        )
+        .unwrap();
+        let mut baseline = ReviewBaseline::default();
+        baseline.add(
+            "lint",
+            &ReviewFinding::new(
+                FindingSeverity::Error,
+                "src/main.rs",
+                "unwrap on user input",
+            )
+            .with_line(9),
+        );
+        baseline.save(temp_dir.path()).unwrap();
+
+        let dispatcher = ReviewDispatcher::new(
+            DispatcherConfig::default()
+                .with_working_dir(temp_dir.path().to_path_buf())
+                .with_forge_dir(temp_dir.path().to_path_buf()),
+        );
+        let review_config = PhaseReviewConfig::new("07", "Schema").add_specialist(
+            ReviewSpecialist::gating(SpecialistType::Custom("lint".to_string())),
+        );
+
+        let result = dispatcher.dispatch(review_config).await.unwrap();
+
+        assert!(!result.aggregation.has_gating_failures());
+        let report = &result.aggregation.reports[0];
+        assert!(report.findings.is_empty());
+        assert_eq!(report.suppressed.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_baselined_tool_finding_does_not_skip_llm_reviewers() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reviewers_dir = ReviewerRegistry::reviewers_dir(temp_dir.path());
+        std::fs::create_dir_all(&reviewers_dir).unwrap();
+        std::fs::write(
+            reviewers_dir.join("lint.toml"),
+            "command = \"cat lint.sarif\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("lint.sarif"),
+            r#"{"runs": [{"results": [{"ruleId": "no-unwrap", "level": "error",
+                "message": {"text": "unwrap on user input"},
+                "locations": [{"physicalLocation": {
+                    "artifactLocation": {"uri": "src/main.rs"},
+                    "region": {"startLine": 7}}}]}]}]}"#,
+        )
+        .unwrap();
+        let mut baseline = ReviewBaseline::default();
+        baseline.add(
+            "lint",
+            &ReviewFinding::new(
+                FindingSeverity::Error,
+                "src/main.rs",
+                "unwrap on user input",
+            )
+            .with_line(9),
+        );
+        baseline.save(temp_dir.path()).unwrap();
+
+        // Reads the whole prompt, then produces an (unparseable) LLM report
+        let claude = temp_dir.path().join("claude");
+        std::fs::write(&claude, "#!/bin/sh\ncat >/dev/null\necho reviewed\n").unwrap();
+        std::fs::set_permissions(&claude, std::os::unix::fs::PermissionsExt::from_mode(0o755))
+            .unwrap();
+
+        let dispatcher = ReviewDispatcher::new(
+            DispatcherConfig::default()
+                .with_claude_cmd(claude.to_str().unwrap())
+                .with_arbiter_enabled(false)
+                .with_working_dir(temp_dir.path().to_path_buf())
+                .with_forge_dir(temp_dir.path().to_path_buf()),
+        );
+        let review_config = PhaseReviewConfig::new("07", "Schema")
+            .add_specialist(ReviewSpecialist::advisory(
+                SpecialistType::PerformanceOracle,
+            ))
+            .add_specialist(ReviewSpecialist::gating(SpecialistType::Custom(
+                "lint".to_string(),
+            )));
+
+        let result = dispatcher.dispatch(review_config).await.unwrap();
+
+        assert_eq!(result.aggregation.reports_count(), 2);
+        assert!(!result.aggregation.has_gating_failures());
+        let report = &result.aggregation.reports[0];
+        assert_eq!(report.reviewer, "lint");
+        assert_eq!(report.suppressed.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_saves_record_and_exports() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reviewers_dir = ReviewerRegistry::reviewers_dir(temp_dir.path());
+        std::fs::create_dir_all(&reviewers_dir).unwrap();
+        std::fs::write(
+            reviewers_dir.join("lint.toml"),
+            "command = \"echo '{\\\"runs\\\": []}'\"\n",
+        )
+        .unwrap();
+
+        let dispatcher = ReviewDispatcher::new(
+            DispatcherConfig::default()
+                .with_forge_dir(temp_dir.path().to_path_buf())
+                .with_export_formats(vec![ReviewExportFormat::Junit]),
+        );
+        let review_config = PhaseReviewConfig::new("07", "Schema").add_specialist(
+            ReviewSpecialist::gating(SpecialistType::Custom("lint".to_string())),
+        );
+
+        dispatcher.dispatch(review_config).await.unwrap();
+
+        let records = ReviewStore::new(temp_dir.path()).load_all().unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].aggregation.reports_count(), 1);
+        let junit_count = std::fs::read_dir(ReviewStore::reviews_dir(temp_dir.path()))
+            .unwrap()
+            .filter(|entry| {
+                entry
+                    .as_ref()
+                    .unwrap()
+                    .file_name()
+                    .to_string_lossy()
+                    .ends_with(".junit.xml")
+            })
+            .count();
+        assert_eq!(junit_count, 1);
+    }
+
+    struct DismissAll;
+
+    #[async_trait::async_trait]
+    impl ManualReviewHandler for DismissAll {
+        async fn resolve(&self, review: &PendingReview) -> Result<crate::review::ManualResolution> {
+            Ok(review.items.iter().fold(
+                crate::review::ManualResolution::default(),
+                |resolution, item| {
+                    resolution.with_finding(&item.id, crate::review::FindingAction::Dismiss, None)
+                },
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_manual_mode_asks_handler() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reviewers_dir = ReviewerRegistry::reviewers_dir(temp_dir.path());
+        std::fs::create_dir_all(&reviewers_dir).unwrap();
+        std::fs::write(
+            reviewers_dir.join("lint.toml"),
+            "command = \"cat lint.sarif\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("lint.sarif"),
+            r#"{"runs": [{"results": [{"ruleId": "no-unwrap", "level": "error",
+                "message": {"text": "unwrap on user input"},
+                "locations": [{"physicalLocation": {
+                    "artifactLocation": {"uri": "src/main.rs"},
+                    "region": {"startLine": 7}}}]}]}]}"#,
+        )
+        .unwrap();
+
+        let arbiter = ArbiterConfig::default().with_mode(crate::review::ResolutionMode::manual(2));
+        let dispatcher = ReviewDispatcher::new(
+            DispatcherConfig::default()
+                .with_working_dir(temp_dir.path().to_path_buf())
+                .with_forge_dir(temp_dir.path().to_path_buf())
+                .with_arbiter(arbiter),
+        )
+        .with_manual_handler(Arc::new(DismissAll));
+        let review_config = PhaseReviewConfig::new("07", "Schema").add_specialist(
+            ReviewSpecialist::gating(SpecialistType::Custom("lint".to_string())),
+        );
+
+        let result = dispatcher.dispatch(review_config).await.unwrap();
+
+        let arbiter_result = result.arbiter_result.as_ref().unwrap();
+        assert_eq!(arbiter_result.source, crate::review::DecisionSource::Human);
+        assert!(result.can_proceed());
+        let history = ArbiterHistory::new(temp_dir.path()).load().unwrap();
+        assert_eq!(history.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_manual_review_timeout_falls_back_to_arbiter() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let reviewers_dir = ReviewerRegistry::reviewers_dir(temp_dir.path());
+        std::fs::create_dir_all(&reviewers_dir).unwrap();
+        std::fs::write(
+            reviewers_dir.join("lint.toml"),
+            "command = \"cat lint.sarif\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp_dir.path().join("lint.sarif"),
+            r#"{"runs": [{"results": [{"ruleId": "no-unwrap", "level": "error",
+                "message": {"text": "unwrap on user input"},
+                "locations": [{"physicalLocation": {
+                    "artifactLocation": {"uri": "src/main.rs"},
+                    "region": {"startLine": 7}}}]}]}]}"#,
+        )
+        .unwrap();
+
+        let arbiter = ArbiterConfig::default().with_mode(crate::review::ResolutionMode::manual(2));
+        let queue = crate::review::FileReviewQueue::new(temp_dir.path())
+            .with_poll_interval(Duration::from_millis(10))
+            .with_timeout(Some(Duration::from_millis(50)));
+        let dispatcher = ReviewDispatcher::new(
+            DispatcherConfig::default()
+                .with_working_dir(temp_dir.path().to_path_buf())
+                .with_forge_dir(temp_dir.path().to_path_buf())
+                .with_arbiter(arbiter),
+        )
+        .with_manual_handler(Arc::new(queue.clone()));
+        let review_config = PhaseReviewConfig::new("07", "Schema").add_specialist(
+            ReviewSpecialist::gating(SpecialistType::Custom("lint".to_string())),
+        );
+
+        let result = dispatcher.dispatch(review_config).await.unwrap();
+
+        let arbiter_result = result.arbiter_result.as_ref().unwrap();
+        assert_ne!(arbiter_result.source, crate::review::DecisionSource::Human);
+        assert!(queue.list().unwrap().is_empty());
+    }
 }
@@ -0,0 +1,447 @@
+//! Export of review results to formats understood by CI tooling.
+//!
+//! - [`ReviewExportFormat::Sarif`]: SARIF 2.1.0, one run per specialist
+//!   report, for code-scanning UIs
+//! - [`ReviewExportFormat::Junit`]: JUnit XML, one test suite per dispatch
+//!   and one test case per specialist; failing gating reviews become test
+//!   failures listing their blocking findings
+//! - [`ReviewExportFormat::Markdown`]: a human-readable summary
+
+use std::fmt::Write as _;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use super::store::ReviewRecord;
+use super::{FindingSeverity, ReviewFinding, ReviewReport, SuppressionReason};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Output format for exported review results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ReviewExportFormat {
+    /// SARIF 2.1.0 JSON.
+    Sarif,
+    /// JUnit XML.
+    Junit,
+    /// Markdown summary.
+    Markdown,
+}
+
+impl ReviewExportFormat {
+    /// File extension used when writing this format to `.forge/reviews/`.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Sarif => "sarif",
+            Self::Junit => "junit.xml",
+            Self::Markdown => "md",
+        }
+    }
+
+    /// Render the given review records in this format.
+    pub fn render(&self, records: &[ReviewRecord]) -> Result<String> {
+        Ok(match self {
+            Self::Sarif => serde_json::to_string_pretty(&to_sarif(records))?,
+            Self::Junit => to_junit(records),
+            Self::Markdown => to_markdown(records),
+        })
+    }
+}
+
+/// Build a SARIF 2.1.0 log with one run per specialist report.
+pub fn to_sarif(records: &[ReviewRecord]) -> Value {
+    let runs: Vec<Value> = records
+        .iter()
+        .flat_map(|record| {
+            record
+                .aggregation
+                .reports
+                .iter()
+                .map(move |report| sarif_run(record, report))
+        })
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": runs,
+    })
+}
+
+fn sarif_run(record: &ReviewRecord, report: &ReviewReport) -> Value {
+    let mut rules: Vec<&str> = report
+        .findings
+        .iter()
+        .chain(
+            report
+                .suppressed
+                .iter()
+                .map(|suppressed| &suppressed.finding),
+        )
+        .map(|finding| sarif_rule_id(report, finding))
+        .collect();
+    rules.sort_unstable();
+    rules.dedup();
+
+    let results: Vec<Value> = report
+        .findings
+        .iter()
+        .map(|finding| sarif_result(report, finding))
+        .chain(report.suppressed.iter().map(|suppressed| {
+            let kind = match suppressed.reason {
+                SuppressionReason::Baseline => "external",
+                SuppressionReason::InlineComment => "inSource",
+            };
+            let mut result = sarif_result(report, &suppressed.finding);
+            result["suppressions"] = json!([{"kind": kind}]);
+            result
+        }))
+        .collect();
+
+    json!({
+        "tool": {
+            "driver": {
+                "name": format!("forge/{}", report.reviewer),
+                "informationUri": "https://github.com/jdsingh122918/forge",
+                "rules": rules.iter().map(|id| json!({"id": id})).collect::<Vec<_>>(),
+            }
+        },
+        "automationDetails": {
+            "id": format!("forge/phase-{}/{}/", record.aggregation.phase, report.reviewer),
+        },
+        "invocations": [{
+            "executionSuccessful": true,
+            "endTimeUtc": record.recorded_at.to_rfc3339(),
+        }],
+        "properties": {
+            "phase": record.aggregation.phase,
+            "verdict": report.verdict,
+            "summary": report.summary,
+        },
+        "results": results,
+    })
+}
+
+fn sarif_rule_id<'a>(report: &'a ReviewReport, finding: &'a ReviewFinding) -> &'a str {
+    finding.category().unwrap_or(&report.reviewer)
+}
+
+fn sarif_result(report: &ReviewReport, finding: &ReviewFinding) -> Value {
+    let level = match finding.severity() {
+        FindingSeverity::Error => "error",
+        FindingSeverity::Warning => "warning",
+        FindingSeverity::Info | FindingSeverity::Note => "note",
+    };
+    let message = match finding.suggestion() {
+        Some(suggestion) => format!("{}\n\nSuggestion: {}", finding.issue(), suggestion),
+        None => finding.issue().to_string(),
+    };
+
+    let mut physical_location = json!({
+        "artifactLocation": {"uri": finding.file()},
+    });
+    if let Some(line) = finding.line() {
+        let mut region = json!({"startLine": line});
+        if let Some(column) = finding.column() {
+            region["startColumn"] = json!(column);
+        }
+        physical_location["region"] = region;
+    }
+
+    json!({
+        "ruleId": sarif_rule_id(report, finding),
+        "level": level,
+        "message": {"text": message},
+        "locations": [{"physicalLocation": physical_location}],
+    })
+}
+
+/// Build a JUnit XML document with one test suite per review record.
+pub fn to_junit(records: &[ReviewRecord]) -> String {
+    let tests: usize = records
+        .iter()
+        .map(|record| record.aggregation.reports_count())
+        .sum();
+    let failures: usize = records
+        .iter()
+        .map(|record| record.aggregation.gating_failures().len())
+        .sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        "<testsuites name=\"forge-review\" tests=\"{}\" failures=\"{}\">",
+        tests, failures
+    );
+
+    for record in records {
+        let aggregation = &record.aggregation;
+        let suite = format!("phase-{}", aggregation.phase);
+        let _ = writeln!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" timestamp=\"{}\" time=\"{}\">",
+            xml_escape(&suite),
+            aggregation.reports_count(),
+            aggregation.gating_failures().len(),
+            record.recorded_at.format("%Y-%m-%dT%H:%M:%S"),
+            seconds(aggregation.total_duration_ms),
+        );
+
+        for report in &aggregation.reports {
+            let _ = write!(
+                xml,
+                "    <testcase classname=\"forge.review.{}\" name=\"{}\" time=\"{}\"",
+                xml_escape(&suite),
+                xml_escape(&report.reviewer),
+                seconds(report.duration_ms),
+            );
+
+            let blocking = report.actionable_findings();
+            if !report.is_gating_failure() && report.findings.is_empty() {
+                xml.push_str("/>\n");
+                continue;
+            }
+            xml.push_str(">\n");
+
+            if report.is_gating_failure() {
+                let text = blocking
+                    .iter()
+                    .map(|finding| finding.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let _ = writeln!(
+                    xml,
+                    "      <failure type=\"{}\" message=\"{} blocking finding(s)\">{}</failure>",
+                    report.verdict,
+                    blocking.len(),
+                    xml_escape(&text),
+                );
+            }
+
+            let mut output = report.summary.clone();
+            for finding in &report.findings {
+                output.push('\n');
+                output.push_str(&finding.to_string());
+            }
+            let _ = writeln!(
+                xml,
+                "      <system-out>{}</system-out>",
+                xml_escape(output.trim())
+            );
+            xml.push_str("    </testcase>\n");
+        }
+
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// Build a Markdown summary with one section per review record.
+pub fn to_markdown(records: &[ReviewRecord]) -> String {
+    let mut md = String::from("# Forge Review Results\n");
+
+    if records.is_empty() {
+        md.push_str("\nNo review runs recorded.\n");
+        return md;
+    }
+
+    for record in records {
+        let aggregation = &record.aggregation;
+        let verdict = aggregation.overall_verdict();
+        let _ = writeln!(
+            md,
+            "\n## Phase {} — {} {}\n",
+            aggregation.phase,
+            verdict.emoji(),
+            verdict
+        );
+        let _ = writeln!(
+            md,
+            "_Recorded {}; {} report(s), {} finding(s)._\n",
+            record.recorded_at.format("%Y-%m-%d %H:%M:%S UTC"),
+            aggregation.reports_count(),
+            aggregation.all_findings_count()
+        );
+
+        if !aggregation.has_reports() {
+            continue;
+        }
+
+        md.push_str("| Specialist | Verdict | Findings | Summary |\n");
+        md.push_str("|---|---|---|---|\n");
+        for report in &aggregation.reports {
+            let _ = writeln!(
+                md,
+                "| {} | {} {} | {} | {} |",
+                report.reviewer,
+                report.verdict.emoji(),
+                report.verdict,
+                report.findings_count(),
+                report.summary.replace('|', "\\|").replace('\n', " ")
+            );
+        }
+
+        for report in aggregation.reports.iter().filter(|r| r.has_findings()) {
+            let _ = writeln!(md, "\n### {}\n", report.reviewer);
+            for finding in &report.findings {
+                let _ = writeln!(
+                    md,
+                    "- {} **{}** `{}`: {}",
+                    finding.severity().emoji(),
+                    finding.severity(),
+                    finding.location(),
+                    finding.issue()
+                );
+                if let Some(suggestion) = finding.suggestion() {
+                    let _ = writeln!(md, "  - Suggestion: {}", suggestion);
+                }
+            }
+        }
+
+        let suppressed: usize = aggregation.reports.iter().map(|r| r.suppressed.len()).sum();
+        if suppressed > 0 {
+            let _ = writeln!(
+                md,
+                "\n_{} finding(s) suppressed by the baseline or inline comments._",
+                suppressed
+            );
+        }
+    }
+
+    md
+}
+
+fn seconds(duration_ms: Option<u64>) -> String {
+    format!("{:.3}", duration_ms.unwrap_or(0) as f64 / 1000.0)
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c if c.is_control() && !matches!(c, '\n' | '\t' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::review::{ReviewAggregation, ReviewVerdict, SuppressedFinding};
+    use chrono::DateTime;
+
+    fn sample_record() -> ReviewRecord {
+        let failing = ReviewReport::new("05", "security-sentinel", ReviewVerdict::Fail)
+            .with_summary("SQL <injection>")
+            .add_finding(
+                ReviewFinding::new(FindingSeverity::Error, "src/db.rs", "Unescaped & query")
+                    .with_line(12)
+                    .with_column(3)
+                    .with_category("sql-injection")
+                    .with_suggestion("Bind parameters"),
+            )
+            .add_finding(ReviewFinding::new(
+                FindingSeverity::Info,
+                "src/db.rs",
+                "Consider a pool",
+            ))
+            .with_duration_ms(1500);
+        let mut passing = ReviewReport::new("05", "performance-oracle", ReviewVerdict::Pass);
+        passing.suppressed.push(SuppressedFinding {
+            finding: ReviewFinding::new(FindingSeverity::Warning, "src/db.rs", "N+1 query"),
+            reason: SuppressionReason::Baseline,
+        });
+
+        ReviewRecord {
+            recorded_at: DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            aggregation: ReviewAggregation::new("05")
+                .add_report(failing)
+                .add_report(passing)
+                .with_total_duration_ms(2000),
+        }
+    }
+
+    #[test]
+    fn test_to_sarif_one_run_per_report() {
+        let sarif = to_sarif(&[sample_record()]);
+
+        assert_eq!(sarif["version"], "2.1.0");
+        let runs = sarif["runs"].as_array().unwrap();
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0]["tool"]["driver"]["name"], "forge/security-sentinel");
+
+        let results = runs[0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0]["ruleId"], "sql-injection");
+        assert_eq!(results[0]["level"], "error");
+        let region = &results[0]["locations"][0]["physicalLocation"]["region"];
+        assert_eq!(region["startLine"], 12);
+        assert_eq!(region["startColumn"], 3);
+        assert_eq!(results[1]["ruleId"], "security-sentinel");
+        assert_eq!(results[1]["level"], "note");
+        assert!(
+            results[1]["locations"][0]["physicalLocation"]
+                .get("region")
+                .is_none()
+        );
+        assert!(results[0].get("suppressions").is_none());
+
+        let suppressed = &runs[1]["results"][0];
+        assert_eq!(suppressed["suppressions"][0]["kind"], "external");
+    }
+
+    #[test]
+    fn test_to_junit_reports_gating_failures() {
+        let xml = to_junit(&[sample_record()]);
+
+        assert!(xml.contains("<testsuites name=\"forge-review\" tests=\"2\" failures=\"1\">"));
+        assert!(xml.contains("<testsuite name=\"phase-05\" tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("name=\"security-sentinel\" time=\"1.500\">"));
+        assert!(xml.contains("<failure type=\"FAIL\" message=\"1 blocking finding(s)\">"));
+        assert!(xml.contains("Unescaped &amp; query"));
+        assert!(xml.contains("SQL &lt;injection&gt;"));
+        assert!(xml.contains("name=\"performance-oracle\" time=\"0.000\"/>"));
+    }
+
+    #[test]
+    fn test_to_markdown_lists_findings() {
+        let md = to_markdown(&[sample_record()]);
+
+        assert!(md.contains("## Phase 05"));
+        assert!(md.contains("| security-sentinel | ✗ FAIL | 2 |"));
+        assert!(md.contains("`src/db.rs:12:3`: Unescaped & query"));
+        assert!(md.contains("  - Suggestion: Bind parameters"));
+        assert!(md.contains("1 finding(s) suppressed"));
+    }
+
+    #[test]
+    fn test_to_markdown_empty() {
+        assert!(to_markdown(&[]).contains("No review runs recorded."));
+    }
+
+    #[test]
+    fn test_export_format_parses_from_config() {
+        let formats: Vec<ReviewExportFormat> =
+            serde_json::from_str(r#"["sarif", "junit", "markdown"]"#).unwrap();
+
+        assert_eq!(
+            formats,
+            vec![
+                ReviewExportFormat::Sarif,
+                ReviewExportFormat::Junit,
+                ReviewExportFormat::Markdown
+            ]
+        );
+    }
+}
@@ -9,6 +9,7 @@
 //! - [`FindingSeverity`]: Severity classification for individual findings
 //! - [`ReviewVerdict`]: Overall verdict for a review (pass/warn/fail)
 //! - [`ReviewFinding`]: A single identified issue with location and suggestion
+//! - [`SuppressedFinding`]: A finding excluded from gating by a baseline or inline comment
 //! - [`ReviewReport`]: Complete output from one review specialist
 //! - [`ReviewAggregation`]: Combined results from multiple specialists
 //!
@@ -148,6 +149,20 @@ impl ReviewVerdict {
         !matches!(self, Self::Fail)
     }
 
+    /// Derive a verdict from findings alone.
+    ///
+    /// A gating review fails on any error-severity finding; otherwise any
+    /// actionable finding warns.
+    pub fn from_findings(findings: &[ReviewFinding], is_gating: bool) -> Self {
+        if is_gating && findings.iter().any(ReviewFinding::is_critical) {
+            Self::Fail
+        } else if findings.iter().any(ReviewFinding::is_actionable) {
+            Self::Warn
+        } else {
+            Self::Pass
+        }
+    }
+
     /// Check if this is a passing verdict.
     pub fn is_pass(&self) -> bool {
         matches!(self, Self::Pass)
@@ -372,6 +387,35 @@ impl fmt::Display for ReviewFinding {
     }
 }
 
+/// Why a finding was excluded from gating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuppressionReason {
+    /// Matched an accepted finding in `.forge/review-baseline.json`.
+    Baseline,
+    /// Silenced by a `forge-review: ignore` comment in the source.
+    InlineComment,
+}
+
+impl fmt::Display for SuppressionReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Baseline => "baseline",
+            Self::InlineComment => "inline comment",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A finding that was reported but does not count towards gating.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuppressedFinding {
+    /// The original finding.
+    pub finding: ReviewFinding,
+    /// What suppressed it.
+    pub reason: SuppressionReason,
+}
+
 /// Complete output from a single review specialist.
 ///
 /// Contains all findings from one specialist examining a phase.
@@ -386,6 +430,9 @@ pub struct ReviewReport {
     /// List of all findings from this review.
     #[serde(default)]
     pub findings: Vec<ReviewFinding>,
+    /// Findings excluded from gating by the baseline or inline comments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressed: Vec<SuppressedFinding>,
     /// High-level summary of the review.
     #[serde(default)]
     pub summary: String,
@@ -425,6 +472,7 @@ impl ReviewReport {
             reviewer: reviewer.into(),
             verdict,
             findings: Vec::new(),
+            suppressed: Vec::new(),
             summary: String::new(),
             timestamp: None,
             duration_ms: None,
@@ -540,6 +588,13 @@ impl fmt::Display for ReviewReport {
             }
         }
 
+        if !self.suppressed.is_empty() {
+            writeln!(f, "  Suppressed ({}):", self.suppressed.len())?;
+            for suppressed in &self.suppressed {
+                writeln!(f, "    {} [{}]", suppressed.finding, suppressed.reason)?;
+            }
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,615 @@
+//! Structured fix loop for failed gating reviews.
+//!
+//! Instead of handing the next iteration one blob of fix instructions, every
+//! actionable finding of a failed gating review becomes a [`FixItem`] with a
+//! stable id (`F1`, `F2`, ...). A fix round then:
+//!
+//! 1. Lists the open items with file/line context in the fix prompt
+//!    ([`FixPlan::fix_prompt`]). The agent may answer per item with
+//!    `<fix-item id="F2" status="disputed">reason</fix-item>`.
+//! 2. Has the specialist that reported each item re-check only those items
+//!    ([`FixPlan::recheck_prompt`] for LLM reviewers, a tool re-run for tool
+//!    reviewers).
+//! 3. Hands the arbiter an aggregation of the unresolved items only
+//!    ([`FixPlan::unresolved_aggregation`]).
+//!
+//! The final item statuses are recorded in the phase audit.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::baseline::BaselineEntry;
+use super::dispatcher::extract_json;
+use super::{ReviewAggregation, ReviewFinding, ReviewReport, ReviewSpecialist, ReviewVerdict};
+
+/// Lines of source shown above and below a finding in the fix prompt.
+const CONTEXT_LINES: u32 = 2;
+
+/// Matches `<fix-item id="F1" status="disputed">reason</fix-item>`.
+static FIX_ITEM_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?s)<fix-item\s+id="([^"]+)"\s+status="([^"]+)"\s*>(.*?)</fix-item>"#).unwrap()
+});
+
+/// Status of a tracked fix item.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FixStatus {
+    /// Not yet confirmed fixed by the specialist.
+    #[default]
+    Pending,
+    /// The specialist no longer reports the finding.
+    Fixed,
+    /// The fix agent argued the finding is not a real issue.
+    Disputed,
+    /// The fix agent postponed the finding (e.g. out of scope for the phase).
+    Deferred,
+}
+
+impl FixStatus {
+    fn parse(status: &str) -> Option<Self> {
+        match status.trim().to_lowercase().as_str() {
+            "pending" | "open" => Some(Self::Pending),
+            "fixed" => Some(Self::Fixed),
+            "disputed" => Some(Self::Disputed),
+            "deferred" => Some(Self::Deferred),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for FixStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => write!(f, "pending"),
+            Self::Fixed => write!(f, "fixed"),
+            Self::Disputed => write!(f, "disputed"),
+            Self::Deferred => write!(f, "deferred"),
+        }
+    }
+}
+
+/// One actionable finding tracked through the fix loop.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixItem {
+    /// Stable id within the plan (`F1`, `F2`, ...).
+    pub id: String,
+    /// Agent name of the specialist that reported the finding.
+    pub specialist: String,
+    /// The finding to fix.
+    pub finding: ReviewFinding,
+    /// Current status.
+    #[serde(default)]
+    pub status: FixStatus,
+    /// Fix rounds spent on this item.
+    #[serde(default)]
+    pub attempts: u32,
+    /// Latest explanation from the fix agent or the specialist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl FixItem {
+    /// Whether the item no longer blocks the phase.
+    pub fn is_resolved(&self) -> bool {
+        self.status == FixStatus::Fixed
+    }
+
+    /// One-line description (`[severity] location: issue`).
+    pub fn label(&self) -> String {
+        format!(
+            "[{}] {}: {}",
+            self.finding.severity(),
+            self.finding.location(),
+            self.finding.issue()
+        )
+    }
+}
+
+/// The tracked fix items of one phase.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FixPlan {
+    /// Phase the findings belong to.
+    pub phase: String,
+    /// Tracked items, in the order they were reported.
+    pub items: Vec<FixItem>,
+}
+
+impl FixPlan {
+    /// Track every actionable finding of the aggregation's gating failures.
+    pub fn from_aggregation(aggregation: &ReviewAggregation) -> Self {
+        let items = aggregation
+            .gating_failures()
+            .into_iter()
+            .flat_map(|report| {
+                report
+                    .actionable_findings()
+                    .into_iter()
+                    .map(move |finding| (report.reviewer.clone(), finding.clone()))
+            })
+            .enumerate()
+            .map(|(index, (specialist, finding))| FixItem {
+                id: format!("F{}", index + 1),
+                specialist,
+                finding,
+                status: FixStatus::Pending,
+                attempts: 0,
+                note: None,
+            })
+            .collect();
+
+        Self {
+            phase: aggregation.phase.clone(),
+            items,
+        }
+    }
+
+    /// Whether the plan tracks no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Items awaiting a fix.
+    pub fn pending(&self) -> Vec<&FixItem> {
+        self.items
+            .iter()
+            .filter(|item| item.status == FixStatus::Pending)
+            .collect()
+    }
+
+    /// Items that still block the phase (pending, disputed or deferred).
+    pub fn unresolved(&self) -> Vec<&FixItem> {
+        self.items
+            .iter()
+            .filter(|item| !item.is_resolved())
+            .collect()
+    }
+
+    /// Specialists with pending items, in report order.
+    pub fn specialists_with_pending(&self) -> Vec<&str> {
+        let mut specialists: Vec<&str> = Vec::new();
+        for item in self.pending() {
+            if !specialists.contains(&item.specialist.as_str()) {
+                specialists.push(&item.specialist);
+            }
+        }
+        specialists
+    }
+
+    /// Keep only the items whose [`FixItem::label`] is listed.
+    ///
+    /// Used when a human reviewer picked which findings to fix; the others
+    /// were accepted or dismissed and no longer block the phase.
+    pub fn retain_labels(&mut self, labels: &[String]) {
+        self.items.retain(|item| labels.contains(&item.label()));
+    }
+
+    /// Reopen disputed and deferred items.
+    ///
+    /// Used when the arbiter asks for another fix round although nothing is
+    /// pending, i.e. it rejected the agent's disputes.
+    pub fn reopen_unresolved(&mut self) {
+        for item in &mut self.items {
+            if matches!(item.status, FixStatus::Disputed | FixStatus::Deferred) {
+                item.status = FixStatus::Pending;
+            }
+        }
+    }
+
+    /// Build the fix iteration prompt listing the pending items.
+    ///
+    /// With a working directory, each item shows the surrounding source lines.
+    pub fn fix_prompt(&self, working_dir: Option<&Path>, instructions: Option<&str>) -> String {
+        let mut prompt = String::from(
+            "## Review Fix Items\n\n\
+             Gating reviews failed. Fix each item below, touching only what is needed.\n",
+        );
+        if let Some(instructions) = instructions.filter(|text| !text.trim().is_empty()) {
+            prompt.push_str(&format!("\nArbiter guidance: {}\n", instructions.trim()));
+        }
+
+        for item in self.pending() {
+            let finding = &item.finding;
+            prompt.push_str(&format!(
+                "\n### {} [{}] {} ({})\n{}\n",
+                item.id,
+                finding.severity(),
+                finding.location(),
+                item.specialist,
+                finding.issue()
+            ));
+            if let Some(suggestion) = finding.suggestion() {
+                prompt.push_str(&format!("Suggestion: {}\n", suggestion));
+            }
+            if let Some(snippet) = working_dir.and_then(|dir| source_context(dir, finding)) {
+                prompt.push_str(&format!("```\n{}\n```\n", snippet));
+            }
+        }
+
+        prompt.push_str(
+            "\nFor any item you do not fix, say why with one tag per item:\n\
+             <fix-item id=\"F1\" status=\"disputed\">not a real issue because ...</fix-item>\n\
+             <fix-item id=\"F2\" status=\"deferred\">out of scope because ...</fix-item>\n\
+             Items without a tag are re-checked by the reviewer that reported them.\n",
+        );
+        prompt
+    }
+
+    /// Apply the fix agent's `<fix-item>` answers.
+    ///
+    /// Only pending items change. A `fixed` answer keeps the item pending
+    /// until the specialist confirms it.
+    pub fn apply_fix_response(&mut self, output: &str) {
+        let answers: HashMap<&str, (FixStatus, &str)> = FIX_ITEM_REGEX
+            .captures_iter(output)
+            .filter_map(|caps| {
+                let id = caps.get(1)?.as_str();
+                let status = FixStatus::parse(caps.get(2)?.as_str())?;
+                Some((id, (status, caps.get(3)?.as_str().trim())))
+            })
+            .collect();
+
+        for item in &mut self.items {
+            if item.status != FixStatus::Pending {
+                continue;
+            }
+            item.attempts += 1;
+            let Some((status, note)) = answers.get(item.id.as_str()) else {
+                continue;
+            };
+            if matches!(status, FixStatus::Disputed | FixStatus::Deferred) {
+                item.status = *status;
+            }
+            if !note.is_empty() {
+                item.note = Some(note.to_string());
+            }
+        }
+    }
+
+    /// Prompt asking `specialist` to re-check only its pending items.
+    pub fn recheck_prompt(&self, specialist: &ReviewSpecialist) -> String {
+        let name = specialist.agent_name();
+        let mut prompt = format!(
+            "# {} Re-check\n\n\
+             You are a code review specialist focused on **{}** concerns. \
+             A fix iteration just ran for phase {}. Do not perform a full review: \
+             only decide, for each finding below, whether it is still present.\n",
+            specialist.display_name(),
+            specialist.display_name(),
+            self.phase
+        );
+        for item in self.pending_for(&name) {
+            prompt.push_str(&format!(
+                "\n- {}: {} - {}",
+                item.id,
+                item.finding.location(),
+                item.finding.issue()
+            ));
+        }
+        prompt.push_str(
+            "\n\n## Output Format\n\n\
+             Respond with a JSON object:\n\
+             ```json\n\
+             {\"items\": [{\"id\": \"F1\", \"status\": \"fixed|open\", \"note\": \"Why\"}]}\n\
+             ```\n",
+        );
+        prompt
+    }
+
+    /// Apply a specialist's re-check answer for its pending items.
+    ///
+    /// Returns false if the answer could not be parsed; items stay pending.
+    pub fn apply_recheck_response(&mut self, specialist: &str, output: &str) -> bool {
+        let Some(value) = extract_json(output)
+            .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        else {
+            return false;
+        };
+        let Some(answers) = value.get("items").and_then(|items| items.as_array()) else {
+            return false;
+        };
+
+        for answer in answers {
+            let Some(id) = answer.get("id").and_then(|id| id.as_str()) else {
+                continue;
+            };
+            let Some(item) = self.items.iter_mut().find(|item| {
+                item.id == id && item.specialist == specialist && item.status == FixStatus::Pending
+            }) else {
+                continue;
+            };
+            if answer.get("status").and_then(|status| status.as_str()) == Some("fixed") {
+                item.status = FixStatus::Fixed;
+            }
+            if let Some(note) = answer.get("note").and_then(|note| note.as_str()) {
+                item.note = Some(note.to_string());
+            }
+        }
+        true
+    }
+
+    /// Mark a tool specialist's pending items fixed unless a re-run still reports them.
+    pub fn apply_tool_findings(&mut self, specialist: &str, findings: &[ReviewFinding]) {
+        for item in &mut self.items {
+            if item.specialist != specialist || item.status != FixStatus::Pending {
+                continue;
+            }
+            let entry = BaselineEntry::from_finding(specialist, &item.finding);
+            if !findings
+                .iter()
+                .any(|finding| entry.matches(specialist, finding))
+            {
+                item.status = FixStatus::Fixed;
+            }
+        }
+    }
+
+    /// Aggregation holding only the unresolved items, one report per specialist.
+    ///
+    /// Specialists whose items are all fixed get a passing report.
+    pub fn unresolved_aggregation(&self) -> ReviewAggregation {
+        let mut reports: Vec<ReviewReport> = Vec::new();
+        for item in &self.items {
+            let index = match reports
+                .iter()
+                .position(|report| report.reviewer == item.specialist)
+            {
+                Some(index) => index,
+                None => {
+                    reports.push(ReviewReport::new(
+                        &self.phase,
+                        &item.specialist,
+                        ReviewVerdict::Pass,
+                    ));
+                    reports.len() - 1
+                }
+            };
+            if !item.is_resolved() {
+                let report = &mut reports[index];
+                report.verdict = ReviewVerdict::Fail;
+                report.findings.push(item.finding.clone());
+            }
+        }
+
+        let reports = reports.into_iter().map(|report| {
+            let summary = match report.findings.len() {
+                0 => "All fix items resolved".to_string(),
+                n => format!("{} unresolved fix item(s)", n),
+            };
+            report.with_summary(summary)
+        });
+        ReviewAggregation::new(&self.phase).add_reports(reports)
+    }
+
+    /// Arbiter context describing the status of every unresolved item.
+    pub fn arbiter_context(&self) -> Option<String> {
+        let unresolved = self.unresolved();
+        if unresolved.is_empty() {
+            return None;
+        }
+        let mut context = format!(
+            "Fix loop status: {}. Only the unresolved items below are blocking:\n",
+            self.summary()
+        );
+        for item in unresolved {
+            context.push_str(&format!(
+                "- {} [{}] {} - {}",
+                item.id,
+                item.status,
+                item.finding.location(),
+                item.finding.issue()
+            ));
+            if let Some(note) = &item.note {
+                context.push_str(&format!(" (note: {})", note));
+            }
+            context.push('\n');
+        }
+        Some(context)
+    }
+
+    /// One-line count of items per status.
+    pub fn summary(&self) -> String {
+        let count = |status: FixStatus| {
+            self.items
+                .iter()
+                .filter(|item| item.status == status)
+                .count()
+        };
+        format!(
+            "{} fixed, {} disputed, {} deferred, {} pending",
+            count(FixStatus::Fixed),
+            count(FixStatus::Disputed),
+            count(FixStatus::Deferred),
+            count(FixStatus::Pending)
+        )
+    }
+
+    fn pending_for<'a>(&'a self, specialist: &'a str) -> impl Iterator<Item = &'a FixItem> {
+        self.items
+            .iter()
+            .filter(move |item| item.specialist == specialist && item.status == FixStatus::Pending)
+    }
+}
+
+/// Numbered source lines around a finding, if the file and line are known.
+fn source_context(working_dir: &Path, finding: &ReviewFinding) -> Option<String> {
+    let line = finding.line()?;
+    let source = std::fs::read_to_string(working_dir.join(finding.file())).ok()?;
+    let first = line.saturating_sub(CONTEXT_LINES).max(1);
+    let last = line + CONTEXT_LINES;
+    let snippet: Vec<String> = source
+        .lines()
+        .enumerate()
+        .map(|(index, text)| (index as u32 + 1, text))
+        .filter(|(number, _)| (first..=last).contains(number))
+        .map(|(number, text)| {
+            let marker = if number == line { ">" } else { " " };
+            format!("{}{:>5} | {}", marker, number, text)
+        })
+        .collect();
+    (!snippet.is_empty()).then(|| snippet.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::review::{FindingSeverity, SpecialistType};
+    use tempfile::TempDir;
+
+    fn aggregation() -> ReviewAggregation {
+        ReviewAggregation::new("05")
+            .add_report(
+                ReviewReport::new("05", "security-sentinel", ReviewVerdict::Fail)
+                    .add_finding(
+                        ReviewFinding::new(FindingSeverity::Error, "src/db.rs", "SQL injection")
+                            .with_line(3)
+                            .with_suggestion("Use bound parameters"),
+                    )
+                    .add_finding(ReviewFinding::new(
+                        FindingSeverity::Info,
+                        "src/db.rs",
+                        "Consider a doc comment",
+                    ))
+                    .add_finding(ReviewFinding::new(
+                        FindingSeverity::Warning,
+                        "src/auth.rs",
+                        "Token logged",
+                    )),
+            )
+            .add_report(
+                ReviewReport::new("05", "performance-oracle", ReviewVerdict::Warn).add_finding(
+                    ReviewFinding::new(FindingSeverity::Warning, "src/lib.rs", "N+1 query"),
+                ),
+            )
+    }
+
+    #[test]
+    fn test_plan_tracks_actionable_gating_findings() {
+        let plan = FixPlan::from_aggregation(&aggregation());
+
+        assert_eq!(plan.items.len(), 2);
+        assert_eq!(plan.items[0].id, "F1");
+        assert_eq!(plan.items[1].finding.issue(), "Token logged");
+        assert_eq!(plan.specialists_with_pending(), vec!["security-sentinel"]);
+    }
+
+    #[test]
+    fn test_fix_prompt_includes_source_context() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::create_dir(temp_dir.path().join("src")).unwrap();
+        std::fs::write(
+            temp_dir.path().join("src/db.rs"),
+            "fn a() {}\nfn b() {}\nlet q = format!(\"{id}\");\nfn c() {}\n",
+        )
+        .unwrap();
+        let plan = FixPlan::from_aggregation(&aggregation());
+
+        let prompt = plan.fix_prompt(Some(temp_dir.path()), Some("Fix the query first"));
+
+        assert!(prompt.contains("### F1 [error] src/db.rs:3 (security-sentinel)"));
+        assert!(prompt.contains("Suggestion: Use bound parameters"));
+        assert!(prompt.contains(">    3 | let q"));
+        assert!(prompt.contains("Arbiter guidance: Fix the query first"));
+        assert!(prompt.contains("### F2 [warning] src/auth.rs"));
+    }
+
+    #[test]
+    fn test_apply_fix_response_records_disputes() {
+        let mut plan = FixPlan::from_aggregation(&aggregation());
+
+        plan.apply_fix_response(
+            "Fixed the query.\n<fix-item id=\"F2\" status=\"disputed\">\nOnly the token id is logged\n</fix-item>",
+        );
+
+        assert_eq!(plan.items[0].status, FixStatus::Pending);
+        assert_eq!(plan.items[0].attempts, 1);
+        assert_eq!(plan.items[1].status, FixStatus::Disputed);
+        assert_eq!(
+            plan.items[1].note.as_deref(),
+            Some("Only the token id is logged")
+        );
+        assert_eq!(plan.pending().len(), 1);
+    }
+
+    #[test]
+    fn test_apply_recheck_response() {
+        let mut plan = FixPlan::from_aggregation(&aggregation());
+        let prompt =
+            plan.recheck_prompt(&ReviewSpecialist::gating(SpecialistType::SecuritySentinel));
+        assert!(prompt.contains("F1: src/db.rs:3 - SQL injection"));
+        assert!(prompt.contains("F2: src/auth.rs - Token logged"));
+
+        let parsed = plan.apply_recheck_response(
+            "security-sentinel",
+            r#"```json
+{"items": [{"id": "F1", "status": "fixed"}, {"id": "F2", "status": "open", "note": "still logged"}]}
+```"#,
+        );
+
+        assert!(parsed);
+        assert_eq!(plan.items[0].status, FixStatus::Fixed);
+        assert_eq!(plan.items[1].status, FixStatus::Pending);
+        assert_eq!(plan.items[1].note.as_deref(), Some("still logged"));
+        assert!(!plan.apply_recheck_response("security-sentinel", "no json here"));
+    }
+
+    #[test]
+    fn test_apply_tool_findings_matches_moved_lines() {
+        let mut plan = FixPlan::from_aggregation(&aggregation());
+        let rerun = vec![
+            ReviewFinding::new(FindingSeverity::Error, "src/db.rs", "SQL injection").with_line(5),
+        ];
+
+        plan.apply_tool_findings("security-sentinel", &rerun);
+
+        assert_eq!(plan.items[0].status, FixStatus::Pending);
+        assert_eq!(plan.items[1].status, FixStatus::Fixed);
+    }
+
+    #[test]
+    fn test_unresolved_aggregation_only_contains_open_items() {
+        let mut plan = FixPlan::from_aggregation(&aggregation());
+        plan.items[0].status = FixStatus::Fixed;
+        plan.items[1].status = FixStatus::Deferred;
+
+        let aggregation = plan.unresolved_aggregation();
+
+        assert!(aggregation.has_gating_failures());
+        assert_eq!(aggregation.all_findings_count(), 1);
+        assert_eq!(aggregation.all_findings()[0].issue(), "Token logged");
+        let context = plan.arbiter_context().unwrap();
+        assert!(context.contains("1 fixed, 0 disputed, 1 deferred, 0 pending"));
+        assert!(context.contains("F2 [deferred]"));
+
+        plan.items[1].status = FixStatus::Fixed;
+        assert!(!plan.unresolved_aggregation().has_gating_failures());
+        assert!(plan.arbiter_context().is_none());
+    }
+
+    #[test]
+    fn test_reopen_unresolved() {
+        let mut plan = FixPlan::from_aggregation(&aggregation());
+        plan.items[0].status = FixStatus::Fixed;
+        plan.items[1].status = FixStatus::Disputed;
+
+        plan.reopen_unresolved();
+
+        assert_eq!(plan.items[0].status, FixStatus::Fixed);
+        assert_eq!(plan.items[1].status, FixStatus::Pending);
+    }
+
+    #[test]
+    fn test_retain_labels() {
+        let mut plan = FixPlan::from_aggregation(&aggregation());
+        assert_eq!(plan.items[0].label(), "[error] src/db.rs:3: SQL injection");
+
+        plan.retain_labels(&["[warning] src/auth.rs: Token logged".to_string()]);
+
+        assert_eq!(plan.items.len(), 1);
+        assert_eq!(plan.items[0].id, "F2");
+    }
+}
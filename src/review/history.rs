@@ -0,0 +1,395 @@
+//! Arbiter decision history and human overrides.
+//!
+//! Every arbiter decision made with a forge directory is appended to
+//! `.forge/arbiter-history.jsonl` together with the input it was made on.
+//! Operators can later override a decision (`forge review override`), and
+//! past decisions, overrides first, are fed back into future arbiter prompts
+//! as [`ArbiterPrecedent`]s so the arbiter converges to the team's actual
+//! risk tolerance.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+use uuid::Uuid;
+
+use super::{ArbiterInput, ArbiterResult, ArbiterVerdict, DecisionSource};
+
+/// History file name under the forge dir.
+const HISTORY_FILE: &str = "arbiter-history.jsonl";
+
+/// Default number of past decisions shown to the arbiter.
+pub const DEFAULT_PRECEDENT_LIMIT: usize = 5;
+
+/// Findings listed per precedent in the arbiter prompt.
+const PRECEDENT_FINDINGS: usize = 5;
+
+/// A human correction of an arbiter decision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HumanOverride {
+    /// The verdict the operator says should have been made.
+    pub verdict: ArbiterVerdict,
+    /// Why the operator overrode the arbiter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// When the override was recorded.
+    pub overridden_at: DateTime<Utc>,
+}
+
+/// One recorded arbiter decision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArbiterHistoryEntry {
+    /// Short unique id used to reference the decision.
+    pub id: String,
+    /// When the decision was made.
+    pub recorded_at: DateTime<Utc>,
+    /// What the arbiter decided on (without its own precedents).
+    pub input: ArbiterInput,
+    /// The decision.
+    pub result: ArbiterResult,
+    /// Human override of the decision, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub human_override: Option<HumanOverride>,
+}
+
+impl ArbiterHistoryEntry {
+    /// The verdict that should have been made: the override if present.
+    pub fn final_verdict(&self) -> ArbiterVerdict {
+        self.human_override
+            .as_ref()
+            .map_or(self.result.decision.decision, |o| o.verdict)
+    }
+
+    /// Condense the entry into a few-shot example for the arbiter prompt.
+    pub fn to_precedent(&self) -> ArbiterPrecedent {
+        ArbiterPrecedent {
+            phase: format!("{} - {}", self.input.phase_id, self.input.phase_name),
+            failed_specialists: self.input.failed_specialists.clone(),
+            findings: self
+                .input
+                .blocking_findings
+                .iter()
+                .take(PRECEDENT_FINDINGS)
+                .map(|finding| {
+                    format!(
+                        "[{}] {}: {}",
+                        finding.severity(),
+                        finding.location(),
+                        finding.issue()
+                    )
+                })
+                .collect(),
+            arbiter_verdict: self.result.decision.decision,
+            arbiter_reasoning: self.result.decision.reasoning.clone(),
+            human_verdict: self.human_override.as_ref().map(|o| o.verdict),
+            human_reason: self.human_override.as_ref().and_then(|o| o.reason.clone()),
+        }
+    }
+}
+
+/// A past decision shown to the arbiter as a few-shot example.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArbiterPrecedent {
+    /// Phase id and name.
+    pub phase: String,
+    /// Specialists whose gating reviews failed.
+    pub failed_specialists: Vec<String>,
+    /// Condensed blocking findings.
+    pub findings: Vec<String>,
+    /// What the arbiter decided.
+    pub arbiter_verdict: ArbiterVerdict,
+    /// The arbiter's reasoning.
+    pub arbiter_reasoning: String,
+    /// What a human said it should have been, if overridden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub human_verdict: Option<ArbiterVerdict>,
+    /// The human's reason for the override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub human_reason: Option<String>,
+}
+
+/// Reads and writes `.forge/arbiter-history.jsonl`.
+#[derive(Debug, Clone)]
+pub struct ArbiterHistory {
+    path: PathBuf,
+}
+
+impl ArbiterHistory {
+    /// Create a history for the given forge directory.
+    pub fn new(forge_dir: &Path) -> Self {
+        Self {
+            path: Self::path(forge_dir),
+        }
+    }
+
+    /// Path of the history file for a forge directory.
+    pub fn path(forge_dir: &Path) -> PathBuf {
+        forge_dir.join(HISTORY_FILE)
+    }
+
+    /// Load all entries, oldest first.
+    ///
+    /// Unreadable lines are skipped with a warning; a missing file yields no
+    /// entries.
+    pub fn load(&self) -> Result<Vec<ArbiterHistoryEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+
+        let mut entries = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(line) {
+                Ok(entry) => entries.push(entry),
+                Err(e) => warn!(
+                    "Skipping arbiter history line {} in {}: {}",
+                    index + 1,
+                    self.path.display(),
+                    e
+                ),
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Append a decision and return the recorded entry.
+    pub fn record(
+        &self,
+        input: &ArbiterInput,
+        result: &ArbiterResult,
+    ) -> Result<ArbiterHistoryEntry> {
+        let mut input = input.clone();
+        input.precedents.clear();
+        let entry = ArbiterHistoryEntry {
+            id: Uuid::new_v4().simple().to_string()[..8].to_string(),
+            recorded_at: Utc::now(),
+            input,
+            result: result.clone(),
+            human_override: None,
+        };
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open {}", self.path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?)
+            .with_context(|| format!("Failed to write {}", self.path.display()))?;
+
+        Ok(entry)
+    }
+
+    /// Record a human override of the decision with the given id.
+    ///
+    /// Only the overridden line is rewritten; every other line, including
+    /// ones that cannot be parsed, is kept as it is.
+    pub fn record_override(
+        &self,
+        id: &str,
+        verdict: ArbiterVerdict,
+        reason: Option<&str>,
+    ) -> Result<ArbiterHistoryEntry> {
+        let content = if self.path.exists() {
+            std::fs::read_to_string(&self.path)
+                .with_context(|| format!("Failed to read {}", self.path.display()))?
+        } else {
+            String::new()
+        };
+
+        let mut updated = None;
+        let mut rewritten = String::with_capacity(content.len());
+        for line in content.lines() {
+            match serde_json::from_str::<ArbiterHistoryEntry>(line) {
+                Ok(mut entry) if updated.is_none() && entry.id == id => {
+                    entry.human_override = Some(HumanOverride {
+                        verdict,
+                        reason: reason.map(str::to_string),
+                        overridden_at: Utc::now(),
+                    });
+                    rewritten.push_str(&serde_json::to_string(&entry)?);
+                    updated = Some(entry);
+                }
+                _ => rewritten.push_str(line),
+            }
+            rewritten.push('\n');
+        }
+        let Some(updated) = updated else {
+            bail!(
+                "No arbiter decision with id '{}' in {}",
+                id,
+                self.path.display()
+            );
+        };
+
+        std::fs::write(&self.path, rewritten)
+            .with_context(|| format!("Failed to write {}", self.path.display()))?;
+
+        Ok(updated)
+    }
+
+    /// Past decisions to show the arbiter, at most `limit`.
+    ///
+    /// Overridden decisions come first since they carry the most signal,
+    /// then the most recent ones. Fallback decisions are skipped.
+    pub fn precedents(&self, limit: usize) -> Result<Vec<ArbiterPrecedent>> {
+        let mut entries: Vec<_> = self
+            .load()?
+            .into_iter()
+            .filter(|entry| entry.result.source != DecisionSource::Fallback)
+            .collect();
+        entries.reverse();
+        entries.sort_by_key(|entry| entry.human_override.is_none());
+
+        Ok(entries
+            .iter()
+            .take(limit)
+            .map(ArbiterHistoryEntry::to_precedent)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::review::{
+        ArbiterDecision, FindingSeverity, ReviewAggregation, ReviewFinding, ReviewReport,
+        ReviewVerdict,
+    };
+    use tempfile::TempDir;
+
+    fn input(phase: &str) -> ArbiterInput {
+        let aggregation = ReviewAggregation::new(phase).add_report(
+            ReviewReport::new(phase, "security-sentinel", ReviewVerdict::Fail).add_finding(
+                ReviewFinding::new(FindingSeverity::Warning, "src/auth.rs", "Token logged")
+                    .with_line(7),
+            ),
+        );
+        ArbiterInput::from_aggregation(&aggregation, 10, 4).with_phase_name("Auth")
+    }
+
+    fn proceed() -> ArbiterResult {
+        ArbiterResult::rule_based(ArbiterDecision::proceed("Minor warning", 0.8))
+    }
+
+    #[test]
+    fn test_record_and_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = ArbiterHistory::new(temp_dir.path());
+
+        let first = history.record(&input("05"), &proceed()).unwrap();
+        history.record(&input("06"), &proceed()).unwrap();
+
+        let entries = history.load().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0], first);
+        assert_eq!(first.id.len(), 8);
+        assert_eq!(entries[1].input.phase_id, "06");
+    }
+
+    #[test]
+    fn test_record_strips_precedents() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = ArbiterHistory::new(temp_dir.path());
+        let first = history.record(&input("05"), &proceed()).unwrap();
+
+        let with_precedents = input("06").with_precedents(vec![first.to_precedent()]);
+        let second = history.record(&with_precedents, &proceed()).unwrap();
+
+        assert!(second.input.precedents.is_empty());
+    }
+
+    #[test]
+    fn test_record_override() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = ArbiterHistory::new(temp_dir.path());
+        let entry = history.record(&input("05"), &proceed()).unwrap();
+
+        let updated = history
+            .record_override(&entry.id, ArbiterVerdict::Fix, Some("Never log tokens"))
+            .unwrap();
+
+        assert_eq!(updated.final_verdict(), ArbiterVerdict::Fix);
+        let loaded = history.load().unwrap();
+        assert_eq!(loaded[0].human_override, updated.human_override);
+        assert!(
+            history
+                .record_override("missing", ArbiterVerdict::Fix, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_record_override_keeps_unparseable_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = ArbiterHistory::new(temp_dir.path());
+        let entry = history.record(&input("05"), &proceed()).unwrap();
+        let path = ArbiterHistory::path(temp_dir.path());
+        let mut content = std::fs::read_to_string(&path).unwrap();
+        content.push_str("{\"id\": \"from-a-newer-forge\"}\n");
+        std::fs::write(&path, &content).unwrap();
+
+        history
+            .record_override(&entry.id, ArbiterVerdict::Fix, None)
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), 2);
+        assert!(content.ends_with("{\"id\": \"from-a-newer-forge\"}\n"));
+        assert!(history.load().unwrap()[0].human_override.is_some());
+    }
+
+    #[test]
+    fn test_precedents_prefer_overrides_then_recent() {
+        let temp_dir = TempDir::new().unwrap();
+        let history = ArbiterHistory::new(temp_dir.path());
+        let overridden = history.record(&input("01"), &proceed()).unwrap();
+        history.record(&input("02"), &proceed()).unwrap();
+        history.record(&input("03"), &proceed()).unwrap();
+        history
+            .record(
+                &input("04"),
+                &ArbiterResult::fallback(ArbiterDecision::default(), "timeout"),
+            )
+            .unwrap();
+        history
+            .record_override(
+                &overridden.id,
+                ArbiterVerdict::Fix,
+                Some("Never log tokens"),
+            )
+            .unwrap();
+
+        let precedents = history.precedents(2).unwrap();
+
+        assert_eq!(precedents.len(), 2);
+        assert_eq!(precedents[0].phase, "01 - Auth");
+        assert_eq!(precedents[0].human_verdict, Some(ArbiterVerdict::Fix));
+        assert_eq!(
+            precedents[0].findings,
+            vec!["[warning] src/auth.rs:7: Token logged"]
+        );
+        assert_eq!(precedents[1].phase, "03 - Auth");
+    }
+
+    #[test]
+    fn test_load_missing_file_is_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(
+            ArbiterHistory::new(temp_dir.path())
+                .load()
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,668 @@
+//! Manual resolution of gating review failures.
+//!
+//! In manual mode ([`ResolutionMode::manual`](super::ResolutionMode::manual))
+//! the dispatcher does not ask the arbiter. It turns the failed aggregation
+//! into a [`PendingReview`] and waits for a [`ManualReviewHandler`] to return
+//! a human [`ManualResolution`]: accept, fix or dismiss per finding, with
+//! optional notes. The resolution becomes an [`ArbiterDecision`] so the fix
+//! loop and the arbiter history treat it like any other decision.
+//!
+//! Two handlers are provided:
+//!
+//! - [`TerminalReviewer`]: an interactive findings browser on the terminal.
+//! - [`FileReviewQueue`]: writes `.forge/pending-reviews/<id>.json` and polls
+//!   for `<id>.decision.json`, written by `forge review resolve` or the
+//!   Factory API. It gives up after a configurable timeout and the dispatcher
+//!   falls back to the arbiter.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{ArbiterDecision, FixItem, FixPlan, ReviewAggregation};
+
+/// Directory under the forge dir holding pending reviews and decisions.
+pub const PENDING_REVIEWS_DIR: &str = "pending-reviews";
+
+/// Default interval between decision file checks.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// No decision for a pending review arrived in time.
+///
+/// The dispatcher falls back to the arbiter when a handler fails with this.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("No decision for review '{id}' within {}s", .timeout.as_secs())]
+pub struct ReviewTimedOut {
+    /// The pending review that was withdrawn.
+    pub id: String,
+    /// How long the handler waited.
+    pub timeout: Duration,
+}
+
+/// What a human decided for one finding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum FindingAction {
+    /// The finding is valid but acceptable as is.
+    Accept,
+    /// The finding must be fixed before the phase proceeds.
+    Fix,
+    /// The finding is a false positive.
+    Dismiss,
+}
+
+impl fmt::Display for FindingAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Accept => write!(f, "accept"),
+            Self::Fix => write!(f, "fix"),
+            Self::Dismiss => write!(f, "dismiss"),
+        }
+    }
+}
+
+/// A human decision on one finding of a pending review.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FindingDecision {
+    /// Item id within the pending review (`F1`, `F2`, ...).
+    pub id: String,
+    /// The chosen action.
+    pub action: FindingAction,
+    /// Optional note passed on to the fix agent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// A gating review failure waiting for a human decision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingReview {
+    /// Short unique id used to reference the review.
+    pub id: String,
+    /// Phase number.
+    pub phase: String,
+    /// Phase name.
+    pub phase_name: String,
+    /// When the review was requested.
+    pub created_at: DateTime<Utc>,
+    /// Fix rounds already spent on the phase.
+    #[serde(default)]
+    pub fix_attempts: u32,
+    /// The blocking findings to decide on.
+    pub items: Vec<FixItem>,
+}
+
+impl PendingReview {
+    /// Create a pending review for the actionable gating findings.
+    pub fn new(aggregation: &ReviewAggregation, phase_name: &str, fix_attempts: u32) -> Self {
+        Self {
+            id: Uuid::new_v4().simple().to_string()[..8].to_string(),
+            phase: aggregation.phase.clone(),
+            phase_name: phase_name.to_string(),
+            created_at: Utc::now(),
+            fix_attempts,
+            items: FixPlan::from_aggregation(aggregation).items,
+        }
+    }
+}
+
+/// A human resolution of a [`PendingReview`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ManualResolution {
+    /// Per-finding decisions. Findings without one are treated as `fix`.
+    #[serde(default)]
+    pub findings: Vec<FindingDecision>,
+    /// Reject the phase outright.
+    #[serde(default)]
+    pub fail_phase: bool,
+    /// Overall note from the reviewer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl ManualResolution {
+    /// A resolution failing the phase.
+    pub fn fail(note: Option<&str>) -> Self {
+        Self {
+            fail_phase: true,
+            note: note.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    /// Set the action for a finding, replacing any earlier decision.
+    pub fn with_finding(mut self, id: &str, action: FindingAction, note: Option<&str>) -> Self {
+        self.set(id, action, note);
+        self
+    }
+
+    /// Set the overall note.
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+
+    /// Set the action for a finding, replacing any earlier decision.
+    pub fn set(&mut self, id: &str, action: FindingAction, note: Option<&str>) {
+        self.findings.retain(|decision| decision.id != id);
+        self.findings.push(FindingDecision {
+            id: id.to_string(),
+            action,
+            note: note.map(str::to_string),
+        });
+    }
+
+    /// The decision for a finding, if any.
+    pub fn decision_for(&self, id: &str) -> Option<&FindingDecision> {
+        self.findings.iter().find(|decision| decision.id == id)
+    }
+
+    /// The action for a finding, `fix` when undecided.
+    pub fn action_for(&self, id: &str) -> FindingAction {
+        self.decision_for(id)
+            .map_or(FindingAction::Fix, |decision| decision.action)
+    }
+
+    /// Turn the resolution into an arbiter decision.
+    ///
+    /// Any finding marked `fix` yields a FIX decision whose instructions list
+    /// those findings with their notes and whose addressed findings are their
+    /// [`FixItem::label`]s. Otherwise the phase proceeds.
+    pub fn to_decision(&self, review: &PendingReview) -> ArbiterDecision {
+        let note = self
+            .note
+            .as_deref()
+            .map(|note| format!(" Reviewer note: {}", note))
+            .unwrap_or_default();
+
+        if self.fail_phase {
+            let summary = self
+                .note
+                .clone()
+                .unwrap_or_else(|| "Phase rejected during manual review".to_string());
+            return ArbiterDecision::fail_phase(
+                &format!("Reviewer rejected the phase.{}", note),
+                1.0,
+                &summary,
+            );
+        }
+
+        let mut fixes = Vec::new();
+        let mut kept = Vec::new();
+        for item in &review.items {
+            let line = match self.decision_for(&item.id).and_then(|d| d.note.as_deref()) {
+                Some(item_note) => format!("- {} ({})", item.label(), item_note),
+                None => format!("- {}", item.label()),
+            };
+            match self.action_for(&item.id) {
+                FindingAction::Fix => fixes.push((item.label(), line)),
+                FindingAction::Accept | FindingAction::Dismiss => kept.push(line),
+            }
+        }
+
+        if fixes.is_empty() {
+            return ArbiterDecision::proceed(
+                &format!(
+                    "Reviewer accepted or dismissed all {} finding(s).{}",
+                    review.items.len(),
+                    note
+                ),
+                1.0,
+            );
+        }
+
+        let mut instructions = format!(
+            "Fix these findings:\n{}",
+            fixes
+                .iter()
+                .map(|(_, line)| line.as_str())
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+        if !kept.is_empty() {
+            instructions.push_str(&format!(
+                "\nLeave these as they are (accepted or dismissed by the reviewer):\n{}",
+                kept.join("\n")
+            ));
+        }
+        if let Some(note) = &self.note {
+            instructions.push_str(&format!("\nReviewer note: {}", note));
+        }
+
+        ArbiterDecision::fix(
+            &format!(
+                "Reviewer requested fixes for {} of {} finding(s).{}",
+                fixes.len(),
+                review.items.len(),
+                note
+            ),
+            1.0,
+            &instructions,
+        )
+        .with_addressed_findings(fixes.into_iter().map(|(label, _)| label).collect())
+    }
+}
+
+/// Asks a human to resolve a pending review.
+#[async_trait::async_trait]
+pub trait ManualReviewHandler: Send + Sync {
+    /// Block until a human resolved the review.
+    async fn resolve(&self, review: &PendingReview) -> Result<ManualResolution>;
+}
+
+/// File-based queue under `.forge/pending-reviews/`.
+///
+/// Each pending review is `<id>.json`; a decision is `<id>.decision.json`
+/// holding a [`ManualResolution`]. Both files are removed once the decision
+/// has been picked up.
+#[derive(Debug, Clone)]
+pub struct FileReviewQueue {
+    dir: PathBuf,
+    poll_interval: Duration,
+    timeout: Option<Duration>,
+}
+
+impl FileReviewQueue {
+    /// Create a queue for the given forge directory.
+    pub fn new(forge_dir: &Path) -> Self {
+        Self {
+            dir: forge_dir.join(PENDING_REVIEWS_DIR),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            timeout: None,
+        }
+    }
+
+    /// Set how often the decision file is checked.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Give up waiting for a decision after `timeout`; `None` waits forever.
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn review_path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", id))
+    }
+
+    fn decision_path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{}.decision.json", id))
+    }
+
+    /// Write a pending review.
+    pub fn submit(&self, review: &PendingReview) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let path = self.review_path(&review.id);
+        std::fs::write(&path, serde_json::to_string_pretty(review)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Load a pending review by id.
+    pub fn get(&self, id: &str) -> Result<Option<PendingReview>> {
+        let path = self.review_path(id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let review = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(review))
+    }
+
+    /// Pending reviews without a decision yet, oldest first.
+    pub fn list(&self) -> Result<Vec<PendingReview>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut reviews = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)
+            .with_context(|| format!("Failed to read {}", self.dir.display()))?
+        {
+            let name = entry?.file_name().to_string_lossy().to_string();
+            let Some(id) = name.strip_suffix(".json") else {
+                continue;
+            };
+            if id.ends_with(".decision") || self.decision_path(id).exists() {
+                continue;
+            }
+            if let Some(review) = self.get(id)? {
+                reviews.push(review);
+            }
+        }
+        reviews.sort_by_key(|review| review.created_at);
+        Ok(reviews)
+    }
+
+    /// Record a decision for a pending review.
+    pub fn decide(&self, id: &str, resolution: &ManualResolution) -> Result<()> {
+        let Some(review) = self.get(id)? else {
+            bail!(
+                "No pending review with id '{}' in {}",
+                id,
+                self.dir.display()
+            );
+        };
+        for decision in &resolution.findings {
+            if !review.items.iter().any(|item| item.id == decision.id) {
+                bail!("Review '{}' has no finding '{}'", id, decision.id);
+            }
+        }
+
+        // Write then rename so the waiting swarm never reads a partial file
+        let path = self.decision_path(id);
+        let tmp = self.dir.join(format!("{}.decision.tmp", id));
+        std::fs::write(&tmp, serde_json::to_string_pretty(resolution)?)
+            .with_context(|| format!("Failed to write {}", tmp.display()))?;
+        std::fs::rename(&tmp, &path).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Take the decision for a review, removing both files.
+    pub fn take_decision(&self, id: &str) -> Result<Option<ManualResolution>> {
+        let path = self.decision_path(id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let resolution = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(self.review_path(id));
+        Ok(Some(resolution))
+    }
+}
+
+#[async_trait::async_trait]
+impl ManualReviewHandler for FileReviewQueue {
+    async fn resolve(&self, review: &PendingReview) -> Result<ManualResolution> {
+        self.submit(review)?;
+        let started = tokio::time::Instant::now();
+        loop {
+            if let Some(resolution) = self.take_decision(&review.id)? {
+                return Ok(resolution);
+            }
+            if let Some(timeout) = self.timeout
+                && started.elapsed() >= timeout
+            {
+                // Withdraw the review so nobody decides it after the fallback
+                let _ = std::fs::remove_file(self.review_path(&review.id));
+                return Err(ReviewTimedOut {
+                    id: review.id.clone(),
+                    timeout,
+                }
+                .into());
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+}
+
+/// Interactive findings browser on the terminal.
+///
+/// Reviews are serialized so parallel phases never share the prompt.
+#[derive(Debug, Default)]
+pub struct TerminalReviewer {
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl TerminalReviewer {
+    /// Create a terminal reviewer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl ManualReviewHandler for TerminalReviewer {
+    async fn resolve(&self, review: &PendingReview) -> Result<ManualResolution> {
+        let _guard = self.lock.lock().await;
+        let review = review.clone();
+        tokio::task::spawn_blocking(move || browse(&review))
+            .await
+            .context("Manual review prompt panicked")?
+    }
+}
+
+/// Run the findings browser until the reviewer finishes or fails the phase.
+fn browse(review: &PendingReview) -> Result<ManualResolution> {
+    use console::style;
+    use dialoguer::{Input, Select, theme::ColorfulTheme};
+
+    let theme = ColorfulTheme::default();
+    let mut resolution = ManualResolution::default();
+
+    eprintln!();
+    eprintln!(
+        "{} Phase {} - {}: {} blocking finding(s)",
+        style("Manual review").bold().yellow(),
+        review.phase,
+        review.phase_name,
+        review.items.len()
+    );
+
+    loop {
+        let mut entries: Vec<String> = review
+            .items
+            .iter()
+            .map(|item| {
+                format!(
+                    "{:<7} {} {} ({})",
+                    format!("[{}]", resolution.action_for(&item.id)),
+                    item.id,
+                    item.label(),
+                    item.specialist
+                )
+            })
+            .collect();
+        entries.push("Done".to_string());
+        entries.push("Add overall note".to_string());
+        entries.push("Fail phase".to_string());
+
+        let choice = Select::with_theme(&theme)
+            .with_prompt("Select a finding to decide on")
+            .items(&entries)
+            .default(review.items.len())
+            .interact()?;
+
+        match choice.checked_sub(review.items.len()) {
+            Some(0) => return Ok(resolution),
+            Some(1) => {
+                let note: String = Input::with_theme(&theme)
+                    .with_prompt("Note")
+                    .allow_empty(true)
+                    .interact_text()?;
+                resolution.note = Some(note).filter(|note| !note.trim().is_empty());
+            }
+            Some(_) => {
+                let reason: String = Input::with_theme(&theme)
+                    .with_prompt("Reason")
+                    .allow_empty(true)
+                    .interact_text()?;
+                let reason = Some(reason.as_str()).filter(|reason| !reason.trim().is_empty());
+                return Ok(ManualResolution::fail(reason));
+            }
+            None => {
+                let item = &review.items[choice];
+                eprintln!();
+                eprintln!("{} {}", style(&item.id).bold(), item.label());
+                if let Some(suggestion) = item.finding.suggestion() {
+                    eprintln!("  Suggestion: {}", suggestion);
+                }
+                if let Some(note) = &item.note {
+                    eprintln!("  Note: {}", note);
+                }
+
+                let actions = [
+                    FindingAction::Fix,
+                    FindingAction::Accept,
+                    FindingAction::Dismiss,
+                ];
+                let current = resolution.action_for(&item.id);
+                let action = Select::with_theme(&theme)
+                    .with_prompt("Action")
+                    .items(actions)
+                    .default(actions.iter().position(|a| *a == current).unwrap_or(0))
+                    .interact()?;
+                let note: String = Input::with_theme(&theme)
+                    .with_prompt("Note (optional)")
+                    .allow_empty(true)
+                    .interact_text()?;
+                let note = Some(note.as_str()).filter(|note| !note.trim().is_empty());
+                resolution.set(&item.id, actions[action], note);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::review::{
+        ArbiterVerdict, FindingSeverity, ReviewFinding, ReviewReport, ReviewVerdict,
+    };
+    use tempfile::TempDir;
+
+    fn review() -> PendingReview {
+        let aggregation = ReviewAggregation::new("05").add_report(
+            ReviewReport::new("05", "security-sentinel", ReviewVerdict::Fail)
+                .add_finding(
+                    ReviewFinding::new(FindingSeverity::Error, "src/db.rs", "SQL injection")
+                        .with_line(3),
+                )
+                .add_finding(ReviewFinding::new(
+                    FindingSeverity::Warning,
+                    "src/auth.rs",
+                    "Token logged",
+                )),
+        );
+        PendingReview::new(&aggregation, "Auth", 0)
+    }
+
+    #[test]
+    fn test_undecided_findings_are_fixed() {
+        let review = review();
+        let decision = ManualResolution::default().to_decision(&review);
+
+        assert_eq!(decision.decision, ArbiterVerdict::Fix);
+        assert_eq!(decision.addressed_findings.len(), 2);
+    }
+
+    #[test]
+    fn test_fix_decision_lists_notes_and_kept_findings() {
+        let review = review();
+        let decision = ManualResolution::default()
+            .with_finding("F1", FindingAction::Fix, Some("use sqlx::query!"))
+            .with_finding("F2", FindingAction::Dismiss, None)
+            .to_decision(&review);
+
+        assert_eq!(decision.decision, ArbiterVerdict::Fix);
+        assert_eq!(
+            decision.addressed_findings,
+            vec!["[error] src/db.rs:3: SQL injection"]
+        );
+        let instructions = decision.fix_instructions.unwrap();
+        assert!(instructions.contains("SQL injection (use sqlx::query!)"));
+        assert!(instructions.contains("Leave these as they are"));
+        assert!(instructions.contains("Token logged"));
+    }
+
+    #[test]
+    fn test_accept_and_dismiss_proceed() {
+        let review = review();
+        let decision = ManualResolution::default()
+            .with_finding("F1", FindingAction::Accept, None)
+            .with_finding("F2", FindingAction::Dismiss, None)
+            .with_note("Known issue, tracked separately")
+            .to_decision(&review);
+
+        assert_eq!(decision.decision, ArbiterVerdict::Proceed);
+        assert!(decision.reasoning.contains("tracked separately"));
+    }
+
+    #[test]
+    fn test_fail_phase() {
+        let decision = ManualResolution::fail(Some("Wrong approach")).to_decision(&review());
+
+        assert_eq!(decision.decision, ArbiterVerdict::FailPhase);
+        assert_eq!(decision.failure_summary.as_deref(), Some("Wrong approach"));
+    }
+
+    #[test]
+    fn test_set_replaces_earlier_decision() {
+        let resolution = ManualResolution::default()
+            .with_finding("F1", FindingAction::Dismiss, None)
+            .with_finding("F1", FindingAction::Accept, Some("ok"));
+
+        assert_eq!(resolution.findings.len(), 1);
+        assert_eq!(resolution.action_for("F1"), FindingAction::Accept);
+        assert_eq!(resolution.action_for("F2"), FindingAction::Fix);
+    }
+
+    #[tokio::test]
+    async fn test_file_queue_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue =
+            FileReviewQueue::new(temp_dir.path()).with_poll_interval(Duration::from_millis(10));
+        let review = review();
+
+        let waiter = {
+            let queue = queue.clone();
+            let review = review.clone();
+            tokio::spawn(async move { queue.resolve(&review).await })
+        };
+
+        let pending = loop {
+            let pending = queue.list().unwrap();
+            if !pending.is_empty() {
+                break pending;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        };
+        assert_eq!(pending, vec![review.clone()]);
+
+        let resolution =
+            ManualResolution::default().with_finding("F2", FindingAction::Dismiss, None);
+        assert!(
+            queue
+                .decide(
+                    &review.id,
+                    &ManualResolution::default().with_finding("F9", FindingAction::Fix, None)
+                )
+                .is_err()
+        );
+        queue.decide(&review.id, &resolution).unwrap();
+
+        assert_eq!(waiter.await.unwrap().unwrap(), resolution);
+        assert!(queue.list().unwrap().is_empty());
+        assert!(queue.decide(&review.id, &resolution).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_file_queue_times_out_and_withdraws_review() {
+        let temp_dir = TempDir::new().unwrap();
+        let queue = FileReviewQueue::new(temp_dir.path())
+            .with_poll_interval(Duration::from_millis(10))
+            .with_timeout(Some(Duration::from_millis(50)));
+        let review = review();
+
+        let error = queue.resolve(&review).await.unwrap_err();
+
+        let timed_out = error.downcast_ref::<ReviewTimedOut>().unwrap();
+        assert_eq!(timed_out.id, review.id);
+        assert!(queue.list().unwrap().is_empty());
+        assert!(
+            queue
+                .decide(&review.id, &ManualResolution::default())
+                .is_err()
+        );
+    }
+}
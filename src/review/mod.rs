@@ -9,6 +9,15 @@
 //! - [`specialists`]: Review specialist types and configuration
 //! - [`findings`]: Review output types (findings, reports, aggregations)
 //! - [`arbiter`]: LLM-based resolution for failed reviews
+//! - [`baseline`]: Accepted-findings baseline and inline suppressions
+//! - [`definitions`]: File-defined custom specialists from `.forge/reviewers/`
+//! - [`diff`]: Diff sources for standalone `forge review` runs
+//! - [`tools`]: Deterministic command-based specialists (linters, SARIF)
+//! - [`store`]: Persisted review runs under `.forge/reviews/`
+//! - [`export`]: SARIF, JUnit and Markdown export of review results
+//! - [`fixes`]: Tracked fix items for the structured review fix loop
+//! - [`history`]: Arbiter decision history and human overrides
+//! - [`manual`]: Human resolution of gating failures in manual mode
 //!
 //! ## Example
 //!
@@ -82,19 +91,41 @@
 //! ```
 
 pub mod arbiter;
+pub mod baseline;
+pub mod definitions;
+pub mod diff;
 pub mod dispatcher;
+pub mod export;
 pub mod findings;
+pub mod fixes;
+pub mod history;
+pub mod manual;
 pub mod prompt_loader;
 pub mod specialists;
+pub mod store;
+pub mod tools;
 
 // Re-export main types
 pub use arbiter::{
     ArbiterConfig, ArbiterDecision, ArbiterExecutor, ArbiterInput, ArbiterResult, ArbiterVerdict,
     DecisionSource, ResolutionMode,
 };
+pub use baseline::{ReviewBaseline, Suppressor};
+pub use definitions::{ReviewerDefinition, ReviewerRegistry};
+pub use diff::{DiffSource, ReviewCheckout, ReviewDiff};
 pub use dispatcher::{DispatchResult, DispatcherConfig, PhaseReviewConfig, ReviewDispatcher};
+pub use export::ReviewExportFormat;
 pub use findings::{
     FindingSeverity, ReviewAggregation, ReviewFinding, ReviewReport, ReviewVerdict,
+    SuppressedFinding, SuppressionReason,
+};
+pub use fixes::{FixItem, FixPlan, FixStatus};
+pub use history::{ArbiterHistory, ArbiterHistoryEntry, ArbiterPrecedent, HumanOverride};
+pub use manual::{
+    FileReviewQueue, FindingAction, FindingDecision, ManualResolution, ManualReviewHandler,
+    PendingReview, ReviewTimedOut, TerminalReviewer,
 };
 pub use prompt_loader::{PromptConfig, PromptLoader, PromptMode};
 pub use specialists::{ReviewSpecialist, SpecialistType};
+pub use store::{ReviewRecord, ReviewStore};
+pub use tools::ToolOutputFormat;
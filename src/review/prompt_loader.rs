@@ -196,6 +196,15 @@ impl PromptLoader {
 
 /// Parse YAML frontmatter delimited by `---` lines.
 fn parse_frontmatter(content: &str) -> Result<(PromptFrontmatter, String), String> {
+    let (yaml_str, body) = split_frontmatter(content)?;
+    let frontmatter: PromptFrontmatter =
+        serde_yaml::from_str(yaml_str).map_err(|e| format!("YAML parse error: {e}"))?;
+
+    Ok((frontmatter, body))
+}
+
+/// Split `---`-delimited frontmatter from the markdown body that follows it.
+pub(crate) fn split_frontmatter(content: &str) -> Result<(&str, String), String> {
     let trimmed = content.trim_start();
     if !trimmed.starts_with("---") {
         return Err("no frontmatter delimiter found".to_string());
@@ -214,10 +223,7 @@ fn parse_frontmatter(content: &str) -> Result<(PromptFrontmatter, String), Strin
         .trim_start_matches(['\r', '\n'])
         .to_string();
 
-    let frontmatter: PromptFrontmatter =
-        serde_yaml::from_str(yaml_str).map_err(|e| format!("YAML parse error: {e}"))?;
-
-    Ok((frontmatter, body))
+    Ok((yaml_str, body))
 }
 
 /// Extract focus areas from a `## Focus Areas` section in markdown.
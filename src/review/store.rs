@@ -0,0 +1,224 @@
+//! Persisted review runs under `.forge/reviews/`.
+//!
+//! Every dispatch with a forge directory saves its [`ReviewAggregation`] as a
+//! [`ReviewRecord`] so that `forge review export` can render past runs, and
+//! writes any exports configured in `[reviews] export` next to it:
+//!
+//! ```text
+//! .forge/reviews/
+//!   phase-05-20260101T120000123.json        # forge-internal record
+//!   phase-05-20260101T120000123.sarif       # [reviews] export = ["sarif"]
+//!   phase-05-20260101T120000123.junit.xml   # [reviews] export = ["junit"]
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::ReviewAggregation;
+use super::export::ReviewExportFormat;
+
+/// Directory under the forge dir that holds review records and exports.
+const REVIEWS_DIR: &str = "reviews";
+
+/// One persisted review dispatch.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewRecord {
+    /// When the dispatch finished.
+    pub recorded_at: DateTime<Utc>,
+    /// The aggregated reports.
+    pub aggregation: ReviewAggregation,
+}
+
+impl ReviewRecord {
+    /// Create a record stamped with the current time.
+    pub fn new(aggregation: ReviewAggregation) -> Self {
+        Self {
+            recorded_at: Utc::now(),
+            aggregation,
+        }
+    }
+
+    /// File stem shared by the record and its exports.
+    fn file_stem(&self) -> String {
+        let phase: String = self
+            .aggregation
+            .phase
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '.' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        format!(
+            "phase-{}-{}",
+            phase,
+            self.recorded_at.format("%Y%m%dT%H%M%S%3f")
+        )
+    }
+}
+
+/// Reads and writes review records in `.forge/reviews/`.
+#[derive(Debug, Clone)]
+pub struct ReviewStore {
+    dir: PathBuf,
+}
+
+impl ReviewStore {
+    /// Create a store for the given forge directory.
+    pub fn new(forge_dir: &Path) -> Self {
+        Self {
+            dir: Self::reviews_dir(forge_dir),
+        }
+    }
+
+    /// Directory that holds review records for a forge directory.
+    pub fn reviews_dir(forge_dir: &Path) -> PathBuf {
+        forge_dir.join(REVIEWS_DIR)
+    }
+
+    /// Save a record and render the requested exports alongside it.
+    ///
+    /// Returns the path of the JSON record.
+    pub fn save(&self, record: &ReviewRecord, exports: &[ReviewExportFormat]) -> Result<PathBuf> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+
+        let stem = record.file_stem();
+        let path = self.dir.join(format!("{stem}.json"));
+        let json = serde_json::to_string_pretty(record)?;
+        std::fs::write(&path, json)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        let records = std::slice::from_ref(record);
+        for format in exports {
+            let export_path = self.dir.join(format!("{stem}.{}", format.extension()));
+            std::fs::write(&export_path, format.render(records)?)
+                .with_context(|| format!("Failed to write {}", export_path.display()))?;
+        }
+
+        Ok(path)
+    }
+
+    /// Load all records, oldest first.
+    ///
+    /// Unreadable records are skipped with a warning; a missing directory
+    /// yields no records.
+    pub fn load_all(&self) -> Result<Vec<ReviewRecord>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut records = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)
+            .with_context(|| format!("Failed to read {}", self.dir.display()))?
+        {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let parsed = std::fs::read_to_string(&path)
+                .map_err(anyhow::Error::from)
+                .and_then(|content| Ok(serde_json::from_str::<ReviewRecord>(&content)?));
+            match parsed {
+                Ok(record) => records.push(record),
+                Err(e) => warn!("Skipping review record {}: {:#}", path.display(), e),
+            }
+        }
+
+        records.sort_by_key(|record| record.recorded_at);
+        Ok(records)
+    }
+
+    /// Load the most recent record of each phase, oldest first.
+    pub fn load_latest(&self) -> Result<Vec<ReviewRecord>> {
+        let mut records = self.load_all()?;
+        let mut seen = std::collections::HashSet::new();
+        records.reverse();
+        records.retain(|record| seen.insert(record.aggregation.phase.clone()));
+        records.reverse();
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::review::{ReviewReport, ReviewVerdict};
+    use tempfile::TempDir;
+
+    fn record(phase: &str, seconds: i64) -> ReviewRecord {
+        ReviewRecord {
+            recorded_at: DateTime::from_timestamp(1_700_000_000 + seconds, 0).unwrap(),
+            aggregation: ReviewAggregation::new(phase).add_report(ReviewReport::new(
+                phase,
+                "security-sentinel",
+                ReviewVerdict::Pass,
+            )),
+        }
+    }
+
+    #[test]
+    fn test_save_and_load_records_in_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = ReviewStore::new(temp_dir.path());
+
+        store.save(&record("06", 10), &[]).unwrap();
+        store.save(&record("05", 0), &[]).unwrap();
+
+        let records = store.load_all().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].aggregation.phase, "05");
+        assert_eq!(records[1].aggregation.phase, "06");
+    }
+
+    #[test]
+    fn test_load_latest_keeps_last_run_per_phase() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = ReviewStore::new(temp_dir.path());
+
+        store.save(&record("05", 0), &[]).unwrap();
+        store.save(&record("06", 5), &[]).unwrap();
+        store.save(&record("05", 10), &[]).unwrap();
+
+        let records = store.load_latest().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].aggregation.phase, "06");
+        assert_eq!(records[1].aggregation.phase, "05");
+        assert_eq!(records[1].recorded_at, record("05", 10).recorded_at);
+    }
+
+    #[test]
+    fn test_save_writes_requested_exports() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = ReviewStore::new(temp_dir.path());
+
+        let path = store
+            .save(
+                &record("05/a", 0),
+                &[ReviewExportFormat::Sarif, ReviewExportFormat::Junit],
+            )
+            .unwrap();
+
+        let stem = path.file_stem().unwrap().to_str().unwrap();
+        assert!(stem.starts_with("phase-05-a-"));
+        let dir = ReviewStore::reviews_dir(temp_dir.path());
+        assert!(dir.join(format!("{stem}.sarif")).exists());
+        assert!(dir.join(format!("{stem}.junit.xml")).exists());
+        assert_eq!(store.load_all().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_load_all_missing_directory_is_empty() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = ReviewStore::new(temp_dir.path());
+
+        assert!(store.load_all().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,593 @@
+//! Deterministic tool-based review specialists.
+//!
+//! A reviewer definition with a `command` runs that command instead of an
+//! LLM and converts its output into [`ReviewFinding`]s. Tool reviewers are
+//! cheap and reproducible, so the dispatcher runs them before LLM reviewers.
+//!
+//! ```toml
+//! # .forge/reviewers/clippy.toml
+//! command = "cargo clippy --message-format=json --quiet"
+//! format = "cargo"
+//! gate = true
+//! applies_to = ["**/*.rs"]
+//! ```
+//!
+//! `{files}` in the command expands to the phase's changed files, shell-quoted.
+//! Supported output formats are SARIF 2.1 (`sarif`, emitted by semgrep, eslint,
+//! CodeQL and most other scanners) and cargo's JSON diagnostics (`cargo`, for
+//! `cargo clippy`/`cargo check --message-format=json`).
+
+use std::path::Path;
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::process::Command;
+
+use super::ReviewerDefinition;
+use super::findings::{FindingSeverity, ReviewFinding, ReviewReport, ReviewVerdict};
+use crate::util::ProcessGroup;
+
+/// Output format produced by a tool reviewer's command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToolOutputFormat {
+    /// A SARIF 2.1 log on stdout.
+    #[default]
+    Sarif,
+    /// Newline-delimited cargo JSON messages (`--message-format=json`).
+    Cargo,
+}
+
+/// A tool diagnostic before severity mapping.
+#[derive(Debug, Clone, PartialEq)]
+struct ToolDiagnostic {
+    level: String,
+    file: String,
+    line: Option<u32>,
+    column: Option<u32>,
+    message: String,
+    rule: Option<String>,
+}
+
+/// Expand `{files}` in a tool command to the shell-quoted changed files.
+pub fn render_tool_command(command: &str, files_changed: &[String]) -> String {
+    let files = files_changed
+        .iter()
+        .map(|file| format!("'{}'", file.replace('\'', r"'\''")))
+        .collect::<Vec<_>>()
+        .join(" ");
+    command.replace("{files}", &files)
+}
+
+/// What a tool reviewer's command printed and how it exited.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToolOutput {
+    pub stdout: String,
+    pub stderr: String,
+    /// Exit code, `None` when the command was killed by a signal.
+    pub status: Option<i32>,
+}
+
+impl ToolOutput {
+    /// Output of a command that exited successfully.
+    pub fn success(stdout: impl Into<String>) -> Self {
+        Self {
+            stdout: stdout.into(),
+            stderr: String::new(),
+            status: Some(0),
+        }
+    }
+
+    fn succeeded(&self) -> bool {
+        self.status == Some(0)
+    }
+
+    /// Describe a failed run, keeping stderr for the message.
+    fn failure(&self) -> String {
+        let status = match self.status {
+            Some(code) => format!("exited with status {code}"),
+            None => "was killed by a signal".to_string(),
+        };
+        match self.stderr.trim() {
+            "" => format!("command {status}"),
+            stderr => format!("command {status}: {stderr}"),
+        }
+    }
+}
+
+/// Run a tool reviewer's command and capture its output and exit status.
+pub async fn run_tool_command(
+    definition: &ReviewerDefinition,
+    files_changed: &[String],
+    working_dir: Option<&Path>,
+    timeout: Duration,
+) -> Result<ToolOutput> {
+    let Some(command) = definition.command.as_deref() else {
+        bail!("Reviewer `{}` has no command", definition.name);
+    };
+    let command = render_tool_command(command, files_changed);
+
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c")
+        .arg(&command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(dir) = working_dir {
+        cmd.current_dir(dir);
+    }
+
+    let (child, group) = ProcessGroup::spawn(&mut cmd)
+        .with_context(|| format!("Failed to run tool reviewer command `{command}`"))?;
+    let output = tokio::time::timeout(timeout, child.wait_with_output())
+        .await
+        .with_context(|| format!("Tool reviewer `{}` timed out", definition.name))?
+        .with_context(|| format!("Failed to run tool reviewer command `{command}`"))?;
+    group.release();
+
+    Ok(ToolOutput {
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        status: output.status.code(),
+    })
+}
+
+/// Build the report for a tool reviewer that could not produce findings.
+///
+/// A tool that did not run proves nothing about the change, so a gating tool
+/// fails instead of passing with zero findings.
+pub fn build_tool_error_report(
+    phase: &str,
+    reviewer: &str,
+    is_gating: bool,
+    error: &anyhow::Error,
+) -> ReviewReport {
+    let verdict = if is_gating {
+        ReviewVerdict::Fail
+    } else {
+        ReviewVerdict::Warn
+    };
+
+    ReviewReport::new(phase, reviewer, verdict)
+        .with_summary(format!("Tool reviewer failed: {error:#}"))
+        .with_timestamp(chrono::Utc::now())
+}
+
+/// Build a report from tool findings.
+///
+/// There is no stated verdict to defer to: a gating tool fails on any
+/// error-severity finding and otherwise warns on actionable ones.
+pub fn build_tool_report(
+    phase: &str,
+    reviewer: &str,
+    is_gating: bool,
+    findings: Vec<ReviewFinding>,
+) -> ReviewReport {
+    let verdict = ReviewVerdict::from_findings(&findings, is_gating);
+    let summary = match findings.len() {
+        0 => "No diagnostics reported".to_string(),
+        1 => "1 diagnostic reported".to_string(),
+        count => format!("{count} diagnostics reported"),
+    };
+
+    ReviewReport::new(phase, reviewer, verdict)
+        .with_summary(summary)
+        .add_findings(findings)
+        .with_timestamp(chrono::Utc::now())
+}
+
+/// Convert a tool reviewer's output into findings.
+///
+/// Linters conventionally exit non-zero when they report problems, so a
+/// failing exit status is only an error when no diagnostics were parsed or
+/// the output is unparseable. File paths are made relative to `working_dir`
+/// where possible. Unless the definition sets `include_unchanged_files`,
+/// findings outside the changed files are dropped when those files are known.
+pub fn parse_tool_output(
+    output: &ToolOutput,
+    definition: &ReviewerDefinition,
+    files_changed: &[String],
+    working_dir: Option<&Path>,
+) -> Result<Vec<ReviewFinding>> {
+    let diagnostics = match definition.format {
+        ToolOutputFormat::Sarif => parse_sarif(&output.stdout),
+        ToolOutputFormat::Cargo => parse_cargo_messages(&output.stdout),
+    };
+    let diagnostics = match diagnostics {
+        Ok(diagnostics) if diagnostics.is_empty() && !output.succeeded() => {
+            bail!("{}", output.failure())
+        }
+        Ok(diagnostics) => diagnostics,
+        Err(error) if !output.succeeded() => return Err(error.context(output.failure())),
+        Err(error) => return Err(error),
+    };
+
+    let mut findings: Vec<ReviewFinding> = Vec::new();
+    for diagnostic in diagnostics {
+        let file = relative_path(&diagnostic.file, working_dir);
+        if !definition.include_unchanged_files
+            && !files_changed.is_empty()
+            && !files_changed
+                .iter()
+                .any(|changed| changed.trim_start_matches("./") == file)
+        {
+            continue;
+        }
+
+        let severity = definition
+            .map_severity(&diagnostic.level)
+            .unwrap_or_else(|| default_severity(&diagnostic.level));
+        let mut finding = ReviewFinding::new(severity, file, diagnostic.message);
+        if let Some(line) = diagnostic.line {
+            finding = finding.with_line(line);
+        }
+        if let Some(column) = diagnostic.column {
+            finding = finding.with_column(column);
+        }
+        if let Some(rule) = diagnostic.rule {
+            finding = finding.with_category(rule);
+        }
+
+        if !findings.contains(&finding) {
+            findings.push(finding);
+        }
+    }
+
+    Ok(findings)
+}
+
+/// Built-in mapping for SARIF and rustc levels.
+fn default_severity(level: &str) -> FindingSeverity {
+    match level.to_lowercase().as_str() {
+        "error" => FindingSeverity::Error,
+        "warning" => FindingSeverity::Warning,
+        "help" => FindingSeverity::Info,
+        _ => FindingSeverity::Note,
+    }
+}
+
+fn parse_sarif(output: &str) -> Result<Vec<ToolDiagnostic>> {
+    let log: Value = serde_json::from_str(output.trim()).context("Tool output is not SARIF")?;
+    let Some(runs) = log.get("runs").and_then(Value::as_array) else {
+        bail!("SARIF log has no `runs` array");
+    };
+
+    let mut diagnostics = Vec::new();
+    for run in runs {
+        let results = run.get("results").and_then(Value::as_array);
+        for result in results.into_iter().flatten() {
+            let location = result
+                .pointer("/locations/0/physicalLocation")
+                .cloned()
+                .unwrap_or(Value::Null);
+            let Some(file) = location
+                .pointer("/artifactLocation/uri")
+                .and_then(Value::as_str)
+            else {
+                continue;
+            };
+            let rule = result
+                .get("ruleId")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            let level = result
+                .get("level")
+                .and_then(Value::as_str)
+                .or_else(|| rule_default_level(run, rule.as_deref()))
+                .unwrap_or("warning");
+            let message = result
+                .pointer("/message/text")
+                .and_then(Value::as_str)
+                .unwrap_or("(no message)");
+
+            diagnostics.push(ToolDiagnostic {
+                level: level.to_string(),
+                file: file.to_string(),
+                line: region_number(&location, "startLine"),
+                column: region_number(&location, "startColumn"),
+                message: message.to_string(),
+                rule,
+            });
+        }
+    }
+
+    Ok(diagnostics)
+}
+
+/// The `defaultConfiguration.level` a SARIF rule declares, if any.
+fn rule_default_level<'a>(run: &'a Value, rule_id: Option<&str>) -> Option<&'a str> {
+    let rule_id = rule_id?;
+    run.pointer("/tool/driver/rules")
+        .and_then(Value::as_array)?
+        .iter()
+        .find(|rule| rule.get("id").and_then(Value::as_str) == Some(rule_id))?
+        .pointer("/defaultConfiguration/level")
+        .and_then(Value::as_str)
+}
+
+fn region_number(location: &Value, field: &str) -> Option<u32> {
+    location
+        .get("region")
+        .and_then(|region| region.get(field))
+        .and_then(Value::as_u64)
+        .map(|value| value as u32)
+}
+
+fn parse_cargo_messages(output: &str) -> Result<Vec<ToolDiagnostic>> {
+    let messages = output
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .collect::<Vec<_>>();
+    if messages.is_empty() && !output.trim().is_empty() {
+        bail!("Tool output is not cargo JSON messages");
+    }
+
+    Ok(messages
+        .into_iter()
+        .filter(|value| value.get("reason").and_then(Value::as_str) == Some("compiler-message"))
+        .filter_map(|value| {
+            let message = value.get("message")?;
+            let spans = message.get("spans").and_then(Value::as_array)?;
+            let span = spans
+                .iter()
+                .find(|span| span.get("is_primary").and_then(Value::as_bool) == Some(true))
+                .or_else(|| spans.first())?;
+
+            Some(ToolDiagnostic {
+                level: message.get("level").and_then(Value::as_str)?.to_string(),
+                file: span.get("file_name").and_then(Value::as_str)?.to_string(),
+                line: span
+                    .get("line_start")
+                    .and_then(Value::as_u64)
+                    .map(|line| line as u32),
+                column: span
+                    .get("column_start")
+                    .and_then(Value::as_u64)
+                    .map(|column| column as u32),
+                message: message.get("message").and_then(Value::as_str)?.to_string(),
+                rule: message
+                    .pointer("/code/code")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+            })
+        })
+        .collect())
+}
+
+/// Strip `file://` URIs and the working directory prefix from a reported path.
+fn relative_path(path: &str, working_dir: Option<&Path>) -> String {
+    let path = path.strip_prefix("file://").unwrap_or(path);
+    let relative = working_dir
+        .and_then(|dir| Path::new(path).strip_prefix(dir).ok())
+        .map(|relative| relative.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string());
+    relative.trim_start_matches("./").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tool_definition(format: ToolOutputFormat) -> ReviewerDefinition {
+        ReviewerDefinition {
+            name: "lint".to_string(),
+            command: Some("true".to_string()),
+            format,
+            ..ReviewerDefinition::default()
+        }
+    }
+
+    fn sarif() -> ToolOutput {
+        ToolOutput::success(SARIF)
+    }
+
+    const SARIF: &str = r#"{
+  "version": "2.1.0",
+  "runs": [{
+    "tool": {"driver": {"name": "semgrep", "rules": [
+      {"id": "sql-injection", "defaultConfiguration": {"level": "error"}}
+    ]}},
+    "results": [
+      {
+        "ruleId": "sql-injection",
+        "message": {"text": "Query built from user input"},
+        "locations": [{"physicalLocation": {
+          "artifactLocation": {"uri": "file:///repo/src/db.rs"},
+          "region": {"startLine": 12, "startColumn": 5}
+        }}]
+      },
+      {
+        "ruleId": "style",
+        "level": "note",
+        "message": {"text": "Prefer iterators"},
+        "locations": [{"physicalLocation": {
+          "artifactLocation": {"uri": "src/other.rs"},
+          "region": {"startLine": 3}
+        }}]
+      }
+    ]
+  }]
+}"#;
+
+    #[test]
+    fn test_parse_sarif_output() {
+        let definition = ReviewerDefinition {
+            include_unchanged_files: true,
+            ..tool_definition(ToolOutputFormat::Sarif)
+        };
+
+        let findings =
+            parse_tool_output(&sarif(), &definition, &[], Some(Path::new("/repo"))).unwrap();
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].severity(), FindingSeverity::Error);
+        assert_eq!(findings[0].location(), "src/db.rs:12:5");
+        assert_eq!(findings[0].category(), Some("sql-injection"));
+        assert_eq!(findings[1].severity(), FindingSeverity::Note);
+    }
+
+    #[test]
+    fn test_parse_tool_output_filters_to_changed_files() {
+        let definition = tool_definition(ToolOutputFormat::Sarif);
+
+        let findings = parse_tool_output(
+            &sarif(),
+            &definition,
+            &["src/db.rs".to_string()],
+            Some(Path::new("/repo")),
+        )
+        .unwrap();
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].file(), "src/db.rs");
+    }
+
+    #[test]
+    fn test_parse_cargo_messages_uses_primary_span_and_severity_map() {
+        let output = concat!(
+            r#"{"reason":"compiler-artifact","target":{}}"#,
+            "\n",
+            r#"{"reason":"compiler-message","message":{"level":"warning","message":"unused variable: `x`","code":{"code":"unused_variables"},"spans":[{"file_name":"src/lib.rs","line_start":4,"column_start":9,"is_primary":true}]}}"#,
+            "\n",
+            r#"{"reason":"compiler-message","message":{"level":"warning","message":"1 warning emitted","code":null,"spans":[]}}"#,
+            "\n",
+        );
+        let definition = ReviewerDefinition {
+            severity_map: [("warning".to_string(), FindingSeverity::Error)]
+                .into_iter()
+                .collect(),
+            ..tool_definition(ToolOutputFormat::Cargo)
+        };
+
+        let findings =
+            parse_tool_output(&ToolOutput::success(output), &definition, &[], None).unwrap();
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].severity(), FindingSeverity::Error);
+        assert_eq!(findings[0].location(), "src/lib.rs:4:9");
+        assert_eq!(findings[0].category(), Some("unused_variables"));
+    }
+
+    #[test]
+    fn test_parse_sarif_rejects_non_sarif_output() {
+        let definition = tool_definition(ToolOutputFormat::Sarif);
+
+        for output in ["not json", "{}"] {
+            let output = ToolOutput::success(output);
+            assert!(parse_tool_output(&output, &definition, &[], None).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_tool_output_nonzero_exit_with_diagnostics_is_ok() {
+        let definition = ReviewerDefinition {
+            include_unchanged_files: true,
+            ..tool_definition(ToolOutputFormat::Sarif)
+        };
+        let output = ToolOutput {
+            status: Some(1),
+            ..sarif()
+        };
+
+        let findings = parse_tool_output(&output, &definition, &[], None).unwrap();
+
+        assert_eq!(findings.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_tool_output_failed_run_without_diagnostics_errors() {
+        let definition = tool_definition(ToolOutputFormat::Cargo);
+        let output = ToolOutput {
+            stdout: String::new(),
+            stderr: "error: could not find `Cargo.toml`".to_string(),
+            status: Some(101),
+        };
+
+        let error = parse_tool_output(&output, &definition, &[], None).unwrap_err();
+
+        let message = format!("{error:#}");
+        assert!(message.contains("exited with status 101"));
+        assert!(message.contains("could not find `Cargo.toml`"));
+    }
+
+    #[test]
+    fn test_parse_cargo_messages_rejects_non_json_output() {
+        let definition = tool_definition(ToolOutputFormat::Cargo);
+
+        let output = ToolOutput::success("error[E0425]: cannot find value `x`");
+
+        assert!(parse_tool_output(&output, &definition, &[], None).is_err());
+    }
+
+    #[test]
+    fn test_build_tool_report_verdicts() {
+        let error = ReviewFinding::new(FindingSeverity::Error, "src/a.rs", "broken");
+        let warning = ReviewFinding::new(FindingSeverity::Warning, "src/a.rs", "smelly");
+
+        let gating = build_tool_report("01", "clippy", true, vec![error.clone()]);
+        let advisory = build_tool_report("01", "clippy", false, vec![error]);
+        let warned = build_tool_report("01", "clippy", true, vec![warning]);
+        let clean = build_tool_report("01", "clippy", true, Vec::new());
+
+        assert_eq!(gating.verdict, ReviewVerdict::Fail);
+        assert_eq!(advisory.verdict, ReviewVerdict::Warn);
+        assert_eq!(warned.verdict, ReviewVerdict::Warn);
+        assert_eq!(clean.verdict, ReviewVerdict::Pass);
+    }
+
+    #[tokio::test]
+    async fn test_run_tool_command_captures_exit_status() {
+        let definition = ReviewerDefinition {
+            command: Some("echo {files}; echo broken >&2; exit 1".to_string()),
+            ..tool_definition(ToolOutputFormat::Sarif)
+        };
+
+        let output = run_tool_command(
+            &definition,
+            &["src/a.rs".to_string()],
+            None,
+            Duration::from_secs(10),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output.stdout.trim(), "src/a.rs");
+        assert_eq!(output.stderr.trim(), "broken");
+        assert_eq!(output.status, Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_missing_tool_binary_fails_gating_review() {
+        let definition = ReviewerDefinition {
+            command: Some("forge-no-such-linter --message-format=json".to_string()),
+            ..tool_definition(ToolOutputFormat::Cargo)
+        };
+
+        let output = run_tool_command(&definition, &[], None, Duration::from_secs(10))
+            .await
+            .unwrap();
+        assert_eq!(output.status, Some(127));
+        let error = parse_tool_output(&output, &definition, &[], None).unwrap_err();
+        let report = build_tool_error_report("01", "clippy", true, &error);
+
+        assert_eq!(report.verdict, ReviewVerdict::Fail);
+        assert!(report.summary.contains("status 127"));
+        assert_eq!(
+            build_tool_error_report("01", "clippy", false, &error).verdict,
+            ReviewVerdict::Warn
+        );
+    }
+
+    #[test]
+    fn test_render_tool_command_quotes_files() {
+        let command = render_tool_command(
+            "semgrep --sarif {files}",
+            &["src/a.rs".to_string(), "it's.rs".to_string()],
+        );
+
+        assert_eq!(command, r"semgrep --sarif 'src/a.rs' 'it'\''s.rs'");
+    }
+}
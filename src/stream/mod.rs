@@ -64,6 +64,16 @@ pub struct FileInfo {
     pub file_path: String,
 }
 
+/// The file a tool use event writes to, for tools that edit files.
+pub fn edited_file<'a>(name: &str, input: &'a Value) -> Option<&'a str> {
+    let key = match name {
+        "Write" | "Edit" | "MultiEdit" => "file_path",
+        "NotebookEdit" => "notebook_path",
+        _ => return None,
+    };
+    input.get(key).and_then(|v| v.as_str())
+}
+
 /// Extract a human-readable description from a tool use event
 pub fn describe_tool_use(name: &str, input: &Value) -> String {
     match name {
@@ -187,6 +197,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_edited_file() {
+        let input = serde_json::json!({"file_path": "/foo/bar.rs"});
+        assert_eq!(edited_file("Edit", &input), Some("/foo/bar.rs"));
+        assert_eq!(edited_file("Write", &input), Some("/foo/bar.rs"));
+        assert_eq!(edited_file("Read", &input), None);
+
+        let notebook = serde_json::json!({"notebook_path": "nb.ipynb"});
+        assert_eq!(edited_file("NotebookEdit", &notebook), Some("nb.ipynb"));
+    }
+
     #[test]
     fn test_parse_assistant_text() {
         let json = r#"{"type":"assistant","message":{"content":[{"type":"text","text":"Hello world"}]},"session_id":"abc"}"#;
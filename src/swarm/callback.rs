@@ -7,6 +7,10 @@
 //! ## Features
 //!
 //! - Binds to localhost on a dynamic port for security
+//! - Requires a per-server bearer token on every event, so other local
+//!   processes cannot forge task completions
+//! - Validates event payloads and rate-limits them; rejected events are
+//!   counted rather than stored
 //! - Accumulates events for batch retrieval
 //! - Configurable max events limit to prevent unbounded memory growth
 //! - Graceful shutdown support
@@ -20,8 +24,9 @@
 //! let mut server = CallbackServer::new();
 //! let callback_url = server.start().await?;
 //!
-//! // Pass callback_url to swarm agents...
+//! // Pass callback_url and server.token() to swarm agents...
 //! // Agents POST to {callback_url}/progress, {callback_url}/complete, etc.
+//! // with an `Authorization: Bearer <token>` header.
 //!
 //! // Poll for events
 //! let events = server.drain_events().await;
@@ -32,25 +37,46 @@
 //! # }
 //! ```
 
+use crate::swarm::prompts::ReviewResult;
+use crate::util::bearer_token_matches;
 use anyhow::{Context, Result};
 use axum::{
     Json, Router,
-    extract::State,
-    http::StatusCode,
+    extract::{State, rejection::JsonRejection},
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION},
     routing::{get, post},
 };
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::net::TcpListener;
 use tokio::sync::{RwLock, oneshot};
-use tracing::error;
+use tracing::{error, warn};
 
 /// Maximum number of events to retain before dropping oldest.
 /// This prevents unbounded memory growth from misbehaving agents.
 const DEFAULT_MAX_EVENTS: usize = 10_000;
 
+/// Events accepted per second before further events are rejected.
+const DEFAULT_MAX_EVENTS_PER_SECOND: u32 = 50;
+
+/// Longest task ID, status or event type accepted.
+const MAX_NAME_LEN: usize = 256;
+
+/// Longest summary or error message accepted.
+const MAX_TEXT_LEN: usize = 16 * 1024;
+
+/// Most files a single completion may report.
+const MAX_FILES_CHANGED: usize = 1_000;
+
+/// Environment variable holding the callback URL for swarm agents.
+pub const CALLBACK_URL_ENV: &str = "FORGE_CALLBACK_URL";
+
+/// Environment variable holding the bearer token for swarm agents.
+pub const CALLBACK_TOKEN_ENV: &str = "FORGE_CALLBACK_TOKEN";
+
 /// Progress update from a swarm task.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProgressUpdate {
@@ -96,6 +122,43 @@ pub struct TaskComplete {
     pub files_changed: Vec<String>,
 }
 
+/// A test command run by the swarm, and its outcome.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TestRun {
+    /// Command that was run (e.g. `cargo test`)
+    pub command: String,
+    /// Whether it passed
+    pub passed: bool,
+    /// Short summary of the output (e.g. "42 passed, 0 failed")
+    #[serde(default)]
+    pub summary: Option<String>,
+}
+
+/// Final result of a swarm run, reported once by the orchestrating agent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwarmReport {
+    /// Whether the phase goal was reached
+    pub success: bool,
+    /// Summary of the work done
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// Final status of every task
+    #[serde(default)]
+    pub tasks: Vec<TaskComplete>,
+    /// Files changed across all tasks
+    #[serde(default)]
+    pub files_changed: Vec<String>,
+    /// Test commands run to verify the work
+    #[serde(default)]
+    pub tests: Vec<TestRun>,
+    /// Review specialist verdicts
+    #[serde(default)]
+    pub reviews: Vec<ReviewResult>,
+    /// Error message if the phase failed
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
 /// Generic event from swarm execution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GenericEvent {
@@ -114,6 +177,7 @@ pub struct GenericEvent {
 /// | `Progress(...)`    | `POST /progress`    | `application/json` |
 /// | `Complete(...)`    | `POST /complete`    | `application/json` |
 /// | `Event(...)`       | `POST /event`       | `application/json` |
+/// | `Result(...)`      | `POST /result`      | `application/json` |
 ///
 /// ## Client Contract
 ///
@@ -123,9 +187,15 @@ pub struct GenericEvent {
 /// 2. Send `POST {callback_url}/complete` exactly once when the task finishes
 ///    (success, failure, or cancellation).
 /// 3. Optionally send `POST {callback_url}/event` for structured custom payloads.
+/// 4. The orchestrating agent sends `POST {callback_url}/result` once with the
+///    final [`SwarmReport`].
+///
+/// Every request must carry `Authorization: Bearer $FORGE_CALLBACK_TOKEN`.
 ///
-/// The server responds `200 OK` for all accepted events. Any other status indicates
-/// a server error and the agent should retry with exponential backoff.
+/// The server responds `200 OK` for all accepted events. Rejected events get
+/// `401` (missing or wrong token), `422`/`400` (payload fails validation) or
+/// `429` (rate limit exceeded); only `429` and `5xx` are worth retrying with
+/// exponential backoff.
 ///
 /// Events are stored in a bounded ring buffer (default `DEFAULT_MAX_EVENTS` = 10 000).
 /// When the buffer is full the oldest event is dropped to make room.
@@ -138,6 +208,99 @@ pub enum SwarmEvent {
     Complete(TaskComplete),
     /// Generic event
     Event(GenericEvent),
+    /// Final swarm result
+    Result(SwarmReport),
+}
+
+impl SwarmEvent {
+    /// Check the payload against the limits the server accepts.
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        match self {
+            Self::Progress(update) => {
+                check_name("task", &update.task)?;
+                check_name("status", &update.status)?;
+                if update.percent.is_some_and(|p| p > 100) {
+                    return Err("percent must be between 0 and 100".to_string());
+                }
+            }
+            Self::Complete(complete) => check_task(complete)?,
+            Self::Event(event) => check_name("event_type", &event.event_type)?,
+            Self::Result(report) => {
+                check_text("summary", report.summary.as_deref())?;
+                check_text("error", report.error.as_deref())?;
+                check_files(&report.files_changed)?;
+                for task in &report.tasks {
+                    check_task(task)?;
+                }
+                for test in &report.tests {
+                    check_name("test command", &test.command)?;
+                    check_text("test summary", test.summary.as_deref())?;
+                }
+                for review in &report.reviews {
+                    check_name("specialist", &review.specialist)?;
+                    check_name("verdict", &review.verdict)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn check_task(complete: &TaskComplete) -> std::result::Result<(), String> {
+    check_name("task", &complete.task)?;
+    check_text("summary", complete.summary.as_deref())?;
+    check_text("error", complete.error.as_deref())?;
+    check_files(&complete.files_changed)
+}
+
+fn check_files(files: &[String]) -> std::result::Result<(), String> {
+    if files.len() > MAX_FILES_CHANGED {
+        return Err(format!(
+            "files_changed lists more than {} files",
+            MAX_FILES_CHANGED
+        ));
+    }
+    if files.iter().any(|f| f.is_empty() || f.len() > MAX_TEXT_LEN) {
+        return Err("files_changed contains an invalid path".to_string());
+    }
+    Ok(())
+}
+
+fn check_name(field: &str, value: &str) -> std::result::Result<(), String> {
+    if value.trim().is_empty() {
+        return Err(format!("{} must not be empty", field));
+    }
+    if value.len() > MAX_NAME_LEN {
+        return Err(format!("{} is longer than {} bytes", field, MAX_NAME_LEN));
+    }
+    Ok(())
+}
+
+fn check_text(field: &str, value: Option<&str>) -> std::result::Result<(), String> {
+    match value {
+        Some(v) if v.len() > MAX_TEXT_LEN => {
+            Err(format!("{} is longer than {} bytes", field, MAX_TEXT_LEN))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Counts of events the server refused, by reason.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RejectedEvents {
+    /// Missing or wrong bearer token
+    pub unauthorized: usize,
+    /// Malformed JSON or a payload that failed validation
+    pub invalid: usize,
+    /// Over the per-second rate limit
+    pub rate_limited: usize,
+}
+
+impl RejectedEvents {
+    /// Total number of rejected events.
+    pub fn total(&self) -> usize {
+        self.unauthorized + self.invalid + self.rate_limited
+    }
 }
 
 /// Internal state shared between handlers.
@@ -152,6 +315,14 @@ pub(crate) struct ServerState {
     pub(crate) running: bool,
     /// Maximum number of events to retain
     pub(crate) max_events: usize,
+    /// Bearer token agents must present
+    pub(crate) token: String,
+    /// Events accepted per second
+    pub(crate) max_events_per_second: u32,
+    /// Start of the current rate limit window and events accepted in it
+    window: (Instant, u32),
+    /// Events refused so far
+    pub(crate) rejected: RejectedEvents,
 }
 
 impl Default for ServerState {
@@ -160,11 +331,35 @@ impl Default for ServerState {
             events: VecDeque::new(),
             running: false,
             max_events: DEFAULT_MAX_EVENTS,
+            token: uuid::Uuid::new_v4().simple().to_string(),
+            max_events_per_second: DEFAULT_MAX_EVENTS_PER_SECOND,
+            window: (Instant::now(), 0),
+            rejected: RejectedEvents::default(),
         }
     }
 }
 
 impl ServerState {
+    /// Check the request's bearer token.
+    fn authorized(&self, headers: &HeaderMap) -> bool {
+        let authorization = headers.get(AUTHORIZATION).and_then(|v| v.to_str().ok());
+        bearer_token_matches(authorization, &self.token)
+    }
+
+    /// Count an event against the rate limit; false if over it.
+    fn take_rate_slot(&mut self) -> bool {
+        let (started, count) = &mut self.window;
+        if started.elapsed() >= Duration::from_secs(1) {
+            *started = Instant::now();
+            *count = 0;
+        }
+        if *count >= self.max_events_per_second {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+
     /// Add an event, dropping oldest if at capacity.
     fn push_event(&mut self, event: SwarmEvent) {
         if self.events.len() >= self.max_events {
@@ -187,6 +382,10 @@ pub struct CallbackServer {
     shutdown_tx: Option<oneshot::Sender<()>>,
     /// Server address once started
     addr: Option<SocketAddr>,
+    /// Bearer token agents must present
+    token: String,
+    /// Events accepted per second once started
+    max_events_per_second: u32,
 }
 
 impl Default for CallbackServer {
@@ -196,15 +395,34 @@ impl Default for CallbackServer {
 }
 
 impl CallbackServer {
-    /// Create a new callback server.
+    /// Create a new callback server with a fresh bearer token.
     pub fn new() -> Self {
+        let state = ServerState::default();
         Self {
-            state: Arc::new(RwLock::new(ServerState::default())),
+            token: state.token.clone(),
+            max_events_per_second: state.max_events_per_second,
+            state: Arc::new(RwLock::new(state)),
             shutdown_tx: None,
             addr: None,
         }
     }
 
+    /// Set how many events per second are accepted.
+    pub fn with_rate_limit(mut self, max_events_per_second: u32) -> Self {
+        self.max_events_per_second = max_events_per_second;
+        self
+    }
+
+    /// The bearer token agents must send with every event.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Counts of events refused so far.
+    pub async fn rejected_events(&self) -> RejectedEvents {
+        self.state.read().await.rejected
+    }
+
     /// Start the callback server on a dynamic port.
     ///
     /// Returns the callback URL that should be passed to swarm agents.
@@ -228,6 +446,7 @@ impl CallbackServer {
         {
             let mut state = self.state.write().await;
             state.running = true;
+            state.max_events_per_second = self.max_events_per_second;
         }
 
         // Create shutdown channel
@@ -323,6 +542,7 @@ fn build_router(state: Arc<RwLock<ServerState>>) -> Router {
         .route("/progress", post(progress_handler))
         .route("/complete", post(complete_handler))
         .route("/event", post(event_handler))
+        .route("/result", post(result_handler))
         .with_state(state)
 }
 
@@ -331,34 +551,80 @@ async fn health_handler() -> &'static str {
     "ok"
 }
 
+/// Authenticate, rate-limit and validate an event, then store it.
+async fn accept(
+    state: &RwLock<ServerState>,
+    headers: &HeaderMap,
+    event: std::result::Result<SwarmEvent, JsonRejection>,
+) -> StatusCode {
+    let mut state = state.write().await;
+    if !state.authorized(headers) {
+        state.rejected.unauthorized += 1;
+        warn!("Rejected swarm callback without a valid token");
+        return StatusCode::UNAUTHORIZED;
+    }
+    if !state.take_rate_slot() {
+        state.rejected.rate_limited += 1;
+        return StatusCode::TOO_MANY_REQUESTS;
+    }
+    let event = match event {
+        Ok(event) => event,
+        Err(rejection) => {
+            state.rejected.invalid += 1;
+            warn!(
+                "Rejected malformed swarm callback: {}",
+                rejection.body_text()
+            );
+            return rejection.status();
+        }
+    };
+    if let Err(reason) = event.validate() {
+        state.rejected.invalid += 1;
+        warn!("Rejected invalid swarm callback: {}", reason);
+        return StatusCode::UNPROCESSABLE_ENTITY;
+    }
+    state.push_event(event);
+    StatusCode::OK
+}
+
 /// Handle progress updates from swarm tasks.
 async fn progress_handler(
     State(state): State<Arc<RwLock<ServerState>>>,
-    Json(update): Json<ProgressUpdate>,
+    headers: HeaderMap,
+    update: std::result::Result<Json<ProgressUpdate>, JsonRejection>,
 ) -> StatusCode {
-    let mut state = state.write().await;
-    state.push_event(SwarmEvent::Progress(update));
-    StatusCode::OK
+    let event = update.map(|Json(u)| SwarmEvent::Progress(u));
+    accept(&state, &headers, event).await
 }
 
 /// Handle task completion notifications.
 async fn complete_handler(
     State(state): State<Arc<RwLock<ServerState>>>,
-    Json(complete): Json<TaskComplete>,
+    headers: HeaderMap,
+    complete: std::result::Result<Json<TaskComplete>, JsonRejection>,
 ) -> StatusCode {
-    let mut state = state.write().await;
-    state.push_event(SwarmEvent::Complete(complete));
-    StatusCode::OK
+    let event = complete.map(|Json(c)| SwarmEvent::Complete(c));
+    accept(&state, &headers, event).await
 }
 
 /// Handle generic events.
 async fn event_handler(
     State(state): State<Arc<RwLock<ServerState>>>,
-    Json(event): Json<GenericEvent>,
+    headers: HeaderMap,
+    event: std::result::Result<Json<GenericEvent>, JsonRejection>,
 ) -> StatusCode {
-    let mut state = state.write().await;
-    state.push_event(SwarmEvent::Event(event));
-    StatusCode::OK
+    let event = event.map(|Json(e)| SwarmEvent::Event(e));
+    accept(&state, &headers, event).await
+}
+
+/// Handle the final swarm result.
+async fn result_handler(
+    State(state): State<Arc<RwLock<ServerState>>>,
+    headers: HeaderMap,
+    report: std::result::Result<Json<SwarmReport>, JsonRejection>,
+) -> StatusCode {
+    let event = report.map(|Json(r)| SwarmEvent::Result(r));
+    accept(&state, &headers, event).await
 }
 
 #[cfg(test)]
@@ -376,6 +642,28 @@ mod tests {
         (router, state)
     }
 
+    /// Helper to build an authenticated JSON POST
+    async fn post(state: &RwLock<ServerState>, uri: &str, body: String) -> Request<Body> {
+        let token = state.read().await.token.clone();
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .header("authorization", format!("Bearer {}", token))
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    fn progress(task: &str) -> String {
+        serde_json::to_string(&ProgressUpdate {
+            task: task.to_string(),
+            status: "running".to_string(),
+            percent: None,
+            metadata: None,
+        })
+        .unwrap()
+    }
+
     #[tokio::test]
     async fn test_server_start_stop() {
         let mut server = CallbackServer::new();
@@ -420,12 +708,7 @@ mod tests {
             metadata: None,
         };
 
-        let request = Request::builder()
-            .method("POST")
-            .uri("/progress")
-            .header("content-type", "application/json")
-            .body(Body::from(serde_json::to_string(&update).unwrap()))
-            .unwrap();
+        let request = post(&state, "/progress", serde_json::to_string(&update).unwrap()).await;
 
         let response = app.oneshot(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
@@ -454,12 +737,12 @@ mod tests {
             files_changed: vec!["src/lib.rs".to_string()],
         };
 
-        let request = Request::builder()
-            .method("POST")
-            .uri("/complete")
-            .header("content-type", "application/json")
-            .body(Body::from(serde_json::to_string(&complete).unwrap()))
-            .unwrap();
+        let request = post(
+            &state,
+            "/complete",
+            serde_json::to_string(&complete).unwrap(),
+        )
+        .await;
 
         let response = app.oneshot(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
@@ -485,12 +768,7 @@ mod tests {
             payload: serde_json::json!({"key": "value"}),
         };
 
-        let request = Request::builder()
-            .method("POST")
-            .uri("/event")
-            .header("content-type", "application/json")
-            .body(Body::from(serde_json::to_string(&event).unwrap()))
-            .unwrap();
+        let request = post(&state, "/event", serde_json::to_string(&event).unwrap()).await;
 
         let response = app.oneshot(request).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
@@ -505,6 +783,134 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_result_endpoint() {
+        let (app, state) = test_router();
+
+        let body = serde_json::json!({
+            "success": true,
+            "summary": "OAuth providers wired up",
+            "tasks": [
+                {"task": "google", "status": "success", "files_changed": ["src/google.rs"]},
+                {"task": "github", "status": "failed", "error": "rate limited"}
+            ],
+            "files_changed": ["src/google.rs"],
+            "tests": [{"command": "cargo test", "passed": true, "summary": "12 passed"}],
+            "reviews": [{"specialist": "security-sentinel", "verdict": "pass"}]
+        });
+        let request = post(&state, "/result", body.to_string()).await;
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let events: Vec<_> = state.read().await.events.iter().cloned().collect();
+        match &events[0] {
+            SwarmEvent::Result(report) => {
+                assert!(report.success);
+                assert_eq!(report.tasks.len(), 2);
+                assert_eq!(report.tasks[1].status, TaskStatus::Failed);
+                assert!(report.tests[0].passed);
+            }
+            _ => panic!("Expected Result event"),
+        }
+
+        // Every task in the report is validated too
+        let body = serde_json::json!({
+            "success": true,
+            "tasks": [{"task": "", "status": "success"}]
+        });
+        let request = post(&state, "/result", body.to_string()).await;
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_unauthenticated_events_rejected() {
+        let (app, state) = test_router();
+
+        let missing = Request::builder()
+            .method("POST")
+            .uri("/complete")
+            .header("content-type", "application/json")
+            .body(Body::from(progress("forged")))
+            .unwrap();
+        let response = app.clone().oneshot(missing).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let wrong = Request::builder()
+            .method("POST")
+            .uri("/progress")
+            .header("content-type", "application/json")
+            .header("authorization", "Bearer not-the-token")
+            .body(Body::from(progress("forged")))
+            .unwrap();
+        let response = app.oneshot(wrong).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let state = state.read().await;
+        assert!(state.events.is_empty());
+        assert_eq!(state.rejected.unauthorized, 2);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_events_rejected() {
+        let (app, state) = test_router();
+
+        let malformed = post(&state, "/progress", "{\"task\": 1}".to_string()).await;
+        let response = app.clone().oneshot(malformed).await.unwrap();
+        assert!(response.status().is_client_error());
+
+        let update = ProgressUpdate {
+            task: "task-1".to_string(),
+            status: "running".to_string(),
+            percent: Some(250),
+            metadata: None,
+        };
+        let out_of_range = post(&state, "/progress", serde_json::to_string(&update).unwrap()).await;
+        let response = app.clone().oneshot(out_of_range).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let empty_task = post(&state, "/progress", progress(" ")).await;
+        let response = app.oneshot(empty_task).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let state = state.read().await;
+        assert!(state.events.is_empty());
+        assert_eq!(state.rejected.invalid, 3);
+        assert_eq!(state.rejected.total(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_events_rate_limited() {
+        let (app, state) = test_router();
+        state.write().await.max_events_per_second = 2;
+
+        let mut statuses = Vec::new();
+        for i in 0..3 {
+            let request = post(&state, "/progress", progress(&format!("task-{}", i))).await;
+            statuses.push(app.clone().oneshot(request).await.unwrap().status());
+        }
+
+        assert_eq!(
+            statuses,
+            vec![
+                StatusCode::OK,
+                StatusCode::OK,
+                StatusCode::TOO_MANY_REQUESTS
+            ]
+        );
+        let state = state.read().await;
+        assert_eq!(state.events.len(), 2);
+        assert_eq!(state.rejected.rate_limited, 1);
+    }
+
+    #[test]
+    fn test_servers_get_distinct_tokens() {
+        let a = CallbackServer::new();
+        let b = CallbackServer::new();
+        assert_eq!(a.token().len(), 32);
+        assert_ne!(a.token(), b.token());
+    }
+
     #[tokio::test]
     async fn test_health_endpoint() {
         let (app, _state) = test_router();
@@ -697,9 +1103,8 @@ mod tests {
     #[test]
     fn test_max_events_limit() {
         let mut state = ServerState {
-            events: VecDeque::new(),
-            running: false,
             max_events: 3,
+            ..Default::default()
         };
 
         // Add 3 events (at capacity)
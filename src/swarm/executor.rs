@@ -5,9 +5,16 @@
 //! - Builds the orchestration prompt using the SwarmContext
 //! - Spawns a Claude Code process with the prompt
 //! - Monitors execution via HTTP callbacks and stdout
-//! - Parses the `<swarm_complete>` signal to determine success/failure
+//! - Takes the outcome from the typed report posted to `/result`, falling
+//!   back to the `<swarm_complete>` tag in the output, then to per-task
+//!   completion callbacks
+//! - Checks the reported files against the git diff of the working directory
 //! - Returns a SwarmResult with execution details
 //!
+//! A swarm only counts as successful when its report says so, no task
+//! failed, every reported test run passed, and every file it claims to have
+//! changed actually changed.
+//!
 //! ## Usage
 //!
 //! ```no_run
@@ -29,14 +36,19 @@
 //! # }
 //! ```
 
-use crate::swarm::callback::{CallbackServer, SwarmEvent, TaskComplete, TaskStatus};
+use crate::swarm::callback::{
+    CALLBACK_TOKEN_ENV, CALLBACK_URL_ENV, CallbackServer, RejectedEvents, SwarmEvent, SwarmReport,
+    TaskComplete, TaskStatus, TestRun,
+};
 use crate::swarm::context::SwarmContext;
 use crate::swarm::prompts::{
     SwarmCompletionResult, build_orchestration_prompt, parse_swarm_completion,
 };
+use crate::tracker::GitTracker;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::time::{Duration, Instant};
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
@@ -138,6 +150,75 @@ pub struct SwarmResult {
     pub progress_events: usize,
     /// Exit code of Claude process.
     pub exit_code: i32,
+    /// Callback events the server refused.
+    #[serde(default)]
+    pub rejected_events: RejectedEvents,
+    /// Summary of the work done, if reported.
+    #[serde(default)]
+    pub summary: Option<String>,
+    /// Test commands the swarm reported running.
+    #[serde(default)]
+    pub tests: Vec<TestRun>,
+    /// Where the result came from.
+    #[serde(default)]
+    pub source: ResultSource,
+    /// How the reported files compare with the working tree, when it is a
+    /// git repository.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reconciliation: Option<ChangeReconciliation>,
+}
+
+/// Where a swarm's result came from, most reliable first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultSource {
+    /// A typed report posted to the callback server
+    Callback,
+    /// A `<swarm_complete>` tag in the output
+    CompletionTag,
+    /// Per-task completion callbacks only
+    TaskEvents,
+    /// Nothing was reported
+    #[default]
+    None,
+}
+
+/// Files a swarm reported, checked against a git diff from the commit the
+/// run started on, which includes work the swarm committed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangeReconciliation {
+    /// Changed in the working tree during the run, but not reported
+    #[serde(default)]
+    pub unreported: Vec<String>,
+    /// Reported as changed, but unchanged in the working tree
+    #[serde(default)]
+    pub unverified: Vec<String>,
+}
+
+impl ChangeReconciliation {
+    /// Compare reported files with the files changed before and after the run.
+    pub fn compare(
+        reported: &[String],
+        before: &BTreeSet<String>,
+        after: &BTreeSet<String>,
+    ) -> Self {
+        let reported: BTreeSet<&str> = reported
+            .iter()
+            .map(|f| f.trim_start_matches("./"))
+            .collect();
+        Self {
+            unreported: after
+                .iter()
+                .filter(|f| !before.contains(*f) && !reported.contains(f.as_str()))
+                .cloned()
+                .collect(),
+            unverified: reported
+                .iter()
+                .filter(|f| !after.contains(**f))
+                .map(|f| f.to_string())
+                .collect(),
+        }
+    }
 }
 
 /// Outcome of a review specialist.
@@ -171,9 +252,35 @@ impl SwarmResult {
             duration,
             progress_events,
             exit_code,
+            rejected_events: RejectedEvents::default(),
+            summary: None,
+            tests: Vec::new(),
+            source: ResultSource::None,
+            reconciliation: None,
         }
     }
 
+    /// Check the reported files against the working tree and fail the
+    /// result if it claims changes that did not happen.
+    fn reconcile(&mut self, before: &BTreeSet<String>, after: &BTreeSet<String>) {
+        let reconciliation = ChangeReconciliation::compare(&self.files_changed, before, after);
+        if self.success && !reconciliation.unverified.is_empty() {
+            self.success = false;
+            self.error = Some(format!(
+                "Swarm reported changes that are not in the working tree: {}",
+                reconciliation.unverified.join(", ")
+            ));
+        }
+        let unverified = &reconciliation.unverified;
+        self.files_changed
+            .retain(|f| !unverified.iter().any(|u| u == f.trim_start_matches("./")));
+        self.files_changed
+            .extend(reconciliation.unreported.iter().cloned());
+        self.files_changed.sort();
+        self.files_changed.dedup();
+        self.reconciliation = Some(reconciliation);
+    }
+
     /// Create a failed result.
     fn failure(
         phase: String,
@@ -195,6 +302,11 @@ impl SwarmResult {
             duration,
             progress_events,
             exit_code,
+            rejected_events: RejectedEvents::default(),
+            summary: None,
+            tests: Vec::new(),
+            source: ResultSource::None,
+            reconciliation: None,
         }
     }
 }
@@ -280,12 +392,28 @@ impl SwarmExecutor {
             .clone()
             .unwrap_or_else(|| context.working_dir.clone());
 
-        // 4. Spawn Claude process and monitor execution
+        // 4. Spawn Claude process and monitor execution. Both diffs are
+        // taken against the commit checked out now, so work the swarm
+        // commits still counts as changed.
+        let base = GitTracker::new(&working_dir)
+            .ok()
+            .and_then(|tracker| tracker.head_sha());
+        let changed_before = base.as_deref().and_then(|b| changed_files(&working_dir, b));
         let result = self
             .run_claude_process(&prompt, &working_dir, &mut callback_server, start)
             .await;
+        let changed_after = base.as_deref().and_then(|b| changed_files(&working_dir, b));
 
         // 5. Cleanup callback server
+        let rejected_events = callback_server.rejected_events().await;
+        if rejected_events.total() > 0 {
+            warn!(
+                unauthorized = rejected_events.unauthorized,
+                invalid = rejected_events.invalid,
+                rate_limited = rejected_events.rate_limited,
+                "Swarm callback server rejected events"
+            );
+        }
         if let Err(e) = callback_server.stop().await {
             warn!("Failed to stop callback server: {}", e);
         }
@@ -294,11 +422,20 @@ impl SwarmExecutor {
         match result {
             Ok(mut res) => {
                 res.phase = phase_number;
+                res.rejected_events = rejected_events;
+                if let (Some(before), Some(after)) = (&changed_before, &changed_after) {
+                    res.reconcile(before, after);
+                    if let Some(r) = &res.reconciliation
+                        && !r.unverified.is_empty()
+                    {
+                        warn!(files = ?r.unverified, "Swarm reported files that did not change");
+                    }
+                }
                 Ok(res)
             }
             Err(e) => {
                 let duration = start.elapsed();
-                Ok(SwarmResult::failure(
+                let mut res = SwarmResult::failure(
                     phase_number,
                     e.to_string(),
                     Vec::new(),
@@ -306,7 +443,9 @@ impl SwarmExecutor {
                     duration,
                     0,
                     -1,
-                ))
+                );
+                res.rejected_events = rejected_events;
+                Ok(res)
             }
         }
     }
@@ -330,6 +469,13 @@ impl SwarmExecutor {
 
         // Note: stderr is set to inherit so Claude errors are visible in terminal.
         // Using piped() without reading could cause the process to block if buffer fills.
+        // Agents authenticate their callbacks with the server's token
+        cmd.env(
+            CALLBACK_URL_ENV,
+            callback_server.callback_url().unwrap_or_default(),
+        )
+        .env(CALLBACK_TOKEN_ENV, callback_server.token());
+
         cmd.current_dir(working_dir)
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
@@ -473,23 +619,35 @@ impl SwarmExecutor {
             ));
         }
 
-        // Parse completion signal from output
-        if let Some(completion) = parse_swarm_completion(&accumulated_output) {
-            return Ok(self.build_result_from_completion(
-                completion,
+        // Prefer the typed report sent to the callback server
+        let report = callback_events.iter().rev().find_map(|e| match e {
+            SwarmEvent::Result(report) => Some(report.clone()),
+            _ => None,
+        });
+        if let Some(report) = report {
+            return Ok(build_result_from_report(
+                report,
                 duration,
                 progress_events,
                 exit_code,
             ));
         }
 
+        // Fall back to the completion tag in the output
+        if let Some(completion) = parse_swarm_completion(&accumulated_output) {
+            let mut result =
+                self.build_result_from_completion(completion, duration, progress_events, exit_code);
+            result.source = ResultSource::CompletionTag;
+            return Ok(result);
+        }
+
         // No completion signal found - check if we have callback data
         let tasks_completed = extract_completed_tasks(&callback_events);
         let files_changed = extract_files_changed(&callback_events);
 
         if exit_code == 0 && !tasks_completed.is_empty() {
             // Process exited successfully and we have completed tasks
-            Ok(SwarmResult::success(
+            let mut result = SwarmResult::success(
                 String::new(),
                 tasks_completed,
                 files_changed,
@@ -497,7 +655,9 @@ impl SwarmExecutor {
                 duration,
                 progress_events,
                 exit_code,
-            ))
+            );
+            result.source = ResultSource::TaskEvents;
+            Ok(result)
         } else if exit_code == 0 {
             // Process exited successfully but no completion signal
             Ok(SwarmResult::failure(
@@ -626,11 +786,104 @@ impl SwarmExecutor {
                 duration,
                 progress_events,
                 exit_code,
+                rejected_events: RejectedEvents::default(),
+                summary: None,
+                tests: Vec::new(),
+                source: ResultSource::None,
+                reconciliation: None,
             }
         }
     }
 }
 
+/// Build a SwarmResult from the report posted to the callback server.
+///
+/// The report's own success flag is not enough: any failed task or test
+/// run fails the result.
+fn build_result_from_report(
+    report: SwarmReport,
+    duration: Duration,
+    progress_events: usize,
+    exit_code: i32,
+) -> SwarmResult {
+    let (completed, failed): (Vec<&TaskComplete>, Vec<&TaskComplete>) = report
+        .tasks
+        .iter()
+        .partition(|t| t.status == TaskStatus::Success);
+    let tasks_failed: Vec<String> = failed.iter().map(|t| t.task.clone()).collect();
+    let failed_tests: Vec<&str> = report
+        .tests
+        .iter()
+        .filter(|t| !t.passed)
+        .map(|t| t.command.as_str())
+        .collect();
+
+    let mut files_changed: Vec<String> = report
+        .files_changed
+        .iter()
+        .chain(report.tasks.iter().flat_map(|t| &t.files_changed))
+        .cloned()
+        .collect();
+    files_changed.sort();
+    files_changed.dedup();
+
+    let error = if !report.success {
+        Some(
+            report
+                .error
+                .clone()
+                .unwrap_or_else(|| "Swarm reported failure".to_string()),
+        )
+    } else if !tasks_failed.is_empty() {
+        Some(format!("Tasks failed: {}", tasks_failed.join(", ")))
+    } else if !failed_tests.is_empty() {
+        Some(format!("Tests failed: {}", failed_tests.join(", ")))
+    } else {
+        None
+    };
+
+    SwarmResult {
+        success: error.is_none(),
+        phase: String::new(),
+        tasks_completed: completed.iter().map(|t| t.task.clone()).collect(),
+        tasks_failed,
+        reviews: report
+            .reviews
+            .into_iter()
+            .map(|r| ReviewOutcome {
+                specialist: r.specialist,
+                verdict: r.verdict,
+            })
+            .collect(),
+        files_changed,
+        error,
+        duration,
+        progress_events,
+        exit_code,
+        rejected_events: RejectedEvents::default(),
+        summary: report.summary,
+        tests: report.tests,
+        source: ResultSource::Callback,
+        reconciliation: None,
+    }
+}
+
+/// Files in a git working tree that differ from the `base` commit, or None
+/// if they cannot be computed.
+fn changed_files(dir: &Path, base: &str) -> Option<BTreeSet<String>> {
+    let tracker = GitTracker::new(dir).ok()?;
+    let changes = tracker.compute_changes(base).ok()?;
+    Some(
+        changes
+            .files_added
+            .iter()
+            .chain(&changes.files_modified)
+            .chain(&changes.files_deleted)
+            .map(|p| p.to_string_lossy().to_string())
+            .collect(),
+    )
+}
+
 /// Extract completed task IDs from callback events.
 fn extract_completed_tasks(events: &[SwarmEvent]) -> Vec<String> {
     events
@@ -920,6 +1173,125 @@ mod tests {
         assert_eq!(result.progress_events, 15);
     }
 
+    fn task(task: &str, status: TaskStatus, files: &[&str]) -> TaskComplete {
+        TaskComplete {
+            task: task.to_string(),
+            status,
+            summary: None,
+            error: None,
+            files_changed: files.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    fn report(tasks: Vec<TaskComplete>, tests: Vec<TestRun>) -> SwarmReport {
+        SwarmReport {
+            success: true,
+            summary: Some("Done".to_string()),
+            tasks,
+            files_changed: vec!["src/lib.rs".to_string()],
+            tests,
+            reviews: vec![],
+            error: None,
+        }
+    }
+
+    fn test_run(command: &str, passed: bool) -> TestRun {
+        TestRun {
+            command: command.to_string(),
+            passed,
+            summary: None,
+        }
+    }
+
+    #[test]
+    fn test_build_result_from_report() {
+        let report = report(
+            vec![
+                task("t1", TaskStatus::Success, &["src/a.rs"]),
+                task("t2", TaskStatus::Success, &["src/lib.rs"]),
+            ],
+            vec![test_run("cargo test", true)],
+        );
+
+        let result = build_result_from_report(report, Duration::from_secs(5), 4, 0);
+
+        assert!(result.success);
+        assert_eq!(result.source, ResultSource::Callback);
+        assert_eq!(result.tasks_completed, vec!["t1", "t2"]);
+        assert_eq!(result.files_changed, vec!["src/a.rs", "src/lib.rs"]);
+        assert_eq!(result.summary.as_deref(), Some("Done"));
+        assert_eq!(result.tests.len(), 1);
+    }
+
+    #[test]
+    fn test_report_success_needs_tasks_and_tests_to_pass() {
+        let failed_task = report(
+            vec![
+                task("t1", TaskStatus::Success, &[]),
+                task("t2", TaskStatus::Failed, &[]),
+            ],
+            vec![],
+        );
+        let result = build_result_from_report(failed_task, Duration::ZERO, 0, 0);
+        assert!(!result.success);
+        assert_eq!(result.tasks_failed, vec!["t2"]);
+        assert_eq!(result.error.as_deref(), Some("Tasks failed: t2"));
+
+        let failed_test = report(vec![], vec![test_run("cargo test", false)]);
+        let result = build_result_from_report(failed_test, Duration::ZERO, 0, 0);
+        assert!(!result.success);
+        assert_eq!(result.error.as_deref(), Some("Tests failed: cargo test"));
+    }
+
+    #[test]
+    fn test_change_reconciliation() {
+        let before: BTreeSet<String> = ["notes.md".to_string()].into();
+        let after: BTreeSet<String> = ["notes.md", "src/a.rs", "src/b.rs"]
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        let reported = vec!["./src/a.rs".to_string(), "src/ghost.rs".to_string()];
+
+        let reconciliation = ChangeReconciliation::compare(&reported, &before, &after);
+
+        assert_eq!(reconciliation.unreported, vec!["src/b.rs"]);
+        assert_eq!(reconciliation.unverified, vec!["src/ghost.rs"]);
+    }
+
+    #[test]
+    fn test_reconcile_fails_result_claiming_unmade_changes() {
+        let mut result = build_result_from_report(
+            report(vec![task("t1", TaskStatus::Success, &["src/a.rs"])], vec![]),
+            Duration::ZERO,
+            0,
+            0,
+        );
+        let after: BTreeSet<String> = ["src/a.rs", "src/b.rs"]
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+
+        result.reconcile(&BTreeSet::new(), &after);
+
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("src/lib.rs"));
+        assert_eq!(result.files_changed, vec!["src/a.rs", "src/b.rs"]);
+
+        let mut result = build_result_from_report(
+            report(vec![task("t1", TaskStatus::Success, &["src/a.rs"])], vec![]),
+            Duration::ZERO,
+            0,
+            0,
+        );
+        let after: BTreeSet<String> = ["src/a.rs", "src/lib.rs"]
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        result.reconcile(&BTreeSet::new(), &after);
+        assert!(result.success);
+        assert!(result.reconciliation.unwrap().unverified.is_empty());
+    }
+
     #[test]
     fn test_build_result_from_completion_failure() {
         use crate::swarm::prompts::{ReviewResult, SwarmCompletionResult};
@@ -1115,4 +1487,55 @@ mod tests {
         assert_eq!(result.progress_events, 12);
         assert_eq!(result.exit_code, 1);
     }
+
+    #[tokio::test]
+    async fn test_execute_counts_committed_changes() {
+        use std::os::unix::fs::PermissionsExt;
+        use std::process::Command as StdCommand;
+
+        let repo = tempfile::tempdir().unwrap();
+        let git = |args: &[&str]| {
+            let output = StdCommand::new("git")
+                .args(args)
+                .current_dir(repo.path())
+                .output()
+                .unwrap();
+            assert!(output.status.success(), "git {args:?} failed");
+        };
+        git(&["init"]);
+        git(&["config", "user.email", "test@test.com"]);
+        git(&["config", "user.name", "Test"]);
+        std::fs::write(repo.path().join("lib.rs"), "fn lib() {}\n").unwrap();
+        git(&["add", "."]);
+        git(&["commit", "-m", "initial"]);
+
+        // A swarm that commits its work, reporting only part of it
+        let bin = tempfile::tempdir().unwrap();
+        let claude = bin.path().join("claude");
+        std::fs::write(
+            &claude,
+            "#!/bin/sh\ncat >/dev/null\n\
+             echo 'fn a() {}' > a.rs && git add a.rs && git commit -qm a\n\
+             echo 'fn b() {}' > b.rs && git add b.rs && git commit -qm b\n\
+             echo '<swarm_complete>{\"success\": true, \"phase\": \"05\", \
+             \"tasks_completed\": [\"a\", \"b\"], \"files_changed\": [\"a.rs\"]}</swarm_complete>'\n",
+        )
+        .unwrap();
+        std::fs::set_permissions(&claude, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let executor = SwarmExecutor::new(
+            SwarmConfig::default()
+                .with_claude_cmd(&claude.display().to_string())
+                .with_timeout(Duration::from_secs(30)),
+        );
+        let phase = PhaseInfo::new("05", "Commit", "DONE", 5);
+        let context = SwarmContext::new(phase, "", repo.path().to_path_buf());
+        let result = executor.execute(context).await.unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.files_changed, vec!["a.rs", "b.rs"]);
+        let reconciliation = result.reconciliation.unwrap();
+        assert!(reconciliation.unverified.is_empty());
+        assert_eq!(reconciliation.unreported, vec!["b.rs"]);
+    }
 }
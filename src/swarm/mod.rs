@@ -5,7 +5,7 @@
 //!
 //! ## Components
 //!
-//! - [`callback`]: HTTP server for receiving progress updates from swarm agents
+//! - [`callback`]: Authenticated HTTP server for receiving progress updates from swarm agents
 //! - [`context`]: Data types for swarm execution configuration
 //! - [`executor`]: SwarmExecutor for orchestrating Claude Code swarm execution
 //! - [`prompts`]: Prompt templates for orchestrating Claude Code swarms
@@ -39,7 +39,8 @@ pub mod prompts;
 
 // Re-export callback types
 pub use callback::{
-    CallbackServer, GenericEvent, ProgressUpdate, SwarmEvent, TaskComplete, TaskStatus,
+    CALLBACK_TOKEN_ENV, CALLBACK_URL_ENV, CallbackServer, GenericEvent, ProgressUpdate,
+    RejectedEvents, SwarmEvent, SwarmReport, TaskComplete, TaskStatus, TestRun,
 };
 
 // Re-export context types
@@ -49,7 +50,9 @@ pub use context::{
 };
 
 // Re-export executor types
-pub use executor::{ReviewOutcome, SwarmConfig, SwarmExecutor, SwarmResult};
+pub use executor::{
+    ChangeReconciliation, ResultSource, ReviewOutcome, SwarmConfig, SwarmExecutor, SwarmResult,
+};
 
 // Re-export prompt parsing types
 pub use prompts::{ReviewResult, SwarmCompletionResult};
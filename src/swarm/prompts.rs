@@ -11,6 +11,7 @@
 //! - **Review prompt**: Instructions for review specialists
 //! - **Progress reporting**: Format for callback updates
 
+use crate::swarm::callback::CALLBACK_TOKEN_ENV;
 use crate::swarm::context::{
     PhaseInfo, ReviewConfig, ReviewSpecialistConfig, SwarmContext, SwarmStrategy, SwarmTask,
 };
@@ -98,7 +99,10 @@ pub fn build_orchestration_prompt(context: &SwarmContext) -> String {
     prompt.push_str(&build_progress_section(&context.callback_url));
 
     // Completion section
-    prompt.push_str(&build_completion_section(&context.phase));
+    prompt.push_str(&build_completion_section(
+        &context.phase,
+        &context.callback_url,
+    ));
 
     prompt
 }
@@ -138,9 +142,13 @@ pub fn build_task_prompt(task: &SwarmTask, phase: &PhaseInfo, callback_url: &str
          Report progress to: `{}/progress`\n\n\
          ```bash\n\
          curl -X POST {}/progress -H 'Content-Type: application/json' \\\n\
+           {auth} \\\n\
            -d '{{\"task\": \"{}\", \"status\": \"in_progress\", \"percent\": 50}}'\n\
          ```\n\n",
-        callback_url, callback_url, task.id
+        callback_url,
+        callback_url,
+        task.id,
+        auth = auth_header()
     ));
 
     prompt.push_str(&format!(
@@ -148,13 +156,17 @@ pub fn build_task_prompt(task: &SwarmTask, phase: &PhaseInfo, callback_url: &str
          When done, report completion:\n\n\
          ```bash\n\
          curl -X POST {}/complete -H 'Content-Type: application/json' \\\n\
+           {auth} \\\n\
            -d '{{\"task\": \"{}\", \"status\": \"success\", \"summary\": \"Brief description\"}}'\n\
          ```\n\n\
          Then output:\n\
          ```\n\
          <task_complete id=\"{}\">SUCCESS</task_complete>\n\
          ```\n",
-        callback_url, task.id, task.id
+        callback_url,
+        task.id,
+        task.id,
+        auth = auth_header()
     ));
 
     prompt
@@ -332,57 +344,83 @@ fn build_responsibilities_section(context: &SwarmContext) -> String {
 fn build_progress_section(callback_url: &str) -> String {
     format!(
         "## Progress Reporting\n\n\
-         Report progress to Forge via HTTP callbacks:\n\n\
+         Report progress to Forge via HTTP callbacks. Every request must send the \
+         `Authorization` header shown below; the token is in the `{token_env}` \
+         environment variable. Requests without it are rejected.\n\n\
          **Task Progress:**\n\
          ```bash\n\
          curl -X POST {}/progress -H 'Content-Type: application/json' \\\n\
+           {auth} \\\n\
            -d '{{\"task\": \"task-id\", \"status\": \"description\", \"percent\": 50}}'\n\
          ```\n\n\
          **Task Completion:**\n\
          ```bash\n\
          curl -X POST {}/complete -H 'Content-Type: application/json' \\\n\
+           {auth} \\\n\
            -d '{{\"task\": \"task-id\", \"status\": \"success\", \"summary\": \"...\", \"files_changed\": []}}'\n\
          ```\n\n\
          **Generic Events:**\n\
          ```bash\n\
          curl -X POST {}/event -H 'Content-Type: application/json' \\\n\
+           {auth} \\\n\
            -d '{{\"event_type\": \"custom\", \"payload\": {{}}}}'\n\
          ```\n\n",
-        callback_url, callback_url, callback_url
+        callback_url,
+        callback_url,
+        callback_url,
+        auth = auth_header(),
+        token_env = CALLBACK_TOKEN_ENV
     )
 }
 
+/// The curl header that authenticates a callback.
+fn auth_header() -> String {
+    format!("-H \"Authorization: Bearer ${}\"", CALLBACK_TOKEN_ENV)
+}
+
 /// Build the completion section of the orchestration prompt.
-fn build_completion_section(phase: &PhaseInfo) -> String {
+fn build_completion_section(phase: &PhaseInfo, callback_url: &str) -> String {
     format!(
         "## Completion Signal\n\n\
-         When all tasks are complete and reviews pass, output:\n\n\
+         When all tasks are finished and reviews are done, report the result once \
+         to `{url}/result`. List every task with its final status, every file \
+         you changed, and every test command you ran with its outcome. Forge \
+         checks the reported files against the git diff, so report them exactly:\n\n\
+         ```bash\n\
+         curl -X POST {url}/result -H 'Content-Type: application/json' \\\n\
+           {auth} \\\n\
+           -d '{{\n\
+             \"success\": true,\n\
+             \"summary\": \"What was done\",\n\
+             \"tasks\": [\n\
+               {{\"task\": \"task-1\", \"status\": \"success\", \"files_changed\": [\"src/file.rs\"]}},\n\
+               {{\"task\": \"task-2\", \"status\": \"failed\", \"error\": \"Why it failed\"}}\n\
+             ],\n\
+             \"files_changed\": [\"src/file.rs\"],\n\
+             \"tests\": [{{\"command\": \"cargo test\", \"passed\": true, \"summary\": \"42 passed\"}}],\n\
+             \"reviews\": [{{\"specialist\": \"security-sentinel\", \"verdict\": \"pass\"}}],\n\
+             \"error\": null\n\
+           }}'\n\
+         ```\n\n\
+         Only if the callback cannot be reached, output the result instead:\n\n\
          ```xml\n\
          <swarm_complete>\n\
          {{\n\
            \"success\": true,\n\
-           \"phase\": \"{}\",\n\
+           \"phase\": \"{phase}\",\n\
            \"tasks_completed\": [\"task-1\", \"task-2\"],\n\
+           \"tasks_failed\": [],\n\
            \"reviews\": [\n\
              {{\"specialist\": \"security-sentinel\", \"verdict\": \"pass\"}}\n\
            ],\n\
-           \"files_changed\": [\"src/file.rs\"]\n\
-         }}\n\
-         </swarm_complete>\n\
-         ```\n\n\
-         If the phase fails, output:\n\n\
-         ```xml\n\
-         <swarm_complete>\n\
-         {{\n\
-           \"success\": false,\n\
-           \"phase\": \"{}\",\n\
-           \"error\": \"Description of what went wrong\",\n\
-           \"tasks_completed\": [\"task-1\"],\n\
-           \"tasks_failed\": [\"task-2\"]\n\
+           \"files_changed\": [\"src/file.rs\"],\n\
+           \"error\": null\n\
          }}\n\
          </swarm_complete>\n\
          ```\n",
-        phase.number, phase.number
+        url = callback_url,
+        auth = auth_header(),
+        phase = phase.number
     )
 }
 
@@ -458,6 +496,8 @@ pub struct ReviewResult {
 /// Parse a swarm completion signal from output.
 ///
 /// Looks for `<swarm_complete>...</swarm_complete>` tags and parses the JSON inside.
+/// This is the fallback for swarms that could not post a
+/// [`SwarmReport`](crate::swarm::SwarmReport) to the callback server.
 pub fn parse_swarm_completion(output: &str) -> Option<SwarmCompletionResult> {
     let start_tag = "<swarm_complete>";
     let end_tag = "</swarm_complete>";
@@ -477,7 +517,8 @@ pub fn parse_swarm_completion(output: &str) -> Option<SwarmCompletionResult> {
 
 /// Parse a task completion signal from output.
 ///
-/// Looks for `<task_complete id="...">...</task_complete>` tags.
+/// Looks for `<task_complete id="...">...</task_complete>` tags. Task status
+/// reported to the callback server takes precedence.
 pub fn parse_task_completion(output: &str, task_id: &str) -> Option<String> {
     let pattern = format!("<task_complete id=\"{}\">", task_id);
     let end_tag = "</task_complete>";
@@ -613,6 +654,12 @@ mod tests {
         assert!(prompt.contains("/progress"));
         assert!(prompt.contains("/complete"));
         assert!(prompt.contains("/event"));
+        assert_eq!(
+            prompt
+                .matches("Authorization: Bearer $FORGE_CALLBACK_TOKEN")
+                .count(),
+            4
+        );
     }
 
     #[test]
@@ -621,6 +668,8 @@ mod tests {
         let prompt = build_orchestration_prompt(&context);
 
         assert!(prompt.contains("## Completion Signal"));
+        assert!(prompt.contains("http://localhost:8080/result"));
+        assert!(prompt.contains("\"tests\""));
         assert!(prompt.contains("<swarm_complete>"));
         assert!(prompt.contains("</swarm_complete>"));
     }
@@ -654,6 +703,7 @@ mod tests {
 
         assert!(prompt.contains("http://localhost:9000/progress"));
         assert!(prompt.contains("http://localhost:9000/complete"));
+        assert!(prompt.contains("Authorization: Bearer $FORGE_CALLBACK_TOKEN"));
         assert!(prompt.contains("<task_complete id=\"t1\">"));
     }
 
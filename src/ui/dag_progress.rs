@@ -7,6 +7,7 @@
 //! - `json`: JSON-formatted events for machine consumption
 
 use crate::dag::{DagSummary, PhaseEvent, PhaseResult};
+use crate::decomposition::{PlanDecision, PreflightDecision};
 use crate::ui::icons::{CHECK, CLOCK, CROSS, REVIEW, RUNNING, SPARKLE, WAVE};
 use console::{Term, style};
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
@@ -144,6 +145,22 @@ impl DagUI {
             PhaseEvent::WaveStarted { wave, phases } => {
                 let _ = writeln!(&self.term, "Wave {}: {}", wave, phases.join(", "));
             }
+            PhaseEvent::ReviewPending { phase, review } => {
+                let _ = writeln!(&self.term, "⏸ {} awaiting review {}", phase, review.id);
+            }
+            PhaseEvent::ScopeConflict {
+                phase,
+                other,
+                files,
+            } => {
+                let _ = writeln!(
+                    &self.term,
+                    "⚠ {} and {} touched {} shared file(s)",
+                    phase,
+                    other,
+                    files.len()
+                );
+            }
             PhaseEvent::Completed { phase, result } => {
                 if result.is_success() {
                     let _ = writeln!(&self.term, "✓ {}", phase);
@@ -192,6 +209,16 @@ impl DagUI {
             PhaseEvent::ReviewStarted { phase } => {
                 self.on_review_started(phase);
             }
+            PhaseEvent::ReviewPending { phase, review } => {
+                self.on_review_pending(phase, &review.id, review.items.len());
+            }
+            PhaseEvent::ReviewResolved {
+                phase,
+                review_id,
+                resolution,
+            } => {
+                self.on_review_resolved(phase, review_id, resolution.fail_phase);
+            }
             PhaseEvent::ReviewCompleted {
                 phase,
                 passed,
@@ -199,6 +226,13 @@ impl DagUI {
             } => {
                 self.on_review_completed(phase, *passed, *findings_count);
             }
+            PhaseEvent::ScopeConflict {
+                phase,
+                other,
+                files,
+            } => {
+                self.on_scope_conflict(phase, other, files);
+            }
             PhaseEvent::WaveCompleted {
                 wave,
                 success_count,
@@ -209,9 +243,15 @@ impl DagUI {
             PhaseEvent::DagCompleted { success, summary } => {
                 self.on_dag_completed(*success, summary);
             }
+            PhaseEvent::PreflightEstimated { phase, decision } => {
+                self.on_preflight_estimated(phase, decision);
+            }
             PhaseEvent::DecompositionStarted { phase, reason } => {
                 self.on_decomposition_started(phase, reason);
             }
+            PhaseEvent::DecompositionReviewed { phase, decision } => {
+                self.on_decomposition_reviewed(phase, decision);
+            }
             PhaseEvent::DecompositionCompleted {
                 phase,
                 task_count,
@@ -425,6 +465,70 @@ impl DagUI {
         }
     }
 
+    /// Handle review pending event.
+    fn on_review_pending(&self, phase: &str, review_id: &str, findings_count: usize) {
+        let bars = self.phase_bars.lock().unwrap_or_else(|p| p.into_inner());
+        if let Some(state) = bars.get(phase) {
+            state
+                .bar
+                .set_message(format!("{} Awaiting manual review...", REVIEW));
+        }
+
+        self.multi
+            .println(format!(
+                "    {} Phase {} awaiting manual review of {} finding(s) (review {})",
+                style("⏸").yellow(),
+                phase,
+                findings_count,
+                review_id
+            ))
+            .ok();
+    }
+
+    /// Handle review resolved event.
+    fn on_review_resolved(&self, phase: &str, review_id: &str, fail_phase: bool) {
+        let bars = self.phase_bars.lock().unwrap_or_else(|p| p.into_inner());
+        if let Some(state) = bars.get(phase) {
+            state
+                .bar
+                .set_message(format!("{} Applying review decision...", REVIEW));
+        }
+
+        self.multi
+            .println(format!(
+                "    {} Phase {} review {} {}",
+                style("▶").cyan(),
+                phase,
+                review_id,
+                if fail_phase { "rejected" } else { "decided" }
+            ))
+            .ok();
+    }
+
+    /// Handle scope conflict event.
+    fn on_scope_conflict(&self, phase: &str, other: &str, files: &[std::path::PathBuf]) {
+        let shown: Vec<String> = files
+            .iter()
+            .take(3)
+            .map(|f| f.display().to_string())
+            .collect();
+        let more = if files.len() > shown.len() {
+            format!(" (+{} more)", files.len() - shown.len())
+        } else {
+            String::new()
+        };
+        self.multi
+            .println(format!(
+                "    {} Phases {} and {} both touched {}{}; they will run one at a time",
+                style("⚠").yellow(),
+                style(phase).yellow(),
+                style(other).yellow(),
+                shown.join(", "),
+                more
+            ))
+            .ok();
+    }
+
     /// Handle review completed event.
     fn on_review_completed(&self, phase: &str, passed: bool, findings_count: usize) {
         let emoji = if passed { CHECK } else { CROSS };
@@ -627,6 +731,41 @@ impl DagUI {
             .ok();
     }
 
+    /// Handle decomposition plan review event.
+    fn on_decomposition_reviewed(&self, phase: &str, decision: &PlanDecision) {
+        self.multi
+            .println(format!(
+                "  {} Phase {} plan {}",
+                style(REVIEW).cyan(),
+                style(phase).yellow().bold(),
+                style(decision).dim()
+            ))
+            .ok();
+    }
+
+    /// Handle pre-flight estimate event.
+    fn on_preflight_estimated(&self, phase: &str, decision: &PreflightDecision) {
+        if decision.decompose {
+            self.multi
+                .println(format!(
+                    "  {} Phase {} estimated at {}% of its budget, planning sub-tasks first",
+                    style("📐").cyan(),
+                    style(phase).yellow().bold(),
+                    style(decision.budget_percent()).yellow()
+                ))
+                .ok();
+        } else if self.verbose {
+            self.multi
+                .println(format!(
+                    "    {} Phase {}: {}",
+                    style("📐").dim(),
+                    phase,
+                    style(decision.estimate.summary()).dim()
+                ))
+                .ok();
+        }
+    }
+
     /// Handle decomposition completed event.
     fn on_decomposition_completed(&self, phase: &str, task_count: usize, total_budget: u32) {
         self.multi
@@ -34,6 +34,61 @@ pub fn extract_json_object(text: &str) -> Option<String> {
     }
 }
 
+/// Whether an `Authorization` header value carries `Bearer <token>`.
+///
+/// Compares in constant time so the token cannot be guessed byte by byte.
+pub fn bearer_token_matches(authorization: Option<&str>, token: &str) -> bool {
+    let Some(presented) = authorization.and_then(|v| v.strip_prefix("Bearer ")) else {
+        return false;
+    };
+    presented.len() == token.len()
+        && presented
+            .bytes()
+            .zip(token.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Kills a spawned command's whole process group unless released.
+///
+/// `kill_on_drop` only reaches the direct child, so a `sh -c` wrapper cut
+/// off by a timeout would leave whatever it started running. The command is
+/// spawned as the leader of its own group and the group is killed when this
+/// guard is dropped before the command finished.
+#[derive(Debug)]
+pub struct ProcessGroup {
+    pgid: Option<i32>,
+}
+
+impl ProcessGroup {
+    /// Spawn `cmd` as the leader of a new process group.
+    pub fn spawn(
+        cmd: &mut tokio::process::Command,
+    ) -> std::io::Result<(tokio::process::Child, Self)> {
+        cmd.process_group(0).kill_on_drop(true);
+        let child = cmd.spawn()?;
+        let pgid = child.id().and_then(|pid| i32::try_from(pid).ok());
+        Ok((child, Self { pgid }))
+    }
+
+    /// The command finished on its own; leave the group alone.
+    pub fn release(mut self) {
+        self.pgid = None;
+    }
+}
+
+impl Drop for ProcessGroup {
+    fn drop(&mut self) {
+        if let Some(pgid) = self.pgid.take() {
+            // SAFETY: killpg has no memory-safety preconditions; a group that
+            // has already exited just yields ESRCH
+            unsafe {
+                libc::killpg(pgid, libc::SIGKILL);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,4 +140,25 @@ mod tests {
         let text = r#"{"key": "value""#;
         assert_eq!(extract_json_object(text), None);
     }
+
+    #[tokio::test]
+    async fn test_process_group_kills_grandchildren_on_drop() {
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("late");
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.arg("-c")
+            .arg(format!("(sleep 1; touch '{}') & wait", marker.display()));
+
+        let (child, group) = ProcessGroup::spawn(&mut cmd).unwrap();
+        let timed_out = tokio::time::timeout(
+            std::time::Duration::from_millis(100),
+            child.wait_with_output(),
+        )
+        .await;
+        assert!(timed_out.is_err());
+        drop(group);
+
+        tokio::time::sleep(std::time::Duration::from_millis(1500)).await;
+        assert!(!marker.exists(), "grandchild must die with the group");
+    }
 }
@@ -73,6 +73,17 @@ export const api = {
   getTaskEvents: (taskId: number, limit: number = 100) =>
     request<import('../types').AgentEvent[]>(`/tasks/${taskId}/events?limit=${limit}`),
 
+  // Manual reviews
+  getPendingReviews: (runId?: number) =>
+    request<import('../types').PendingReviewRecord[]>(
+      runId === undefined ? '/reviews/pending' : `/reviews/pending?run_id=${runId}`,
+    ),
+  decidePendingReview: (reviewId: string, resolution: import('../types').ManualResolution) =>
+    request<import('../types').PendingReviewRecord>(`/reviews/${reviewId}/decision`, {
+      method: 'POST',
+      body: JSON.stringify(resolution),
+    }),
+
   // GitHub OAuth
   githubStatus: () => request<import('../types').GitHubAuthStatus>('/github/status'),
   githubDeviceCode: () => request<import('../types').GitHubDeviceCode>('/github/device-code', { method: 'POST' }),
@@ -412,6 +412,17 @@ export default function useMissionControl(): MissionControlReturn {
         break;
       }
 
+      case 'PipelineReviewPending': {
+        const { review } = msg.data;
+        addLogEntry('review', `Awaiting manual review of ${review.items.length} findings (review ${review.id})`, undefined, msg.data.run_id);
+        break;
+      }
+
+      case 'PipelineReviewDecided': {
+        addLogEntry('review', `Manual review ${msg.data.review_id} decided`, undefined, msg.data.run_id);
+        break;
+      }
+
       case 'PipelineBranchCreated': {
         const { run_id, branch_name } = msg.data;
         setState(prev => {
@@ -146,6 +146,47 @@ export interface PipelineFileChange {
   action: FileAction;
 }
 
+export type FindingAction = 'accept' | 'fix' | 'dismiss';
+
+export interface PendingReviewItem {
+  id: string;
+  specialist: string;
+  finding: {
+    severity: 'info' | 'warning' | 'error';
+    file: string;
+    line?: number;
+    column?: number;
+    issue: string;
+    suggestion?: string;
+    category?: string;
+  };
+  status: string;
+  attempts: number;
+  note?: string;
+}
+
+export interface PendingReview {
+  id: string;
+  phase: string;
+  phase_name: string;
+  created_at: string;
+  fix_attempts: number;
+  items: PendingReviewItem[];
+}
+
+export interface ManualResolution {
+  findings: { id: string; action: FindingAction; note?: string }[];
+  fail_phase: boolean;
+  note?: string;
+}
+
+export interface PendingReviewRecord {
+  run_id: number;
+  review: PendingReview;
+  resolution: ManualResolution | null;
+  decided_at: string | null;
+}
+
 export type WsMessage =
   | { type: 'IssueCreated'; data: { issue: Issue } }
   | { type: 'IssueUpdated'; data: { issue: Issue } }
@@ -161,6 +202,8 @@ export type WsMessage =
   | { type: 'PipelinePhaseCompleted'; data: { run_id: number; phase_number: string; success: boolean } }
   | { type: 'PipelineReviewStarted'; data: { run_id: number; phase_number: string } }
   | { type: 'PipelineReviewCompleted'; data: { run_id: number; phase_number: string; passed: boolean; findings_count: number } }
+  | { type: 'PipelineReviewPending'; data: { run_id: number; phase_number: string; review: PendingReview } }
+  | { type: 'PipelineReviewDecided'; data: { run_id: number; phase_number: string; review_id: string; resolution: ManualResolution } }
   | { type: 'TeamCreated'; data: { run_id: number; team_id: number; strategy: ExecutionStrategy; isolation: IsolationStrategy; plan_summary: string; tasks: AgentTask[] } }
   | { type: 'WaveStarted'; data: { run_id: number; team_id: number; wave: number; task_ids: number[] } }
   | { type: 'WaveCompleted'; data: { run_id: number; team_id: number; wave: number; success_count: number; failed_count: number } }